@@ -1,20 +1,85 @@
 mod binary_finder;
+mod cancellation;
+mod concurrency;
 mod error;
+mod invocation_counter;
 pub mod logger;
+mod redaction;
 mod response;
+mod retry;
+mod schema;
+mod version;
 
+pub use cancellation::CancellationToken;
+pub use concurrency::{AgentPermit, AgentSemaphore};
 pub use error::ClaudeCodeClientError;
-pub use response::CliResponse;
+pub use invocation_counter::InvocationCounter;
+pub use response::{CliResponse, Usage};
+pub use retry::RetryPolicy;
+pub use version::{CliVersion, MINIMUM_CLI_VERSION, verify_cli_binary};
 
-use std::path::PathBuf;
-use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::io::{BufRead, Read, Write};
 use std::process::{Command, Stdio};
 
 use serde::de::DeserializeOwned;
 
+use crate::text::truncate_chars;
+use cancellation::ChildProcessWatcher;
+use schema::validate_against_schema;
+
 const TOOLS_LIST: &str = "AskUserQuestion,Bash,TaskOutput,Edit,ExitPlanMode,Glob,Grep,\
     KillShell,MCPSearch,Read,Skill,Task,TaskCreate,TaskGet,TaskList,TaskUpdate,\
     WebFetch,WebSearch,Write,LSP";
+/// 파일을 고치지 않는 단계에 허용하는 도구. Bash로도 파일을 고칠 수 있으므로 셸 도구는 뺀다.
+/// 리뷰어는 워크트리에 체크아웃된 파일을 직접 읽는다.
+const READ_ONLY_TOOLS_LIST: &str = "Glob,Grep,Read,WebFetch,WebSearch,LSP";
+
+/// CLI에 `--tools`로 허용할 도구 묶음.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ToolSet {
+    /// 파일 편집을 포함한 모든 도구.
+    #[default]
+    Full,
+    /// 파일 편집(`Edit`, `Write`), 셸(`Bash`)과 하위 에이전트 도구를 뺀 읽기 전용 도구.
+    ReadOnly,
+}
+
+impl ToolSet {
+    pub fn tools_argument(self) -> &'static str {
+        match self {
+            ToolSet::Full => TOOLS_LIST,
+            ToolSet::ReadOnly => READ_ONLY_TOOLS_LIST,
+        }
+    }
+}
+
+/// 스트리밍 중 화면에 보여줄 에이전트 메시지의 양.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// 어시스턴트의 텍스트만 보여주고 도구 호출과 도구 결과는 숨긴다.
+    Quiet,
+    /// 어시스턴트의 텍스트와 도구 호출, 도구 결과를 줄여서 보여준다.
+    #[default]
+    Normal,
+    /// 어시스턴트의 생각(thinking) 블록까지 보여주고, 긴 메시지도 줄이지 않는다.
+    Verbose,
+}
+
+impl std::str::FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "quiet" => Ok(Verbosity::Quiet),
+            "normal" => Ok(Verbosity::Normal),
+            "verbose" => Ok(Verbosity::Verbose),
+            _ => Err(format!("unknown verbosity: {}", value)),
+        }
+    }
+}
+
+pub const DEFAULT_MODEL: &str = "claude-opus-4-6";
 
 struct TempFileGuard(Option<PathBuf>);
 
@@ -42,10 +107,12 @@ pub struct ClaudeCodeRequest {
 struct ParsedOutput<T> {
     result: T,
     session_id: String,
+    usage: Option<Usage>,
 }
 
 fn parse_cli_output<T: DeserializeOwned>(
     stdout: &[u8],
+    output_schema: Option<&serde_json::Value>,
 ) -> Result<ParsedOutput<T>, ClaudeCodeClientError> {
     // CLI 출력에서 메시지 배열을 추출한다. 표준 출력 형식은 JSON 배열이지만,
     // 단일 객체가 올 수도 있으므로 둘 다 처리한다.
@@ -63,26 +130,103 @@ fn parse_cli_output<T: DeserializeOwned>(
         .find(|msg| msg.get("type").and_then(|v| v.as_str()) == Some("result"))
         .ok_or(ClaudeCodeClientError::NoResultMessage)?;
 
-    let response: CliResponse = serde_json::from_value(result_value)?;
+    let mut response: CliResponse = serde_json::from_value(result_value)?;
+    let usage = response.usage();
     if response.is_error {
         return Err(ClaudeCodeClientError::CliReturnedError {
             message: response.result.unwrap_or_default(),
         });
     }
 
-    let output_value = match response.structured_output {
-        Some(value) => value,
-        None => return Err(ClaudeCodeClientError::MissingStructuredOutput),
-    };
-
-    let result: T = serde_json::from_value(output_value)?;
+    let result: T = deserialize_response_output(&mut response, output_schema)?;
 
     Ok(ParsedOutput {
         result,
         session_id: response.session_id,
+        usage,
     })
 }
 
+/// `structured_output`을 `T`로 바꾼다. 스키마가 주어지면 먼저 검사하여, 모양이 어긋난 출력은
+/// serde 오류 대신 어긋난 필드 경로를 담은 오류로 보고한다.
+fn deserialize_structured_output<T: DeserializeOwned>(
+    output_value: serde_json::Value,
+    output_schema: Option<&serde_json::Value>,
+) -> Result<T, ClaudeCodeClientError> {
+    if let Some(schema) = output_schema
+        && let Err(violation) = validate_against_schema(&output_value, schema)
+    {
+        return Err(ClaudeCodeClientError::SchemaMismatch {
+            path: violation.path,
+            message: violation.message,
+        });
+    }
+    Ok(serde_json::from_value(output_value)?)
+}
+
+/// 응답의 `structured_output`을 `T`로 바꾼다. 모델이 스키마를 지키지 않고 JSON을 `result`
+/// 텍스트로 내는 경우가 있어, `structured_output`이 비어 있으면 텍스트를 대신 읽어 본다.
+/// 텍스트가 그 자체로 JSON 객체이고 스키마와 `T`에 모두 맞을 때만 쓰며, 그렇지 않으면
+/// 텍스트 속 JSON이 우연히 섞인 것일 수 있으므로 `MissingStructuredOutput`을 반환한다.
+fn deserialize_response_output<T: DeserializeOwned>(
+    response: &mut CliResponse,
+    output_schema: Option<&serde_json::Value>,
+) -> Result<T, ClaudeCodeClientError> {
+    if let Some(output_value) = response.structured_output.take() {
+        return deserialize_structured_output(output_value, output_schema);
+    }
+    let recovered = response
+        .result
+        .as_deref()
+        .and_then(json_object_in_result_text)
+        .and_then(|value| deserialize_structured_output(value, output_schema).ok());
+    match recovered {
+        Some(result) => {
+            crate::cli_log!("[구조화 출력 누락] result 텍스트의 JSON을 대신 사용합니다.");
+            Ok(result)
+        }
+        None => Err(ClaudeCodeClientError::MissingStructuredOutput),
+    }
+}
+
+/// `result` 텍스트 전체가 JSON 객체이면 그 값을 반환한다. 모델이 흔히 붙이는 코드 펜스
+/// (```` ```json ````)는 걷어 내지만, 설명 문장 사이에 섞인 JSON은 찾지 않는다.
+fn json_object_in_result_text(text: &str) -> Option<serde_json::Value> {
+    let trimmed = text.trim();
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|body| body.strip_suffix("```"))
+        .unwrap_or(trimmed);
+    serde_json::from_str::<serde_json::Value>(unfenced.trim())
+        .ok()
+        .filter(serde_json::Value::is_object)
+}
+
+/// 쿼리별 디버그 로그(`bear-<세션 ID>.log`)를 쓸 위치.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DebugLogLocation {
+    /// 저널 디렉토리를 아직 모를 때 쓰는 시스템 임시 디렉토리.
+    #[default]
+    TempDir,
+    /// 지정한 디렉토리. 없으면 만든다.
+    Directory(PathBuf),
+    /// 디버그 로그를 쓰지 않는다.
+    Disabled,
+}
+
+impl DebugLogLocation {
+    /// 이 위치에서 해당 CLI 세션의 디버그 로그 파일 경로. 디버그 로그가 꺼져 있으면 None이다.
+    pub fn log_path(&self, session_id: &str) -> Option<PathBuf> {
+        let file_name = format!("bear-{}.log", session_id);
+        match self {
+            DebugLogLocation::TempDir => Some(std::env::temp_dir().join(file_name)),
+            DebugLogLocation::Directory(dir) => Some(dir.join(file_name)),
+            DebugLogLocation::Disabled => None,
+        }
+    }
+}
+
 pub struct ClaudeCodeClient {
     binary_path: PathBuf,
     api_key: String,
@@ -90,6 +234,36 @@ pub struct ClaudeCodeClient {
     working_directory: PathBuf,
     system_prompt: Option<String>,
     pending_system_prompt: Option<String>,
+    cancellation_token: Option<CancellationToken>,
+    retry_policy: RetryPolicy,
+    /// 첫 쿼리가 재시도되는 동안 같은 `--session-id`를 쓰기 위해 보관하는 세션 ID.
+    pending_session_id: Option<String>,
+    accumulated_usage: Option<Usage>,
+    /// `--model`로 전달하는 현재 모델. 대체 모델로 바뀌면 `set_model`로 다시 정할 때까지
+    /// 이후 쿼리에도 계속 사용한다.
+    model: String,
+    /// 현재 모델을 쓸 수 없을 때 쿼리마다 순서대로 바꿔 시도할 대체 모델들.
+    model_fallbacks: Vec<String>,
+    debug_log_location: DebugLogLocation,
+    /// 역직렬화 전에 `structured_output`을 요청한 스키마로 검사할지 여부.
+    validate_output_schema: bool,
+    /// `--tools`로 허용할 도구. `set_tool_set`으로 다시 정할 때까지 이후 쿼리에 계속 사용한다.
+    tool_set: ToolSet,
+    /// 지정하면 `ANTHROPIC_BASE_URL`로 CLI에 넘긴다.
+    base_url: Option<String>,
+    /// 지정하면 `HTTPS_PROXY`로 CLI에 넘긴다.
+    https_proxy: Option<String>,
+    /// 작업 디렉토리 밖에서 에이전트가 읽을 수 있도록 `--add-dir`로 넘기는 디렉토리들.
+    additional_directories: Vec<PathBuf>,
+    /// `with_session_id`로 기존 세션에 다시 연결한 뒤 아직 첫 쿼리를 마치지 않았는지 여부.
+    /// 이 동안 세션을 찾을 수 없으면 새 세션으로 다시 시작한다.
+    reattached_session: bool,
+    /// 스트리밍 메시지를 얼마나 자세히 보여줄지.
+    verbosity: Verbosity,
+    /// 지정하면 CLI 프로세스를 띄우기 전에 자리를 차지하고, 프로세스가 끝나면 돌려준다.
+    agent_semaphore: Option<AgentSemaphore>,
+    /// 지정하면 CLI 프로세스를 띄울 때마다 하나씩 센다.
+    invocation_counter: Option<InvocationCounter>,
 }
 
 impl ClaudeCodeClient {
@@ -97,10 +271,22 @@ impl ClaudeCodeClient {
         self.session_id.as_deref()
     }
 
+    /// 기존 Claude 세션에 다시 연결한다. 첫 쿼리부터 `--resume`으로 그 세션을 이어 가며,
+    /// CLI가 세션을 찾지 못하면(다른 작업 디렉토리에서 만든 세션 등) 새 세션으로 시작한다.
+    pub fn with_session_id(mut self, session_id: String) -> Self {
+        self.session_id = Some(session_id);
+        self.reattached_session = true;
+        self
+    }
+
     pub fn reset_session(&mut self) {
         self.session_id = None;
     }
 
+    pub fn working_directory(&self) -> &Path {
+        &self.working_directory
+    }
+
     pub fn set_working_directory(&mut self, path: PathBuf) {
         self.working_directory = path;
     }
@@ -113,21 +299,187 @@ impl ClaudeCodeClient {
         self.pending_system_prompt = Some(prompt);
     }
 
+    /// 이후 쿼리에서 취소 플래그를 감시하도록 설정한다. 취소되면 실행 중인
+    /// CLI 프로세스를 종료하고 `ClaudeCodeClientError::Cancelled`를 반환한다.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
+
+    /// 마지막으로 호출한 이후 성공한 쿼리들의 사용량 합계를 가져오고 초기화한다.
+    pub fn take_usage(&mut self) -> Option<Usage> {
+        self.accumulated_usage.take()
+    }
+
+    fn record_usage(&mut self, usage: Option<Usage>) {
+        if let Some(usage) = usage {
+            self.accumulated_usage.get_or_insert_with(Usage::default).add(&usage);
+        }
+    }
+
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    pub fn set_model_fallbacks(&mut self, model_fallbacks: Vec<String>) {
+        self.model_fallbacks = model_fallbacks;
+    }
+
+    /// 이후 쿼리에 `--model`로 전달할 모델을 바꾼다.
+    pub fn set_model(&mut self, model: String) {
+        self.model = model;
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub fn set_debug_log_location(&mut self, debug_log_location: DebugLogLocation) {
+        self.debug_log_location = debug_log_location;
+    }
+
+    pub fn set_tool_set(&mut self, tool_set: ToolSet) {
+        self.tool_set = tool_set;
+    }
+
+    pub fn tool_set(&self) -> ToolSet {
+        self.tool_set
+    }
+
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.verbosity = verbosity;
+    }
+
+    pub fn set_agent_semaphore(&mut self, agent_semaphore: Option<AgentSemaphore>) {
+        self.agent_semaphore = agent_semaphore;
+    }
+
+    pub fn set_invocation_counter(&mut self, invocation_counter: Option<InvocationCounter>) {
+        self.invocation_counter = invocation_counter;
+    }
+
+    pub fn set_validate_output_schema(&mut self, validate_output_schema: bool) {
+        self.validate_output_schema = validate_output_schema;
+    }
+
+    pub fn set_base_url(&mut self, base_url: Option<String>) {
+        self.base_url = base_url;
+    }
+
+    /// 프록시 주소에 인증 정보가 들어 있을 수 있으므로 로그에서 가리도록 등록한다.
+    pub fn set_https_proxy(&mut self, https_proxy: Option<String>) {
+        if let Some(proxy) = &https_proxy {
+            redaction::register_known_secret(proxy);
+        }
+        self.https_proxy = https_proxy;
+    }
+
+    /// 이후 쿼리에서 `--add-dir`로 넘길 디렉토리를 정한다. 작업 디렉토리는 바꾸지 않는다.
+    pub fn set_additional_directories(&mut self, directories: Vec<PathBuf>) {
+        self.additional_directories = directories;
+    }
+
+    fn output_schema_to_validate<'a>(
+        &self,
+        request: &'a ClaudeCodeRequest,
+    ) -> Option<&'a serde_json::Value> {
+        self.validate_output_schema.then_some(&request.output_schema)
+    }
+
+    /// 해당 CLI 세션의 디버그 로그 파일 경로. 디버그 로그가 꺼져 있으면 None이다.
+    pub fn debug_log_path(&self, session_id: &str) -> Option<PathBuf> {
+        self.debug_log_location.log_path(session_id)
+    }
+
+    /// 시스템 프롬프트, 사용자 프롬프트, CLI 출력을 디버그 로그에 쓴다.
+    /// 도구 결과에 섞인 비밀 값이 남지 않도록 쓰기 전에 가리고, 다른 사용자가 읽을 수 없게
+    /// 소유자 전용 권한으로 만든다. 기록 실패는 무시한다.
+    fn write_debug_log(
+        &self,
+        system_prompt: &Option<String>,
+        user_prompt: &str,
+        session_id: &str,
+        cli_stdout: &[u8],
+    ) {
+        let Some(path) = self.debug_log_path(session_id) else {
+            return;
+        };
+        let system_prompt_text = system_prompt.as_deref().unwrap_or("");
+        let cli_output = String::from_utf8_lossy(cli_stdout);
+
+        let content = format!(
+            "<SYSTEM_PROMPT>\n{}\n</SYSTEM_PROMPT>\n\n<USER_PROMPT>\n{}\n</USER_PROMPT>\n\n<CLAUDE_CODE_CLI_OUTPUT>\n{}\n</CLAUDE_CODE_CLI_OUTPUT>\n",
+            system_prompt_text,
+            user_prompt,
+            cli_output,
+        );
+
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        let _ = write_owner_only_file(&path, redaction::redact(&content).as_bytes());
+    }
+
+    /// `fallback_index`번째 대체 모델로 바꾸고 인덱스를 늘린다.
+    /// 남은 대체 모델이 없으면 false를 반환한다.
+    fn switch_to_fallback_model(&mut self, fallback_index: &mut usize) -> bool {
+        let Some(next_model) = self.model_fallbacks.get(*fallback_index).cloned() else {
+            return false;
+        };
+        *fallback_index += 1;
+        crate::cli_log!("[모델 전환] {} -> {}", self.model, next_model);
+        self.model = next_model;
+        true
+    }
+
+    fn ensure_not_cancelled(&self) -> Result<(), ClaudeCodeClientError> {
+        match &self.cancellation_token {
+            Some(token) if token.is_cancelled() => Err(ClaudeCodeClientError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
     pub fn new(
         api_key: String,
         working_directory: PathBuf,
         system_prompt: Option<String>,
     ) -> Result<Self, ClaudeCodeClientError> {
-        let binary_path = binary_finder::find_claude_binary()?;
+        let binary_path = binary_finder::cached_claude_binary()?;
 
-        Ok(Self {
+        Ok(Self::with_binary_path(api_key, working_directory, system_prompt, binary_path))
+    }
+
+    /// 바이너리를 탐색하지 않고 지정한 경로의 CLI 바이너리를 사용하는 클라이언트를 만든다.
+    pub fn with_binary_path(
+        api_key: String,
+        working_directory: PathBuf,
+        system_prompt: Option<String>,
+        binary_path: PathBuf,
+    ) -> Self {
+        redaction::register_known_secret(&api_key);
+        Self {
             binary_path,
             api_key,
             session_id: None,
             working_directory,
             system_prompt,
             pending_system_prompt: None,
-        })
+            cancellation_token: None,
+            retry_policy: RetryPolicy::default(),
+            pending_session_id: None,
+            accumulated_usage: None,
+            model: DEFAULT_MODEL.to_string(),
+            model_fallbacks: Vec::new(),
+            debug_log_location: DebugLogLocation::default(),
+            validate_output_schema: true,
+            tool_set: ToolSet::default(),
+            base_url: None,
+            https_proxy: None,
+            additional_directories: Vec::new(),
+            reattached_session: false,
+            verbosity: Verbosity::default(),
+            agent_semaphore: None,
+            invocation_counter: None,
+        }
     }
 
     fn build_base_command(&mut self, request: &ClaudeCodeRequest) -> Result<BaseCommandOutput, ClaudeCodeClientError> {
@@ -146,7 +498,17 @@ impl ClaudeCodeClient {
             .arg("-p")
             .arg("--allow-dangerously-skip-permissions")
             .arg("--permission-mode").arg("bypassPermissions")
-            .arg("--tools").arg(TOOLS_LIST);
+            .arg("--tools").arg(self.tool_set.tools_argument());
+        for directory in &self.additional_directories {
+            command.arg("--add-dir").arg(directory);
+        }
+        // 설정하지 않았으면 bear 프로세스의 환경 변수를 그대로 물려받는다.
+        if let Some(base_url) = &self.base_url {
+            command.env("ANTHROPIC_BASE_URL", base_url);
+        }
+        if let Some(https_proxy) = &self.https_proxy {
+            command.env("HTTPS_PROXY", https_proxy);
+        }
 
         // 최초 실행이면 새 세션 ID를 생성하고, 후속 실행이면 기존 세션을 재개한다.
         let new_session_id = match &self.session_id {
@@ -155,13 +517,16 @@ impl ClaudeCodeClient {
                 None
             }
             None => {
-                let id = uuid::Uuid::new_v4().to_string();
+                let id = self
+                    .pending_session_id
+                    .get_or_insert_with(|| uuid::Uuid::new_v4().to_string())
+                    .clone();
                 command.arg("--session-id").arg(&id);
                 Some(id)
             }
         };
 
-        command.arg("--model").arg("claude-opus-4-6");
+        command.arg("--model").arg(&self.model);
 
         // 커스텀 시스템 프롬프트는 기존 세션 컨텍스트에 저장되지 않기 때문에 과거 세션을 불러와서
         // 재사용하는 경우에는 기존에 입력했던 커스텀 시스템 프롬프트를 다시 입력해주어야 한다.
@@ -220,11 +585,17 @@ impl ClaudeCodeClient {
              CLAUDE_CODE_DISABLE_FEEDBACK_SURVEY=1",
             mode,
         ));
+        if let Some(base_url) = &self.base_url {
+            log(format!("[{}] 환경 변수: ANTHROPIC_BASE_URL={}", mode, base_url));
+        }
+        if self.https_proxy.is_some() {
+            log(format!("[{}] 환경 변수: HTTPS_PROXY=***", mode));
+        }
 
         log(format!(
             "[{}] CLI 기본 인수: -p --allow-dangerously-skip-permissions \
              --permission-mode bypassPermissions --tools {}",
-            mode, TOOLS_LIST,
+            mode, self.tool_set.tools_argument(),
         ));
 
         let session_info = match new_session_id {
@@ -236,7 +607,7 @@ impl ClaudeCodeClient {
         };
         log(format!("[{}] 세션: {}", mode, session_info));
 
-        log(format!("[{}] 모델 (--model): claude-opus-4-6", mode));
+        log(format!("[{}] 모델 (--model): {}", mode, self.model));
 
         if !extra_args.is_empty() {
             log(format!(
@@ -273,9 +644,96 @@ impl ClaudeCodeClient {
         ));
     }
 
+    /// 일시적인 CLI 실패는 재시도 정책에 따라 지수 백오프로 재시도한다.
+    /// 현재 모델이 과부하 상태이면 대체 모델이 남아 있는 동안 기다리지 않고 다음 모델로 바꿔
+    /// 다시 시도하며, 이 전환은 재시도 횟수에 포함하지 않는다.
+    /// 첫 쿼리가 재시도되더라도 세션이 둘로 갈라지지 않도록 같은 세션 ID를 다시 사용하고,
+    /// 한 번만 전달되는 추가 시스템 프롬프트도 매 시도마다 다시 전달한다.
+    /// 다시 연결한 세션을 CLI가 찾지 못하면 새 세션으로 한 번 더 시도한다.
+    fn with_retry<T>(
+        &mut self,
+        mode: &str,
+        mut attempt: impl FnMut(&mut Self) -> Result<T, ClaudeCodeClientError>,
+    ) -> Result<T, ClaudeCodeClientError> {
+        let pending_system_prompt = self.pending_system_prompt.clone();
+        let mut retry_index = 0;
+        let mut fallback_index = 0;
+
+        let result = loop {
+            self.pending_system_prompt = pending_system_prompt.clone();
+            match attempt(self) {
+                Err(err)
+                    if err.is_model_unavailable()
+                        && self.switch_to_fallback_model(&mut fallback_index) =>
+                {
+                    crate::cli_log!("[{} 대체 모델로 재시도] {}: {}", mode, self.model, err);
+                }
+                Err(err) if self.reattached_session && err.is_missing_session() => {
+                    crate::cli_log!("[{} 새 세션으로 재시도] 이전 세션을 찾을 수 없음: {}", mode, err);
+                    self.session_id = None;
+                    self.reattached_session = false;
+                }
+                Err(err) if err.is_retryable() && retry_index < self.retry_policy.max_retries => {
+                    let delay = self.retry_policy.backoff_delay(retry_index);
+                    retry_index += 1;
+                    crate::cli_log!(
+                        "[{} 재시도] {}/{} ({}ms 후): {}",
+                        mode,
+                        retry_index,
+                        self.retry_policy.max_retries,
+                        delay.as_millis(),
+                        err,
+                    );
+                    if !retry::sleep_unless_cancelled(delay, self.cancellation_token.as_ref()) {
+                        break Err(ClaudeCodeClientError::Cancelled);
+                    }
+                }
+                other => break other,
+            }
+        };
+
+        self.pending_session_id = None;
+        self.reattached_session = false;
+        result
+    }
+
+    /// 동시 실행 제한이 있으면 CLI 프로세스를 띄울 자리가 날 때까지 기다린다. 기다리는 동안
+    /// 취소되면 `Cancelled`를 반환한다. 제한이 없으면 None이다.
+    fn acquire_agent_permit(&self) -> Result<Option<AgentPermit>, ClaudeCodeClientError> {
+        let Some(semaphore) = &self.agent_semaphore else {
+            return Ok(None);
+        };
+        if let Some(permit) = semaphore.try_acquire() {
+            return Ok(Some(permit));
+        }
+        crate::cli_log!(
+            "[동시 실행 제한] CLI 프로세스 {}개가 실행 중이라 자리가 날 때까지 기다립니다.",
+            semaphore.limit(),
+        );
+        semaphore
+            .acquire(self.cancellation_token.as_ref())
+            .map(Some)
+            .ok_or(ClaudeCodeClientError::Cancelled)
+    }
+
+    /// 호출 수를 세고 있으면 CLI 프로세스 하나를 더한다. 프로세스를 띄우기 직전에 부른다.
+    fn count_invocation(&self) {
+        if let Some(counter) = &self.invocation_counter {
+            let count = counter.increment();
+            crate::cli_log!("[CLI 호출] 이 세션의 {}번째 호출", count);
+        }
+    }
+
     pub fn query<T: DeserializeOwned>(
         &mut self,
         request: &ClaudeCodeRequest,
+    ) -> Result<T, ClaudeCodeClientError> {
+        self.with_retry("비스트리밍 쿼리", |client| client.query_once(request))
+    }
+
+    fn query_once<T: DeserializeOwned>(
+        &mut self,
+        request: &ClaudeCodeRequest,
     ) -> Result<T, ClaudeCodeClientError> {
         let BaseCommandOutput {
             mut command,
@@ -296,6 +754,9 @@ impl ClaudeCodeClient {
         );
         let _temp_file_guard = TempFileGuard(system_prompt_file);
 
+        let _agent_permit = self.acquire_agent_permit()?;
+        self.ensure_not_cancelled()?;
+        self.count_invocation();
         command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
         let mut child = command.spawn().map_err(|err| {
             crate::cli_log!("[비스트리밍 쿼리 실패] 프로세스 생성 오류: {}", err);
@@ -303,6 +764,7 @@ impl ClaudeCodeClient {
                 message: err.to_string(),
             }
         })?;
+        let watcher = ChildProcessWatcher::spawn(self.cancellation_token.clone(), child.id());
 
         if let Some(mut stdin) = child.stdin.take() {
             stdin.write_all(request.user_prompt.as_bytes()).map_err(|err| {
@@ -313,12 +775,34 @@ impl ClaudeCodeClient {
             })?;
         }
 
-        let output = child.wait_with_output().map_err(|err| {
-            crate::cli_log!("[비스트리밍 쿼리 실패] 명령 실행 오류: {}", err);
-            ClaudeCodeClientError::CommandExecutionFailed {
-                message: err.to_string(),
-            }
-        })?;
+        // 취소 감시를 끝낸 뒤에 프로세스를 회수하기 위해 출력을 직접 읽는다.
+        let mut stderr = child.stderr.take().expect("stderr must be piped");
+        let stderr_thread = std::thread::spawn(move || {
+            let mut buffer = Vec::new();
+            let _ = stderr.read_to_end(&mut buffer);
+            buffer
+        });
+        let mut stdout = Vec::new();
+        let read_result = child
+            .stdout
+            .take()
+            .expect("stdout must be piped")
+            .read_to_end(&mut stdout);
+
+        watcher.finish();
+        let status = child.wait();
+        let stderr = stderr_thread.join().unwrap_or_default();
+
+        let output = read_result
+            .and(status)
+            .map(|status| std::process::Output { status, stdout, stderr })
+            .map_err(|err| {
+                crate::cli_log!("[비스트리밍 쿼리 실패] 명령 실행 오류: {}", err);
+                ClaudeCodeClientError::CommandExecutionFailed {
+                    message: err.to_string(),
+                }
+            })?;
+        self.ensure_not_cancelled()?;
 
         crate::cli_log!("[비스트리밍 쿼리 완료] 종료 코드: {}", output.status);
 
@@ -341,9 +825,16 @@ impl ClaudeCodeClient {
             .as_deref()
             .or(self.session_id.as_deref())
             .unwrap_or("unknown");
-        write_debug_log(&sent_system_prompt, &request.user_prompt, command_session_id, &output.stdout);
+        self.write_debug_log(
+            &sent_system_prompt,
+            &request.user_prompt,
+            command_session_id,
+            &output.stdout,
+        );
 
-        let parsed: ParsedOutput<T> = parse_cli_output(&output.stdout)?;
+        let parsed: ParsedOutput<T> =
+            parse_cli_output(&output.stdout, self.output_schema_to_validate(request))?;
+        self.record_usage(parsed.usage);
 
         if new_session_id.is_some() {
             self.session_id = Some(parsed.session_id);
@@ -359,7 +850,21 @@ impl ClaudeCodeClient {
     ) -> Result<T, ClaudeCodeClientError>
     where
         T: DeserializeOwned,
-        F: Fn(String),
+        F: Fn(StreamMessage),
+    {
+        self.with_retry("스트리밍 쿼리", |client| {
+            client.query_streaming_once(request, &on_stream_message)
+        })
+    }
+
+    fn query_streaming_once<T, F>(
+        &mut self,
+        request: &ClaudeCodeRequest,
+        on_stream_message: &F,
+    ) -> Result<T, ClaudeCodeClientError>
+    where
+        T: DeserializeOwned,
+        F: Fn(StreamMessage),
     {
         let BaseCommandOutput {
             mut command,
@@ -387,6 +892,9 @@ impl ClaudeCodeClient {
         );
         let _temp_file_guard = TempFileGuard(system_prompt_file);
 
+        let _agent_permit = self.acquire_agent_permit()?;
+        self.ensure_not_cancelled()?;
+        self.count_invocation();
         let mut child = command
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -403,6 +911,7 @@ impl ClaudeCodeClient {
             "[스트리밍 쿼리] 프로세스 생성 완료 (pid: {})",
             child.id(),
         );
+        let watcher = ChildProcessWatcher::spawn(self.cancellation_token.clone(), child.id());
 
         // 사용자 프롬프트를 stdin으로 전달한 후 파이프를 닫는다.
         if let Some(mut stdin) = child.stdin.take() {
@@ -432,7 +941,7 @@ impl ClaudeCodeClient {
         let mut result_value: Option<serde_json::Value> = None;
         // result 직전의 assistant+user 메시지 쌍은 최종 결과와 중복되므로 버퍼링 후 스킵한다.
         // 새 assistant 메시지가 도착할 때만 이전 버퍼를 플러시한다.
-        let mut pending_messages: Vec<String> = Vec::new();
+        let mut pending_messages: Vec<StreamMessage> = Vec::new();
 
         for line_result in reader.lines() {
             let line = line_result.map_err(|err| {
@@ -457,12 +966,12 @@ impl ClaudeCodeClient {
                     for msg in pending_messages.drain(..) {
                         on_stream_message(msg);
                     }
-                    if let Some(formatted) = format_stream_message(&json) {
+                    if let Some(formatted) = format_stream_message(&json, self.verbosity) {
                         pending_messages.push(formatted);
                     }
                 }
                 "user" => {
-                    if let Some(formatted) = format_stream_message(&json) {
+                    if let Some(formatted) = format_stream_message(&json, self.verbosity) {
                         pending_messages.push(formatted);
                     }
                 }
@@ -474,6 +983,7 @@ impl ClaudeCodeClient {
             }
         }
 
+        watcher.finish();
         let status = child.wait().map_err(|err| {
             crate::cli_log!("[스트리밍 쿼리 실패] 프로세스 대기 오류: {}", err);
             ClaudeCodeClientError::CommandExecutionFailed {
@@ -484,6 +994,10 @@ impl ClaudeCodeClient {
         let stderr_content = stderr_thread.join().unwrap_or_default();
 
         crate::cli_log!("[스트리밍 쿼리 완료] 종료 코드: {}", status);
+        if self.ensure_not_cancelled().is_err() {
+            crate::cli_log!("[스트리밍 쿼리 취소] 사용자 요청으로 중단됨");
+            return Err(ClaudeCodeClientError::Cancelled);
+        }
         if !stderr_content.is_empty() {
             crate::cli_log!("[스트리밍 쿼리] CLI stderr:\n{}", &stderr_content);
         }
@@ -505,10 +1019,16 @@ impl ClaudeCodeClient {
             .or(self.session_id.as_deref())
             .unwrap_or("unknown");
         let raw_output = raw_lines.join("\n");
-        write_debug_log(&sent_system_prompt, &request.user_prompt, command_session_id, raw_output.as_bytes());
+        self.write_debug_log(
+            &sent_system_prompt,
+            &request.user_prompt,
+            command_session_id,
+            raw_output.as_bytes(),
+        );
 
         let result_json = result_value.ok_or(ClaudeCodeClientError::NoResultMessage)?;
-        let response: CliResponse = serde_json::from_value(result_json)?;
+        let mut response: CliResponse = serde_json::from_value(result_json)?;
+        let usage = response.usage();
 
         if response.is_error {
             let error_message = response.result.unwrap_or_default();
@@ -521,10 +1041,9 @@ impl ClaudeCodeClient {
             });
         }
 
-        let output_value = response
-            .structured_output
-            .ok_or(ClaudeCodeClientError::MissingStructuredOutput)?;
-        let result: T = serde_json::from_value(output_value)?;
+        let result: T =
+            deserialize_response_output(&mut response, self.output_schema_to_validate(request))?;
+        self.record_usage(usage);
 
         if new_session_id.is_some() {
             self.session_id = Some(response.session_id);
@@ -534,50 +1053,78 @@ impl ClaudeCodeClient {
     }
 }
 
-fn write_debug_log(
-    system_prompt: &Option<String>,
-    user_prompt: &str,
-    session_id: &str,
-    cli_stdout: &[u8],
-) {
-    let path = format!("/tmp/bear-{}.log", session_id);
-    let system_prompt_text = system_prompt.as_deref().unwrap_or("");
-    let cli_output = String::from_utf8_lossy(cli_stdout);
-
-    let content = format!(
-        "<SYSTEM_PROMPT>\n{}\n</SYSTEM_PROMPT>\n\n<USER_PROMPT>\n{}\n</USER_PROMPT>\n\n<CLAUDE_CODE_CLI_OUTPUT>\n{}\n</CLAUDE_CODE_CLI_OUTPUT>\n",
-        system_prompt_text,
-        user_prompt,
-        cli_output,
-    );
-
-    // 디버그 로그 기록 실패는 무시한다.
-    let _ = std::fs::write(&path, content);
+fn write_owner_only_file(path: &std::path::Path, content: &[u8]) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(content)
 }
 
-const MAX_STREAM_DISPLAY_LINES: usize = 3;
+/// 스트리밍 중 화면에 보여줄 메시지. 표시용 `content`는 줄 수와 줄 길이가 제한되며,
+/// 그 과정에서 잘린 내용이 있으면 원문을 `full_content`에 보관한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamMessage {
+    pub content: String,
+    pub full_content: Option<String>,
+}
+
+impl StreamMessage {
+    pub fn from_full_text(full_text: String) -> Self {
+        let content = truncate_to_max_lines(&full_text);
+        let full_content = (content != full_text).then_some(full_text);
+        Self {
+            content,
+            full_content,
+        }
+    }
+}
 
-fn format_stream_message(json: &serde_json::Value) -> Option<String> {
+const MAX_STREAM_DISPLAY_LINES: usize = 3;
+/// 스트리밍 메시지의 한 줄에 표시할 최대 문자 수. 도구 입력 JSON처럼 긴 한 줄이
+/// 화면을 가득 채우지 않도록 자른다.
+const MAX_STREAM_LINE_CHARS: usize = 300;
+
+fn format_stream_message(
+    json: &serde_json::Value,
+    verbosity: Verbosity,
+) -> Option<StreamMessage> {
     let msg_type = json.get("type")?.as_str()?;
     let formatted = match msg_type {
-        "assistant" => format_assistant_message(json),
-        "user" => format_user_message(json),
+        "assistant" => format_assistant_message(json, verbosity),
+        // user 메시지에는 도구 결과와 하위 에이전트에 보낸 프롬프트만 들어 있다.
+        "user" if verbosity != Verbosity::Quiet => format_user_message(json),
         _ => None,
-    };
-    formatted.map(|text| truncate_to_max_lines(&text))
+    }?;
+    Some(match verbosity {
+        Verbosity::Verbose => StreamMessage {
+            content: formatted,
+            full_content: None,
+        },
+        Verbosity::Quiet | Verbosity::Normal => StreamMessage::from_full_text(formatted),
+    })
 }
 
 fn truncate_to_max_lines(text: &str) -> String {
-    let lines: Vec<&str> = text.lines().collect();
+    let lines: Vec<String> = text.lines().map(truncate_long_line).collect();
     if lines.len() <= MAX_STREAM_DISPLAY_LINES {
-        return text.to_string();
+        return lines.join("\n");
     }
     let visible: String = lines[..MAX_STREAM_DISPLAY_LINES].join("\n");
     let omitted = lines.len() - MAX_STREAM_DISPLAY_LINES;
     format!("{}\n... (+{} lines)", visible, omitted)
 }
 
-fn format_assistant_message(json: &serde_json::Value) -> Option<String> {
+fn truncate_long_line(line: &str) -> String {
+    let truncated = truncate_chars(line, MAX_STREAM_LINE_CHARS);
+    if truncated.len() == line.len() {
+        line.to_string()
+    } else {
+        format!("{}...", truncated)
+    }
+}
+
+fn format_assistant_message(json: &serde_json::Value, verbosity: Verbosity) -> Option<String> {
     let content = json.get("message")?.get("content")?.as_array()?;
     let mut parts: Vec<String> = Vec::new();
 
@@ -592,7 +1139,15 @@ fn format_assistant_message(json: &serde_json::Value) -> Option<String> {
                     }
                 }
             }
-            "tool_use" => {
+            "thinking" if verbosity == Verbosity::Verbose => {
+                if let Some(thinking) = block.get("thinking").and_then(|v| v.as_str()) {
+                    let trimmed = thinking.trim();
+                    if !trimmed.is_empty() {
+                        parts.push(format!("[Thinking]\n{}", trimmed));
+                    }
+                }
+            }
+            "tool_use" if verbosity != Verbosity::Quiet => {
                 let name = block.get("name").and_then(|v| v.as_str()).unwrap_or("unknown");
                 let input = block.get("input").cloned().unwrap_or(serde_json::Value::Null);
                 parts.push(format!("[Tool Call: {}]\n{}", name, input));
@@ -689,7 +1244,7 @@ mod tests {
         ];
         let stdout = make_json_array_output(&messages);
 
-        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout).unwrap();
+        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout, None).unwrap();
 
         assert_eq!(parsed.result, TestOutput { answer: "hello".to_string() });
         assert_eq!(parsed.session_id, "sess-1");
@@ -705,7 +1260,7 @@ mod tests {
         );
         let stdout = serde_json::to_vec(&message).unwrap();
 
-        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout).unwrap();
+        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout, None).unwrap();
 
         assert_eq!(parsed.result, TestOutput { answer: "world".to_string() });
         assert_eq!(parsed.session_id, "sess-2");
@@ -719,7 +1274,7 @@ mod tests {
         ];
         let stdout = make_json_array_output(&messages);
 
-        let err = parse_cli_output::<TestOutput>(&stdout).unwrap_err();
+        let err = parse_cli_output::<TestOutput>(&stdout, None).unwrap_err();
 
         assert!(
             matches!(err, ClaudeCodeClientError::NoResultMessage),
@@ -734,7 +1289,7 @@ mod tests {
         ];
         let stdout = make_json_array_output(&messages);
 
-        let err = parse_cli_output::<TestOutput>(&stdout).unwrap_err();
+        let err = parse_cli_output::<TestOutput>(&stdout, None).unwrap_err();
 
         assert!(
             matches!(err, ClaudeCodeClientError::MissingStructuredOutput),
@@ -749,7 +1304,7 @@ mod tests {
         ];
         let stdout = make_json_array_output(&messages);
 
-        let err = parse_cli_output::<TestOutput>(&stdout).unwrap_err();
+        let err = parse_cli_output::<TestOutput>(&stdout, None).unwrap_err();
 
         assert!(
             matches!(err, ClaudeCodeClientError::MissingStructuredOutput),
@@ -757,6 +1312,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn recovers_json_object_from_result_text_when_structured_output_missing() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "answer": { "type": "string" } },
+            "required": ["answer"],
+            "additionalProperties": false
+        });
+        for text in [
+            r#"{"answer": "from text"}"#,
+            "```json\n{\"answer\": \"from text\"}\n```",
+        ] {
+            let stdout =
+                make_json_array_output(&[make_result_message("sess-6", false, Some(text), None)]);
+
+            let parsed: ParsedOutput<TestOutput> =
+                parse_cli_output(&stdout, Some(&schema)).unwrap();
+
+            assert_eq!(parsed.result, TestOutput { answer: "from text".to_string() });
+            assert_eq!(parsed.session_id, "sess-6");
+        }
+    }
+
+    #[test]
+    fn result_text_that_does_not_match_schema_is_still_missing_output() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "answer": { "type": "string" } },
+            "required": ["answer"],
+            "additionalProperties": false
+        });
+        for text in [
+            r#"{"answer": 42}"#,
+            r#"["answer"]"#,
+            r#"Here is the result: {"answer": "hi"}"#,
+        ] {
+            let stdout =
+                make_json_array_output(&[make_result_message("sess-7", false, Some(text), None)]);
+
+            let err = parse_cli_output::<TestOutput>(&stdout, Some(&schema)).unwrap_err();
+
+            assert!(
+                matches!(err, ClaudeCodeClientError::MissingStructuredOutput),
+                "expected MissingStructuredOutput for {text}, got: {err}",
+            );
+        }
+    }
+
     #[test]
     fn error_when_cli_returned_error() {
         let messages = vec![
@@ -764,7 +1367,7 @@ mod tests {
         ];
         let stdout = make_json_array_output(&messages);
 
-        let err = parse_cli_output::<TestOutput>(&stdout).unwrap_err();
+        let err = parse_cli_output::<TestOutput>(&stdout, None).unwrap_err();
 
         match err {
             ClaudeCodeClientError::CliReturnedError { message } => {
@@ -774,11 +1377,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn schema_mismatch_names_the_bad_field() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "answer": { "type": "string" } },
+            "required": ["answer"],
+            "additionalProperties": false
+        });
+        let cases = [
+            (serde_json::json!({"answer": 42}), "$.answer", "expected type \"string\", got number"),
+            (serde_json::json!({}), "$.answer", "required field is missing"),
+            (
+                serde_json::json!({"answer": "hi", "reply": "hi"}),
+                "$.reply",
+                "field is not allowed by the schema",
+            ),
+        ];
+
+        for (output, expected_path, expected_message) in cases {
+            let stdout = make_json_array_output(&[make_result_message(
+                "sess-5",
+                false,
+                None,
+                Some(output),
+            )]);
+
+            let err = parse_cli_output::<TestOutput>(&stdout, Some(&schema)).unwrap_err();
+
+            match err {
+                ClaudeCodeClientError::SchemaMismatch { path, message } => {
+                    assert_eq!(path, expected_path);
+                    assert_eq!(message, expected_message);
+                }
+                other => panic!("expected SchemaMismatch, got: {other}"),
+            }
+        }
+    }
+
     #[test]
     fn error_when_invalid_json() {
         let stdout = b"this is not json";
 
-        let err = parse_cli_output::<TestOutput>(stdout).unwrap_err();
+        let err = parse_cli_output::<TestOutput>(stdout, None).unwrap_err();
 
         assert!(
             matches!(err, ClaudeCodeClientError::JsonParsingFailed { .. }),
@@ -795,7 +1436,7 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
 
         assert_eq!(result, "프로젝트를 분석하겠습니다.");
     }
@@ -813,7 +1454,7 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
 
         let lines: Vec<&str> = result.lines().collect();
         assert_eq!(lines[0], "[Tool Call: Bash]");
@@ -834,16 +1475,81 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
 
         assert_eq!(result, "[Tool Result]\nCargo.toml\nsrc");
     }
 
+    fn assistant_text_and_tool_use() -> serde_json::Value {
+        serde_json::json!({
+            "type": "assistant",
+            "message": {
+                "content": [
+                    {"type": "thinking", "thinking": "Check the layout first."},
+                    {"type": "text", "text": "파일 목록을 확인합니다."},
+                    {"type": "tool_use", "name": "Bash", "input": {"command": "ls"}}
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn quiet_verbosity_drops_tool_messages_and_keeps_text() {
+        let tool_result = serde_json::json!({
+            "type": "user",
+            "message": {
+                "content": [{"type": "tool_result", "tool_use_id": "t1", "content": "src"}]
+            }
+        });
+        let tool_only = serde_json::json!({
+            "type": "assistant",
+            "message": {"content": [{"type": "tool_use", "name": "Read", "input": {}}]}
+        });
+
+        let message = format_stream_message(&assistant_text_and_tool_use(), Verbosity::Quiet);
+
+        assert_eq!(message.unwrap().content, "파일 목록을 확인합니다.");
+        assert!(format_stream_message(&tool_result, Verbosity::Quiet).is_none());
+        assert!(format_stream_message(&tool_only, Verbosity::Quiet).is_none());
+    }
+
+    #[test]
+    fn verbose_verbosity_adds_thinking_and_keeps_long_messages_whole() {
+        let normal = format_stream_message(&assistant_text_and_tool_use(), Verbosity::Normal);
+        assert!(!normal.unwrap().content.contains("[Thinking]"));
+
+        let verbose = format_stream_message(&assistant_text_and_tool_use(), Verbosity::Verbose)
+            .unwrap();
+        assert!(verbose.content.starts_with("[Thinking]\nCheck the layout first.\n"));
+        assert!(verbose.content.contains("[Tool Call: Bash]"));
+
+        let long_result = serde_json::json!({
+            "type": "user",
+            "message": {
+                "content": [{
+                    "type": "tool_result",
+                    "tool_use_id": "t1",
+                    "content": "a\nb\nc\nd\ne"
+                }]
+            }
+        });
+        let verbose = format_stream_message(&long_result, Verbosity::Verbose).unwrap();
+        assert_eq!(verbose.content, "[Tool Result]\na\nb\nc\nd\ne");
+        assert_eq!(verbose.full_content, None);
+    }
+
+    #[test]
+    fn parses_verbosity_names() {
+        assert_eq!("quiet".parse(), Ok(Verbosity::Quiet));
+        assert_eq!("Verbose".parse(), Ok(Verbosity::Verbose));
+        assert!("loud".parse::<Verbosity>().is_err());
+    }
+
     #[test]
     fn format_stream_ignores_system_type() {
         let json = serde_json::json!({"type": "system", "subtype": "init"});
 
-        assert!(format_stream_message(&json).is_none());
+        assert!(format_stream_message(&json, Verbosity::Normal).is_none());
     }
 
     #[test]
@@ -853,7 +1559,7 @@ mod tests {
             "message": {"content": [{"type": "text", "text": "  \n  "}]}
         });
 
-        assert!(format_stream_message(&json).is_none());
+        assert!(format_stream_message(&json, Verbosity::Normal).is_none());
     }
 
     #[test]
@@ -865,11 +1571,55 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
 
         assert_eq!(result, "Explore the project.");
     }
 
+    #[test]
+    fn truncate_long_korean_line_on_char_boundary() {
+        let line = "가".repeat(MAX_STREAM_LINE_CHARS + 10);
+
+        let result = truncate_to_max_lines(&line);
+
+        assert_eq!(result, format!("{}...", "가".repeat(MAX_STREAM_LINE_CHARS)));
+    }
+
+    #[test]
+    fn truncated_stream_message_keeps_full_text() {
+        let json = serde_json::json!({
+            "type": "user",
+            "message": {
+                "content": [{
+                    "type": "tool_result",
+                    "tool_use_id": "toolu_123",
+                    "content": "line1\nline2\nline3\nline4\nline5",
+                    "is_error": false
+                }]
+            }
+        });
+
+        let message = format_stream_message(&json, Verbosity::Normal).unwrap();
+
+        assert_eq!(message.content, "[Tool Result]\nline1\nline2\n... (+3 lines)");
+        assert_eq!(
+            message.full_content.as_deref(),
+            Some("[Tool Result]\nline1\nline2\nline3\nline4\nline5"),
+        );
+    }
+
+    #[test]
+    fn long_line_keeps_full_text_and_short_message_has_none() {
+        let long_line = "x".repeat(MAX_STREAM_LINE_CHARS + 1);
+
+        let truncated = StreamMessage::from_full_text(long_line.clone());
+        let short = StreamMessage::from_full_text("short".to_string());
+
+        assert_eq!(truncated.full_content, Some(long_line));
+        assert_eq!(short.content, "short");
+        assert_eq!(short.full_content, None);
+    }
+
     #[test]
     fn truncate_long_tool_result() {
         let json = serde_json::json!({
@@ -884,7 +1634,7 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
         let lines: Vec<&str> = result.lines().collect();
 
         assert_eq!(lines[0], "[Tool Result]");
@@ -902,7 +1652,7 @@ mod tests {
             }
         });
 
-        let result = format_stream_message(&json).unwrap();
+        let result = format_stream_message(&json, Verbosity::Normal).unwrap().content;
 
         assert_eq!(result, "line1\nline2\nline3");
     }
@@ -921,6 +1671,418 @@ mod tests {
             }
         });
 
-        assert!(format_stream_message(&json).is_none());
+        assert!(format_stream_message(&json, Verbosity::Normal).is_none());
+    }
+
+    fn client_without_binary() -> ClaudeCodeClient {
+        ClaudeCodeClient {
+            binary_path: PathBuf::from("claude"),
+            api_key: "test-key".to_string(),
+            session_id: None,
+            working_directory: std::env::temp_dir(),
+            system_prompt: None,
+            pending_system_prompt: None,
+            cancellation_token: None,
+            retry_policy: RetryPolicy {
+                max_retries: 2,
+                initial_backoff: std::time::Duration::ZERO,
+            },
+            pending_session_id: None,
+            accumulated_usage: None,
+            model: DEFAULT_MODEL.to_string(),
+            model_fallbacks: Vec::new(),
+            debug_log_location: DebugLogLocation::default(),
+            validate_output_schema: true,
+            tool_set: ToolSet::default(),
+            base_url: None,
+            https_proxy: None,
+            additional_directories: Vec::new(),
+            reattached_session: false,
+            verbosity: Verbosity::default(),
+            agent_semaphore: None,
+            invocation_counter: None,
+        }
+    }
+
+    #[test]
+    fn debug_log_path_depends_on_location() {
+        let mut client = client_without_binary();
+        assert_eq!(
+            client.debug_log_path("abc"),
+            Some(std::env::temp_dir().join("bear-abc.log"))
+        );
+
+        client.set_debug_log_location(DebugLogLocation::Directory(PathBuf::from("/journal/logs")));
+        assert_eq!(client.debug_log_path("abc"), Some(PathBuf::from("/journal/logs/bear-abc.log")));
+
+        client.set_debug_log_location(DebugLogLocation::Disabled);
+        assert_eq!(client.debug_log_path("abc"), None);
+    }
+
+    fn test_request() -> ClaudeCodeRequest {
+        ClaudeCodeRequest {
+            user_prompt: "hello".to_string(),
+            output_schema: serde_json::json!({"type": "object"}),
+        }
+    }
+
+    fn overloaded_error() -> ClaudeCodeClientError {
+        ClaudeCodeClientError::CommandExecutionFailed {
+            message: "API Error: 529 Overloaded".to_string(),
+        }
+    }
+
+    #[test]
+    fn retry_reuses_session_id_and_system_prompt_of_first_call() {
+        let mut client = client_without_binary();
+        client.append_system_prompt("extra instructions".to_string());
+        let request = test_request();
+        let mut attempts = Vec::new();
+
+        let result = client.with_retry("test", |client| {
+            let base = client.build_base_command(&request)?;
+            attempts.push((base.new_session_id, base.sent_system_prompt));
+            let _guard = TempFileGuard(base.system_prompt_file);
+            if attempts.len() < 3 {
+                Err(overloaded_error())
+            } else {
+                Ok("done")
+            }
+        });
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(attempts.len(), 3);
+        let first_session_id = attempts[0].0.clone().unwrap();
+        for (session_id, system_prompt) in &attempts {
+            assert_eq!(session_id.as_deref(), Some(first_session_id.as_str()));
+            assert_eq!(system_prompt.as_deref(), Some("extra instructions"));
+        }
+        assert!(client.pending_session_id.is_none());
+    }
+
+    #[test]
+    fn overloaded_error_retries_with_fallback_model() {
+        let mut client = client_without_binary();
+        client.retry_policy.max_retries = 0;
+        client.set_model_fallbacks(vec!["fallback-model".to_string()]);
+        let request = test_request();
+        let mut attempted_models = Vec::new();
+
+        let result = client.with_retry("test", |client| {
+            let base = client.build_base_command(&request)?;
+            let _guard = TempFileGuard(base.system_prompt_file);
+            attempted_models.push(model_argument(&base.command));
+            if attempted_models.len() == 1 {
+                Err(overloaded_error())
+            } else {
+                Ok("done")
+            }
+        });
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(attempted_models, vec![DEFAULT_MODEL, "fallback-model"]);
+        assert_eq!(client.model(), "fallback-model");
+    }
+
+    fn model_argument(command: &Command) -> String {
+        let args: Vec<_> = command.get_args().collect();
+        let model_index = args.iter().position(|arg| *arg == "--model").unwrap();
+        args[model_index + 1].to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn command_uses_default_model_unless_overridden() {
+        let mut client = client_without_binary();
+        let request = test_request();
+
+        let base = client.build_base_command(&request).unwrap();
+        assert_eq!(model_argument(&base.command), DEFAULT_MODEL);
+
+        client.set_model("claude-haiku-4-5".to_string());
+        let base = client.build_base_command(&request).unwrap();
+        assert_eq!(model_argument(&base.command), "claude-haiku-4-5");
+    }
+
+    fn env_value(command: &Command, name: &str) -> Option<String> {
+        command
+            .get_envs()
+            .find(|(key, _)| *key == name)
+            .and_then(|(_, value)| value)
+            .map(|value| value.to_string_lossy().to_string())
+    }
+
+    #[test]
+    fn command_sets_endpoint_env_only_when_configured() {
+        let mut client = client_without_binary();
+        let request = test_request();
+
+        let base = client.build_base_command(&request).unwrap();
+        assert_eq!(env_value(&base.command, "ANTHROPIC_API_KEY").as_deref(), Some("test-key"));
+        assert_eq!(env_value(&base.command, "ANTHROPIC_BASE_URL"), None);
+        assert_eq!(env_value(&base.command, "HTTPS_PROXY"), None);
+
+        client.set_base_url(Some("https://gateway.example.com".to_string()));
+        client.set_https_proxy(Some("http://proxy.example.com:3128".to_string()));
+        let base = client.build_base_command(&request).unwrap();
+        assert_eq!(
+            env_value(&base.command, "ANTHROPIC_BASE_URL").as_deref(),
+            Some("https://gateway.example.com"),
+        );
+        assert_eq!(
+            env_value(&base.command, "HTTPS_PROXY").as_deref(),
+            Some("http://proxy.example.com:3128"),
+        );
+    }
+
+    #[test]
+    fn command_adds_additional_directories_without_changing_working_directory() {
+        let mut client = client_without_binary();
+        let request = test_request();
+
+        let base = client.build_base_command(&request).unwrap();
+        assert!(!base.command.get_args().any(|arg| arg == "--add-dir"));
+
+        client.set_additional_directories(vec![
+            PathBuf::from("/repos/shared-docs"),
+            PathBuf::from("/repos/api-client"),
+        ]);
+        let base = client.build_base_command(&request).unwrap();
+        let args: Vec<_> = base.command.get_args().map(|arg| arg.to_string_lossy()).collect();
+        let add_dir_values: Vec<&str> = args
+            .iter()
+            .enumerate()
+            .filter(|(_, arg)| *arg == "--add-dir")
+            .map(|(index, _)| args[index + 1].as_ref())
+            .collect();
+        assert_eq!(add_dir_values, vec!["/repos/shared-docs", "/repos/api-client"]);
+        assert_eq!(base.command.get_current_dir(), Some(client.working_directory.as_path()));
+    }
+
+    #[test]
+    fn agent_semaphore_gates_process_spawns() {
+        let mut client = client_without_binary();
+        assert!(client.acquire_agent_permit().unwrap().is_none());
+
+        let semaphore = AgentSemaphore::new(1);
+        client.set_agent_semaphore(Some(semaphore.clone()));
+        let permit = client.acquire_agent_permit().unwrap();
+        assert!(permit.is_some());
+        assert_eq!(semaphore.in_use(), 1);
+
+        // 자리가 없으면 취소될 때까지 기다리다가 프로세스를 띄우지 않고 포기한다.
+        let token = CancellationToken::new();
+        token.cancel();
+        client.set_cancellation_token(token);
+        assert!(matches!(
+            client.acquire_agent_permit(),
+            Err(ClaudeCodeClientError::Cancelled),
+        ));
+
+        drop(permit);
+        client.set_cancellation_token(CancellationToken::new());
+        assert!(client.acquire_agent_permit().unwrap().is_some());
+        assert_eq!(semaphore.in_use(), 0);
+    }
+
+    #[test]
+    fn invocation_counter_is_shared_between_clients() {
+        let counter = InvocationCounter::new();
+        let mut first = client_without_binary();
+        let mut second = client_without_binary();
+        first.count_invocation();
+        first.set_invocation_counter(Some(counter.clone()));
+        second.set_invocation_counter(Some(counter.clone()));
+
+        first.count_invocation();
+        second.count_invocation();
+
+        assert_eq!(counter.count(), 2);
+    }
+
+    fn argument_after(command: &Command, flag: &str) -> Option<String> {
+        let args: Vec<_> = command.get_args().collect();
+        let index = args.iter().position(|arg| *arg == flag)?;
+        Some(args[index + 1].to_string_lossy().to_string())
+    }
+
+    #[test]
+    fn provided_session_id_resumes_on_first_query() {
+        let mut client = client_without_binary().with_session_id("sess-earlier".to_string());
+        let request = test_request();
+
+        let base = client.build_base_command(&request).unwrap();
+
+        assert_eq!(argument_after(&base.command, "--resume").as_deref(), Some("sess-earlier"));
+        assert_eq!(argument_after(&base.command, "--session-id"), None);
+        assert!(base.new_session_id.is_none());
+        assert_eq!(client.session_id(), Some("sess-earlier"));
+    }
+
+    #[test]
+    fn missing_reattached_session_falls_back_to_new_session() {
+        let mut client = client_without_binary().with_session_id("sess-gone".to_string());
+        let request = test_request();
+        let mut resumed = Vec::new();
+
+        let result = client.with_retry("test", |client| {
+            let base = client.build_base_command(&request)?;
+            resumed.push(argument_after(&base.command, "--resume"));
+            match base.new_session_id {
+                None => Err(ClaudeCodeClientError::CommandExecutionFailed {
+                    message: "No conversation found with session ID: sess-gone".to_string(),
+                }),
+                Some(id) => {
+                    client.session_id = Some(id);
+                    Ok("done")
+                }
+            }
+        });
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(resumed, vec![Some("sess-gone".to_string()), None]);
+        assert_ne!(client.session_id(), Some("sess-gone"));
+
+        // 첫 쿼리가 끝난 뒤에는 세션을 찾지 못해도 새 세션으로 바꾸지 않는다.
+        let result: Result<(), _> = client.with_retry("test", |_| {
+            Err(ClaudeCodeClientError::CliReturnedError {
+                message: "No conversation found with session ID: x".to_string(),
+            })
+        });
+        assert!(result.unwrap_err().is_missing_session());
+    }
+
+    fn tools_argument(command: &Command) -> String {
+        let args: Vec<_> = command.get_args().collect();
+        let tools_index = args.iter().position(|arg| *arg == "--tools").unwrap();
+        args[tools_index + 1].to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn command_emits_restricted_tools_for_read_only_tool_set() {
+        let mut client = client_without_binary();
+        let request = test_request();
+
+        let base = client.build_base_command(&request).unwrap();
+        assert_eq!(tools_argument(&base.command), TOOLS_LIST);
+
+        client.set_tool_set(ToolSet::ReadOnly);
+        let base = client.build_base_command(&request).unwrap();
+        let tools = tools_argument(&base.command);
+        let tools: Vec<&str> = tools.split(',').collect();
+
+        assert!(tools.contains(&"Read") && tools.contains(&"Grep"), "{:?}", tools);
+        for editing_tool in ["Edit", "Write", "Bash", "KillShell", "TaskOutput", "Task", "Skill"] {
+            assert!(!tools.contains(&editing_tool), "{:?}", tools);
+        }
+    }
+
+    #[test]
+    fn retry_gives_up_after_max_retries() {
+        let mut client = client_without_binary();
+        let mut attempt_count = 0;
+
+        let result: Result<(), _> = client.with_retry("test", |_| {
+            attempt_count += 1;
+            Err(overloaded_error())
+        });
+
+        assert!(matches!(result, Err(ClaudeCodeClientError::CommandExecutionFailed { .. })));
+        assert_eq!(attempt_count, 3);
+    }
+
+    #[test]
+    fn retry_does_not_repeat_non_retryable_errors() {
+        let mut client = client_without_binary();
+        let mut attempt_count = 0;
+
+        let result: Result<(), _> = client.with_retry("test", |_| {
+            attempt_count += 1;
+            Err(ClaudeCodeClientError::CliReturnedError {
+                message: "invalid request".to_string(),
+            })
+        });
+
+        assert!(matches!(result, Err(ClaudeCodeClientError::CliReturnedError { .. })));
+        assert_eq!(attempt_count, 1);
+    }
+
+    #[test]
+    fn retry_stops_when_cancelled_during_backoff() {
+        let mut client = client_without_binary();
+        client.retry_policy.initial_backoff = std::time::Duration::from_secs(30);
+        let token = CancellationToken::new();
+        client.set_cancellation_token(token.clone());
+        let mut attempt_count = 0;
+
+        let result: Result<(), _> = client.with_retry("test", |_| {
+            attempt_count += 1;
+            token.cancel();
+            Err(overloaded_error())
+        });
+
+        assert!(matches!(result, Err(ClaudeCodeClientError::Cancelled)));
+        assert_eq!(attempt_count, 1);
+    }
+
+    #[test]
+    fn parse_carries_usage_from_result_message() {
+        let mut message = make_result_message(
+            "sess-3",
+            false,
+            None,
+            Some(serde_json::json!({"answer": "ok"})),
+        );
+        message["total_cost_usd"] = serde_json::json!(0.25);
+        message["usage"] = serde_json::json!({"input_tokens": 100, "output_tokens": 20});
+        let stdout = make_json_array_output(&[message]);
+
+        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout, None).unwrap();
+
+        assert_eq!(
+            parsed.usage,
+            Some(Usage {
+                input_tokens: 100,
+                output_tokens: 20,
+                cost_usd: 0.25,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_without_usage_fields_yields_none() {
+        let message = make_result_message(
+            "sess-4",
+            false,
+            None,
+            Some(serde_json::json!({"answer": "ok"})),
+        );
+        let stdout = make_json_array_output(&[message]);
+
+        let parsed: ParsedOutput<TestOutput> = parse_cli_output(&stdout, None).unwrap();
+
+        assert_eq!(parsed.usage, None);
+    }
+
+    #[test]
+    fn take_usage_returns_sum_since_last_take() {
+        let mut client = client_without_binary();
+        let usage = Usage {
+            input_tokens: 10,
+            output_tokens: 2,
+            cost_usd: 0.5,
+        };
+        client.record_usage(Some(usage));
+        client.record_usage(None);
+        client.record_usage(Some(usage));
+
+        assert_eq!(
+            client.take_usage(),
+            Some(Usage {
+                input_tokens: 20,
+                output_tokens: 4,
+                cost_usd: 1.0,
+            })
+        );
+        assert_eq!(client.take_usage(), None);
     }
 }
@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use super::error::ClaudeCodeClientError;
 
@@ -15,6 +16,27 @@ const HOME_RELATIVE_FALLBACK_PATHS: &[&str] = &[
     ".claude/local/claude"
 ];
 
+/// 프로세스 전체에서 한 번 찾은 CLI 바이너리 경로.
+static CACHED_CLAUDE_BINARY: OnceLock<PathBuf> = OnceLock::new();
+
+/// CLI 바이너리 경로를 찾는다. 클라이언트를 만들 때마다 PATH와 폴백 경로를 다시 뒤지지 않도록
+/// 처음 찾은 경로를 프로세스 전체에서 재사용한다. 찾지 못한 결과는 저장하지 않으므로
+/// 실행 중에 CLI를 설치하면 다음 호출에서 찾을 수 있다.
+pub fn cached_claude_binary() -> Result<PathBuf, ClaudeCodeClientError> {
+    cached_or_find(&CACHED_CLAUDE_BINARY, find_claude_binary)
+}
+
+fn cached_or_find(
+    cache: &OnceLock<PathBuf>,
+    find: impl FnOnce() -> Result<PathBuf, ClaudeCodeClientError>,
+) -> Result<PathBuf, ClaudeCodeClientError> {
+    if let Some(path) = cache.get() {
+        return Ok(path.clone());
+    }
+    let path = find()?;
+    Ok(cache.get_or_init(|| path).clone())
+}
+
 pub fn find_claude_binary() -> Result<PathBuf, ClaudeCodeClientError> {
     // PATH에서 먼저 찾아본다.
     if let Ok(path) = which::which("claude") {
@@ -42,3 +64,37 @@ pub fn find_claude_binary() -> Result<PathBuf, ClaudeCodeClientError> {
 
     Err(ClaudeCodeClientError::BinaryNotFound)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn cached_path_is_reused_without_searching_again() {
+        let cache = OnceLock::new();
+        let search_count = Cell::new(0);
+        let find = || {
+            search_count.set(search_count.get() + 1);
+            Ok(PathBuf::from("/opt/claude/bin/claude"))
+        };
+
+        let first = cached_or_find(&cache, find).unwrap();
+        let second = cached_or_find(&cache, || Ok(PathBuf::from("/other/claude"))).unwrap();
+
+        assert_eq!(first, PathBuf::from("/opt/claude/bin/claude"));
+        assert_eq!(second, first);
+        assert_eq!(search_count.get(), 1);
+    }
+
+    #[test]
+    fn failed_search_is_not_cached() {
+        let cache = OnceLock::new();
+
+        let missing = cached_or_find(&cache, || Err(ClaudeCodeClientError::BinaryNotFound));
+        let found = cached_or_find(&cache, || Ok(PathBuf::from("/usr/bin/claude"))).unwrap();
+
+        assert!(matches!(missing, Err(ClaudeCodeClientError::BinaryNotFound)));
+        assert_eq!(found, PathBuf::from("/usr/bin/claude"));
+    }
+}
@@ -0,0 +1,138 @@
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const WATCH_INTERVAL: Duration = Duration::from_millis(50);
+
+/// UI 스레드와 에이전트 스레드가 공유하는 취소 플래그. 이 토큰으로 감시 중인 CLI 프로세스 수도
+/// 함께 세어, 취소한 쪽이 프로세스가 끝날 때까지 기다릴 수 있게 한다.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    running_processes: Arc<AtomicUsize>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// 이 토큰으로 감시 중인 프로세스가 모두 끝날 때까지 최대 `timeout` 동안 기다린다.
+    /// 제한 시간 안에 모두 끝났으면 true를 반환한다.
+    pub fn wait_for_processes(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.running_processes.load(Ordering::SeqCst) > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(WATCH_INTERVAL);
+        }
+        true
+    }
+}
+
+/// 자식 프로세스가 실행되는 동안 취소 플래그를 감시하다가 취소되면 프로세스를 종료한다.
+/// 프로세스를 wait 하기 전에 `finish`를 호출해야 PID가 재사용되기 전에 감시가 끝난다.
+/// 오류로 중간에 빠져나가는 경우에도 drop 시점에 감시 스레드가 정리된다.
+/// 감시하는 동안에는 토큰의 실행 중인 프로세스 수에 포함된다.
+pub(super) struct ChildProcessWatcher {
+    finished: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    running_processes: Option<Arc<AtomicUsize>>,
+}
+
+impl ChildProcessWatcher {
+    pub(super) fn spawn(token: Option<CancellationToken>, pid: u32) -> Self {
+        let finished = Arc::new(AtomicBool::new(false));
+        let running_processes = token.as_ref().map(|token| {
+            token.running_processes.fetch_add(1, Ordering::SeqCst);
+            Arc::clone(&token.running_processes)
+        });
+        let handle = token.map(|token| {
+            let finished = Arc::clone(&finished);
+            std::thread::spawn(move || {
+                while !finished.load(Ordering::SeqCst) {
+                    if token.is_cancelled() {
+                        crate::cli_log!("[취소] 자식 프로세스 종료 요청 (pid: {})", pid);
+                        let _ = Command::new("kill").arg(pid.to_string()).status();
+                        return;
+                    }
+                    std::thread::sleep(WATCH_INTERVAL);
+                }
+            })
+        });
+
+        Self { finished, handle, running_processes }
+    }
+
+    pub(super) fn finish(self) {
+        drop(self);
+    }
+}
+
+impl Drop for ChildProcessWatcher {
+    fn drop(&mut self) {
+        self.finished.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        if let Some(running_processes) = self.running_processes.take() {
+            running_processes.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Stdio;
+
+    #[test]
+    fn token_clones_share_state() {
+        let token = CancellationToken::new();
+        let shared = token.clone();
+        assert!(!shared.is_cancelled());
+
+        token.cancel();
+        assert!(shared.is_cancelled());
+    }
+
+    #[test]
+    fn watcher_kills_child_when_cancelled() {
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        let token = CancellationToken::new();
+        let watcher = ChildProcessWatcher::spawn(Some(token.clone()), child.id());
+
+        assert!(!token.wait_for_processes(Duration::ZERO));
+
+        token.cancel();
+        let status = child.wait().unwrap();
+        watcher.finish();
+
+        assert!(!status.success());
+        assert!(token.wait_for_processes(Duration::ZERO));
+    }
+
+    #[test]
+    fn watcher_without_token_leaves_child_alone() {
+        let mut child = Command::new("true").spawn().unwrap();
+        let watcher = ChildProcessWatcher::spawn(None, child.id());
+        watcher.finish();
+
+        assert!(child.wait().unwrap().success());
+    }
+}
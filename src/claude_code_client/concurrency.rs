@@ -0,0 +1,145 @@
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use super::CancellationToken;
+
+/// 자리를 기다리는 동안 취소 여부를 확인하는 간격.
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 동시에 실행할 수 있는 CLI 프로세스 수를 제한하는 세마포어. 복제본끼리 같은 한도를 공유하므로
+/// 하나를 만들어 모든 클라이언트에 나눠 주면 단계와 관계없이 전체 프로세스 수가 제한된다.
+#[derive(Debug, Clone)]
+pub struct AgentSemaphore {
+    state: Arc<(Mutex<usize>, Condvar)>,
+    limit: usize,
+}
+
+/// 세마포어의 자리 하나. drop되면 자리를 돌려주고 기다리는 쪽 하나를 깨운다.
+#[derive(Debug)]
+pub struct AgentPermit {
+    semaphore: AgentSemaphore,
+}
+
+impl AgentSemaphore {
+    /// `limit`개까지 동시에 실행을 허용한다. 0은 아무것도 실행할 수 없게 되므로 1로 본다.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            state: Arc::new((Mutex::new(0), Condvar::new())),
+            limit: limit.max(1),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// 지금 실행 중인 프로세스 수.
+    pub fn in_use(&self) -> usize {
+        *self.lock()
+    }
+
+    /// 자리가 있으면 바로 차지하고, 없으면 None을 반환한다.
+    pub fn try_acquire(&self) -> Option<AgentPermit> {
+        let mut in_use = self.lock();
+        if *in_use >= self.limit {
+            return None;
+        }
+        *in_use += 1;
+        Some(AgentPermit { semaphore: self.clone() })
+    }
+
+    /// 자리가 날 때까지 기다려 차지한다. 기다리는 동안 `token`이 취소되면 None을 반환한다.
+    pub fn acquire(&self, token: Option<&CancellationToken>) -> Option<AgentPermit> {
+        let (_, available) = &*self.state;
+        let mut in_use = self.lock();
+        while *in_use >= self.limit {
+            if token.is_some_and(|token| token.is_cancelled()) {
+                return None;
+            }
+            in_use = available
+                .wait_timeout(in_use, CANCELLATION_CHECK_INTERVAL)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        if token.is_some_and(|token| token.is_cancelled()) {
+            return None;
+        }
+        *in_use += 1;
+        Some(AgentPermit { semaphore: self.clone() })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.state.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for AgentPermit {
+    fn drop(&mut self) {
+        *self.semaphore.lock() -= 1;
+        self.semaphore.state.1.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn permits_are_limited_and_returned_on_drop() {
+        let semaphore = AgentSemaphore::new(2);
+        let shared = semaphore.clone();
+
+        let first = semaphore.try_acquire().unwrap();
+        let _second = shared.try_acquire().unwrap();
+        assert!(semaphore.try_acquire().is_none());
+        assert_eq!(semaphore.in_use(), 2);
+
+        drop(first);
+        assert_eq!(shared.in_use(), 1);
+        assert!(shared.try_acquire().is_some());
+    }
+
+    #[test]
+    fn zero_limit_still_allows_one_process() {
+        let semaphore = AgentSemaphore::new(0);
+
+        assert_eq!(semaphore.limit(), 1);
+        let _permit = semaphore.try_acquire().unwrap();
+        assert!(semaphore.try_acquire().is_none());
+    }
+
+    #[test]
+    fn acquire_waits_until_a_permit_is_released() {
+        let semaphore = AgentSemaphore::new(1);
+        let held = semaphore.acquire(None).unwrap();
+        let (sender, receiver) = mpsc::channel();
+        let waiting = semaphore.clone();
+
+        let handle = std::thread::spawn(move || {
+            let permit = waiting.acquire(None);
+            sender.send(()).unwrap();
+            permit.is_some()
+        });
+
+        assert!(receiver.recv_timeout(Duration::from_millis(200)).is_err());
+        drop(held);
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    fn cancelled_wait_gives_up_without_a_permit() {
+        let semaphore = AgentSemaphore::new(1);
+        let _held = semaphore.try_acquire().unwrap();
+        let token = CancellationToken::new();
+        let waiting = semaphore.clone();
+        let waiting_token = token.clone();
+
+        let handle = std::thread::spawn(move || waiting.acquire(Some(&waiting_token)).is_none());
+        token.cancel();
+
+        assert!(handle.join().unwrap());
+        assert_eq!(semaphore.in_use(), 1);
+    }
+}
@@ -1,8 +1,17 @@
 #[derive(Debug, thiserror::Error)]
 pub enum ClaudeCodeClientError {
-    #[error("claude binary not found")]
+    #[error("claude binary not found; install the Claude Code CLI and make sure it is on PATH")]
     BinaryNotFound,
 
+    #[error(
+        "claude CLI {found} is older than the minimum supported version {minimum}; \
+         upgrade the Claude Code CLI"
+    )]
+    UnsupportedCliVersion { found: String, minimum: String },
+
+    #[error("could not read the claude CLI version from `claude --version` output: {output:?}")]
+    UnrecognizedCliVersion { output: String },
+
     #[error("CLI execution failed: {message}")]
     CommandExecutionFailed { message: String },
 
@@ -21,6 +30,164 @@ pub enum ClaudeCodeClientError {
     #[error("structured_output field is missing from the response")]
     MissingStructuredOutput,
 
+    #[error("structured_output does not match the schema at {path}: {message}")]
+    SchemaMismatch { path: String, message: String },
+
     #[error("no result message found in CLI output")]
     NoResultMessage,
+
+    #[error("query was cancelled")]
+    Cancelled,
+}
+
+/// 재시도하면 성공할 가능성이 있는 일시적 장애를 나타내는 stderr 패턴.
+const TRANSIENT_FAILURE_PATTERNS: &[&str] = &[
+    "overloaded",
+    "529",
+    "rate limit",
+    "rate_limit",
+    "429",
+    "502 bad gateway",
+    "503 service unavailable",
+    "504 gateway timeout",
+    "timed out",
+    "timeout",
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "econnreset",
+    "econnrefused",
+    "etimedout",
+    "socket hang up",
+    "network error",
+    "temporarily unavailable",
+];
+
+/// 현재 모델을 쓸 수 없어 다른 모델로 바꾸면 성공할 수 있는 오류를 나타내는 패턴.
+const MODEL_UNAVAILABLE_PATTERNS: &[&str] = &[
+    "overloaded",
+    "529",
+    "model is unavailable",
+    "model_not_available",
+    "not_found_error",
+];
+
+impl ClaudeCodeClientError {
+    /// 같은 요청을 다시 보내면 성공할 수 있는 일시적 오류인지 판단한다.
+    /// CLI 프로세스가 비정상 종료한 경우만 stderr 내용으로 분류하며, 스키마/파싱 오류나
+    /// CLI가 명시적으로 반환한 오류는 재시도해도 같은 결과가 나오므로 재시도하지 않는다.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::CommandExecutionFailed { message } => {
+                let message = message.to_lowercase();
+                TRANSIENT_FAILURE_PATTERNS
+                    .iter()
+                    .any(|pattern| message.contains(pattern))
+            }
+            _ => false,
+        }
+    }
+
+    /// 현재 모델이 과부하 상태이거나 사용할 수 없어 대체 모델로 다시 시도해야 하는지 판단한다.
+    /// CLI가 비정상 종료한 경우와 결과 메시지에 오류를 담아 반환한 경우를 모두 확인한다.
+    pub fn is_model_unavailable(&self) -> bool {
+        let message = match self {
+            Self::CommandExecutionFailed { message } | Self::CliReturnedError { message } => {
+                message.to_lowercase()
+            }
+            _ => return false,
+        };
+        MODEL_UNAVAILABLE_PATTERNS
+            .iter()
+            .any(|pattern| message.contains(pattern))
+    }
+
+    /// `--resume`으로 지정한 세션을 CLI가 찾지 못했는지 판단한다. Claude 세션은 작업 디렉토리별로
+    /// 저장되므로 다른 워크트리에서 만든 세션을 재개하려 하면 이 오류가 난다.
+    pub fn is_missing_session(&self) -> bool {
+        match self {
+            Self::CommandExecutionFailed { message } | Self::CliReturnedError { message } => {
+                message.to_lowercase().contains("no conversation found")
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution_failed(message: &str) -> ClaudeCodeClientError {
+        ClaudeCodeClientError::CommandExecutionFailed {
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn transient_stderr_is_retryable() {
+        let transient = [
+            "API Error: 529 {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}",
+            "API Error: 429 rate_limit_error: Number of request tokens has exceeded your rate limit",
+            "Error: Request timed out.",
+            "API Error: Connection error. read ECONNRESET",
+            "FetchError: request to https://api.anthropic.com failed, reason: socket hang up",
+            "API Error: 503 Service Unavailable",
+        ];
+
+        for stderr in transient {
+            assert!(execution_failed(stderr).is_retryable(), "{}", stderr);
+        }
+    }
+
+    #[test]
+    fn permanent_stderr_is_not_retryable() {
+        let permanent = [
+            "Invalid API key · Please run /login",
+            "error: unknown option '--bogus'",
+            "No such file or directory (os error 2)",
+            "",
+        ];
+
+        for stderr in permanent {
+            assert!(!execution_failed(stderr).is_retryable(), "{}", stderr);
+        }
+    }
+
+    #[test]
+    fn non_execution_errors_are_not_retryable() {
+        let parse_error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+
+        assert!(!ClaudeCodeClientError::from(parse_error).is_retryable());
+        assert!(!ClaudeCodeClientError::CliReturnedError {
+            message: "API Error: 529 Overloaded".to_string(),
+        }
+        .is_retryable());
+        assert!(!ClaudeCodeClientError::MissingStructuredOutput.is_retryable());
+        assert!(!ClaudeCodeClientError::NoResultMessage.is_retryable());
+        assert!(!ClaudeCodeClientError::Cancelled.is_retryable());
+    }
+
+    #[test]
+    fn overloaded_errors_mean_model_unavailable() {
+        assert!(execution_failed("API Error: 529 overloaded_error").is_model_unavailable());
+        assert!(ClaudeCodeClientError::CliReturnedError {
+            message: "API Error: 529 Overloaded".to_string(),
+        }
+        .is_model_unavailable());
+        assert!(!execution_failed("API Error: 429 rate_limit_error").is_model_unavailable());
+        assert!(!ClaudeCodeClientError::NoResultMessage.is_model_unavailable());
+    }
+
+    #[test]
+    fn missing_resumed_session_is_detected() {
+        let missing = "No conversation found with session ID: abc";
+        assert!(execution_failed(missing).is_missing_session());
+        assert!(ClaudeCodeClientError::CliReturnedError {
+            message: "No conversation found with session ID: abc".to_string(),
+        }
+        .is_missing_session());
+        assert!(!execution_failed("API Error: 529 overloaded_error").is_missing_session());
+        assert!(!ClaudeCodeClientError::NoResultMessage.is_missing_session());
+    }
 }
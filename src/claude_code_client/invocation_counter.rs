@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 띄운 CLI 프로세스 수. 복제본끼리 같은 값을 공유하므로 하나를 만들어 모든 클라이언트에
+/// 나눠 주면 재시도와 리뷰 반복을 포함해 세션 전체의 호출 수를 센다.
+#[derive(Debug, Clone, Default)]
+pub struct InvocationCounter {
+    count: Arc<AtomicUsize>,
+}
+
+impl InvocationCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 호출 하나를 더하고 더한 뒤의 수를 반환한다.
+    pub fn increment(&self) -> usize {
+        self.count.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
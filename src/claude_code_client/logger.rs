@@ -20,13 +20,14 @@ pub fn init() {
     }
 }
 
-/// 로그 파일에 한 줄을 기록한다.
+/// 로그 파일에 한 줄을 기록한다. 비밀 값은 기록 전에 가린다.
 /// 형식: "로컬_타임스탬프: 코드_위치: 로그_메시지"
 pub fn write_log(location: &str, message: &str) {
     let Some(mutex) = LOG_FILE.get() else { return };
     let Ok(mut file) = mutex.lock() else { return };
 
     let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+    let message = super::redaction::redact(message);
     let _ = writeln!(file, "{}: {}: {}", timestamp, location, message);
     let _ = file.flush();
 }
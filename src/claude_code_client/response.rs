@@ -10,4 +10,131 @@ pub struct CliResponse {
     pub result: Option<String>,
     #[serde(default)]
     pub structured_output: Option<serde_json::Value>,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub total_cost_usd: Option<f64>,
+    #[serde(default)]
+    pub usage: Option<CliUsage>,
+}
+
+/// ResultMessage의 `usage` 필드. CLI 버전에 따라 일부 필드가 빠질 수 있다.
+#[derive(Debug, Default, Deserialize)]
+pub struct CliUsage {
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    #[serde(default)]
+    pub cache_creation_input_tokens: Option<u64>,
+    #[serde(default)]
+    pub cache_read_input_tokens: Option<u64>,
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
+}
+
+/// 쿼리 한 번 또는 여러 번의 토큰 사용량과 비용.
+/// 입력 토큰에는 캐시 생성/조회 토큰도 포함한다.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Usage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl Usage {
+    pub fn add(&mut self, other: &Usage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_usd += other.cost_usd;
+    }
+}
+
+impl CliResponse {
+    /// 사용량과 비용 정보가 모두 없으면 None을 반환한다.
+    pub fn usage(&self) -> Option<Usage> {
+        if self.usage.is_none() && self.total_cost_usd.is_none() {
+            return None;
+        }
+
+        let usage = self.usage.as_ref();
+        let tokens = |field: fn(&CliUsage) -> Option<u64>| usage.and_then(field).unwrap_or(0);
+        Some(Usage {
+            input_tokens: tokens(|u| u.input_tokens)
+                + tokens(|u| u.cache_creation_input_tokens)
+                + tokens(|u| u.cache_read_input_tokens),
+            output_tokens: tokens(|u| u.output_tokens),
+            cost_usd: self.total_cost_usd.unwrap_or(0.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_from_realistic_result_message() {
+        let message = serde_json::json!({
+            "type": "result",
+            "subtype": "success",
+            "is_error": false,
+            "duration_ms": 48211,
+            "duration_api_ms": 45032,
+            "num_turns": 7,
+            "result": "",
+            "session_id": "4f2c8a0e-3b1d-4c6e-9f7a-2d5b8e1c0a93",
+            "total_cost_usd": 0.4127,
+            "usage": {
+                "input_tokens": 12,
+                "cache_creation_input_tokens": 15234,
+                "cache_read_input_tokens": 48120,
+                "output_tokens": 2310,
+                "server_tool_use": { "web_search_requests": 0 },
+                "service_tier": "standard"
+            },
+            "structured_output": { "answer": "ok" }
+        });
+
+        let response: CliResponse = serde_json::from_value(message).unwrap();
+
+        assert_eq!(
+            response.usage(),
+            Some(Usage {
+                input_tokens: 63366,
+                output_tokens: 2310,
+                cost_usd: 0.4127,
+            })
+        );
+    }
+
+    #[test]
+    fn usage_is_none_when_fields_are_absent() {
+        let message = serde_json::json!({
+            "type": "result",
+            "is_error": false,
+            "session_id": "sess-1"
+        });
+
+        let response: CliResponse = serde_json::from_value(message).unwrap();
+
+        assert_eq!(response.usage(), None);
+    }
+
+    #[test]
+    fn usage_tolerates_partial_fields() {
+        let message = serde_json::json!({
+            "type": "result",
+            "is_error": false,
+            "session_id": "sess-1",
+            "usage": { "output_tokens": 40 }
+        });
+
+        let response: CliResponse = serde_json::from_value(message).unwrap();
+
+        assert_eq!(
+            response.usage(),
+            Some(Usage {
+                input_tokens: 0,
+                output_tokens: 40,
+                cost_usd: 0.0,
+            })
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use super::CancellationToken;
+
+const DEFAULT_MAX_RETRIES: u32 = 2;
+const DEFAULT_INITIAL_BACKOFF_MILLIS: u64 = 1000;
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 일시적인 CLI 실패에 대한 재시도 정책. 재시도 간격은 시도마다 두 배로 늘어난다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MILLIS),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `retry_index`번째(0부터) 재시도 전에 기다릴 시간.
+    pub fn backoff_delay(&self, retry_index: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry_index))
+    }
+}
+
+/// 지정한 시간만큼 기다리되 그 사이에 취소되면 즉시 false를 반환한다.
+pub(super) fn sleep_unless_cancelled(delay: Duration, token: Option<&CancellationToken>) -> bool {
+    let mut remaining = delay;
+    while !remaining.is_zero() {
+        if token.is_some_and(|token| token.is_cancelled()) {
+            return false;
+        }
+        let step = remaining.min(CANCELLATION_CHECK_INTERVAL);
+        std::thread::sleep(step);
+        remaining -= step;
+    }
+    !token.is_some_and(|token| token.is_cancelled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_each_retry() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+        };
+
+        assert_eq!(policy.backoff_delay(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff_delay(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff_delay(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn backoff_saturates_instead_of_overflowing() {
+        let policy = RetryPolicy {
+            max_retries: u32::MAX,
+            initial_backoff: Duration::from_secs(u64::MAX / 2),
+        };
+
+        assert_eq!(policy.backoff_delay(40), Duration::MAX);
+    }
+
+    #[test]
+    fn sleep_returns_early_when_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        assert!(!sleep_unless_cancelled(Duration::from_secs(30), Some(&token)));
+        assert!(sleep_unless_cancelled(Duration::from_millis(1), None));
+    }
+}
@@ -0,0 +1,199 @@
+use serde_json::Value;
+
+/// 구조화된 출력이 요청한 스키마와 어긋난 위치와 이유.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// `$.tasks[0].task_id` 형태의 필드 경로.
+    pub path: String,
+    pub message: String,
+}
+
+/// 쿼리에 쓰는 JSON 스키마의 일부(`type`, `enum`, `properties`, `required`,
+/// `additionalProperties: false`, `items`, `minItems`)만으로 값을 검사한다.
+/// serde 역직렬화 오류보다 어느 필드가 어긋났는지 알기 쉽도록 첫 번째 위반을 반환한다.
+pub fn validate_against_schema(value: &Value, schema: &Value) -> Result<(), SchemaViolation> {
+    validate_at("$", value, schema)
+}
+
+fn validate_at(path: &str, value: &Value, schema: &Value) -> Result<(), SchemaViolation> {
+    let violation = |message: String| SchemaViolation {
+        path: path.to_string(),
+        message,
+    };
+
+    if let Some(expected) = schema.get("type")
+        && !matches_type(value, expected)
+    {
+        return Err(violation(format!(
+            "expected type {}, got {}",
+            expected,
+            type_name(value),
+        )));
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        return Err(violation(format!("{} is not one of {}", value, Value::from(allowed.clone()))));
+    }
+
+    match value {
+        Value::Object(object) => validate_object(path, object, schema),
+        Value::Array(items) => validate_array(path, items, schema),
+        _ => Ok(()),
+    }
+}
+
+fn validate_object(
+    path: &str,
+    object: &serde_json::Map<String, Value>,
+    schema: &Value,
+) -> Result<(), SchemaViolation> {
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    for required in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+        if let Some(name) = required.as_str()
+            && !object.contains_key(name)
+        {
+            return Err(SchemaViolation {
+                path: format!("{}.{}", path, name),
+                message: "required field is missing".to_string(),
+            });
+        }
+    }
+
+    for (name, field_value) in object {
+        let field_path = format!("{}.{}", path, name);
+        match properties.and_then(|properties| properties.get(name)) {
+            Some(field_schema) => validate_at(&field_path, field_value, field_schema)?,
+            None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                return Err(SchemaViolation {
+                    path: field_path,
+                    message: "field is not allowed by the schema".to_string(),
+                });
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn validate_array(path: &str, items: &[Value], schema: &Value) -> Result<(), SchemaViolation> {
+    if let Some(min_items) = schema.get("minItems").and_then(Value::as_u64)
+        && (items.len() as u64) < min_items
+    {
+        return Err(SchemaViolation {
+            path: path.to_string(),
+            message: format!("expected at least {} items, got {}", min_items, items.len()),
+        });
+    }
+    if let Some(item_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(&format!("{}[{}]", path, index), item, item_schema)?;
+        }
+    }
+    Ok(())
+}
+
+fn matches_type(value: &Value, expected: &Value) -> bool {
+    match expected {
+        Value::String(name) => matches_type_name(value, name),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| matches_type_name(value, name)),
+        _ => true,
+    }
+}
+
+fn matches_type_name(value: &Value, name: &str) -> bool {
+    match name {
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        other => type_name(value) == other,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn task_list_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "tasks": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "task_id": { "type": "string" },
+                            "priority": { "type": "integer" },
+                            "status": { "type": "string", "enum": ["OPEN", "DONE"] }
+                        },
+                        "required": ["task_id"],
+                        "additionalProperties": false
+                    },
+                    "minItems": 1
+                }
+            },
+            "required": ["tasks"]
+        })
+    }
+
+    fn violation_of(value: Value) -> SchemaViolation {
+        validate_against_schema(&value, &task_list_schema()).unwrap_err()
+    }
+
+    #[test]
+    fn accepts_matching_value() {
+        let value = json!({"tasks": [{"task_id": "TASK-00", "priority": 2, "status": "DONE"}]});
+
+        assert_eq!(validate_against_schema(&value, &task_list_schema()), Ok(()));
+    }
+
+    #[test]
+    fn names_missing_required_field() {
+        let violation = violation_of(json!({"tasks": [{"priority": 1}]}));
+
+        assert_eq!(violation.path, "$.tasks[0].task_id");
+        assert_eq!(violation.message, "required field is missing");
+    }
+
+    #[test]
+    fn names_field_with_wrong_type() {
+        let violation = violation_of(json!({"tasks": [{"task_id": "T", "priority": "high"}]}));
+
+        assert_eq!(violation.path, "$.tasks[0].priority");
+        assert_eq!(violation.message, "expected type \"integer\", got string");
+    }
+
+    #[test]
+    fn names_value_outside_enum_and_unknown_field() {
+        let violation = violation_of(json!({"tasks": [{"task_id": "T", "status": "WIP"}]}));
+        assert_eq!(violation.path, "$.tasks[0].status");
+        assert!(violation.message.contains("\"WIP\" is not one of"), "{}", violation.message);
+
+        let violation = violation_of(json!({"tasks": [{"task_id": "T", "owner": "me"}]}));
+        assert_eq!(violation.path, "$.tasks[0].owner");
+    }
+
+    #[test]
+    fn reports_too_few_items() {
+        let violation = violation_of(json!({"tasks": []}));
+
+        assert_eq!(violation.path, "$.tasks");
+        assert_eq!(violation.message, "expected at least 1 items, got 0");
+    }
+}
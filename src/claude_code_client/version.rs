@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+
+use super::binary_finder;
+use super::error::ClaudeCodeClientError;
+
+/// `--json-schema`로 구조화된 출력을 받고 `--tools`로 허용 도구를 제한할 수 있는 최소 CLI 버전.
+pub const MINIMUM_CLI_VERSION: CliVersion = CliVersion {
+    major: 2,
+    minor: 0,
+    patch: 0,
+};
+
+/// `claude --version`이 출력하는 `major.minor.patch` 형식의 버전.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CliVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl fmt::Display for CliVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// 확인을 통과한 바이너리 경로별 버전. 실패한 결과는 저장하지 않으므로 실행 중에 CLI를
+/// 업그레이드하면 다음 확인에서 통과할 수 있다.
+static VERIFIED_VERSIONS: OnceLock<Mutex<HashMap<PathBuf, CliVersion>>> = OnceLock::new();
+
+/// `claude --version` 출력에서 처음 나오는 `major.minor.patch` 형식의 단어를 버전으로 읽는다.
+/// `2.1.3 (Claude Code)`, `claude-code v2.1.3`, `2.1.3-beta.1` 같은 형식을 받아들인다.
+pub fn parse_cli_version(output: &str) -> Option<CliVersion> {
+    output.split_whitespace().find_map(|word| {
+        let word = word.trim_start_matches('v');
+        let core = word.split(['-', '+']).next().unwrap_or(word);
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => {
+                Some(CliVersion { major, minor, patch })
+            }
+            _ => None,
+        }
+    })
+}
+
+/// 읽은 버전이 지원하는 최소 버전 이상인지 확인한다.
+pub fn check_cli_version(output: &str) -> Result<CliVersion, ClaudeCodeClientError> {
+    let version = parse_cli_version(output).ok_or_else(|| {
+        ClaudeCodeClientError::UnrecognizedCliVersion {
+            output: output.trim().to_string(),
+        }
+    })?;
+    if version < MINIMUM_CLI_VERSION {
+        return Err(ClaudeCodeClientError::UnsupportedCliVersion {
+            found: version.to_string(),
+            minimum: MINIMUM_CLI_VERSION.to_string(),
+        });
+    }
+    Ok(version)
+}
+
+/// 시작할 때 CLI 바이너리를 찾아 지원하는 버전인지 확인한다.
+pub fn verify_cli_binary() -> Result<CliVersion, ClaudeCodeClientError> {
+    verify_cli_binary_at(&binary_finder::cached_claude_binary()?)
+}
+
+/// `binary_path`의 CLI 버전을 확인한다. 같은 경로는 한 번만 실행해 확인한다.
+fn verify_cli_binary_at(binary_path: &Path) -> Result<CliVersion, ClaudeCodeClientError> {
+    let cache = VERIFIED_VERSIONS.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(version) = cache.lock().unwrap().get(binary_path) {
+        return Ok(*version);
+    }
+
+    let version = check_cli_version(&run_version_command(binary_path)?)?;
+    cache.lock().unwrap().insert(binary_path.to_path_buf(), version);
+    Ok(version)
+}
+
+fn run_version_command(binary_path: &Path) -> Result<String, ClaudeCodeClientError> {
+    let output = Command::new(binary_path)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| ClaudeCodeClientError::CommandExecutionFailed {
+            message: format!("failed to run {} --version: {}", binary_path.display(), err),
+        })?;
+    if !output.status.success() {
+        return Err(ClaudeCodeClientError::CommandExecutionFailed {
+            message: format!(
+                "{} --version exited with {}: {}",
+                binary_path.display(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim(),
+            ),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(major: u64, minor: u64, patch: u64) -> CliVersion {
+        CliVersion { major, minor, patch }
+    }
+
+    #[test]
+    fn parses_version_strings_printed_by_the_cli() {
+        let samples = [
+            ("2.1.3 (Claude Code)\n", Some(version(2, 1, 3))),
+            ("claude-code v2.0.14", Some(version(2, 0, 14))),
+            ("1.0.128-beta.2 (Claude Code)", Some(version(1, 0, 128))),
+            ("2.10.0+build.7", Some(version(2, 10, 0))),
+            ("Claude Code", None),
+            ("2.1", None),
+            ("1.2.3.4", None),
+            ("", None),
+        ];
+
+        for (output, expected) in samples {
+            assert_eq!(parse_cli_version(output), expected, "{:?}", output);
+        }
+    }
+
+    #[test]
+    fn rejects_versions_below_minimum() {
+        assert_eq!(check_cli_version("2.0.0 (Claude Code)").unwrap(), version(2, 0, 0));
+        assert_eq!(check_cli_version("2.10.1").unwrap(), version(2, 10, 1));
+        assert!(version(2, 9, 9) < version(2, 10, 0));
+
+        let err = check_cli_version("1.0.128 (Claude Code)").unwrap_err();
+        assert!(matches!(
+            &err,
+            ClaudeCodeClientError::UnsupportedCliVersion { found, minimum }
+                if found == "1.0.128" && minimum == "2.0.0"
+        ));
+        assert!(matches!(
+            check_cli_version("unknown"),
+            Err(ClaudeCodeClientError::UnrecognizedCliVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn verifies_binary_by_running_version_command() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let write_script = |name: &str, body: &str| {
+            let path = temp_dir.path().join(name);
+            std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+            std::fs::set_permissions(&path, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+                .unwrap();
+            path
+        };
+        let current = write_script("claude-current", "echo '2.1.3 (Claude Code)'");
+        let outdated = write_script("claude-outdated", "echo '1.0.0 (Claude Code)'");
+        let broken = write_script("claude-broken", "echo 'boom' >&2; exit 3");
+
+        assert_eq!(verify_cli_binary_at(&current).unwrap(), version(2, 1, 3));
+        assert!(matches!(
+            verify_cli_binary_at(&outdated),
+            Err(ClaudeCodeClientError::UnsupportedCliVersion { .. })
+        ));
+        let err = verify_cli_binary_at(&broken).unwrap_err();
+        assert!(err.to_string().contains("boom"), "{}", err);
+        assert!(verify_cli_binary_at(&temp_dir.path().join("missing")).is_err());
+    }
+}
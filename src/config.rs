@@ -1,22 +1,973 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+mod overrides;
+mod values;
+
+pub use overrides::{ConfigOverrides, WORKSPACE_CONFIG_FILE_NAME};
+pub use values::{
+    CommitMessageTemplate, DEFAULT_BUILD_TEST_TIMEOUT, DEFAULT_COMMIT_MESSAGE_TEMPLATE,
+    DEFAULT_INTEGRATION_BRANCH_PREFIX, MergeStrategy, NameCasing, PromptOverrideMode,
+    SessionNameStyle, UsagePhase,
+};
+
+use crate::claude_code_client::{DEFAULT_MODEL, Verbosity};
+
+const DEFAULT_POLL_INTERVAL_MILLIS: u64 = 100;
+const DEFAULT_MAX_REVIEW_ITERATIONS: usize = 3;
+const DEFAULT_MAX_RETRIES: u32 = 2;
+const DEFAULT_INITIAL_BACKOFF_MILLIS: u64 = 1000;
+const DEFAULT_MAX_PHASE_DURATION_SECS: u64 = 3600;
+const DEFAULT_MAX_BUILD_REPAIR_ATTEMPTS: usize = 1;
+const DEFAULT_OUTPUT_LANGUAGE: &str = "Korean";
+
+/// `quick` 프리셋이 쓰는 더 작고 빠른 모델.
+const QUICK_PRESET_MODEL: &str = "claude-sonnet-4-5";
+const QUICK_PRESET_MAX_REVIEW_ITERATIONS: usize = 1;
+const QUICK_PRESET_BUILD_TEST_TIMEOUT: Duration = Duration::from_secs(60);
+const THOROUGH_PRESET_MAX_REVIEW_ITERATIONS: usize = 5;
+const THOROUGH_PRESET_BUILD_TEST_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// 여러 설정을 한 번에 정하는 이름 있는 묶음. 개별 설정은 프리셋보다 우선한다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Preset {
+    /// 각 설정의 기본값을 그대로 쓴다.
+    #[default]
+    Default,
+    /// 프로토타이핑용. 작은 모델, 명확화 질문과 AI 파일 검증 생략, 리뷰 1회, 짧은 빌드/테스트 제한 시간.
+    Quick,
+    /// 리뷰 반복과 빌드/테스트 제한 시간을 늘린다.
+    Thorough,
+}
+
+impl std::str::FromStr for Preset {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "default" => Ok(Preset::Default),
+            "quick" => Ok(Preset::Quick),
+            "thorough" => Ok(Preset::Thorough),
+            _ => Err(format!("unknown preset: {}", value)),
+        }
+    }
+}
+
+/// 화면 폭보다 긴 줄을 나누는 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WrapMode {
+    /// 문자 폭만 보고 나눈다. 영어 단어가 중간에 잘릴 수 있다.
+    #[default]
+    Char,
+    /// 가능하면 공백에서 나누고, 줄보다 긴 토큰만 문자 단위로 자른다.
+    /// 공백이 없는 한중일 문자는 문자마다 나눌 수 있다.
+    Word,
+}
+
+impl std::str::FromStr for WrapMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "char" => Ok(WrapMode::Char),
+            "word" => Ok(WrapMode::Word),
+            _ => Err(format!("unknown wrap mode: {}", value)),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
     #[error("missing environment variable: {name}")]
     MissingEnvVar { name: String },
+
+    #[error("invalid value for environment variable {name}: {value}")]
+    InvalidEnvVar { name: String, value: String },
+
+    #[error("invalid value for {flag}: {value}")]
+    InvalidCliArg { flag: String, value: String },
+
+    #[error("invalid config file {path}: {message}")]
+    InvalidConfigFile { path: String, message: String },
 }
 
 pub struct Config {
     api_key: String,
+    poll_interval: Duration,
+    max_review_iterations: usize,
+    commit_spec_plan: bool,
+    commit_reports: bool,
+    spec_plan_branch: Option<String>,
+    max_retries: u32,
+    initial_backoff: Duration,
+    continue_on_build_failure: bool,
+    max_build_repair_attempts: usize,
+    continue_on_fatal: bool,
+    verify_build_test: bool,
+    concurrent_review_build: bool,
+    create_pull_request: bool,
+    auto_stash: bool,
+    manual_conflict_resolution: bool,
+    manual_merge_approval: bool,
+    force_add_ignored_artifacts: bool,
+    invocation_warning_threshold: Option<usize>,
+    format_before_build: bool,
+    format_command: Option<String>,
+    output_language: String,
+    worktree_root: Option<PathBuf>,
+    incremental_planning: bool,
+    merge_strategy: MergeStrategy,
+    session_name_style: SessionNameStyle,
+    model_fallbacks: Vec<String>,
+    default_model: String,
+    phase_models: HashMap<UsagePhase, String>,
+    allow_dependency_changes: bool,
+    skip_clarification: bool,
+    validate_session_files: bool,
+    validate_output_schema: bool,
+    build_test_timeout: Duration,
+    max_phase_duration: Option<Duration>,
+    max_concurrent_agents: Option<usize>,
+    build_cache_dir: Option<PathBuf>,
+    debug_log_enabled: bool,
+    debug_log_dir: Option<PathBuf>,
+    keep_failed_worktrees: bool,
+    wrap_mode: WrapMode,
+    verbosity: Verbosity,
+    mouse_scroll: bool,
+    notify_bell: bool,
+    notify_command: Option<String>,
+    base_url: Option<String>,
+    https_proxy: Option<String>,
+    branch_prefix: String,
+    integration_base_branch: Option<String>,
+    prompt_override_mode: PromptOverrideMode,
+    prompt_dir: Option<PathBuf>,
+    context_dirs: Vec<PathBuf>,
+    events_file: Option<PathBuf>,
+    commit_message_template: CommitMessageTemplate,
+    /// 환경 변수와 명령줄 인자로 지정한 값. 설정 파일보다 우선하므로 파일을 읽은 뒤 다시 적용한다.
+    overrides: ConfigOverrides,
 }
 
 impl Config {
+    /// 필수 값만 받고 나머지는 기본값으로 채운다.
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            poll_interval: Duration::from_millis(DEFAULT_POLL_INTERVAL_MILLIS),
+            max_review_iterations: DEFAULT_MAX_REVIEW_ITERATIONS,
+            commit_spec_plan: true,
+            commit_reports: true,
+            spec_plan_branch: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MILLIS),
+            continue_on_build_failure: false,
+            max_build_repair_attempts: DEFAULT_MAX_BUILD_REPAIR_ATTEMPTS,
+            continue_on_fatal: false,
+            verify_build_test: true,
+            concurrent_review_build: false,
+            create_pull_request: false,
+            auto_stash: false,
+            manual_conflict_resolution: false,
+            manual_merge_approval: false,
+            force_add_ignored_artifacts: false,
+            invocation_warning_threshold: None,
+            format_before_build: false,
+            format_command: None,
+            output_language: DEFAULT_OUTPUT_LANGUAGE.to_string(),
+            worktree_root: None,
+            incremental_planning: false,
+            merge_strategy: MergeStrategy::default(),
+            session_name_style: SessionNameStyle::default(),
+            model_fallbacks: Vec::new(),
+            default_model: DEFAULT_MODEL.to_string(),
+            phase_models: HashMap::new(),
+            allow_dependency_changes: false,
+            skip_clarification: false,
+            validate_session_files: true,
+            validate_output_schema: true,
+            build_test_timeout: DEFAULT_BUILD_TEST_TIMEOUT,
+            max_phase_duration: Some(Duration::from_secs(DEFAULT_MAX_PHASE_DURATION_SECS)),
+            max_concurrent_agents: None,
+            build_cache_dir: None,
+            debug_log_enabled: true,
+            debug_log_dir: None,
+            keep_failed_worktrees: false,
+            wrap_mode: WrapMode::default(),
+            verbosity: Verbosity::default(),
+            mouse_scroll: true,
+            notify_bell: true,
+            notify_command: None,
+            base_url: None,
+            https_proxy: None,
+            branch_prefix: DEFAULT_INTEGRATION_BRANCH_PREFIX.to_string(),
+            integration_base_branch: None,
+            prompt_override_mode: PromptOverrideMode::default(),
+            prompt_dir: None,
+            context_dirs: Vec::new(),
+            events_file: None,
+            commit_message_template: CommitMessageTemplate::default(),
+            overrides: ConfigOverrides::default(),
+        }
+    }
+
+    /// 프리셋의 설정 묶음을 적용한다. 이후에 호출한 개별 `with_*`가 프리셋 값을 덮어쓴다.
+    pub fn with_preset(self, preset: Preset) -> Self {
+        match preset {
+            Preset::Default => self,
+            Preset::Quick => self
+                .with_default_model(QUICK_PRESET_MODEL.to_string())
+                .with_max_review_iterations(QUICK_PRESET_MAX_REVIEW_ITERATIONS)
+                .with_skip_clarification(true)
+                .with_validate_session_files(false)
+                .with_build_test_timeout(QUICK_PRESET_BUILD_TEST_TIMEOUT),
+            Preset::Thorough => self
+                .with_max_review_iterations(THOROUGH_PRESET_MAX_REVIEW_ITERATIONS)
+                .with_build_test_timeout(THOROUGH_PRESET_BUILD_TEST_TIMEOUT),
+        }
+    }
+
     pub fn from_env() -> Result<Self, ConfigError> {
         let api_key = read_required_env("ANTHROPIC_API_KEY")?;
-        Ok(Self { api_key })
+        // 프리셋이 정한 값은 개별 환경 변수가 없을 때의 기본값이 된다.
+        let preset = read_optional_env("BEAR_PRESET", Preset::default())?;
+        let base = Self::new(api_key).with_preset(preset);
+
+        let poll_interval_millis =
+            read_optional_env("BEAR_POLL_INTERVAL_MS", DEFAULT_POLL_INTERVAL_MILLIS)?;
+        let commit_spec_plan = read_optional_env("BEAR_COMMIT_SPEC_PLAN", true)?;
+        let commit_reports = read_optional_env("BEAR_COMMIT_REPORTS", true)?;
+        let spec_plan_branch = std::env::var("BEAR_SPEC_PLAN_BRANCH")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let max_retries = read_optional_env("BEAR_MAX_RETRIES", DEFAULT_MAX_RETRIES)?;
+        let initial_backoff_millis =
+            read_optional_env("BEAR_INITIAL_BACKOFF_MS", DEFAULT_INITIAL_BACKOFF_MILLIS)?;
+        let continue_on_build_failure =
+            read_optional_env("BEAR_CONTINUE_ON_BUILD_FAILURE", false)?;
+        let max_build_repair_attempts = read_optional_env(
+            "BEAR_MAX_BUILD_REPAIR_ATTEMPTS",
+            DEFAULT_MAX_BUILD_REPAIR_ATTEMPTS,
+        )?;
+        let incremental_planning = read_optional_env("BEAR_INCREMENTAL_PLANNING", false)?;
+        let session_name_style = read_session_name_style()?;
+        let model_fallbacks = std::env::var("BEAR_MODEL_FALLBACKS")
+            .map(|value| parse_model_list(&value))
+            .unwrap_or_default();
+        let allow_dependency_changes =
+            read_optional_env("BEAR_ALLOW_DEPENDENCY_CHANGES", false)?;
+        let skip_clarification =
+            read_optional_env("BEAR_SKIP_CLARIFICATION", base.skip_clarification)?;
+        let validate_session_files =
+            read_optional_env("BEAR_VALIDATE_SESSION_FILES", base.validate_session_files)?;
+        let validate_output_schema = read_optional_env("BEAR_VALIDATE_OUTPUT_SCHEMA", true)?;
+        let debug_log_enabled = read_optional_env("BEAR_DEBUG_LOG", true)?;
+        let max_phase_duration_secs =
+            read_optional_env("BEAR_MAX_PHASE_DURATION_SECS", DEFAULT_MAX_PHASE_DURATION_SECS)?;
+        let keep_failed_worktrees = read_optional_env("BEAR_KEEP_FAILED_WORKTREES", false)?;
+        let max_concurrent_agents: usize = read_optional_env("BEAR_MAX_CONCURRENT_AGENTS", 0)?;
+        let mouse_scroll = read_optional_env("BEAR_MOUSE_SCROLL", true)?;
+        let notify_bell = read_optional_env("BEAR_NOTIFY_BELL", true)?;
+        let notify_command = std::env::var("BEAR_NOTIFY_COMMAND")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let base_url = std::env::var("BEAR_BASE_URL")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let https_proxy = std::env::var("BEAR_HTTPS_PROXY")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        // 빌드는 태스크 워크트리에서 실행되므로 상대 경로는 bear를 실행한 디렉토리 기준으로 바꾼다.
+        let build_cache_dir = std::env::var("BEAR_BUILD_CACHE_DIR")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .map(|path| std::path::absolute(&path).unwrap_or(path));
+        let debug_log_dir = std::env::var("BEAR_DEBUG_LOG_DIR")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
+        let prompt_dir = std::env::var("BEAR_PROMPT_DIR")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
+        let context_dirs = read_context_dirs();
+
+        Ok(base
+            .with_poll_interval(Duration::from_millis(poll_interval_millis))
+            .with_commit_spec_plan(commit_spec_plan)
+            .with_commit_reports(commit_reports)
+            .with_spec_plan_branch(spec_plan_branch)
+            .with_max_retries(max_retries)
+            .with_initial_backoff(Duration::from_millis(initial_backoff_millis))
+            .with_continue_on_build_failure(continue_on_build_failure)
+            .with_max_build_repair_attempts(max_build_repair_attempts)
+            .with_incremental_planning(incremental_planning)
+            .with_session_name_style(session_name_style)
+            .with_model_fallbacks(model_fallbacks)
+            .with_phase_models(read_phase_models())
+            .with_allow_dependency_changes(allow_dependency_changes)
+            .with_skip_clarification(skip_clarification)
+            .with_validate_session_files(validate_session_files)
+            .with_validate_output_schema(validate_output_schema)
+            .with_debug_log_enabled(debug_log_enabled)
+            .with_max_phase_duration(
+                (max_phase_duration_secs > 0).then(|| Duration::from_secs(max_phase_duration_secs)),
+            )
+            .with_max_concurrent_agents(
+                (max_concurrent_agents > 0).then_some(max_concurrent_agents),
+            )
+            .with_debug_log_dir(debug_log_dir)
+            .with_build_cache_dir(build_cache_dir)
+            .with_keep_failed_worktrees(keep_failed_worktrees)
+            .with_mouse_scroll(mouse_scroll)
+            .with_notify_bell(notify_bell)
+            .with_notify_command(notify_command)
+            .with_base_url(base_url)
+            .with_https_proxy(https_proxy)
+            .with_prompt_dir(prompt_dir)
+            .with_context_dirs(context_dirs)
+            .with_overrides(ConfigOverrides::from_env()?))
+    }
+
+    /// 환경 변수나 명령줄 인자로 지정한 값을 적용한다. 나중에 적용한 값이 우선하며,
+    /// 이후 `load_workspace_file`로 읽는 설정 파일보다 항상 우선한다.
+    pub fn with_overrides(mut self, overrides: ConfigOverrides) -> Self {
+        overrides.apply_to(&mut self);
+        self.overrides = std::mem::take(&mut self.overrides).merged_with(overrides);
+        self
+    }
+
+    /// 워크스페이스 루트의 `.bearconfig`를 읽어 적용한다. 환경 변수와 명령줄 인자로 지정한 값은
+    /// 파일 값보다 우선한다. 파일이 없으면 아무것도 바꾸지 않고 `None`을 반환한다.
+    pub fn load_workspace_file(
+        &mut self,
+        workspace: &Path,
+    ) -> Result<Option<PathBuf>, ConfigError> {
+        let path = workspace.join(WORKSPACE_CONFIG_FILE_NAME);
+        let Some(file_overrides) = ConfigOverrides::from_file(&path)? else {
+            return Ok(None);
+        };
+        file_overrides.apply_to(self);
+        self.overrides.clone().apply_to(self);
+        Ok(Some(path))
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_max_review_iterations(mut self, max_review_iterations: usize) -> Self {
+        self.max_review_iterations = max_review_iterations;
+        self
+    }
+
+    pub fn with_commit_spec_plan(mut self, commit_spec_plan: bool) -> Self {
+        self.commit_spec_plan = commit_spec_plan;
+        self
+    }
+
+    pub fn with_commit_reports(mut self, commit_reports: bool) -> Self {
+        self.commit_reports = commit_reports;
+        self
+    }
+
+    pub fn with_spec_plan_branch(mut self, spec_plan_branch: Option<String>) -> Self {
+        self.spec_plan_branch = spec_plan_branch;
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_build_repair_attempts(mut self, max_build_repair_attempts: usize) -> Self {
+        self.max_build_repair_attempts = max_build_repair_attempts;
+        self
+    }
+
+    pub fn with_continue_on_build_failure(mut self, continue_on_build_failure: bool) -> Self {
+        self.continue_on_build_failure = continue_on_build_failure;
+        self
+    }
+
+    pub fn with_continue_on_fatal(mut self, continue_on_fatal: bool) -> Self {
+        self.continue_on_fatal = continue_on_fatal;
+        self
+    }
+
+    pub fn with_verify_build_test(mut self, verify_build_test: bool) -> Self {
+        self.verify_build_test = verify_build_test;
+        self
+    }
+
+    pub fn with_concurrent_review_build(mut self, concurrent_review_build: bool) -> Self {
+        self.concurrent_review_build = concurrent_review_build;
+        self
+    }
+
+    pub fn with_create_pull_request(mut self, create_pull_request: bool) -> Self {
+        self.create_pull_request = create_pull_request;
+        self
+    }
+
+    pub fn with_auto_stash(mut self, auto_stash: bool) -> Self {
+        self.auto_stash = auto_stash;
+        self
+    }
+
+    pub fn with_manual_merge_approval(mut self, manual_merge_approval: bool) -> Self {
+        self.manual_merge_approval = manual_merge_approval;
+        self
+    }
+
+    pub fn with_manual_conflict_resolution(mut self, manual_conflict_resolution: bool) -> Self {
+        self.manual_conflict_resolution = manual_conflict_resolution;
+        self
+    }
+
+    pub fn with_format_before_build(mut self, format_before_build: bool) -> Self {
+        self.format_before_build = format_before_build;
+        self
+    }
+
+    pub fn with_format_command(mut self, format_command: Option<String>) -> Self {
+        self.format_command = format_command;
+        self
+    }
+
+    pub fn with_output_language(mut self, output_language: String) -> Self {
+        self.output_language = output_language;
+        self
+    }
+
+    pub fn with_force_add_ignored_artifacts(mut self, force_add_ignored_artifacts: bool) -> Self {
+        self.force_add_ignored_artifacts = force_add_ignored_artifacts;
+        self
+    }
+
+    pub fn with_invocation_warning_threshold(
+        mut self,
+        invocation_warning_threshold: Option<usize>,
+    ) -> Self {
+        self.invocation_warning_threshold = invocation_warning_threshold;
+        self
+    }
+
+    pub fn with_worktree_root(mut self, worktree_root: Option<PathBuf>) -> Self {
+        self.worktree_root = worktree_root;
+        self
+    }
+
+    pub fn with_incremental_planning(mut self, incremental_planning: bool) -> Self {
+        self.incremental_planning = incremental_planning;
+        self
+    }
+
+    pub fn with_merge_strategy(mut self, merge_strategy: MergeStrategy) -> Self {
+        self.merge_strategy = merge_strategy;
+        self
+    }
+
+    pub fn with_session_name_style(mut self, session_name_style: SessionNameStyle) -> Self {
+        self.session_name_style = session_name_style;
+        self
+    }
+
+    pub fn with_model_fallbacks(mut self, model_fallbacks: Vec<String>) -> Self {
+        self.model_fallbacks = model_fallbacks;
+        self
+    }
+
+    pub fn with_default_model(mut self, default_model: String) -> Self {
+        self.default_model = default_model;
+        self
+    }
+
+    pub fn with_phase_model(mut self, phase: UsagePhase, model: String) -> Self {
+        self.phase_models.insert(phase, model);
+        self
+    }
+
+    pub fn with_allow_dependency_changes(mut self, allow_dependency_changes: bool) -> Self {
+        self.allow_dependency_changes = allow_dependency_changes;
+        self
+    }
+
+    pub fn with_skip_clarification(mut self, skip_clarification: bool) -> Self {
+        self.skip_clarification = skip_clarification;
+        self
+    }
+
+    pub fn with_validate_session_files(mut self, validate_session_files: bool) -> Self {
+        self.validate_session_files = validate_session_files;
+        self
+    }
+
+    pub fn with_validate_output_schema(mut self, validate_output_schema: bool) -> Self {
+        self.validate_output_schema = validate_output_schema;
+        self
+    }
+
+    pub fn with_build_test_timeout(mut self, build_test_timeout: Duration) -> Self {
+        self.build_test_timeout = build_test_timeout;
+        self
+    }
+
+    pub fn with_max_concurrent_agents(mut self, max_concurrent_agents: Option<usize>) -> Self {
+        self.max_concurrent_agents = max_concurrent_agents;
+        self
+    }
+
+    pub fn with_max_phase_duration(mut self, max_phase_duration: Option<Duration>) -> Self {
+        self.max_phase_duration = max_phase_duration;
+        self
+    }
+
+    pub fn with_debug_log_enabled(mut self, debug_log_enabled: bool) -> Self {
+        self.debug_log_enabled = debug_log_enabled;
+        self
+    }
+
+    pub fn with_debug_log_dir(mut self, debug_log_dir: Option<PathBuf>) -> Self {
+        self.debug_log_dir = debug_log_dir;
+        self
+    }
+
+    pub fn with_build_cache_dir(mut self, build_cache_dir: Option<PathBuf>) -> Self {
+        self.build_cache_dir = build_cache_dir;
+        self
+    }
+
+    pub fn with_keep_failed_worktrees(mut self, keep_failed_worktrees: bool) -> Self {
+        self.keep_failed_worktrees = keep_failed_worktrees;
+        self
+    }
+
+    pub fn with_wrap_mode(mut self, wrap_mode: WrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    pub fn with_mouse_scroll(mut self, mouse_scroll: bool) -> Self {
+        self.mouse_scroll = mouse_scroll;
+        self
+    }
+
+    pub fn with_notify_bell(mut self, notify_bell: bool) -> Self {
+        self.notify_bell = notify_bell;
+        self
+    }
+
+    pub fn with_notify_command(mut self, notify_command: Option<String>) -> Self {
+        self.notify_command = notify_command;
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_https_proxy(mut self, https_proxy: Option<String>) -> Self {
+        self.https_proxy = https_proxy;
+        self
+    }
+
+    pub fn with_branch_prefix(mut self, branch_prefix: String) -> Self {
+        self.branch_prefix = branch_prefix;
+        self
+    }
+
+    pub fn with_integration_base_branch(mut self, integration_base_branch: Option<String>) -> Self {
+        self.integration_base_branch = integration_base_branch;
+        self
+    }
+
+    pub fn with_prompt_override_mode(mut self, prompt_override_mode: PromptOverrideMode) -> Self {
+        self.prompt_override_mode = prompt_override_mode;
+        self
+    }
+
+    pub fn with_prompt_dir(mut self, prompt_dir: Option<PathBuf>) -> Self {
+        self.prompt_dir = prompt_dir;
+        self
+    }
+
+    pub fn with_context_dirs(mut self, context_dirs: Vec<PathBuf>) -> Self {
+        self.context_dirs = context_dirs;
+        self
+    }
+
+    pub fn with_events_file(mut self, events_file: Option<PathBuf>) -> Self {
+        self.events_file = events_file;
+        self
+    }
+
+    pub fn with_commit_message_template(
+        mut self,
+        commit_message_template: CommitMessageTemplate,
+    ) -> Self {
+        self.commit_message_template = commit_message_template;
+        self
+    }
+
+    fn with_phase_models(mut self, phase_models: HashMap<UsagePhase, String>) -> Self {
+        self.phase_models = phase_models;
+        self
     }
 
     pub fn api_key(&self) -> &str {
         &self.api_key
     }
+
+    /// 에이전트 메시지나 터미널 입력이 없을 때 화면을 다시 그리는 최대 대기 시간.
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// 태스크당 코드 리뷰 최대 반복 횟수. 0이면 리뷰를 건너뛴다.
+    pub fn max_review_iterations(&self) -> usize {
+        self.max_review_iterations
+    }
+
+    /// 승인된 스펙/플랜 파일을 워크스페이스 저장소에 자동으로 커밋할지 여부.
+    pub fn commit_spec_plan(&self) -> bool {
+        self.commit_spec_plan
+    }
+
+    /// 태스크 리포트를 태스크 브랜치의 `.bear/` 아래에 커밋해 통합 브랜치 히스토리에 남길지 여부.
+    /// false이면 리포트는 워크스페이스의 저널 디렉토리에만 저장한다.
+    pub fn commit_reports(&self) -> bool {
+        self.commit_reports
+    }
+
+    /// 스펙/플랜을 커밋할 브랜치. None이면 현재 체크아웃된 브랜치에 커밋한다.
+    pub fn spec_plan_branch(&self) -> Option<&str> {
+        self.spec_plan_branch.as_deref()
+    }
+
+    /// 일시적인 CLI 실패를 재시도하는 최대 횟수. 0이면 재시도하지 않는다.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// 첫 재시도 전 대기 시간. 이후 재시도마다 두 배로 늘어난다.
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// 빌드/테스트가 실패했을 때 태스크마다 수리 에이전트를 실행할 최대 횟수. 수리할 때마다
+    /// 빌드/테스트를 다시 실행하고, 그 결과의 오류 출력을 다음 수리에 넘긴다. 0이면 수리하지 않는다.
+    pub fn max_build_repair_attempts(&self) -> usize {
+        self.max_build_repair_attempts
+    }
+
+    /// 빌드/테스트가 수리 후에도 실패한 태스크를 차단하지 않고 그대로 머지할지 여부.
+    /// 탐색용 실행을 위한 위험한 옵션이므로 기본값은 false다.
+    pub fn continue_on_build_failure(&self) -> bool {
+        self.continue_on_build_failure
+    }
+
+    /// 코딩 중 에이전트와의 통신 오류를 실행 전체의 치명적 오류로 보지 않고,
+    /// 해당 태스크만 차단된 것으로 처리한 뒤 다음 태스크로 넘어갈지 여부.
+    pub fn continue_on_fatal(&self) -> bool {
+        self.continue_on_fatal
+    }
+
+    /// 리베이스(또는 충돌 해결)에 성공한 태스크를 머지하기 전에 빌드/테스트로 검증할지 여부.
+    /// false이면 검증 없이 바로 머지하며, 작업 요약에 검증을 건너뛰었다고 남긴다.
+    pub fn verify_build_test(&self) -> bool {
+        self.verify_build_test
+    }
+
+    /// 코드 리뷰와 빌드/테스트 검증을 태스크 워크트리에서 동시에 실행할지 여부.
+    /// 켜져 있으면 둘 다 통과해야 머지하며, 리베이스로 커밋이 바뀌지 않으면 빌드/테스트를
+    /// 다시 실행하지 않는다.
+    pub fn concurrent_review_build(&self) -> bool {
+        self.concurrent_review_build
+    }
+
+    /// 코딩 단계가 끝나면 통합 브랜치를 푸시하고 `gh` CLI로 풀 리퀘스트를 만들지 여부.
+    pub fn create_pull_request(&self) -> bool {
+        self.create_pull_request
+    }
+
+    /// 통합 브랜치를 만들기 전에 워크스페이스의 커밋하지 않은 변경을 stash에 넣고, 코딩 단계가
+    /// 끝나거나 종료할 때 되돌릴지 여부. 꺼져 있으면 변경이 있는 워크스페이스에서는 시작하지 않는다.
+    pub fn auto_stash(&self) -> bool {
+        self.auto_stash
+    }
+
+    /// 충돌 해결 에이전트가 실패했을 때 태스크를 차단하는 대신, 충돌난 워크트리를 남겨 두고
+    /// 사용자가 직접 해결하기를 기다릴지 여부. headless 모드에서는 쓰지 않는다.
+    pub fn manual_conflict_resolution(&self) -> bool {
+        self.manual_conflict_resolution
+    }
+
+    /// 리뷰와 빌드/테스트를 통과한 태스크를 통합 브랜치에 머지하기 전에 변경 요약을 보여 주고
+    /// 사용자의 승인을 기다릴지 여부. headless 모드에서는 쓰지 않는다.
+    pub fn manual_merge_approval(&self) -> bool {
+        self.manual_merge_approval
+    }
+
+    /// 태스크 워크트리에 복사한 스펙과 플랜이 대상 저장소의 `.gitignore`에 걸릴 때 `git add -f`로
+    /// 스테이징해 태스크 커밋에 넣을지 여부. 꺼져 있으면 걸린다는 사실만 알린다.
+    pub fn force_add_ignored_artifacts(&self) -> bool {
+        self.force_add_ignored_artifacts
+    }
+
+    /// 이 세션의 CLI 호출 수가 이만큼 늘어날 때마다 경고하고, 코딩 중이면 다음 태스크를 시작하기
+    /// 전에 계속할지 묻는다. None이면 경고하지 않는다.
+    pub fn invocation_warning_threshold(&self) -> Option<usize> {
+        self.invocation_warning_threshold
+    }
+
+    /// 빌드/테스트 전에 태스크 워크트리에서 포매터를 실행하고, 바뀐 파일을 커밋할지 여부.
+    pub fn format_before_build(&self) -> bool {
+        self.format_before_build
+    }
+
+    /// 언어별로 고른 포매터 대신 실행할 셸 명령. None이면 프로젝트 종류로 포매터를 고른다.
+    pub fn format_command(&self) -> Option<&str> {
+        self.format_command.as_deref()
+    }
+
+    /// 스펙, 플랜, 태스크, 리포트와 코드 주석을 쓸 언어. 시스템 프롬프트와 작성 지시에 그대로
+    /// 들어가므로 `English`처럼 영어로 된 언어 이름을 쓴다.
+    pub fn output_language(&self) -> &str {
+        &self.output_language
+    }
+
+    /// 태스크 워크트리를 만들 디렉토리. 상대 경로는 워크스페이스 기준이며, None이면 시스템
+    /// 임시 디렉토리 아래에 만든다.
+    pub fn worktree_root(&self) -> Option<&Path> {
+        self.worktree_root.as_deref()
+    }
+
+    /// 전체 플랜을 미리 작성하는 대신 작업을 하나씩 계획하고 구현하는 점진적 계획 모드 여부.
+    pub fn incremental_planning(&self) -> bool {
+        self.incremental_planning
+    }
+
+    /// 리뷰와 빌드/테스트를 통과한 태스크 브랜치를 통합 브랜치에 합치는 방식.
+    pub fn merge_strategy(&self) -> MergeStrategy {
+        self.merge_strategy
+    }
+
+    /// 저널 디렉토리와 통합 브랜치 이름에 쓰이는 세션 이름의 형식.
+    pub fn session_name_style(&self) -> &SessionNameStyle {
+        &self.session_name_style
+    }
+
+    /// 기본 모델이 과부하 등으로 사용할 수 없을 때 순서대로 바꿔 시도할 모델 목록.
+    pub fn model_fallbacks(&self) -> &[String] {
+        &self.model_fallbacks
+    }
+
+    /// 해당 단계의 쿼리에 쓸 모델. 단계별로 지정하지 않았으면 기본 모델을 쓴다.
+    pub fn model_for(&self, phase: UsagePhase) -> &str {
+        self.phase_models
+            .get(&phase)
+            .unwrap_or(&self.default_model)
+    }
+
+    /// 코딩 에이전트가 의존성을 추가하거나 매니페스트/락파일을 수정해도 되는지 여부.
+    /// false면 코딩 프롬프트에 금지 지시를 넣고, 매니페스트 변경을 리뷰 대상으로 표시한다.
+    pub fn allow_dependency_changes(&self) -> bool {
+        self.allow_dependency_changes
+    }
+
+    /// 요구사항 명확화 질문 없이 바로 스펙 작성을 시작할지 여부.
+    pub fn skip_clarification(&self) -> bool {
+        self.skip_clarification
+    }
+
+    /// 이전 세션을 이어서 진행할 때 스펙/플랜 파일을 에이전트로 검증할지 여부.
+    pub fn validate_session_files(&self) -> bool {
+        self.validate_session_files
+    }
+
+    /// 에이전트의 구조화된 출력을 역직렬화하기 전에 요청한 스키마로 검사할지 여부.
+    pub fn validate_output_schema(&self) -> bool {
+        self.validate_output_schema
+    }
+
+    /// 빌드 명령과 테스트 명령 각각에 적용하는 제한 시간.
+    pub fn build_test_timeout(&self) -> Duration {
+        self.build_test_timeout
+    }
+
+    /// 동시에 실행할 수 있는 Claude Code CLI 프로세스 수. None이면 제한하지 않는다.
+    pub fn max_concurrent_agents(&self) -> Option<usize> {
+        self.max_concurrent_agents
+    }
+
+    /// 에이전트 작업 하나가 이 시간을 넘기면 멈춘 것일 수 있다고 알리고 취소를 권한다.
+    /// None이면 알리지 않는다.
+    pub fn max_phase_duration(&self) -> Option<Duration> {
+        self.max_phase_duration
+    }
+
+    /// 쿼리별 프롬프트와 CLI 출력을 디버그 로그 파일에 남길지 여부.
+    pub fn debug_log_enabled(&self) -> bool {
+        self.debug_log_enabled
+    }
+
+    /// 디버그 로그를 쓸 디렉토리. None이면 세션 저널 아래의 `logs` 디렉토리에 쓴다.
+    pub fn debug_log_dir(&self) -> Option<&Path> {
+        self.debug_log_dir.as_deref()
+    }
+
+    /// 태스크 워크트리끼리 공유할 빌드 출력/캐시 디렉토리. None이면 빌드마다 워크트리 안에서
+    /// 처음부터 빌드한다.
+    pub fn build_cache_dir(&self) -> Option<&Path> {
+        self.build_cache_dir.as_deref()
+    }
+
+    /// 차단된 태스크의 워크트리와 브랜치를 사후 분석용으로 남길지 여부.
+    /// 성공한 태스크의 워크트리는 이 설정과 관계없이 정리한다.
+    pub fn keep_failed_worktrees(&self) -> bool {
+        self.keep_failed_worktrees
+    }
+
+    /// 메시지와 입력 영역의 긴 줄을 나누는 방식.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap_mode
+    }
+
+    /// 에이전트가 작업하는 동안 화면에 보여줄 스트리밍 메시지의 양.
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// 마우스 휠로 대화 기록을 스크롤할지 여부. 켜면 터미널의 마우스 이벤트를 가로채므로
+    /// 대부분의 터미널에서 텍스트 선택에 Shift를 함께 눌러야 한다.
+    pub fn mouse_scroll(&self) -> bool {
+        self.mouse_scroll
+    }
+
+    /// 에이전트 작업이 끝나 입력이 필요하거나 전체 작업이 끝났을 때 터미널 벨을 울릴지 여부.
+    pub fn notify_bell(&self) -> bool {
+        self.notify_bell
+    }
+
+    /// 벨과 같은 시점에 `sh -c`로 실행할 알림 명령. 데스크톱 알림 등에 쓴다.
+    pub fn notify_command(&self) -> Option<&str> {
+        self.notify_command.as_deref()
+    }
+
+    /// CLI에 `ANTHROPIC_BASE_URL`로 넘길 API 엔드포인트. 게이트웨이를 거칠 때 쓴다.
+    pub fn base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+
+    /// CLI에 `HTTPS_PROXY`로 넘길 프록시 주소.
+    pub fn https_proxy(&self) -> Option<&str> {
+        self.https_proxy.as_deref()
+    }
+
+    /// 통합 브랜치 이름 `<접두사>/<세션 이름>-<UUID>`의 접두사.
+    pub fn branch_prefix(&self) -> &str {
+        &self.branch_prefix
+    }
+
+    /// 통합 브랜치를 분기할 기준 브랜치. 지정하지 않으면 현재 HEAD에서 분기한다.
+    pub fn integration_base_branch(&self) -> Option<&str> {
+        self.integration_base_branch.as_deref()
+    }
+
+    /// 시스템 프롬프트 오버라이드 파일을 기본 프롬프트에 덧붙일지, 기본 프롬프트를 대신할지.
+    pub fn prompt_override_mode(&self) -> PromptOverrideMode {
+        self.prompt_override_mode
+    }
+
+    /// 워크스페이스의 `.bear/prompts/`에 없는 오버라이드 파일을 찾는 사용자 디렉토리.
+    pub fn prompt_dir(&self) -> Option<&Path> {
+        self.prompt_dir.as_deref()
+    }
+
+    /// 코딩·리뷰 에이전트가 워크트리 밖에서 읽을 수 있도록 `--add-dir`로 넘기는 디렉토리들.
+    /// 에이전트의 작업 디렉토리는 이 값과 관계없이 태스크 워크트리다.
+    pub fn context_dirs(&self) -> &[PathBuf] {
+        &self.context_dirs
+    }
+
+    /// 단계·태스크 진행 상황을 JSON Lines로 덧붙여 쓰는 파일. 지정하지 않으면 쓰지 않는다.
+    pub fn events_file(&self) -> Option<&Path> {
+        self.events_file.as_deref()
+    }
+
+    /// bear가 태스크마다 만드는 squash 커밋, 머지 커밋, 별도 리포트 커밋의 메시지 템플릿.
+    pub fn commit_message_template(&self) -> &CommitMessageTemplate {
+        &self.commit_message_template
+    }
+}
+
+/// 단계별 모델 환경 변수. 지정하지 않은 단계는 `BEAR_MODEL`을 따른다.
+const PHASE_MODEL_ENV_VARS: &[(UsagePhase, &str)] = &[
+    (UsagePhase::Clarification, "BEAR_MODEL_CLARIFICATION"),
+    (UsagePhase::Spec, "BEAR_MODEL_SPEC"),
+    (UsagePhase::Plan, "BEAR_MODEL_PLAN"),
+    (UsagePhase::Coding, "BEAR_MODEL_CODING"),
+    (UsagePhase::Review, "BEAR_MODEL_REVIEW"),
+];
+
+fn read_phase_models() -> HashMap<UsagePhase, String> {
+    PHASE_MODEL_ENV_VARS
+        .iter()
+        .filter_map(|(phase, name)| read_model_env(name).map(|model| (*phase, model)))
+        .collect()
+}
+
+fn read_model_env(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// 쉼표로 구분된 모델 이름 목록을 읽는다. 빈 항목은 무시한다.
+fn parse_model_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|model| model.trim())
+        .filter(|model| !model.is_empty())
+        .map(|model| model.to_string())
+        .collect()
+}
+
+/// `BEAR_CONTEXT_DIRS`를 `PATH`처럼 플랫폼의 경로 구분자로 나눠 읽는다. 에이전트의 작업
+/// 디렉토리는 워크트리이므로 상대 경로는 bear를 실행한 디렉토리 기준의 절대 경로로 바꾼다.
+fn read_context_dirs() -> Vec<PathBuf> {
+    let Some(value) = std::env::var_os("BEAR_CONTEXT_DIRS") else {
+        return Vec::new();
+    };
+    std::env::split_paths(&value)
+        .filter(|path| !path.as_os_str().is_empty())
+        .map(|path| std::path::absolute(&path).unwrap_or(path))
+        .collect()
 }
 
 fn read_required_env(name: &str) -> Result<String, ConfigError> {
@@ -24,3 +975,99 @@ fn read_required_env(name: &str) -> Result<String, ConfigError> {
         name: name.to_string(),
     })
 }
+
+/// 세션 이름 형식 관련 환경 변수를 읽는다. 구분자는 디렉토리와 브랜치 이름에
+/// 안전한 `-`, `_`, `.` 중 하나여야 한다.
+fn read_session_name_style() -> Result<SessionNameStyle, ConfigError> {
+    let default = SessionNameStyle::default();
+    let prefix = std::env::var("BEAR_SESSION_NAME_PREFIX")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let separator: char = read_optional_env("BEAR_SESSION_NAME_SEPARATOR", default.separator)?;
+    if !matches!(separator, '-' | '_' | '.') {
+        return Err(ConfigError::InvalidEnvVar {
+            name: "BEAR_SESSION_NAME_SEPARATOR".to_string(),
+            value: separator.to_string(),
+        });
+    }
+    let max_length = read_optional_env("BEAR_SESSION_NAME_MAX_LENGTH", default.max_length)?;
+    let casing: NameCasing = read_optional_env("BEAR_SESSION_NAME_CASE", default.casing)?;
+
+    Ok(SessionNameStyle {
+        prefix,
+        separator,
+        max_length,
+        casing,
+    })
+}
+
+fn read_optional_env<T: std::str::FromStr>(name: &str, default: T) -> Result<T, ConfigError> {
+    match std::env::var(name) {
+        Ok(value) => parse_env_value(name, &value),
+        Err(_) => Ok(default),
+    }
+}
+
+fn parse_env_value<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, ConfigError> {
+    value.trim().parse().map_err(|_| ConfigError::InvalidEnvVar {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quick_preset_sets_bundled_options() {
+        let config = Config::new("test-key".to_string()).with_preset(Preset::Quick);
+
+        assert_eq!(config.model_for(UsagePhase::Coding), QUICK_PRESET_MODEL);
+        assert_eq!(config.max_review_iterations(), 1);
+        assert!(config.skip_clarification());
+        assert!(!config.validate_session_files());
+        assert_eq!(config.build_test_timeout(), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn explicit_options_override_preset() {
+        let config = Config::new("test-key".to_string())
+            .with_preset(Preset::Quick)
+            .with_max_review_iterations(3)
+            .with_default_model("claude-opus-4-6".to_string())
+            .with_skip_clarification(false);
+
+        assert_eq!(config.max_review_iterations(), 3);
+        assert_eq!(config.model_for(UsagePhase::Review), "claude-opus-4-6");
+        assert!(!config.skip_clarification());
+        assert!(!config.validate_session_files());
+    }
+
+    #[test]
+    fn default_preset_keeps_defaults() {
+        let config = Config::new("test-key".to_string()).with_preset(Preset::Default);
+
+        assert_eq!(config.max_review_iterations(), DEFAULT_MAX_REVIEW_ITERATIONS);
+        assert_eq!(config.model_for(UsagePhase::Coding), DEFAULT_MODEL);
+        assert!(!config.skip_clarification());
+        assert!(config.validate_session_files());
+        assert_eq!(config.build_test_timeout(), DEFAULT_BUILD_TEST_TIMEOUT);
+    }
+
+    #[test]
+    fn preset_parses_case_insensitively() {
+        assert_eq!("Quick".parse::<Preset>(), Ok(Preset::Quick));
+        assert_eq!("thorough".parse::<Preset>(), Ok(Preset::Thorough));
+        assert!("fast".parse::<Preset>().is_err());
+    }
+
+    #[test]
+    fn wrap_mode_parses_case_insensitively() {
+        assert_eq!("Word".parse::<WrapMode>(), Ok(WrapMode::Word));
+        assert_eq!("char".parse::<WrapMode>(), Ok(WrapMode::Char));
+        assert!("line".parse::<WrapMode>().is_err());
+        assert_eq!(Config::new("test-key".to_string()).wrap_mode(), WrapMode::Char);
+    }
+}
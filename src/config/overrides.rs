@@ -0,0 +1,611 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::{
+    CommitMessageTemplate, Config, ConfigError, MergeStrategy, PromptOverrideMode, WrapMode,
+};
+use crate::claude_code_client::Verbosity;
+
+/// 워크스페이스 루트에서 읽는 설정 파일 이름.
+pub const WORKSPACE_CONFIG_FILE_NAME: &str = ".bearconfig";
+
+/// 설정 파일, 환경 변수, 명령줄 인자로 바꿀 수 있는 설정 값. 지정하지 않은 항목은 `None`이며
+/// 적용할 때 기존 값을 그대로 둔다. 우선순위는 명령줄 인자 > 환경 변수 > 설정 파일 > 기본값이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    pub model: Option<String>,
+    pub max_review_iterations: Option<usize>,
+    pub build_test_timeout_secs: Option<u64>,
+    pub merge_strategy: Option<MergeStrategy>,
+    pub wrap_mode: Option<WrapMode>,
+    pub verbosity: Option<Verbosity>,
+    pub continue_on_fatal: Option<bool>,
+    pub verify_build_test: Option<bool>,
+    pub concurrent_review_build: Option<bool>,
+    pub create_pull_request: Option<bool>,
+    pub auto_stash: Option<bool>,
+    pub manual_conflict_resolution: Option<bool>,
+    pub manual_merge_approval: Option<bool>,
+    pub force_add_ignored_artifacts: Option<bool>,
+    pub invocation_warning_threshold: Option<usize>,
+    pub format_before_build: Option<bool>,
+    pub format_command: Option<String>,
+    pub output_language: Option<String>,
+    pub worktree_root: Option<PathBuf>,
+    pub branch_prefix: Option<String>,
+    pub integration_base_branch: Option<String>,
+    pub prompt_override_mode: Option<PromptOverrideMode>,
+    pub commit_message_template: Option<CommitMessageTemplate>,
+    /// 실행하는 컴퓨터마다 다른 경로이므로 설정 파일에서는 읽지 않는다.
+    pub events_file: Option<PathBuf>,
+}
+
+/// `.bearconfig` 파일의 형식. 오타를 조용히 무시하지 않도록 모르는 키는 오류로 처리한다.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct WorkspaceConfigFile {
+    model: Option<String>,
+    max_review_iterations: Option<usize>,
+    build_test_timeout_secs: Option<u64>,
+    merge_strategy: Option<String>,
+    wrap_mode: Option<String>,
+    verbosity: Option<String>,
+    continue_on_fatal: Option<bool>,
+    verify_build_test: Option<bool>,
+    concurrent_review_build: Option<bool>,
+    create_pull_request: Option<bool>,
+    auto_stash: Option<bool>,
+    manual_conflict_resolution: Option<bool>,
+    manual_merge_approval: Option<bool>,
+    force_add_ignored_artifacts: Option<bool>,
+    invocation_warning_threshold: Option<usize>,
+    format_before_build: Option<bool>,
+    format_command: Option<String>,
+    output_language: Option<String>,
+    worktree_root: Option<String>,
+    branch_prefix: Option<String>,
+    integration_base_branch: Option<String>,
+    prompt_override_mode: Option<String>,
+    commit_message_template: Option<String>,
+}
+
+impl ConfigOverrides {
+    /// 환경 변수에서 읽는다. 설정되지 않은 변수는 `None`으로 남긴다.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            model: super::read_model_env("BEAR_MODEL"),
+            max_review_iterations: read_env("BEAR_MAX_REVIEW_ITERATIONS")?,
+            build_test_timeout_secs: read_env("BEAR_BUILD_TEST_TIMEOUT_SECS")?,
+            merge_strategy: read_env("BEAR_MERGE_STRATEGY")?,
+            wrap_mode: read_env("BEAR_WRAP_MODE")?,
+            verbosity: read_env("BEAR_VERBOSITY")?,
+            continue_on_fatal: read_env("BEAR_CONTINUE_ON_FATAL")?,
+            verify_build_test: read_env("BEAR_VERIFY_BUILD_TEST")?,
+            concurrent_review_build: read_env("BEAR_CONCURRENT_REVIEW_BUILD")?,
+            create_pull_request: read_env("BEAR_CREATE_PULL_REQUEST")?,
+            auto_stash: read_env("BEAR_AUTO_STASH")?,
+            manual_conflict_resolution: read_env("BEAR_MANUAL_CONFLICT_RESOLUTION")?,
+            manual_merge_approval: read_env("BEAR_MANUAL_MERGE_APPROVAL")?,
+            force_add_ignored_artifacts: read_env("BEAR_FORCE_ADD_IGNORED_ARTIFACTS")?,
+            invocation_warning_threshold: read_env("BEAR_INVOCATION_WARNING_THRESHOLD")?,
+            format_before_build: read_env("BEAR_FORMAT_BEFORE_BUILD")?,
+            format_command: read_env::<String>("BEAR_FORMAT_COMMAND")?.and_then(non_empty),
+            output_language: read_env::<String>("BEAR_OUTPUT_LANGUAGE")?.and_then(non_empty),
+            worktree_root: read_env::<String>("BEAR_WORKTREE_ROOT")?
+                .and_then(non_empty)
+                .map(PathBuf::from),
+            branch_prefix: read_env::<String>("BEAR_BRANCH_PREFIX")?.and_then(branch_prefix),
+            integration_base_branch: read_env::<String>("BEAR_INTEGRATION_BASE_BRANCH")?
+                .and_then(non_empty),
+            prompt_override_mode: read_env("BEAR_PROMPT_OVERRIDE_MODE")?,
+            commit_message_template: read_env("BEAR_COMMIT_MESSAGE_TEMPLATE")?,
+            events_file: read_env::<String>("BEAR_EVENTS_FILE")?
+                .and_then(non_empty)
+                .map(events_file),
+        })
+    }
+
+    /// TOML 형식의 설정 파일을 읽는다. 파일이 없으면 `None`을 반환한다.
+    pub fn from_file(path: &Path) -> Result<Option<Self>, ConfigError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(invalid_file(path, err.to_string())),
+        };
+        let file: WorkspaceConfigFile =
+            toml::from_str(&content).map_err(|err| invalid_file(path, err.to_string()))?;
+
+        Ok(Some(Self {
+            model: file.model.map(|model| model.trim().to_string()).filter(|m| !m.is_empty()),
+            max_review_iterations: file.max_review_iterations,
+            build_test_timeout_secs: file.build_test_timeout_secs,
+            merge_strategy: parse_file_field(path, "merge_strategy", file.merge_strategy)?,
+            wrap_mode: parse_file_field(path, "wrap_mode", file.wrap_mode)?,
+            verbosity: parse_file_field(path, "verbosity", file.verbosity)?,
+            continue_on_fatal: file.continue_on_fatal,
+            verify_build_test: file.verify_build_test,
+            concurrent_review_build: file.concurrent_review_build,
+            create_pull_request: file.create_pull_request,
+            auto_stash: file.auto_stash,
+            manual_conflict_resolution: file.manual_conflict_resolution,
+            manual_merge_approval: file.manual_merge_approval,
+            force_add_ignored_artifacts: file.force_add_ignored_artifacts,
+            invocation_warning_threshold: file.invocation_warning_threshold,
+            format_before_build: file.format_before_build,
+            format_command: file.format_command.and_then(non_empty),
+            output_language: file.output_language.and_then(non_empty),
+            worktree_root: file.worktree_root.and_then(non_empty).map(PathBuf::from),
+            branch_prefix: file.branch_prefix.and_then(branch_prefix),
+            integration_base_branch: file.integration_base_branch.and_then(non_empty),
+            prompt_override_mode: parse_file_field(
+                path,
+                "prompt_override_mode",
+                file.prompt_override_mode,
+            )?,
+            commit_message_template: parse_file_field(
+                path,
+                "commit_message_template",
+                file.commit_message_template,
+            )?,
+            events_file: None,
+        }))
+    }
+
+    /// 명령줄 설정 플래그 하나를 반영한다. 설정 플래그가 아니면 false를 반환한다.
+    pub fn set_cli_flag(&mut self, flag: &str, value: &str) -> Result<bool, ConfigError> {
+        let invalid = || ConfigError::InvalidCliArg {
+            flag: flag.to_string(),
+            value: value.to_string(),
+        };
+        match flag {
+            "--model" => {
+                let model = value.trim();
+                if model.is_empty() {
+                    return Err(invalid());
+                }
+                self.model = Some(model.to_string());
+            }
+            "--max-review-iterations" => {
+                self.max_review_iterations = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--build-test-timeout-secs" => {
+                self.build_test_timeout_secs = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--merge-strategy" => {
+                self.merge_strategy = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--wrap-mode" => {
+                self.wrap_mode = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--verbosity" => {
+                self.verbosity = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--invocation-warning-threshold" => {
+                self.invocation_warning_threshold =
+                    Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--branch-prefix" => {
+                self.branch_prefix = Some(branch_prefix(value.to_string()).ok_or_else(invalid)?);
+            }
+            "--integration-base-branch" => {
+                self.integration_base_branch =
+                    Some(non_empty(value.to_string()).ok_or_else(invalid)?);
+            }
+            "--format-command" => {
+                self.format_command = Some(non_empty(value.to_string()).ok_or_else(invalid)?);
+            }
+            "--output-language" => {
+                self.output_language = Some(non_empty(value.to_string()).ok_or_else(invalid)?);
+            }
+            "--worktree-root" => {
+                let path = non_empty(value.to_string()).ok_or_else(invalid)?;
+                self.worktree_root = Some(PathBuf::from(path));
+            }
+            "--prompt-override-mode" => {
+                self.prompt_override_mode = Some(value.trim().parse().map_err(|_| invalid())?);
+            }
+            "--commit-message-template" => {
+                self.commit_message_template = Some(value.parse().map_err(|_| invalid())?);
+            }
+            "--events" => {
+                let path = non_empty(value.to_string()).ok_or_else(invalid)?;
+                self.events_file = Some(events_file(path));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// 값을 받지 않는 명령줄 스위치 하나를 반영한다. 설정 스위치가 아니면 false를 반환한다.
+    pub fn set_cli_switch(&mut self, flag: &str) -> bool {
+        match flag {
+            "--continue-on-fatal" => self.continue_on_fatal = Some(true),
+            "--skip-build-test" => self.verify_build_test = Some(false),
+            "--concurrent-review-build" => self.concurrent_review_build = Some(true),
+            "--create-pr" => self.create_pull_request = Some(true),
+            "--auto-stash" => self.auto_stash = Some(true),
+            "--manual-conflict-resolution" => self.manual_conflict_resolution = Some(true),
+            "--manual-merge-approval" => self.manual_merge_approval = Some(true),
+            "--force-add-ignored-artifacts" => self.force_add_ignored_artifacts = Some(true),
+            "--format-before-build" => self.format_before_build = Some(true),
+            _ => return false,
+        }
+        true
+    }
+
+    /// `higher`에 지정된 값이 이 값보다 우선하도록 합친다.
+    pub fn merged_with(self, higher: ConfigOverrides) -> Self {
+        Self {
+            model: higher.model.or(self.model),
+            max_review_iterations: higher.max_review_iterations.or(self.max_review_iterations),
+            build_test_timeout_secs: higher
+                .build_test_timeout_secs
+                .or(self.build_test_timeout_secs),
+            merge_strategy: higher.merge_strategy.or(self.merge_strategy),
+            wrap_mode: higher.wrap_mode.or(self.wrap_mode),
+            verbosity: higher.verbosity.or(self.verbosity),
+            continue_on_fatal: higher.continue_on_fatal.or(self.continue_on_fatal),
+            verify_build_test: higher.verify_build_test.or(self.verify_build_test),
+            concurrent_review_build: higher
+                .concurrent_review_build
+                .or(self.concurrent_review_build),
+            create_pull_request: higher.create_pull_request.or(self.create_pull_request),
+            auto_stash: higher.auto_stash.or(self.auto_stash),
+            manual_conflict_resolution: higher
+                .manual_conflict_resolution
+                .or(self.manual_conflict_resolution),
+            manual_merge_approval: higher.manual_merge_approval.or(self.manual_merge_approval),
+            force_add_ignored_artifacts: higher
+                .force_add_ignored_artifacts
+                .or(self.force_add_ignored_artifacts),
+            invocation_warning_threshold: higher
+                .invocation_warning_threshold
+                .or(self.invocation_warning_threshold),
+            format_before_build: higher.format_before_build.or(self.format_before_build),
+            format_command: higher.format_command.or(self.format_command),
+            output_language: higher.output_language.or(self.output_language),
+            worktree_root: higher.worktree_root.or(self.worktree_root),
+            branch_prefix: higher.branch_prefix.or(self.branch_prefix),
+            integration_base_branch: higher
+                .integration_base_branch
+                .or(self.integration_base_branch),
+            prompt_override_mode: higher.prompt_override_mode.or(self.prompt_override_mode),
+            commit_message_template: higher
+                .commit_message_template
+                .or(self.commit_message_template),
+            events_file: higher.events_file.or(self.events_file),
+        }
+    }
+
+    /// 지정된 값만 `config`에 덮어쓴다.
+    pub(super) fn apply_to(&self, config: &mut Config) {
+        if let Some(model) = &self.model {
+            config.default_model = model.clone();
+        }
+        if let Some(max_review_iterations) = self.max_review_iterations {
+            config.max_review_iterations = max_review_iterations;
+        }
+        if let Some(secs) = self.build_test_timeout_secs {
+            config.build_test_timeout = std::time::Duration::from_secs(secs);
+        }
+        if let Some(merge_strategy) = self.merge_strategy {
+            config.merge_strategy = merge_strategy;
+        }
+        if let Some(wrap_mode) = self.wrap_mode {
+            config.wrap_mode = wrap_mode;
+        }
+        if let Some(verbosity) = self.verbosity {
+            config.verbosity = verbosity;
+        }
+        if let Some(continue_on_fatal) = self.continue_on_fatal {
+            config.continue_on_fatal = continue_on_fatal;
+        }
+        if let Some(verify_build_test) = self.verify_build_test {
+            config.verify_build_test = verify_build_test;
+        }
+        if let Some(concurrent_review_build) = self.concurrent_review_build {
+            config.concurrent_review_build = concurrent_review_build;
+        }
+        if let Some(create_pull_request) = self.create_pull_request {
+            config.create_pull_request = create_pull_request;
+        }
+        if let Some(auto_stash) = self.auto_stash {
+            config.auto_stash = auto_stash;
+        }
+        if let Some(manual_conflict_resolution) = self.manual_conflict_resolution {
+            config.manual_conflict_resolution = manual_conflict_resolution;
+        }
+        if let Some(manual_merge_approval) = self.manual_merge_approval {
+            config.manual_merge_approval = manual_merge_approval;
+        }
+        if let Some(force_add_ignored_artifacts) = self.force_add_ignored_artifacts {
+            config.force_add_ignored_artifacts = force_add_ignored_artifacts;
+        }
+        if let Some(threshold) = self.invocation_warning_threshold {
+            // 0은 경고를 끈다.
+            config.invocation_warning_threshold = (threshold > 0).then_some(threshold);
+        }
+        if let Some(format_before_build) = self.format_before_build {
+            config.format_before_build = format_before_build;
+        }
+        if let Some(format_command) = &self.format_command {
+            config.format_command = Some(format_command.clone());
+        }
+        if let Some(output_language) = &self.output_language {
+            config.output_language = output_language.clone();
+        }
+        if let Some(worktree_root) = &self.worktree_root {
+            config.worktree_root = Some(worktree_root.clone());
+        }
+        if let Some(branch_prefix) = &self.branch_prefix {
+            config.branch_prefix = branch_prefix.clone();
+        }
+        if let Some(integration_base_branch) = &self.integration_base_branch {
+            config.integration_base_branch = Some(integration_base_branch.clone());
+        }
+        if let Some(prompt_override_mode) = self.prompt_override_mode {
+            config.prompt_override_mode = prompt_override_mode;
+        }
+        if let Some(commit_message_template) = &self.commit_message_template {
+            config.commit_message_template = commit_message_template.clone();
+        }
+        if let Some(events_file) = &self.events_file {
+            config.events_file = Some(events_file.clone());
+        }
+    }
+}
+
+fn read_env<T: std::str::FromStr>(name: &str) -> Result<Option<T>, ConfigError> {
+    match std::env::var(name) {
+        Ok(value) => super::parse_env_value(name, &value).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// 앞뒤 공백과 끝의 `/`를 뗀 브랜치 접두사. 남는 것이 없으면 None이다.
+fn branch_prefix(value: String) -> Option<String> {
+    non_empty(value.trim().trim_end_matches('/').to_string())
+}
+
+/// 이벤트 파일 경로. 워크스페이스를 나중에 고르므로 상대 경로는 bear를 실행한 디렉토리 기준의
+/// 절대 경로로 바꿔 둔다.
+fn events_file(value: String) -> PathBuf {
+    let path = PathBuf::from(value);
+    std::path::absolute(&path).unwrap_or(path)
+}
+
+fn parse_file_field<T: std::str::FromStr<Err = String>>(
+    path: &Path,
+    field: &str,
+    value: Option<String>,
+) -> Result<Option<T>, ConfigError> {
+    value
+        .map(|value| value.parse())
+        .transpose()
+        .map_err(|err| invalid_file(path, format!("{}: {}", field, err)))
+}
+
+fn invalid_file(path: &Path, message: String) -> ConfigError {
+    ConfigError::InvalidConfigFile {
+        path: path.display().to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn write_workspace_config(content: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join(WORKSPACE_CONFIG_FILE_NAME), content).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn precedence_is_cli_then_env_then_file_then_default() {
+        let workspace = write_workspace_config(
+            "model = \"file-model\"\n\
+             max_review_iterations = 4\n\
+             merge_strategy = \"squash\"\n\
+             wrap_mode = \"word\"\n",
+        );
+        let env = ConfigOverrides {
+            model: Some("env-model".to_string()),
+            max_review_iterations: Some(6),
+            ..ConfigOverrides::default()
+        };
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--max-review-iterations", "7").unwrap());
+        let mut config = Config::new("test-key".to_string())
+            .with_overrides(env)
+            .with_overrides(cli);
+
+        let loaded = config.load_workspace_file(workspace.path()).unwrap();
+
+        assert_eq!(loaded, Some(workspace.path().join(WORKSPACE_CONFIG_FILE_NAME)));
+        assert_eq!(config.max_review_iterations(), 7);
+        assert_eq!(config.model_for(crate::config::UsagePhase::Coding), "env-model");
+        assert_eq!(config.merge_strategy(), MergeStrategy::Squash);
+        assert_eq!(config.wrap_mode(), WrapMode::Word);
+        assert_eq!(config.build_test_timeout(), crate::config::DEFAULT_BUILD_TEST_TIMEOUT);
+    }
+
+    #[test]
+    fn missing_file_keeps_defaults() {
+        let workspace = TempDir::new().unwrap();
+        let mut config = Config::new("test-key".to_string());
+
+        assert_eq!(config.load_workspace_file(workspace.path()).unwrap(), None);
+        assert_eq!(config.merge_strategy(), MergeStrategy::default());
+        assert_eq!(config.wrap_mode(), WrapMode::default());
+    }
+
+    #[test]
+    fn file_values_replace_defaults() {
+        let workspace = write_workspace_config("build_test_timeout_secs = 30\n");
+        let mut config = Config::new("test-key".to_string());
+
+        config.load_workspace_file(workspace.path()).unwrap();
+
+        assert_eq!(config.build_test_timeout(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn branch_naming_is_read_from_file_and_cli() {
+        let workspace = write_workspace_config(
+            "branch_prefix = \"team/ai/\"\n\
+             integration_base_branch = \"develop\"\n",
+        );
+        let mut config = Config::new("test-key".to_string());
+        assert_eq!(config.branch_prefix(), "bear/integration");
+        assert_eq!(config.integration_base_branch(), None);
+
+        config.load_workspace_file(workspace.path()).unwrap();
+        assert_eq!(config.branch_prefix(), "team/ai");
+        assert_eq!(config.integration_base_branch(), Some("develop"));
+
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--integration-base-branch", "main").unwrap());
+        assert!(cli.set_cli_flag("--branch-prefix", "/").is_err());
+        let config = config.with_overrides(cli);
+        assert_eq!(config.integration_base_branch(), Some("main"));
+        assert_eq!(config.branch_prefix(), "team/ai");
+    }
+
+    #[test]
+    fn prompt_override_mode_is_read_from_file_and_cli() {
+        let workspace = write_workspace_config("prompt_override_mode = \"replace\"\n");
+        let mut config = Config::new("test-key".to_string());
+        assert_eq!(config.prompt_override_mode(), PromptOverrideMode::Append);
+
+        config.load_workspace_file(workspace.path()).unwrap();
+        assert_eq!(config.prompt_override_mode(), PromptOverrideMode::Replace);
+
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--prompt-override-mode", "append").unwrap());
+        assert!(cli.set_cli_flag("--prompt-override-mode", "merge").is_err());
+        let config = config.with_overrides(cli);
+        assert_eq!(config.prompt_override_mode(), PromptOverrideMode::Append);
+    }
+
+    #[test]
+    fn verbosity_is_read_from_file_and_cli() {
+        let workspace = write_workspace_config("verbosity = \"quiet\"\n");
+        let mut config = Config::new("test-key".to_string());
+        assert_eq!(config.verbosity(), Verbosity::Normal);
+
+        config.load_workspace_file(workspace.path()).unwrap();
+        assert_eq!(config.verbosity(), Verbosity::Quiet);
+
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--verbosity", "verbose").unwrap());
+        assert!(cli.set_cli_flag("--verbosity", "loud").is_err());
+        let config = config.with_overrides(cli);
+        assert_eq!(config.verbosity(), Verbosity::Verbose);
+    }
+
+    #[test]
+    fn commit_message_template_is_read_from_file_and_validated() {
+        let workspace =
+            write_workspace_config("commit_message_template = \"feat({task_id}): {title}\"\n");
+        let invalid = write_workspace_config("commit_message_template = \"{title}\"\n");
+        let mut config = Config::new("test-key".to_string());
+        let template = config.commit_message_template();
+        assert_eq!(template.render("TASK-00", "Login"), "[TASK-00] Login");
+
+        config.load_workspace_file(workspace.path()).unwrap();
+        assert_eq!(
+            config.commit_message_template().render("TASK-00", "Login"),
+            "feat(TASK-00): Login",
+        );
+
+        let err = config.load_workspace_file(invalid.path()).unwrap_err();
+        assert!(err.to_string().contains("commit_message_template"), "{}", err);
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--commit-message-template", "{title}").is_err());
+    }
+
+    #[test]
+    fn events_file_is_set_from_cli_as_an_absolute_path_but_not_from_file() {
+        let workspace = write_workspace_config("events_file = \"events.jsonl\"\n");
+        let mut config = Config::new("test-key".to_string());
+        assert!(config.load_workspace_file(workspace.path()).is_err());
+        assert_eq!(config.events_file(), None);
+
+        let mut cli = ConfigOverrides::default();
+        assert!(cli.set_cli_flag("--events", " ").is_err());
+        assert!(cli.set_cli_flag("--events", "monitor/events.jsonl").unwrap());
+        let config = Config::new("test-key".to_string()).with_overrides(cli);
+
+        let events_file = config.events_file().unwrap();
+        assert!(events_file.is_absolute(), "{}", events_file.display());
+        assert!(events_file.ends_with("monitor/events.jsonl"));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_invalid_values() {
+        let unknown = write_workspace_config("max_reviews = 2\n");
+        let invalid = write_workspace_config("merge_strategy = \"rebase\"\n");
+        let mut config = Config::new("test-key".to_string());
+
+        let err = config.load_workspace_file(unknown.path()).unwrap_err();
+        assert!(err.to_string().contains("max_reviews"), "{}", err);
+        let err = config.load_workspace_file(invalid.path()).unwrap_err();
+        assert!(err.to_string().contains("merge_strategy"), "{}", err);
+        assert_eq!(config.merge_strategy(), MergeStrategy::default());
+    }
+
+    #[test]
+    fn cli_flags_are_parsed_and_unknown_flags_are_reported() {
+        let mut cli = ConfigOverrides::default();
+
+        assert!(cli.set_cli_flag("--wrap-mode", "word").unwrap());
+        assert!(cli.set_cli_flag("--model", "claude-sonnet-4-5").unwrap());
+        assert!(!cli.set_cli_flag("--unknown", "1").unwrap());
+        assert!(cli.set_cli_switch("--continue-on-fatal"));
+        assert!(cli.set_cli_switch("--skip-build-test"));
+        assert!(cli.set_cli_switch("--concurrent-review-build"));
+        assert!(cli.set_cli_switch("--create-pr"));
+        assert!(cli.set_cli_switch("--auto-stash"));
+        assert!(cli.set_cli_switch("--manual-conflict-resolution"));
+        assert!(cli.set_cli_switch("--manual-merge-approval"));
+        assert!(cli.set_cli_switch("--force-add-ignored-artifacts"));
+        assert!(cli.set_cli_flag("--invocation-warning-threshold", "50").unwrap());
+        assert!(cli.set_cli_flag("--invocation-warning-threshold", "-1").is_err());
+        assert!(cli.set_cli_switch("--format-before-build"));
+        assert!(cli.set_cli_flag("--format-command", "make fmt").unwrap());
+        assert!(cli.set_cli_flag("--format-command", "  ").is_err());
+        assert!(cli.set_cli_flag("--output-language", " English ").unwrap());
+        assert!(cli.set_cli_flag("--output-language", "").is_err());
+        assert!(cli.set_cli_flag("--worktree-root", ".bear/worktrees").unwrap());
+        assert!(cli.set_cli_flag("--worktree-root", " ").is_err());
+        assert!(!cli.set_cli_switch("--wrap-mode"));
+        assert!(cli.set_cli_flag("--max-review-iterations", "many").is_err());
+
+        assert_eq!(cli.wrap_mode, Some(WrapMode::Word));
+        assert_eq!(cli.model.as_deref(), Some("claude-sonnet-4-5"));
+        assert_eq!(cli.max_review_iterations, None);
+        assert_eq!(cli.continue_on_fatal, Some(true));
+        assert_eq!(cli.verify_build_test, Some(false));
+        assert_eq!(cli.concurrent_review_build, Some(true));
+        assert_eq!(cli.create_pull_request, Some(true));
+        assert_eq!(cli.auto_stash, Some(true));
+        assert_eq!(cli.worktree_root, Some(PathBuf::from(".bear/worktrees")));
+        assert_eq!(cli.manual_conflict_resolution, Some(true));
+        assert_eq!(cli.manual_merge_approval, Some(true));
+        assert_eq!(cli.force_add_ignored_artifacts, Some(true));
+        assert_eq!(cli.invocation_warning_threshold, Some(50));
+        assert_eq!(cli.format_before_build, Some(true));
+        assert_eq!(cli.format_command.as_deref(), Some("make fmt"));
+        assert_eq!(cli.output_language.as_deref(), Some("English"));
+    }
+}
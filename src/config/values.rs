@@ -0,0 +1,222 @@
+//! 설정 항목의 값으로 쓰는 타입과 기본값. 설정이 UI 모듈에 기대지 않도록 여기에 두고, UI 모듈이
+//! 이 타입들을 가져다 쓴다.
+
+use std::time::Duration;
+
+/// 세션 이름의 기본 최대 길이.
+const DEFAULT_SESSION_NAME_MAX_LENGTH: usize = 64;
+
+/// 빌드 명령과 테스트 명령 각각의 기본 제한 시간.
+pub const DEFAULT_BUILD_TEST_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// 통합 브랜치 이름의 기본 접두사.
+pub const DEFAULT_INTEGRATION_BRANCH_PREFIX: &str = "bear/integration";
+
+/// 사용량을 집계하는 작업 단계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UsagePhase {
+    Clarification,
+    Spec,
+    Plan,
+    Coding,
+    Review,
+    Other,
+}
+
+impl UsagePhase {
+    pub fn label(self) -> &'static str {
+        match self {
+            UsagePhase::Clarification => "요구사항 명확화",
+            UsagePhase::Spec => "스펙 작성",
+            UsagePhase::Plan => "개발 계획",
+            UsagePhase::Coding => "코딩",
+            UsagePhase::Review => "코드 리뷰",
+            UsagePhase::Other => "기타",
+        }
+    }
+}
+
+/// 세션 이름의 대소문자 규칙.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NameCasing {
+    #[default]
+    Preserve,
+    Lower,
+    Upper,
+}
+
+impl std::str::FromStr for NameCasing {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "preserve" => Ok(NameCasing::Preserve),
+            "lower" => Ok(NameCasing::Lower),
+            "upper" => Ok(NameCasing::Upper),
+            _ => Err(format!("unknown casing: {}", value)),
+        }
+    }
+}
+
+/// 저널 디렉토리와 통합 브랜치 이름에 쓰이는 세션 이름의 형식.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionNameStyle {
+    pub prefix: Option<String>,
+    pub separator: char,
+    pub max_length: usize,
+    pub casing: NameCasing,
+}
+
+impl Default for SessionNameStyle {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            separator: '-',
+            max_length: DEFAULT_SESSION_NAME_MAX_LENGTH,
+            casing: NameCasing::Preserve,
+        }
+    }
+}
+
+/// 오버라이드 파일의 내용을 기본 시스템 프롬프트에 적용하는 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PromptOverrideMode {
+    /// 기본 프롬프트 뒤에 오버라이드 파일의 내용을 덧붙인다.
+    #[default]
+    Append,
+    /// 기본 프롬프트 대신 오버라이드 파일의 내용만 쓴다.
+    Replace,
+}
+
+impl std::str::FromStr for PromptOverrideMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "append" => Ok(PromptOverrideMode::Append),
+            "replace" => Ok(PromptOverrideMode::Replace),
+            _ => Err(format!("unknown prompt override mode: {}", value)),
+        }
+    }
+}
+
+/// bear가 태스크마다 만드는 커밋(squash 커밋, 머지 커밋, 별도 리포트 커밋) 메시지의 기본 템플릿.
+pub const DEFAULT_COMMIT_MESSAGE_TEMPLATE: &str = "[{task_id}] {title}";
+
+/// 태스크 커밋 메시지 템플릿. `{task_id}`와 `{title}` 자리표시자를 쓸 수 있으며, 히스토리에서
+/// 태스크를 찾을 수 있도록 `{task_id}`는 반드시 들어 있어야 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitMessageTemplate(String);
+
+impl CommitMessageTemplate {
+    /// 태스크의 ID와 제목으로 커밋 메시지를 만든다.
+    pub fn render(&self, task_id: &str, title: &str) -> String {
+        self.0.replace("{task_id}", task_id).replace("{title}", title)
+    }
+}
+
+impl Default for CommitMessageTemplate {
+    fn default() -> Self {
+        Self(DEFAULT_COMMIT_MESSAGE_TEMPLATE.to_string())
+    }
+}
+
+impl std::str::FromStr for CommitMessageTemplate {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let template = value.trim();
+        if !template.contains("{task_id}") {
+            return Err(format!("commit message template must contain {{task_id}}: {}", value));
+        }
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let Some(length) = rest[start..].find('}') else {
+                return Err(format!("unclosed placeholder in commit message template: {}", value));
+            };
+            let placeholder = &rest[start..start + length + 1];
+            if placeholder != "{task_id}" && placeholder != "{title}" {
+                return Err(format!(
+                    "unknown placeholder {} in commit message template \
+                     (use {{task_id}} or {{title}})",
+                    placeholder,
+                ));
+            }
+            rest = &rest[start + length + 1..];
+        }
+        Ok(Self(template.to_string()))
+    }
+}
+
+/// 리베이스를 마친 태스크 브랜치를 통합 브랜치에 합치는 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// 태스크 브랜치의 커밋을 그대로 fast-forward 한다.
+    /// 리베이스 이후 통합 브랜치가 앞서 나갔으면 실패한다.
+    #[default]
+    FastForwardOnly,
+    /// 태스크 브랜치의 변경을 태스크 ID가 붙은 커밋 하나로 합친다.
+    Squash,
+    /// 항상 머지 커밋을 만들어 태스크 브랜치의 커밋 이력을 보존한다.
+    MergeCommit,
+}
+
+impl MergeStrategy {
+    pub fn label(self) -> &'static str {
+        match self {
+            MergeStrategy::FastForwardOnly => "fast-forward",
+            MergeStrategy::Squash => "squash",
+            MergeStrategy::MergeCommit => "merge commit",
+        }
+    }
+}
+
+impl std::str::FromStr for MergeStrategy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "ff-only" | "fast-forward" => Ok(MergeStrategy::FastForwardOnly),
+            "squash" => Ok(MergeStrategy::Squash),
+            "merge" | "merge-commit" => Ok(MergeStrategy::MergeCommit),
+            _ => Err(format!("unknown merge strategy: {}", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_strategy_parses_env_values() {
+        assert_eq!("ff-only".parse(), Ok(MergeStrategy::FastForwardOnly));
+        assert_eq!("Squash".parse(), Ok(MergeStrategy::Squash));
+        assert_eq!("merge".parse(), Ok(MergeStrategy::MergeCommit));
+        assert!("rebase".parse::<MergeStrategy>().is_err());
+        assert_eq!(MergeStrategy::default(), MergeStrategy::FastForwardOnly);
+    }
+
+    #[test]
+    fn commit_message_template_requires_task_id_and_known_placeholders() {
+        let template: CommitMessageTemplate = " feat({task_id}): {title} ".parse().unwrap();
+        assert_eq!(template.render("TASK-03", "Add login"), "feat(TASK-03): Add login");
+        assert_eq!(
+            CommitMessageTemplate::default().render("TASK-03", "Add login"),
+            "[TASK-03] Add login",
+        );
+
+        let missing = "{title}".parse::<CommitMessageTemplate>().unwrap_err();
+        assert!(missing.contains("{task_id}"), "{}", missing);
+        let unknown = "[{task_id}] {name}".parse::<CommitMessageTemplate>().unwrap_err();
+        assert!(unknown.contains("{name}"), "{}", unknown);
+        assert!("[{task_id}] {title".parse::<CommitMessageTemplate>().is_err());
+    }
+
+    #[test]
+    fn prompt_override_mode_parses_mode_names() {
+        assert_eq!("append".parse(), Ok(PromptOverrideMode::Append));
+        assert_eq!("Replace".parse(), Ok(PromptOverrideMode::Replace));
+        assert!("merge".parse::<PromptOverrideMode>().is_err());
+    }
+}
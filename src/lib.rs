@@ -1,3 +1,4 @@
 pub mod claude_code_client;
 pub mod config;
+pub mod text;
 pub mod ui;
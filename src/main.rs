@@ -1,18 +1,56 @@
 use std::process::ExitCode;
 
-use bear::config::Config;
+use bear::config::{Config, ConfigOverrides};
+
+const USAGE: &str = "Usage: bear [--headless <session-dir>] [--model <model>] \
+[--max-review-iterations <n>] [--build-test-timeout-secs <secs>] \
+[--merge-strategy <ff-only|squash|merge>] [--wrap-mode <char|word>] \
+[--verbosity <quiet|normal|verbose>] [--continue-on-fatal] \
+[--skip-build-test] [--concurrent-review-build] [--branch-prefix <prefix>] \
+[--integration-base-branch <branch>] [--create-pr] [--auto-stash] \
+[--manual-conflict-resolution] [--manual-merge-approval] [--force-add-ignored-artifacts] \
+[--invocation-warning-threshold <n>] \
+[--format-before-build] [--format-command <command>] [--output-language <language>] \
+[--worktree-root <path>] \
+[--prompt-override-mode <append|replace>] [--commit-message-template <template>] \
+[--events <path>]";
 
 fn main() -> ExitCode {
     bear::claude_code_client::logger::init();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (headless_session_dir, cli_overrides) = match parse_args(&args) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // 명령줄 인자는 환경 변수보다 우선한다.
     let config = match Config::from_env() {
-        Ok(config) => config,
+        Ok(config) => config.with_overrides(cli_overrides),
         Err(err) => {
             eprintln!("Error: {err}");
             return ExitCode::FAILURE;
         }
     };
 
+    // 호환되지 않는 CLI로 실행하다가 도중에 알 수 없는 플래그/스키마 오류를 만나지 않도록
+    // 시작할 때 바이너리와 버전을 확인한다.
+    if let Err(err) = bear::claude_code_client::verify_cli_binary() {
+        eprintln!("Error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(session_dir) = headless_session_dir {
+        return run_headless(config, &session_dir);
+    }
+
     if let Err(err) = bear::ui::run(config) {
         eprintln!("Error: {err}");
         return ExitCode::FAILURE;
@@ -20,3 +58,48 @@ fn main() -> ExitCode {
 
     ExitCode::SUCCESS
 }
+
+/// `--플래그 값` 쌍과 값 없는 스위치로 된 인자를 읽는다.
+/// 모르는 플래그나 값이 빠진 플래그가 있으면 `None`.
+fn parse_args(
+    args: &[String],
+) -> Result<Option<(Option<String>, ConfigOverrides)>, bear::config::ConfigError> {
+    let mut headless_session_dir = None;
+    let mut cli_overrides = ConfigOverrides::default();
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        if cli_overrides.set_cli_switch(flag) {
+            continue;
+        }
+        let Some(value) = args.next() else {
+            return Ok(None);
+        };
+        if flag == "--headless" {
+            headless_session_dir = Some(value.clone());
+        } else if !cli_overrides.set_cli_flag(flag, value)? {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some((headless_session_dir, cli_overrides)))
+}
+
+fn run_headless(config: Config, session_dir: &str) -> ExitCode {
+    let workspace = match std::env::current_dir() {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let session_dir = workspace.join(session_dir);
+
+    match bear::ui::run_headless(config, workspace, &session_dir, &mut std::io::stdout()) {
+        Ok(exit) => ExitCode::from(exit.code()),
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/// 문자열을 최대 `max_chars`개 문자까지 자른다. 바이트 오프셋이 아닌 문자 경계를
+/// 기준으로 자르므로 한글처럼 여러 바이트로 인코딩된 문자가 중간에 잘리지 않는다.
+/// 문자열을 자를 때는 바이트 슬라이싱 대신 항상 이 함수를 사용한다.
+pub fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((byte_index, _)) => &text[..byte_index],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_korean_text_on_char_boundary() {
+        let text = "한국어 출력";
+        // 바이트 기준으로 4에서 자르면 두 번째 글자의 중간이 된다.
+        assert!(!text.is_char_boundary(4));
+
+        let truncated = truncate_chars(text, 4);
+
+        assert_eq!(truncated, "한국어 ");
+        assert!(std::str::from_utf8(truncated.as_bytes()).is_ok());
+        assert_eq!(truncate_chars(text, 1), "한");
+    }
+
+    #[test]
+    fn returns_whole_text_when_shorter_than_limit() {
+        assert_eq!(truncate_chars("abc", 3), "abc");
+        assert_eq!(truncate_chars("abc", 10), "abc");
+        assert_eq!(truncate_chars("abc", 0), "");
+        assert_eq!(truncate_chars("", 5), "");
+    }
+}
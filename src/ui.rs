@@ -1,22 +1,39 @@
 pub mod app;
 mod clarification;
+mod clipboard;
 pub mod coding;
 mod error;
 mod event;
+mod events;
 mod file_validation;
+pub mod git_error;
+mod headless;
+mod input_history;
+mod invocation_budget;
+mod log_viewer;
 mod renderer;
 mod planning;
-mod session_naming;
+pub mod prompt_overrides;
+mod pull_request;
+pub mod session_naming;
+mod session_history;
 mod spec_writing;
+mod termination;
+#[cfg(test)]
+mod test_support;
+mod timing;
+mod transcript;
+pub mod usage;
 
 pub use error::UiError;
+pub use headless::{HeadlessExit, run_headless};
 
 use std::io::stdout;
 use std::time::Duration;
 
 use crossterm::cursor;
 use crossterm::event::{
-    DisableBracketedPaste, EnableBracketedPaste,
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
     Event, KeyEventKind, KeyboardEnhancementFlags,
     PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
 };
@@ -24,6 +41,7 @@ use crossterm::terminal;
 
 use crate::config::Config;
 use app::App;
+use event::{EventSource, UiEvent};
 use renderer::TerminalWriter;
 
 pub fn run(config: Config) -> Result<(), UiError> {
@@ -45,79 +63,129 @@ pub fn run(config: Config) -> Result<(), UiError> {
         )?;
     }
 
+    let mouse_scroll = config.mouse_scroll();
+    if mouse_scroll {
+        crossterm::execute!(stdout(), EnableMouseCapture)?;
+    }
+
+    let poll_interval = config.poll_interval();
+    let events = EventSource::start(poll_interval);
+
     let mut app = App::new(config)?;
+    if let Err(err) = termination::install_termination_handler(app.cleanup_registry(), true) {
+        crate::cli_log!("종료 시그널 처리기 등록 실패: {}", err);
+    }
     app.set_keyboard_enhancement_enabled(keyboard_enhancement_enabled);
+    app.set_wake_sender(events.wake_sender());
 
     let mut writer = TerminalWriter::new()?;
     app.terminal_width = writer.terminal_width();
+    app.terminal_height = terminal::size().map(|(_, height)| height).unwrap_or(24);
+
+    let loop_result = run_event_loop(
+        &mut app,
+        &mut writer,
+        &events,
+        poll_interval,
+        keyboard_enhancement_enabled,
+        mouse_scroll,
+    );
+
+    writer.finalize()?;
+
+    if mouse_scroll {
+        crossterm::execute!(stdout(), DisableMouseCapture)?;
+    }
+
+    if keyboard_enhancement_enabled {
+        crossterm::execute!(stdout(), PopKeyboardEnhancementFlags)?;
+    }
+
+    crossterm::execute!(
+        stdout(),
+        cursor::Show,
+        cursor::SetCursorStyle::DefaultUserShape,
+        DisableBracketedPaste,
+    )?;
+    terminal::disable_raw_mode()?;
+
+    loop_result?;
+
+    if let Some(message) = app.fatal_error() {
+        return Err(UiError::AgentError {
+            message: message.to_string(),
+        });
+    }
+
+    Ok(())
+}
 
+fn run_event_loop(
+    app: &mut App,
+    writer: &mut TerminalWriter,
+    events: &EventSource,
+    poll_interval: Duration,
+    keyboard_enhancement_enabled: bool,
+    mouse_scroll: bool,
+) -> Result<(), UiError> {
     loop {
         app.tick();
         app.terminal_width = writer.terminal_width();
-        writer.render(&app)?;
-
-        if let Some(event) = event::poll_event(Duration::from_millis(100))? {
-            match event {
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    app.handle_key_event(key_event);
-                }
-                Event::Paste(text) => {
-                    app.handle_paste(text);
-                }
-                Event::Resize(width, _) => {
-                    writer.handle_resize(width);
-                    app.terminal_width = width;
-                }
-                _ => {}
-            }
+        writer.render(app)?;
+        if app.take_pending_bell() {
+            writer.ring_bell()?;
         }
 
-        if app.pending_external_editor {
-            // 터미널 상태 복원
-            writer.finalize()?;
-            if keyboard_enhancement_enabled {
-                crossterm::execute!(stdout(), PopKeyboardEnhancementFlags)?;
+        // 에이전트 메시지가 도착하면 즉시 깨어나고, 아무 일도 없으면
+        // poll_interval마다 깨어나 진행 표시를 갱신한다.
+        match events.next(poll_interval) {
+            Some(UiEvent::Terminal(Event::Key(key_event)))
+                if key_event.kind == KeyEventKind::Press =>
+            {
+                app.handle_key_event(key_event);
+            }
+            Some(UiEvent::Terminal(Event::Paste(text))) => {
+                app.handle_paste(text);
             }
-            crossterm::execute!(
-                stdout(),
-                cursor::Show,
-                cursor::SetCursorStyle::DefaultUserShape,
-                DisableBracketedPaste,
-            )?;
-            terminal::disable_raw_mode()?;
-
-            app.open_external_editor();
-
-            // 터미널 상태 재설정
-            terminal::enable_raw_mode()?;
-            crossterm::execute!(
-                stdout(),
-                EnableBracketedPaste,
-                cursor::Hide,
-                cursor::SetCursorStyle::SteadyBlock,
-            )?;
-            if keyboard_enhancement_enabled {
-                crossterm::execute!(
-                    stdout(),
-                    PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
-                )?;
+            Some(UiEvent::Terminal(Event::Mouse(mouse_event))) => {
+                app.handle_mouse_event(mouse_event);
             }
+            Some(UiEvent::Terminal(Event::Resize(width, height))) => {
+                writer.handle_resize(width);
+                app.terminal_width = width;
+                app.terminal_height = height;
+            }
+            Some(UiEvent::TerminalReadFailed(err)) => return Err(err.into()),
+            _ => {}
+        }
 
-            writer.reset_for_redraw();
-            app.terminal_width = writer.terminal_width();
+        if app.pending_external_editor {
+            let _paused_input = events.pause_terminal_input();
+            run_external_editor(app, writer, keyboard_enhancement_enabled, mouse_scroll)?;
         }
 
         if app.should_quit {
-            break;
+            app.restore_auto_stash();
+            writer.render(app)?;
+            return Ok(());
         }
     }
+}
 
+fn run_external_editor(
+    app: &mut App,
+    writer: &mut TerminalWriter,
+    keyboard_enhancement_enabled: bool,
+    mouse_scroll: bool,
+) -> Result<(), UiError> {
+    // 터미널 상태 복원
     writer.finalize()?;
-
+    if mouse_scroll {
+        crossterm::execute!(stdout(), DisableMouseCapture)?;
+    }
     if keyboard_enhancement_enabled {
         crossterm::execute!(stdout(), PopKeyboardEnhancementFlags)?;
     }
-
     crossterm::execute!(
         stdout(),
         cursor::Show,
@@ -126,11 +194,27 @@ pub fn run(config: Config) -> Result<(), UiError> {
     )?;
     terminal::disable_raw_mode()?;
 
-    if let Some(message) = app.fatal_error() {
-        return Err(UiError::AgentError {
-            message: message.to_string(),
-        });
+    app.open_external_editor();
+
+    // 터미널 상태 재설정
+    terminal::enable_raw_mode()?;
+    crossterm::execute!(
+        stdout(),
+        EnableBracketedPaste,
+        cursor::Hide,
+        cursor::SetCursorStyle::SteadyBlock,
+    )?;
+    if keyboard_enhancement_enabled {
+        crossterm::execute!(
+            stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+    }
+    if mouse_scroll {
+        crossterm::execute!(stdout(), EnableMouseCapture)?;
     }
 
+    writer.reset_for_redraw();
+    app.terminal_width = writer.terminal_width();
     Ok(())
 }
@@ -1,26 +1,50 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::io::Write;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
-use crate::claude_code_client::{ClaudeCodeClient, ClaudeCodeRequest};
-use crate::config::Config;
+use crate::claude_code_client::{
+    AgentSemaphore, CancellationToken, ClaudeCodeClient, ClaudeCodeClientError, ClaudeCodeRequest,
+    DebugLogLocation, InvocationCounter, RetryPolicy, StreamMessage, ToolSet,
+};
+use crate::config::{Config, UsagePhase, WrapMode};
 use super::clarification::{self, ClarificationQuestions, QaRound};
+use super::clipboard::SystemClipboard;
 use super::coding::{
     self, BuildTestCommands, BuildTestOutcome, BuildTestRepairResult,
     BuildTestRepairStatus, CodingPhaseState, CodingTask, CodingTaskResult,
-    CodingTaskStatus, ConflictResolutionResult, ConflictResolutionStatus,
-    RebaseOutcome, ReviewResult, ReviewStatus, TaskExtractionResponse,
-    TaskReport, TaskWorktreeInfo,
+    CodingTaskStatus, ConflictResolutionResult, ConflictResolutionStatus, FormatterCommand,
+    FormatterOutcome, MergeFailure,
+    RebaseOutcome, ReviewApproval, ReviewResult, ReviewStatus, TaskExtractionResponse, TaskReviewState,
+    RetainedWorktree, TaskProgress, TaskReport, TaskWorktreeInfo,
 };
+use super::events::{BuildResultKind, EventLog, EventPhase, SessionEvent};
 use super::file_validation::{self, FileKind, FileValidationResponse};
-use super::planning::{self, PlanResponseType, PlanWritingResponse};
+use super::git_error::GitError;
+use super::headless::HeadlessExit;
+use super::input_history::{INPUT_HISTORY_FILE_NAME, InputField, InputHistory};
+use super::invocation_budget::InvocationBudget;
+use super::log_viewer;
+use super::prompt_overrides::{PromptKind, PromptOverrides};
+use super::pull_request;
+use super::planning::{
+    self, NextTaskPlanResponse, NextTaskPlanStatus, PlanResponseType, PlanWritingResponse,
+};
+use super::session_history;
 use super::session_naming;
-use super::spec_writing::{self, SpecResponseType, SpecWritingResponse};
+use super::termination::{ActiveWorktree, AGENT_EXIT_TIMEOUT, CleanupRegistry, StashedChanges};
+use super::timing::{self, OperationKind, TimingRecorder};
+use super::transcript::{self, TranscriptSources};
+use super::usage::UsageTracker;
+use super::spec_writing::{
+    self, SectionReviewState, SectionRevisionResponse, SpecResponseType, SpecWritingResponse,
+};
 use super::error::UiError;
-use super::renderer::{USER_PREFIX, wrap_text_by_char_width};
+use super::event::{self, UiEvent};
+use super::renderer::{USER_PREFIX, wrap_text};
 
 pub enum MessageRole {
     System,
@@ -29,9 +53,69 @@ pub enum MessageRole {
 
 pub struct ChatMessage {
     pub role: MessageRole,
+    /// 화면에 표시하는 내용. 스트리밍 메시지는 잘린 상태일 수 있다.
     pub content: String,
+    /// `content`가 잘린 스트리밍 메시지이면 원문. 펼쳐서 대화 기록에 추가하면 `None`이 된다.
+    pub full_content: Option<String>,
+}
+
+/// 라이브 영역 위쪽에 띄우는 읽기 전용 창.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    /// 현재 세션의 디버그 로그 끝부분. Ctrl+L로 켜고 끈다.
+    DebugLog,
+    /// 세션 이름, 저널 경로, 브랜치 같은 세션 정보. Ctrl+T로 켜고 끈다.
+    SessionInfo,
+    /// 남은 태스크의 실행 예정표. Ctrl+U로 켜고 끈다.
+    TaskSchedule,
+    /// 피드백 중인 스펙/플랜 초안. 스펙/플랜 피드백 단계에서 Ctrl+P로 켜고 끄며,
+    /// 켜져 있는 동안 PageUp/PageDown과 마우스 휠은 대화 기록 대신 초안을 스크롤한다.
+    DraftPreview,
+}
+
+/// 초안 미리보기 창에 보여 줄 초안의 일부.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DraftPreview {
+    /// "스펙" 또는 "개발 계획".
+    pub title: &'static str,
+    /// 창에 보이는 줄들. 터미널 폭에 맞게 줄바꿈되어 있다.
+    pub lines: Vec<String>,
+    /// 첫 번째로 보이는 줄의 인덱스.
+    pub first_line: usize,
+    pub total_lines: usize,
+}
+
+/// 디버그나 수동 git 작업에 필요한 현재 세션 정보. 아직 정해지지 않은 값은 None이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInfo {
+    pub session_name: Option<String>,
+    pub session_date_dir: Option<String>,
+    pub journal_dir: Option<PathBuf>,
+    pub integration_branch: Option<String>,
+    /// 가장 최근에 쓴 Claude Code CLI 세션 ID.
+    pub claude_session_id: Option<String>,
+    pub log_path: Option<PathBuf>,
+}
+
+impl SessionInfo {
+    /// 정보 창에 한 줄씩 보여 줄 항목.
+    pub fn lines(&self) -> Vec<String> {
+        let path_text = |path: &Option<PathBuf>| path.as_ref().map(|path| path.display().to_string());
+        [
+            ("세션 이름", self.session_name.clone()),
+            ("날짜 디렉토리", self.session_date_dir.clone()),
+            ("저널 디렉토리", path_text(&self.journal_dir)),
+            ("통합 브랜치", self.integration_branch.clone()),
+            ("Claude 세션 ID", self.claude_session_id.clone()),
+            ("디버그 로그", path_text(&self.log_path)),
+        ]
+        .into_iter()
+        .map(|(label, value)| format!("{}: {}", label, value.as_deref().unwrap_or("(없음)")))
+        .collect()
+    }
 }
 
+#[derive(Clone, Copy)]
 enum InputMode {
     WorkspaceConfirm,
     ModeSelection,
@@ -39,26 +123,115 @@ enum InputMode {
     RequirementsInput,
     AgentThinking,
     ClarificationAnswer,
+    /// 명확화 도중 원래 요구사항을 고치는 중. 제출하면 기존 질의응답을 유지한 채 명확화를
+    /// 다시 시작한다.
+    RequirementsRevision,
     SpecClarificationAnswer,
     SpecFeedback,
+    SectionReview,
     PlanClarificationAnswer,
     PlanFeedback,
     Coding,
     BuildTestCommandInput,
+    /// 추출된 태스크의 순서와 포함 여부를 코딩 시작 전에 확인하는 중.
+    TaskReview,
+    CodingPaused,
+    /// 충돌 해결 에이전트가 실패해 사용자가 태스크 워크트리에서 직접 충돌을 해결하기를 기다리는 중.
+    ManualConflict,
+    /// 리뷰와 빌드/테스트를 통과한 태스크의 변경 요약을 보여 주고 머지 승인을 기다리는 중.
+    MergeApproval,
+    /// CLI 호출 수가 경고 한도에 닿아, 다음 태스크를 시작하기 전에 계속할지 묻는 중.
+    InvocationBudget,
+    /// 코딩 단계가 끝났지만 차단된 태스크가 있어, 다시 실행할 태스크를 고르는 중.
+    BlockedTaskSelection,
+    /// 통합 브랜치를 푸시하고 풀 리퀘스트를 만드는 중. 에이전트를 쓰지 않으며 취소할 수 없다.
+    Publishing,
     Done,
+    /// 치명적 오류로 작업을 멈췄다. 오류 화면을 띄워 두고 사용자가 키를 눌러야 종료한다.
+    FatalError,
+}
+
+impl InputMode {
+    fn is_waiting_for_input(self) -> bool {
+        matches!(
+            self,
+            InputMode::WorkspaceConfirm
+                | InputMode::SessionDirInput
+                | InputMode::RequirementsInput
+                | InputMode::ClarificationAnswer
+                | InputMode::RequirementsRevision
+                | InputMode::SpecClarificationAnswer
+                | InputMode::SpecFeedback
+                | InputMode::SectionReview
+                | InputMode::PlanClarificationAnswer
+                | InputMode::PlanFeedback
+                | InputMode::BuildTestCommandInput
+                | InputMode::TaskReview
+                | InputMode::BlockedTaskSelection
+        )
+    }
+
+    fn is_thinking(self) -> bool {
+        matches!(self, InputMode::AgentThinking | InputMode::Coding | InputMode::Publishing)
+    }
+}
+
+/// 에이전트 작업이 끝나 사용자의 주의가 필요한 상황.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttentionEvent {
+    InputNeeded,
+    Finished,
+}
+
+impl AttentionEvent {
+    /// 알림 명령에 `BEAR_NOTIFY_EVENT`로 전달하는 값.
+    fn label(self) -> &'static str {
+        match self {
+            AttentionEvent::InputNeeded => "input",
+            AttentionEvent::Finished => "done",
+        }
+    }
+}
+
+/// 에이전트 작업 중이던 모드에서 입력 대기 모드나 `Done`으로 바뀌었으면 알릴 이벤트를 반환한다.
+fn attention_event(previous: InputMode, current: InputMode) -> Option<AttentionEvent> {
+    if !previous.is_thinking() {
+        return None;
+    }
+    if matches!(current, InputMode::Done | InputMode::FatalError) {
+        Some(AttentionEvent::Finished)
+    } else if current.is_waiting_for_input()
+        || matches!(
+            current,
+            InputMode::ManualConflict | InputMode::MergeApproval | InputMode::InvocationBudget
+        )
+    {
+        Some(AttentionEvent::InputNeeded)
+    } else {
+        None
+    }
 }
 
 enum AgentOutcome {
     Clarification(ClarificationQuestions),
     SpecWriting(SpecWritingResponse),
+    SectionRevision(SectionRevisionResponse),
     Planning(PlanWritingResponse),
     TaskExtraction(TaskExtractionResponse),
     CodingTaskCompleted(CodingTaskResult),
     ReviewCompleted(ReviewResult),
+    /// `concurrent_review_build` 모드에서 리뷰와 빌드/테스트가 모두 끝났다. `revision`은
+    /// 포매터를 실행한 뒤 둘이 함께 검증한 커밋이다.
+    ReviewWithBuildTestCompleted {
+        review: ReviewResult,
+        outcome: BuildTestOutcome,
+        revision: String,
+    },
     ConflictResolutionCompleted(ConflictResolutionResult),
     BuildTestCompleted(BuildTestOutcome),
     BuildTestRepairCompleted(BuildTestRepairResult),
     FileValidation(FileValidationResponse),
+    NextTaskPlanned(NextTaskPlanResponse),
 }
 
 struct AgentThreadResult {
@@ -66,10 +239,33 @@ struct AgentThreadResult {
     outcome: Result<AgentOutcome, String>,
 }
 
+/// 에이전트 작업 결과로 사용량을 집계할 단계를 정한다.
+fn usage_phase(outcome: &Result<AgentOutcome, String>) -> UsagePhase {
+    match outcome {
+        Ok(AgentOutcome::Clarification(_)) => UsagePhase::Clarification,
+        Ok(AgentOutcome::SpecWriting(_) | AgentOutcome::SectionRevision(_)) => UsagePhase::Spec,
+        Ok(
+            AgentOutcome::Planning(_)
+            | AgentOutcome::TaskExtraction(_)
+            | AgentOutcome::NextTaskPlanned(_),
+        ) => UsagePhase::Plan,
+        Ok(
+            AgentOutcome::CodingTaskCompleted(_)
+            | AgentOutcome::ConflictResolutionCompleted(_)
+            | AgentOutcome::BuildTestCompleted(_)
+            | AgentOutcome::BuildTestRepairCompleted(_),
+        ) => UsagePhase::Coding,
+        Ok(
+            AgentOutcome::ReviewCompleted(_) | AgentOutcome::ReviewWithBuildTestCompleted { .. },
+        ) => UsagePhase::Review,
+        Ok(AgentOutcome::FileValidation(_)) | Err(_) => UsagePhase::Other,
+    }
+}
+
 enum AgentStreamMessage {
     SessionName { name: String, date_dir: String },
-    StreamLine(String),
-    Completed(AgentThreadResult),
+    StreamLine(StreamMessage),
+    Completed(Box<AgentThreadResult>),
 }
 
 pub struct App {
@@ -78,6 +274,7 @@ pub struct App {
     pub input_buffer: String,
     pub cursor_position: usize,
     pub terminal_width: u16,
+    pub terminal_height: u16,
     pub confirmed_workspace: Option<PathBuf>,
     pub confirmed_requirements: Option<String>,
     pub should_quit: bool,
@@ -86,47 +283,249 @@ pub struct App {
     config: Config,
     claude_client: Option<ClaudeCodeClient>,
     agent_result_receiver: Option<mpsc::Receiver<AgentStreamMessage>>,
+    /// 풀 리퀘스트 작업의 결과를 받는 채널. 에이전트를 쓰지 않으므로 에이전트 채널과 따로 둔다.
+    publish_receiver: Option<mpsc::Receiver<pull_request::PublishResult>>,
+    /// 테스트에서 가짜 CLI로 쓸 바이너리. None이면 PATH와 알려진 설치 경로에서 찾는다.
+    #[cfg(test)]
+    claude_binary: Option<PathBuf>,
+    wake_sender: Option<mpsc::Sender<UiEvent>>,
+    cancellation_token: Option<CancellationToken>,
+    cancel_requested: bool,
+    mode_before_agent: InputMode,
     qa_log: Vec<QaRound>,
     current_round_questions: Vec<String>,
+    /// 요구사항을 고치는 동안 보관해 둔, 작성 중이던 명확화 답변.
+    clarification_answer_draft: String,
     thinking_started_at: Instant,
+    /// 최대 작업 시간 초과를 알린 작업의 `thinking_started_at`. 같은 작업에서 다시 알리지 않는다.
+    phase_deadline_warned_for: Option<Instant>,
     last_spec_draft: Option<String>,
     spec_clarification_questions: Vec<String>,
     last_plan_draft: Option<String>,
     plan_clarification_questions: Vec<String>,
+    /// 스펙/플랜 수정을 요청한 마지막 피드백. 수정 쿼리를 취소하면 입력창에 되돌려 고쳐 쓸 수
+    /// 있게 한다.
+    last_submitted_feedback: Option<String>,
     approved_spec: Option<String>,
     spec_revision_instructions_sent: bool,
+    section_review: Option<SectionReviewState>,
+    usage_tracker: UsageTracker,
+    timings: TimingRecorder,
+    operation_started_at: Instant,
     session_name: Option<String>,
     session_date_dir: Option<String>,
     base_journal_dir: Option<PathBuf>,
     integration_branch: Option<String>,
+    /// 통합 브랜치를 만들기 전에 감지한 저장소의 기본 브랜치. 기준 브랜치를 설정하지 않았을 때
+    /// 풀 리퀘스트의 base로 쓴다.
+    default_branch: Option<String>,
     coding_state: Option<CodingPhaseState>,
+    /// 코딩 시작 전 사용자가 확인 중인 태스크 목록.
+    task_review: Option<TaskReviewState>,
+    /// 추출된 작업이 없어 태스크 추출을 한 번 다시 시도했는지 여부.
+    task_extraction_retried: bool,
     pending_coding_report: Option<String>,
     review_state: Option<ReviewState>,
+    concurrent_build_test: Option<ConcurrentBuildTest>,
     pending_build_test: Option<PendingBuildTest>,
+    pending_merge: Option<PendingMerge>,
     build_test_command_phase: BuildTestCommandPhase,
     fatal_error: Option<String>,
     selected_mode_index: usize,
     resumed_session_dir: Option<PathBuf>,
     resumed_has_plan: bool,
+    /// 현재 태스크의 코딩 에이전트가 다시 연결한 이전 세션의 Claude 세션 ID.
+    reattached_coding_session: Option<String>,
+    /// 종료 시그널을 받았을 때 정리할 워크트리. 시그널 처리 스레드와 공유한다.
+    cleanup_registry: CleanupRegistry,
     pending_validation_kind: Option<FileKind>,
     pub pending_external_editor: bool,
+    /// 다음 렌더링 때 터미널 벨을 울린다.
+    pending_bell: bool,
+    /// 메시지 뷰를 위로 스크롤한 경우, 화면 맨 위에 보여줄 메시지가 끝에서 몇 번째인지.
+    scroll_offset: usize,
+    /// 새 메시지가 도착하면 항상 맨 아래를 보여줄지 여부. 위로 스크롤하면 해제된다.
+    follow_tail: bool,
+    /// headless 모드 여부. 사용자 확인이 필요한 단계에서 자동으로 기본값을 택한다.
+    headless: bool,
+    /// 작업 중에 종료하려고 Esc를 처음 누른 시각. `QUIT_CONFIRM_WINDOW` 안에 다시 누르면 종료한다.
+    pending_quit: Option<Instant>,
+    /// 라이브 영역에 띄운 읽기 전용 창.
+    overlay: Option<Overlay>,
+    /// 초안 미리보기 창에서 맨 위에 보이는 줄의 인덱스.
+    draft_scroll: usize,
+    /// Ctrl+Y로 메시지나 리포트를 복사할 시스템 클립보드. 처음 복사할 때 연다.
+    clipboard: SystemClipboard,
+    /// 입력란 종류별로 제출한 입력의 기록. Alt+Up/Down으로 불러온다.
+    input_history: InputHistory,
+    /// 이 세션에서 띄운 CLI 프로세스 수. 모든 에이전트 클라이언트가 공유한다.
+    invocation_counter: InvocationCounter,
+    /// CLI 호출 수 경고 한도를 넘겼는지와 사용자가 계속하기로 한 시점.
+    invocation_budget: InvocationBudget,
+    /// 현재 태스크의 다음 리뷰가 변경을 요청하면 이어서 수정하는 대신 통합 브랜치에서 새로
+    /// 구현할지 여부. 코딩 중에 Ctrl+N으로 켜고 끈다.
+    regenerate_task_requested: bool,
+    /// 이 세션에서 만든 풀 리퀘스트의 URL. 차단된 태스크를 다시 실행한 뒤에는 새로 만들지 않고
+    /// 통합 브랜치만 다시 푸시한다.
+    pull_request_url: Option<String>,
+    /// 마지막으로 끝난 쿼리의 CLI 세션 ID. 로그 디렉토리에 아직 로그가 없을 때 로그 경로를 정하는 데 쓴다.
+    last_cli_session_id: Option<String>,
+    /// `--events`로 지정한 진행 상황 이벤트 파일.
+    event_log: Option<EventLog>,
+    /// 모든 에이전트 클라이언트가 공유하는 CLI 프로세스 동시 실행 제한.
+    agent_semaphore: Option<AgentSemaphore>,
+}
+
+/// 외부 에디터로 편집할 대상.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExternalEditTarget {
+    InputBuffer,
+    SpecDraft,
+    PlanDraft,
+}
+
+/// 사용자의 승인을 기다리는 머지. 승인하면 그대로 `merge_and_advance`에 넘긴다.
+struct PendingMerge {
+    task_id: String,
+    report: String,
+    merged_with_failing_build: bool,
 }
 
 struct PendingBuildTest {
     task_id: String,
     report: String,
-    is_retry: bool,
+    /// 이 빌드/테스트를 실행하기 전까지 수리 에이전트를 실행한 횟수.
+    repair_attempts: usize,
 }
 
 struct ReviewState {
     task_id: String,
     report: String,
     iteration_count: usize,
+    max_iterations: usize,
     reviewer_client: Option<ClaudeCodeClient>,
     coding_client: Option<ClaudeCodeClient>,
 }
 
-const MAX_REVIEW_ITERATIONS: usize = 3;
+/// 리뷰와 동시에 실행한 빌드/테스트의 결과. 리베이스 후에도 `revision`이 그대로면
+/// 빌드/테스트를 다시 실행하지 않고 이 결과로 머지 여부를 정한다.
+struct ConcurrentBuildTest {
+    revision: String,
+    outcome: BuildTestOutcome,
+}
+
+/// 태스크 워크트리에서 빌드/테스트를 실행하는 데 필요한 값. 에이전트 스레드로 옮겨 실행한다.
+struct BuildTestJob {
+    worktree_path: PathBuf,
+    /// 포매터가 바꾼 파일을 합칠 태스크 커밋을 찾는 기준 브랜치.
+    integration_branch: String,
+    commands: BuildTestCommands,
+    timeout: Duration,
+    cache_env: Vec<(String, PathBuf)>,
+    formatter: Option<FormatterCommand>,
+}
+
+impl BuildTestJob {
+    /// 포매터가 있으면 먼저 실행한 뒤 빌드/테스트를 실행하면서 출력 줄을 스트리밍 메시지로 UI에
+    /// 보낸다.
+    fn run(&self, sender: &mpsc::Sender<AgentStreamMessage>) -> Result<BuildTestOutcome, String> {
+        self.format(sender);
+        self.build_and_test(sender)
+    }
+
+    /// 포매터가 있으면 실행하고 결과를 한 줄로 UI에 보낸다. 포매터가 커밋을 바꿀 수 있으므로
+    /// 검증 대상 커밋은 이 단계가 끝난 뒤에 정해야 한다.
+    fn format(&self, sender: &mpsc::Sender<AgentStreamMessage>) {
+        let Some(formatter) = &self.formatter else {
+            return;
+        };
+        let forward_line = |line: String| forward_output_line(sender, line);
+        let outcome = coding::run_formatter(
+            &self.worktree_path,
+            &self.integration_branch,
+            formatter,
+            self.timeout,
+            &forward_line,
+        );
+        forward_line(formatter_outcome_line(&formatter.command, outcome));
+    }
+
+    fn build_and_test(
+        &self,
+        sender: &mpsc::Sender<AgentStreamMessage>,
+    ) -> Result<BuildTestOutcome, String> {
+        let forward_line = |line: String| forward_output_line(sender, line);
+        coding::run_build_and_test(
+            &self.worktree_path,
+            &self.commands,
+            self.timeout,
+            &self.cache_env,
+            &forward_line,
+        )
+    }
+}
+
+/// 빌드/테스트나 포매터의 출력 줄을 스트리밍 메시지로 UI에 보낸다.
+fn forward_output_line(sender: &mpsc::Sender<AgentStreamMessage>, line: String) {
+    let message = StreamMessage::from_full_text(line);
+    let _ = sender.send(AgentStreamMessage::StreamLine(message));
+}
+
+/// 포매터 실행 결과를 빌드/테스트 출력 앞에 보여 줄 한 줄로 만든다.
+fn formatter_outcome_line(command: &str, outcome: Result<FormatterOutcome, String>) -> String {
+    match outcome {
+        Ok(FormatterOutcome::Skipped { program }) => {
+            format!("[포매터] {}가 설치되어 있지 않아 건너뜁니다.", program)
+        }
+        Ok(FormatterOutcome::Unchanged) => format!("[포매터] {}: 바뀐 파일 없음", command),
+        Ok(FormatterOutcome::Committed) => format!("[포매터] {}: 바뀐 파일을 커밋했습니다.", command),
+        Ok(FormatterOutcome::Failed { output }) => {
+            format!("[포매터] {} 실패, 포맷하지 않고 계속합니다:\n{}", command, output.trim_end())
+        }
+        Err(err) => format!("[포매터] {} 실행 실패, 포맷하지 않고 계속합니다: {}", command, err),
+    }
+}
+
+/// 사용자가 직접 충돌을 해결하도록 충돌난 워크트리와 파일, 이어 가는 방법을 알리는 메시지.
+fn manual_conflict_guidance(
+    task_id: &str,
+    worktree_path: &Path,
+    conflicted_files: &[String],
+) -> String {
+    let files = if conflicted_files.is_empty() {
+        "  (충돌 파일 목록 없음, `git status`로 리베이스가 멈춘 곳을 확인하세요)".to_string()
+    } else {
+        conflicted_files
+            .iter()
+            .map(|file| format!("  - {}", file))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "[{}] 충돌을 직접 해결해 주세요.\n워크트리: {}\n충돌 파일:\n{}\n\
+         파일을 고치고 `git add`한 뒤 Enter를 누르면 `git rebase --continue`로 리베이스를 이어 가고 \
+         빌드/테스트 검증을 계속합니다. Ctrl+B를 누르면 리베이스를 중단하고 태스크를 차단합니다.",
+        task_id,
+        worktree_path.display(),
+        files,
+    )
+}
+
+const FAILING_BUILD_REPORT_MARKER: &str =
+    "> ⚠ MERGED WITH FAILING BUILD: 이 태스크는 빌드/테스트가 실패한 상태로 \
+     continue_on_build_failure 설정에 따라 머지되었습니다.";
+const MISSING_WORKSPACE_MESSAGE: &str = "워크스페이스가 설정되지 않았습니다.";
+/// PageUp/PageDown 한 번에 이동하는 메시지 수.
+const SCROLL_PAGE_MESSAGES: usize = 5;
+/// 마우스 휠 한 칸에 이동하는 메시지 수.
+const SCROLL_WHEEL_MESSAGES: usize = 1;
+
+/// 초안 미리보기 창에서 마우스 휠 한 칸에 이동하는 줄 수.
+const DRAFT_SCROLL_WHEEL_LINES: isize = 3;
+/// 작업 중에 Esc를 한 번 누른 뒤 종료로 확정하는 두 번째 Esc를 기다리는 시간.
+const QUIT_CONFIRM_WINDOW: Duration = Duration::from_secs(3);
+/// 점진적 계획 모드에서 계획이 끝나지 않고 반복되는 것을 막기 위한 최대 태스크 수.
+const MAX_INCREMENTAL_TASKS: usize = coding::MAX_TASKS_PER_PLAN;
 
 enum BuildTestCommandPhase {
     BuildCommand,
@@ -145,14 +544,19 @@ impl App {
         let messages = vec![ChatMessage {
             role: MessageRole::System,
             content: initial_message,
+            full_content: None,
         }];
 
+        let event_log = config.events_file().map(|path| EventLog::new(path.to_path_buf()));
+        let agent_semaphore = config.max_concurrent_agents().map(AgentSemaphore::new);
+
         Ok(Self {
             messages,
             input_mode: InputMode::WorkspaceConfirm,
             input_buffer: String::new(),
             cursor_position: 0,
             terminal_width: 80,
+            terminal_height: 24,
             confirmed_workspace: None,
             confirmed_requirements: None,
             should_quit: false,
@@ -161,38 +565,166 @@ impl App {
             config,
             claude_client: None,
             agent_result_receiver: None,
+            publish_receiver: None,
+            #[cfg(test)]
+            claude_binary: None,
+            wake_sender: None,
+            cancellation_token: None,
+            cancel_requested: false,
+            mode_before_agent: InputMode::RequirementsInput,
             qa_log: Vec::new(),
             current_round_questions: Vec::new(),
+            clarification_answer_draft: String::new(),
             thinking_started_at: Instant::now(),
+            phase_deadline_warned_for: None,
             last_spec_draft: None,
             spec_clarification_questions: Vec::new(),
             last_plan_draft: None,
             plan_clarification_questions: Vec::new(),
+            last_submitted_feedback: None,
             approved_spec: None,
             spec_revision_instructions_sent: false,
+            section_review: None,
+            usage_tracker: UsageTracker::default(),
+            timings: TimingRecorder::default(),
+            operation_started_at: Instant::now(),
             session_name: None,
             session_date_dir: None,
             base_journal_dir: None,
             integration_branch: None,
+            default_branch: None,
             coding_state: None,
+            task_review: None,
+            task_extraction_retried: false,
             pending_coding_report: None,
             review_state: None,
+            concurrent_build_test: None,
             pending_build_test: None,
+            pending_merge: None,
             build_test_command_phase: BuildTestCommandPhase::BuildCommand,
             fatal_error: None,
             selected_mode_index: 0,
             resumed_session_dir: None,
             resumed_has_plan: false,
+            reattached_coding_session: None,
+            cleanup_registry: CleanupRegistry::default(),
             pending_validation_kind: None,
             pending_external_editor: false,
+            pending_bell: false,
+            scroll_offset: 0,
+            follow_tail: true,
+            headless: false,
+            pending_quit: None,
+            overlay: None,
+            draft_scroll: 0,
+            clipboard: SystemClipboard::default(),
+            input_history: InputHistory::default(),
+            invocation_counter: InvocationCounter::new(),
+            invocation_budget: InvocationBudget::default(),
+            regenerate_task_requested: false,
+            pull_request_url: None,
+            last_cli_session_id: None,
+            event_log,
+            agent_semaphore,
         })
     }
 
+    /// 이벤트 파일이 지정되어 있으면 이벤트를 한 줄 덧붙인다. 모니터링용 기록이므로 쓰지 못해도
+    /// 진행을 멈추지 않고 로그만 남긴다.
+    fn emit_event(&self, event: SessionEvent) {
+        if let Some(event_log) = &self.event_log
+            && let Err(err) = event_log.append(&event)
+        {
+            crate::cli_log!("이벤트 파일 기록 실패: {}", err);
+        }
+    }
+
+    /// 테스트에서 실제 CLI 대신 `binary`를 Claude Code CLI로 실행하게 한다.
+    #[cfg(test)]
+    pub(crate) fn with_claude_binary(mut self, binary: PathBuf) -> Self {
+        self.claude_binary = Some(binary);
+        self
+    }
+
+    /// 종료 시그널 처리 스레드에 넘길, 정리할 워크트리 목록의 공유 핸들.
+    pub fn cleanup_registry(&self) -> CleanupRegistry {
+        self.cleanup_registry.clone()
+    }
+
     pub fn fatal_error(&self) -> Option<&str> {
         self.fatal_error.as_deref()
     }
 
+    /// headless 모드 실행을 시작한다. 이전 세션 재시작(스펙 + 플랜)과 같은 흐름이지만
+    /// 파일 내용 검증과 사용자 입력 없이 곧바로 작업 추출과 코딩으로 진행한다.
+    pub fn start_headless_run(
+        &mut self,
+        workspace: PathBuf,
+        session_dir: &Path,
+    ) -> Result<(), String> {
+        let read = |name: &str| {
+            let path = session_dir.join(name);
+            std::fs::read_to_string(&path)
+                .map_err(|err| format!("{} 읽기 실패: {}", path.display(), err))
+        };
+        let spec = read("spec.md")?;
+        let plan = read("plan.md")?;
+        if let Some(error_message) = bear_worktree_error(&workspace) {
+            return Err(error_message);
+        }
+
+        self.headless = true;
+        self.load_workspace_config(&workspace);
+        self.confirmed_workspace = Some(workspace);
+        self.approved_spec = Some(spec);
+        self.last_plan_draft = Some(plan);
+        self.resumed_session_dir = Some(session_dir.to_path_buf());
+        self.resumed_has_plan = true;
+        self.start_resumed_session_workflow();
+        Ok(())
+    }
+
+    /// headless 실행이 끝났으면 종료 결과를 반환한다. 에이전트 작업이 진행 중이면 None.
+    /// 사용자 입력을 기다리는 상태는 headless 모드에서 진행할 수 없으므로 중단으로 본다.
+    pub fn headless_exit(&self) -> Option<HeadlessExit> {
+        if self.fatal_error.is_some() {
+            return Some(HeadlessExit::Aborted);
+        }
+        match self.input_mode {
+            InputMode::Done => {
+                let Some(coding_state) = &self.coding_state else {
+                    return Some(HeadlessExit::Aborted);
+                };
+                let any_blocked = coding_state
+                    .task_reports
+                    .iter()
+                    .any(|r| r.status == CodingTaskStatus::ImplementationBlocked);
+                if any_blocked {
+                    Some(HeadlessExit::TasksBlocked)
+                } else {
+                    Some(HeadlessExit::Success)
+                }
+            }
+            InputMode::CodingPaused
+            | InputMode::ManualConflict
+            | InputMode::MergeApproval
+            | InputMode::InvocationBudget => Some(HeadlessExit::Aborted),
+            InputMode::BlockedTaskSelection => Some(HeadlessExit::TasksBlocked),
+            _ if self.is_waiting_for_input() => Some(HeadlessExit::Aborted),
+            _ => None,
+        }
+    }
+
     pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.handle_scroll_key(key_event)
+            || self.handle_expand_key(key_event)
+            || self.handle_export_key(key_event)
+            || self.handle_copy_key(key_event)
+            || self.handle_overlay_key(key_event)
+        {
+            return;
+        }
+
         match self.input_mode {
             InputMode::WorkspaceConfirm => self.handle_workspace_confirm(key_event),
             InputMode::ModeSelection => self.handle_mode_selection(key_event),
@@ -203,7 +735,22 @@ impl App {
                 self.handle_multiline_input(key_event, Self::submit_requirements);
             }
             InputMode::ClarificationAnswer => {
-                self.handle_multiline_input(key_event, Self::submit_clarification_answer);
+                if key_event.code == KeyCode::Char('r')
+                    && key_event.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    self.start_requirements_revision();
+                } else {
+                    self.handle_multiline_input(key_event, Self::submit_clarification_answer);
+                }
+            }
+            InputMode::RequirementsRevision => {
+                if key_event.code == KeyCode::Char('r')
+                    && key_event.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    self.cancel_requirements_revision();
+                } else {
+                    self.handle_multiline_input(key_event, Self::submit_requirements_revision);
+                }
             }
             InputMode::SpecClarificationAnswer => {
                 self.handle_multiline_input(key_event, Self::submit_spec_clarification_answer);
@@ -213,10 +760,23 @@ impl App {
                     && key_event.modifiers.contains(KeyModifiers::CONTROL)
                 {
                     self.approve_spec();
+                } else if key_event.code == KeyCode::Char('s')
+                    && key_event.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    self.start_section_review();
                 } else {
                     self.handle_multiline_input(key_event, Self::submit_spec_feedback);
                 }
             }
+            InputMode::SectionReview => {
+                if key_event.code == KeyCode::Char('a')
+                    && key_event.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    self.approve_current_section();
+                } else {
+                    self.handle_multiline_input(key_event, Self::submit_section_feedback);
+                }
+            }
             InputMode::PlanClarificationAnswer => {
                 self.handle_multiline_input(key_event, Self::submit_plan_clarification_answer);
             }
@@ -232,114 +792,621 @@ impl App {
             InputMode::BuildTestCommandInput => {
                 self.handle_multiline_input(key_event, Self::submit_build_test_command);
             }
-            InputMode::AgentThinking | InputMode::Coding | InputMode::Done => {
+            InputMode::AgentThinking | InputMode::Coding => {
+                let is_control = key_event.modifiers.contains(KeyModifiers::CONTROL);
+                if key_event.code == KeyCode::Char('c') && is_control {
+                    self.request_cancellation();
+                } else if key_event.code == KeyCode::Char('n')
+                    && is_control
+                    && matches!(self.input_mode, InputMode::Coding)
+                {
+                    self.toggle_regenerate_task_request();
+                } else if key_event.code == KeyCode::Esc {
+                    self.request_quit_during_work();
+                }
+            }
+            InputMode::Publishing => {
+                if key_event.code == KeyCode::Esc {
+                    self.request_quit_during_work();
+                }
+            }
+            InputMode::CodingPaused => match key_event.code {
+                KeyCode::Enter => self.start_next_coding_task(),
+                KeyCode::Esc => self.should_quit = true,
+                _ => {}
+            },
+            InputMode::ManualConflict => match key_event.code {
+                KeyCode::Enter => self.continue_manual_conflict_resolution(),
+                KeyCode::Char('b') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.abandon_manual_conflict_resolution();
+                }
+                KeyCode::Esc => self.should_quit = true,
+                _ => {}
+            },
+            InputMode::MergeApproval => match key_event.code {
+                KeyCode::Enter => self.approve_pending_merge(),
+                KeyCode::Char('b') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.reject_pending_merge();
+                }
+                KeyCode::Esc => self.should_quit = true,
+                _ => {}
+            },
+            InputMode::InvocationBudget => match key_event.code {
+                KeyCode::Enter => self.continue_after_invocation_warning(),
+                KeyCode::Esc => self.should_quit = true,
+                _ => {}
+            },
+            InputMode::TaskReview => {
+                self.handle_single_line_input(key_event, Self::submit_task_review);
+            }
+            InputMode::BlockedTaskSelection => {
+                self.handle_single_line_input(key_event, Self::submit_blocked_task_selection);
+            }
+            InputMode::Done => {
                 if key_event.code == KeyCode::Esc {
                     self.should_quit = true;
                 }
             }
+            InputMode::FatalError => {
+                if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+                    self.should_quit = true;
+                }
+            }
+        }
+    }
+
+    /// 모든 모드에서 공통으로 동작하는 스크롤 키를 처리한다. 초안 미리보기 창이 떠 있으면
+    /// 초안을 스크롤한다. 처리했으면 true를 반환한다.
+    fn handle_scroll_key(&mut self, key_event: KeyEvent) -> bool {
+        let is_control = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        if self.overlay == Some(Overlay::DraftPreview) {
+            let page = self.draft_preview_rows() as isize;
+            match key_event.code {
+                KeyCode::PageUp => self.scroll_draft(-page),
+                KeyCode::PageDown => self.scroll_draft(page),
+                KeyCode::Home if is_control => self.draft_scroll = 0,
+                KeyCode::End if is_control => self.draft_scroll = self.max_draft_scroll(),
+                _ => return false,
+            }
+            return true;
+        }
+        match key_event.code {
+            KeyCode::PageUp => self.scroll_up(SCROLL_PAGE_MESSAGES),
+            KeyCode::PageDown => self.scroll_down(SCROLL_PAGE_MESSAGES),
+            KeyCode::Home if is_control => self.scroll_up(self.messages.len()),
+            KeyCode::End if is_control => self.scroll_to_bottom(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 마우스 휠을 PageUp/PageDown과 같은 스크롤 오프셋으로 처리한다.
+    /// 클릭, 드래그 같은 나머지 마우스 이벤트는 무시한다.
+    pub fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+        if self.overlay == Some(Overlay::DraftPreview) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollUp => self.scroll_draft(-DRAFT_SCROLL_WHEEL_LINES),
+                MouseEventKind::ScrollDown => self.scroll_draft(DRAFT_SCROLL_WHEEL_LINES),
+                _ => {}
+            }
+            return;
+        }
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp => self.scroll_up(SCROLL_WHEEL_MESSAGES),
+            MouseEventKind::ScrollDown => self.scroll_down(SCROLL_WHEEL_MESSAGES),
+            _ => {}
+        }
+    }
+
+    /// Ctrl+O는 가장 최근의 잘린 스트리밍 메시지를, Alt+O는 아직 펼치지 않은 모든 잘린
+    /// 메시지를 전체 내용으로 펼친다. 모든 모드에서 동작하며, 처리했으면 true를 반환한다.
+    fn handle_expand_key(&mut self, key_event: KeyEvent) -> bool {
+        if !matches!(key_event.code, KeyCode::Char('o') | KeyCode::Char('O')) {
+            return false;
+        }
+        let all = if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            false
+        } else if key_event.modifiers.contains(KeyModifiers::ALT) {
+            true
+        } else {
+            return false;
+        };
+
+        if !self.expand_truncated_messages(all) {
+            self.add_system_message("펼칠 수 있는 잘린 출력이 없습니다.");
+        }
+        true
+    }
+
+    /// Ctrl+E는 지금까지의 세션 전체를 저널 디렉토리의 마크다운 트랜스크립트로 내보낸다.
+    /// 모든 모드에서 동작하며, 처리했으면 true를 반환한다.
+    fn handle_export_key(&mut self, key_event: KeyEvent) -> bool {
+        if !matches!(key_event.code, KeyCode::Char('e') | KeyCode::Char('E'))
+            || !key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return false;
+        }
+        self.export_transcript();
+        true
+    }
+
+    /// Ctrl+Y는 보고 있는 메시지나 최근 리포트를 시스템 클립보드에 복사한다. 모든 모드에서
+    /// 동작하며, 처리했으면 true를 반환한다.
+    fn handle_copy_key(&mut self, key_event: KeyEvent) -> bool {
+        if !matches!(key_event.code, KeyCode::Char('y') | KeyCode::Char('Y'))
+            || !key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return false;
+        }
+        self.copy_focused_message();
+        true
+    }
+
+    /// Ctrl+L은 현재 세션의 디버그 로그 끝부분을, Ctrl+T는 세션 정보를, Ctrl+U는 남은 태스크의
+    /// 실행 예정표를 보여 주는 읽기 전용 창을 켜고 끈다. 다른 창이 떠 있으면 바꿔 띄운다.
+    /// 모든 모드에서 동작하며, 처리했으면 true를 반환한다.
+    fn handle_overlay_key(&mut self, key_event: KeyEvent) -> bool {
+        if !key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+        let overlay = match key_event.code {
+            KeyCode::Char('l') | KeyCode::Char('L') => Overlay::DebugLog,
+            KeyCode::Char('t') | KeyCode::Char('T') => Overlay::SessionInfo,
+            KeyCode::Char('u') | KeyCode::Char('U') => Overlay::TaskSchedule,
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                if self.overlay != Some(Overlay::DraftPreview) && self.preview_draft().is_none() {
+                    self.add_system_message(
+                        "초안 미리보기는 스펙/플랜 피드백 단계에서만 열 수 있습니다.",
+                    );
+                    return true;
+                }
+                self.draft_scroll = 0;
+                Overlay::DraftPreview
+            }
+            _ => return false,
+        };
+        self.overlay = if self.overlay == Some(overlay) { None } else { Some(overlay) };
+        true
+    }
+
+    pub fn overlay(&self) -> Option<Overlay> {
+        self.overlay
+    }
+
+    /// 현재 세션 정보. 통합 브랜치는 코딩 단계이면 코딩 상태의 값을 쓰고, Claude 세션 ID는
+    /// 마지막으로 끝난 쿼리의 값이 없으면 대화용 클라이언트의 값을 쓴다.
+    pub fn session_info(&self) -> SessionInfo {
+        let journal_dir = self.journal_dir();
+        let integration_branch = match &self.coding_state {
+            Some(state) => Some(state.integration_branch.clone()),
+            None => self.integration_branch.clone(),
+        };
+        let claude_session_id = self.last_cli_session_id.clone().or_else(|| {
+            self.claude_client
+                .as_ref()
+                .and_then(|client| client.session_id().map(str::to_string))
+        });
+        SessionInfo {
+            session_name: self.session_name.clone(),
+            session_date_dir: self.session_date_dir.clone(),
+            journal_dir: (!journal_dir.as_os_str().is_empty()).then_some(journal_dir),
+            integration_branch,
+            claude_session_id,
+            log_path: self.current_log_path(),
+        }
+    }
+
+    /// 지금 피드백 중인 초안의 이름과 내용. 스펙/플랜 피드백 단계가 아니면 None이다.
+    fn preview_draft(&self) -> Option<(&'static str, &str)> {
+        match self.input_mode {
+            InputMode::SpecFeedback => Some(("스펙", self.last_spec_draft.as_deref()?)),
+            InputMode::PlanFeedback => Some(("개발 계획", self.last_plan_draft.as_deref()?)),
+            _ => None,
+        }
+    }
+
+    /// 초안을 미리보기 창의 폭에 맞게 줄바꿈한 줄들.
+    fn draft_preview_all_lines(&self) -> Vec<String> {
+        let Some((_, draft)) = self.preview_draft() else {
+            return Vec::new();
+        };
+        let width = (self.terminal_width as usize).saturating_sub(1);
+        draft
+            .lines()
+            .flat_map(|line| wrap_text(line, width, self.wrap_mode()))
+            .collect()
+    }
+
+    /// 초안 미리보기 창에 보이는 줄 수. 입력란이 가려지지 않도록 터미널 높이의 절반에서
+    /// 헤더 한 줄을 뺀다.
+    fn draft_preview_rows(&self) -> usize {
+        (self.terminal_height as usize / 2).max(3) - 1
+    }
+
+    /// 초안의 마지막 줄이 창 맨 아래에 오는 스크롤 위치.
+    fn max_draft_scroll(&self) -> usize {
+        self.draft_preview_all_lines()
+            .len()
+            .saturating_sub(self.draft_preview_rows())
+    }
+
+    fn scroll_draft(&mut self, delta: isize) {
+        self.draft_scroll = self
+            .draft_scroll
+            .saturating_add_signed(delta)
+            .min(self.max_draft_scroll());
+    }
+
+    /// 초안 미리보기 창에 보여 줄 부분. 터미널 크기가 바뀌어도 스크롤 위치가 초안 끝을 넘지 않게
+    /// 맞춘다.
+    pub fn draft_preview(&self) -> Option<DraftPreview> {
+        let (title, _) = self.preview_draft()?;
+        let lines = self.draft_preview_all_lines();
+        let first_line = self.draft_scroll.min(self.max_draft_scroll());
+        let visible = lines
+            .iter()
+            .skip(first_line)
+            .take(self.draft_preview_rows())
+            .cloned()
+            .collect();
+        Some(DraftPreview {
+            title,
+            lines: visible,
+            first_line,
+            total_lines: lines.len(),
+        })
+    }
+
+    /// 실행 예정 창에 보여 줄 줄들. 코딩 단계가 아니면 안내 문구 한 줄이다.
+    pub fn task_schedule_lines(&self) -> Vec<String> {
+        match &self.coding_state {
+            Some(state) => state.task_schedule().lines(&state.tasks),
+            None => vec!["코딩 단계가 시작되면 남은 태스크의 실행 예정을 보여 줍니다.".to_string()],
+        }
+    }
+
+    /// 로그 보기 창에 보여 줄 디버그 로그 파일. 로그 디렉토리에서 가장 최근에 기록된 쿼리 로그를
+    /// 고르고, 아직 없으면 마지막으로 끝난 CLI 세션의 로그 경로를 쓴다. 세션 이름이 정해지기 전에는
+    /// 임시 디렉토리를 여러 프로세스가 함께 쓰므로 마지막 CLI 세션의 로그만 본다.
+    /// 디버그 로그가 꺼져 있거나 아직 끝난 쿼리가 없으면 None이다.
+    pub fn current_log_path(&self) -> Option<PathBuf> {
+        let location = self.debug_log_location();
+        if let DebugLogLocation::Directory(dir) = &location
+            && let Some(path) = log_viewer::newest_debug_log(dir)
+        {
+            return Some(path);
+        }
+        location.log_path(self.last_cli_session_id.as_deref()?)
+    }
+
+    /// 복사할 내용과 그 이름. 위로 스크롤했으면 화면 맨 위의 메시지를, 스펙/플랜 피드백 중이면
+    /// 초안을, 코딩 단계에서는 가장 최근 태스크 리포트를, 그 밖에는 마지막 메시지를 고른다.
+    /// 잘린 스트리밍 메시지는 원문을 복사한다.
+    fn copy_target(&self) -> Option<(String, String)> {
+        let message_text = |message: &ChatMessage| {
+            message.full_content.clone().unwrap_or_else(|| message.content.clone())
+        };
+        if let Some(view_start) = self.scrolled_view_start() {
+            let message = self.messages.get(view_start)?;
+            return Some((format!("메시지 {}", view_start + 1), message_text(message)));
+        }
+        if let Some((title, draft)) = self.preview_draft() {
+            return Some((format!("{} 초안", title), draft.to_string()));
+        }
+        let last_report = self.coding_state.as_ref().and_then(|state| state.task_reports.last());
+        if let Some(report) = last_report {
+            return Some((format!("[{}] 리포트", report.task_id), report.report.clone()));
+        }
+        let message = self.messages.last()?;
+        Some(("마지막 메시지".to_string(), message_text(message)))
+    }
+
+    /// 보고 있는 메시지나 최근 리포트를 시스템 클립보드에 복사하고 결과를 알린다. 클립보드를
+    /// 쓸 수 없는 환경이면 대화 기록 내보내기(Ctrl+E)를 안내한다.
+    fn copy_focused_message(&mut self) {
+        let Some((label, content)) = self.copy_target() else {
+            self.add_system_message("복사할 내용이 없습니다.");
+            return;
+        };
+        match self.clipboard.copy(&content) {
+            Ok(()) => self.add_system_message(&format!(
+                "{}을(를) 클립보드에 복사했습니다 ({}자).",
+                label,
+                content.chars().count(),
+            )),
+            Err(err) => self.add_system_message(&format!(
+                "클립보드를 쓸 수 없어 복사하지 못했습니다: {}. Ctrl+E로 대화 기록을 파일로 \
+                 내보낼 수 있습니다.",
+                err,
+            )),
+        }
+    }
+
+    fn export_transcript(&mut self) {
+        let workspace_journal_dir = self.workspace_journal_dir();
+        if workspace_journal_dir.as_os_str().is_empty() {
+            self.add_system_message("세션이 시작되기 전에는 대화 기록을 내보낼 수 없습니다.");
+            return;
+        }
+
+        // 진행 중인 태스크의 리뷰 코멘트는 워크트리 쪽 저널에 있으므로 그쪽을 먼저 찾는다.
+        let mut journal_dirs = vec![self.journal_dir()];
+        if !journal_dirs.contains(&workspace_journal_dir) {
+            journal_dirs.push(workspace_journal_dir.clone());
+        }
+        let (tasks, task_reports) = match &self.coding_state {
+            Some(state) => (state.tasks.as_slice(), state.task_reports.as_slice()),
+            None => (&[][..], &[][..]),
+        };
+        let content = transcript::build_transcript(&TranscriptSources {
+            journal_dirs,
+            qa_log: &self.qa_log,
+            tasks,
+            task_reports,
+            messages: &self.messages,
+        });
+
+        match transcript::save_transcript(&workspace_journal_dir, &content) {
+            Ok(path) => self.add_system_message(&format!(
+                "대화 기록을 내보냈습니다: {}",
+                path.display()
+            )),
+            Err(err) => {
+                self.add_system_message(&format!("대화 기록을 내보내지 못했습니다: {}", err))
+            }
+        }
+    }
+
+    fn scroll_up(&mut self, message_count: usize) {
+        if self.messages.is_empty() {
+            return;
+        }
+        self.follow_tail = false;
+        self.scroll_offset = (self.scroll_offset + message_count).min(self.messages.len());
+    }
+
+    fn scroll_down(&mut self, message_count: usize) {
+        if self.follow_tail {
+            return;
+        }
+        self.scroll_offset = self.scroll_offset.saturating_sub(message_count);
+        if self.scroll_offset == 0 {
+            self.follow_tail = true;
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        self.scroll_offset = 0;
+        self.follow_tail = true;
+    }
+
+    /// 위로 스크롤한 상태이면 화면 맨 위에 보여줄 메시지 인덱스를 반환한다.
+    pub fn scrolled_view_start(&self) -> Option<usize> {
+        if self.follow_tail {
+            return None;
         }
+        Some(self.messages.len() - self.scroll_offset)
     }
 
     pub fn handle_paste(&mut self, text: String) {
         match self.input_mode {
-            InputMode::WorkspaceConfirm
-            | InputMode::SessionDirInput => {
+            InputMode::WorkspaceConfirm | InputMode::SessionDirInput => self.paste_path(&text),
+            InputMode::TaskReview | InputMode::BlockedTaskSelection => {
                 let cleaned = text.replace("\r\n", " ").replace(['\r', '\n'], " ");
                 self.insert_text_at_cursor(&cleaned);
             }
             InputMode::ModeSelection => {}
             InputMode::RequirementsInput
             | InputMode::ClarificationAnswer
+            | InputMode::RequirementsRevision
             | InputMode::SpecClarificationAnswer
             | InputMode::SpecFeedback
+            | InputMode::SectionReview
             | InputMode::PlanClarificationAnswer
             | InputMode::PlanFeedback
             | InputMode::BuildTestCommandInput => {
                 let cleaned = text.replace("\r\n", "\n").replace('\r', "\n");
                 self.insert_text_at_cursor(&cleaned);
             }
-            InputMode::AgentThinking | InputMode::Coding | InputMode::Done => {}
+            InputMode::AgentThinking
+            | InputMode::Coding
+            | InputMode::Publishing
+            | InputMode::CodingPaused
+            | InputMode::ManualConflict
+            | InputMode::MergeApproval
+            | InputMode::InvocationBudget
+            | InputMode::Done
+            | InputMode::FatalError => {}
         }
     }
 
-    pub fn tick(&mut self) {
-        self.tick_agent_result();
-    }
-
-    fn tick_agent_result(&mut self) {
-        let receiver = match self.agent_result_receiver.take() {
-            Some(r) => r,
-            None => return,
+    /// 경로 입력란에는 붙여넣은 내용의 앞뒤 공백과 줄바꿈을 떼고 넣는다. 복사할 때 딸려 온 줄바꿈이
+    /// 공백으로 바뀌어 경로 검증에 실패하지 않도록 한다. 내용이 있는 줄이 여러 개이면 어느 줄이
+    /// 경로인지 알 수 없으므로 넣지 않고 알린다.
+    fn paste_path(&mut self, text: &str) {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        let Some(path) = lines.next() else {
+            return;
         };
+        if lines.next().is_some() {
+            self.add_system_message(
+                "경로 입력란에는 한 줄만 붙여넣을 수 있습니다. 여러 줄로 된 붙여넣기는 무시했습니다.",
+            );
+            return;
+        }
+        self.insert_text_at_cursor(path);
+    }
 
-        loop {
-            match receiver.try_recv() {
-                Ok(AgentStreamMessage::SessionName { name, date_dir }) => {
-                    if self.base_journal_dir.is_none()
-                        && let Some(ws) = &self.confirmed_workspace
-                    {
-                        self.base_journal_dir =
-                            Some(ws.join(".bear").join(&date_dir).join(&name));
-                    }
-                    let journal_dir = self.journal_dir();
-                    if let Some(user_request) = &self.confirmed_requirements
-                        && let Err(err) =
-                            spec_writing::save_user_request(&journal_dir, user_request)
-                    {
-                        self.add_system_message(
-                            &format!("사용자 요청 파일 저장 실패: {}", err),
-                        );
-                    }
-                    self.session_name = Some(name.clone());
-                    self.session_date_dir = Some(date_dir);
+    /// 세션 이름이 정해지면 저널 디렉토리에 사용자 요청을 저장하고 통합 브랜치를 만든다.
+    fn handle_session_name(&mut self, name: String, date_dir: String) {
+        if self.base_journal_dir.is_none()
+            && let Some(ws) = &self.confirmed_workspace
+        {
+            self.base_journal_dir = Some(ws.join(".bear").join(&date_dir).join(&name));
+        }
+        let journal_dir = self.journal_dir();
+        if let Some(user_request) = &self.confirmed_requirements
+            && let Err(err) = spec_writing::save_user_request(&journal_dir, user_request)
+        {
+            self.add_system_message(&format!("사용자 요청 파일 저장 실패: {}", err));
+        }
+        self.session_name = Some(name.clone());
+        self.session_date_dir = Some(date_dir);
 
-                    if self.integration_branch.is_none()
-                        && let Some(ws) = self.confirmed_workspace.clone()
+        if self.integration_branch.is_none()
+            && let Some(ws) = self.confirmed_workspace.clone()
+        {
+            match self.prepare_integration_branch(&ws, &name) {
+                Ok(branch) => {
+                    self.add_system_message(&format!("통합 브랜치 생성: {}", branch));
+                    self.integration_branch = Some(branch);
+
+                    let user_request_path = journal_dir.join("user-request.md");
+                    if user_request_path.exists()
+                        && let Err(err) = coding::commit_file_in_workspace(
+                            &ws,
+                            &user_request_path,
+                            "Add user request",
+                        )
                     {
-                        match coding::create_integration_branch(&ws, &name) {
-                            Ok(branch) => {
-                                self.add_system_message(
-                                    &format!("통합 브랜치 생성: {}", branch),
-                                );
-                                self.integration_branch = Some(branch);
-
-                                let user_request_path =
-                                    journal_dir.join("user-request.md");
-                                if user_request_path.exists()
-                                    && let Err(err) =
-                                        coding::commit_file_in_workspace(
-                                            &ws,
-                                            &user_request_path,
-                                            "Add user request",
-                                        )
-                                {
-                                    self.add_system_message(&format!(
-                                        "사용자 요청 파일 커밋 실패: {}",
-                                        err,
-                                    ));
-                                }
-                            }
-                            Err(err) => {
-                                self.add_system_message(&format!(
-                                    "통합 브랜치 생성 실패: {}",
-                                    err,
-                                ));
-                            }
-                        }
+                        self.add_system_message(&format!(
+                            "사용자 요청 파일 커밋 실패: {}",
+                            err,
+                        ));
                     }
                 }
-                Ok(AgentStreamMessage::StreamLine(line)) => {
-                    self.add_system_message(&line);
+                Err(err) => {
+                    self.add_system_message(&format!("통합 브랜치 생성 실패: {}", err));
+                }
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let previous_mode = self.input_mode;
+        self.tick_agent_result();
+        self.tick_publish_result();
+        self.check_phase_deadline_at(Instant::now());
+        self.check_invocation_budget();
+        if let Some(event) = attention_event(previous_mode, self.input_mode) {
+            self.notify_attention(event);
+        }
+    }
+
+    /// 자리를 비운 사용자가 알 수 있도록 터미널 벨을 예약하고 설정된 알림 명령을 실행한다.
+    /// 알림 명령은 기다리지 않으며, headless 모드에서는 알리지 않는다.
+    fn notify_attention(&mut self, event: AttentionEvent) {
+        if self.headless {
+            return;
+        }
+        if self.config.notify_bell() {
+            self.pending_bell = true;
+        }
+        let Some(command) = self.config.notify_command() else {
+            return;
+        };
+        let spawned = std::process::Command::new("sh")
+            .args(["-c", command])
+            .env("BEAR_NOTIFY_EVENT", event.label())
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn();
+        match spawned {
+            Ok(mut child) => {
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+            }
+            Err(err) => {
+                self.add_system_message(&format!("알림 명령 실행 실패: {}", err));
+            }
+        }
+    }
+
+    /// 에이전트 스레드가 CLI의 제한 시간을 지나서도 끝나지 않으면 스피너만 계속 돌기 때문에,
+    /// 현재 작업이 `max_phase_duration`을 넘으면 한 번 알리고 취소를 권한다.
+    fn check_phase_deadline_at(&mut self, now: Instant) {
+        let Some(max_duration) = self.config.max_phase_duration() else {
+            return;
+        };
+        if self.agent_result_receiver.is_none()
+            || self.cancel_requested
+            || self.phase_deadline_warned_for == Some(self.thinking_started_at)
+            || now.saturating_duration_since(self.thinking_started_at) < max_duration
+        {
+            return;
+        }
+        self.phase_deadline_warned_for = Some(self.thinking_started_at);
+        let task_prefix = self
+            .current_task_id()
+            .map(|task_id| format!("[{}] ", task_id))
+            .unwrap_or_default();
+        self.add_system_message(&format!(
+            "{}에이전트 작업이 최대 시간({})을 넘었습니다. 멈춘 것 같으면 Ctrl+C로 취소하세요.",
+            task_prefix,
+            timing::format_duration(max_duration),
+        ));
+        self.notify_attention(AttentionEvent::InputNeeded);
+    }
+
+    /// 울려야 할 터미널 벨이 있으면 true를 반환하고 초기화한다.
+    pub fn take_pending_bell(&mut self) -> bool {
+        std::mem::take(&mut self.pending_bell)
+    }
+
+    fn tick_agent_result(&mut self) {
+        let receiver = match self.agent_result_receiver.take() {
+            Some(r) => r,
+            None => return,
+        };
+
+        loop {
+            match receiver.try_recv() {
+                Ok(AgentStreamMessage::SessionName { name, date_dir }) => {
+                    self.handle_session_name(name, date_dir);
+                }
+                Ok(AgentStreamMessage::StreamLine(message)) => {
+                    self.add_stream_message(message);
                 }
                 Ok(AgentStreamMessage::Completed(result)) => {
-                    self.claude_client = Some(result.client);
-                    match result.outcome {
+                    let AgentThreadResult { mut client, outcome } = *result;
+                    if let Some(session_id) = client.session_id() {
+                        self.last_cli_session_id = Some(session_id.to_string());
+                    }
+                    let phase = usage_phase(&outcome);
+                    if let Some(usage) = client.take_usage() {
+                        self.usage_tracker.record(phase, &usage);
+                    }
+                    let operation = match outcome {
+                        Ok(AgentOutcome::BuildTestCompleted(_)) => OperationKind::BuildTest,
+                        _ => OperationKind::AgentQuery,
+                    };
+                    let task_id = self.current_task_id();
+                    self.timings.record(
+                        operation,
+                        phase,
+                        task_id.as_deref(),
+                        self.operation_started_at.elapsed(),
+                    );
+                    // 세션 이름이 정해지기 전에 만든 클라이언트도 이후 로그는 세션 저널에 쓴다.
+                    client.set_debug_log_location(self.debug_log_location());
+                    self.claude_client = Some(client);
+                    self.cancellation_token = None;
+                    if self.cancel_requested {
+                        self.finish_cancellation();
+                        return;
+                    }
+                    match outcome {
                         Ok(AgentOutcome::Clarification(response)) => {
                             self.handle_clarification_response(response);
                         }
                         Ok(AgentOutcome::SpecWriting(response)) => {
                             self.handle_spec_response(response);
                         }
+                        Ok(AgentOutcome::SectionRevision(response)) => {
+                            self.handle_section_revision_response(response);
+                        }
                         Ok(AgentOutcome::Planning(response)) => {
                             self.handle_plan_response(response);
                         }
@@ -352,6 +1419,13 @@ impl App {
                         Ok(AgentOutcome::ReviewCompleted(result)) => {
                             self.handle_review_result(result);
                         }
+                        Ok(AgentOutcome::ReviewWithBuildTestCompleted {
+                            review,
+                            outcome,
+                            revision,
+                        }) => {
+                            self.handle_review_with_build_test_result(review, outcome, revision);
+                        }
                         Ok(AgentOutcome::ConflictResolutionCompleted(result)) => {
                             self.handle_conflict_resolution_result(result);
                         }
@@ -364,6 +1438,9 @@ impl App {
                         Ok(AgentOutcome::FileValidation(result)) => {
                             self.handle_file_validation_result(result);
                         }
+                        Ok(AgentOutcome::NextTaskPlanned(response)) => {
+                            self.handle_next_task_plan_response(response);
+                        }
                         Err(error_message) => {
                             if matches!(self.input_mode, InputMode::Coding) {
                                 self.handle_coding_task_error(error_message);
@@ -379,30 +1456,184 @@ impl App {
                     return;
                 }
                 Err(mpsc::TryRecvError::Disconnected) => {
-                    self.handle_agent_error("에이전트 통신이 중단되었습니다.".to_string());
+                    self.cancellation_token = None;
+                    if self.cancel_requested {
+                        self.finish_cancellation();
+                    } else {
+                        self.handle_agent_error("에이전트 통신이 중단되었습니다.".to_string());
+                    }
                     return;
                 }
             }
         }
     }
 
+    /// 풀 리퀘스트 작업이 끝났으면 결과를 처리한다.
+    fn tick_publish_result(&mut self) {
+        let Some(receiver) = &self.publish_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(result) => {
+                self.publish_receiver = None;
+                self.handle_publish_result(result);
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.publish_receiver = None;
+                self.add_system_message("풀 리퀘스트 작업이 중단되었습니다.");
+                self.report_session_totals();
+            }
+        }
+    }
+
     pub fn set_keyboard_enhancement_enabled(&mut self, enabled: bool) {
         self.keyboard_enhancement_enabled = enabled;
     }
 
+    pub fn set_wake_sender(&mut self, wake_sender: mpsc::Sender<UiEvent>) {
+        self.wake_sender = Some(wake_sender);
+    }
+
+    /// 에이전트 스레드의 수신 채널을 등록한다. UI 루프의 깨우기 채널이 있으면
+    /// 전달 스레드를 거쳐 메시지 도착 시 UI가 즉시 깨어나도록 한다.
+    fn attach_agent_receiver(&mut self, receiver: mpsc::Receiver<AgentStreamMessage>) {
+        let receiver = match &self.wake_sender {
+            Some(wake_sender) => event::forward_agent_messages(receiver, wake_sender.clone()),
+            None => receiver,
+        };
+        self.agent_result_receiver = Some(receiver);
+    }
+
+    /// 새 에이전트 작업을 위한 취소 토큰을 만들고, 취소 시 돌아갈 입력 모드를 기억한다.
+    /// 입력 모드에서 시작된 작업만 복귀 지점을 갱신하므로, 에이전트 작업이 연쇄적으로
+    /// 이어지는 동안에는 최초로 작업을 시작한 입력 모드가 유지된다.
+    fn begin_cancellable_operation(&mut self) -> CancellationToken {
+        if self.is_waiting_for_input() {
+            self.mode_before_agent = self.input_mode;
+        }
+        self.cancel_requested = false;
+        self.operation_started_at = Instant::now();
+        let token = CancellationToken::new();
+        self.cancellation_token = Some(token.clone());
+        self.cleanup_registry.register_cancellation(token.clone());
+        token
+    }
+
+    /// 에이전트가 작업 중일 때의 Esc. 실수로 진행 중인 작업을 버리지 않도록 처음에는
+    /// 안내만 하고, `QUIT_CONFIRM_WINDOW` 안에 다시 누르면 실행 중인 에이전트를 취소하고
+    /// 현재 태스크 워크트리를 정리한 뒤 종료한다.
+    fn request_quit_during_work(&mut self) {
+        let confirmed = self
+            .pending_quit
+            .take()
+            .is_some_and(|pressed_at| pressed_at.elapsed() <= QUIT_CONFIRM_WINDOW);
+        if !confirmed {
+            self.pending_quit = Some(Instant::now());
+            self.add_system_message("작업이 진행 중입니다. 종료하려면 다시 Esc.");
+            return;
+        }
+
+        // 에이전트가 워크트리에 쓰는 도중에 워크트리를 지우지 않도록 CLI가 끝나기를 기다린다.
+        self.cancellation_token = None;
+        self.cleanup_registry.cancel_running_agent(AGENT_EXIT_TIMEOUT);
+        if self.coding_state.is_some() {
+            self.cleanup_current_task_worktree();
+        }
+        self.should_quit = true;
+    }
+
+    fn request_cancellation(&mut self) {
+        if self.cancel_requested {
+            return;
+        }
+        let Some(token) = &self.cancellation_token else {
+            return;
+        };
+        token.cancel();
+        self.cancel_requested = true;
+        self.add_system_message("현재 작업을 취소하는 중입니다...");
+    }
+
+    /// 에이전트 스레드가 취소를 확인하고 종료한 뒤 호출된다. 상태 전이는 다음과 같다.
+    ///
+    /// - 코딩 단계(태스크 구현, 리뷰, 충돌 해결, 빌드/테스트, 수리): 현재 태스크의
+    ///   워크트리와 브랜치를 정리하고 `CodingPaused`로 전환한다. 이미 머지된 태스크와
+    ///   통합 브랜치는 유지되며, Enter를 누르면 현재 태스크를 처음부터 다시 시작한다.
+    ///   빌드/테스트 명령은 실행 중인 명령이 끝난 뒤에 취소가 반영된다.
+    ///   점진적 계획 모드에서 다음 태스크를 계획하던 중이었다면 Enter로 계획을 다시 시작한다.
+    /// - 요구사항 분석 (`RequirementsInput`, `RequirementsRevision`에서 시작): 시작한 입력
+    ///   모드로 돌아가며 이전 요구사항을 입력창에 채운다. 질의응답 기록은 유지된다.
+    /// - 명확화 답변 이후 분석 또는 최초 스펙 작성 (`ClarificationAnswer`에서 시작):
+    ///   마지막 질의응답 라운드를 되돌리고 `ClarificationAnswer`로 돌아가며 답변을 입력창에 채운다.
+    /// - 섹션 재작성 (`SectionReview`에서 시작): `SectionReview`로 돌아가며 현재 섹션과
+    ///   승인 상태는 그대로 유지된다.
+    /// - 스펙 작성/수정 (`SpecClarificationAnswer`, `SpecFeedback`에서 시작),
+    ///   플랜 작성/수정 및 작업 추출 (`PlanClarificationAnswer`, `PlanFeedback`에서 시작):
+    ///   시작한 입력 모드로 돌아간다. 마지막 드래프트가 유지되므로 다시 승인할 수 있다.
+    ///   피드백으로 시작한 수정이었다면 그 피드백을 입력창에 채워 고쳐 다시 제출할 수 있다.
+    /// - 이전 세션 검증 및 재시작 (`SessionDirInput`에서 시작): 재시작 상태를 초기화하고
+    ///   `SessionDirInput`으로 돌아간다.
+    fn finish_cancellation(&mut self) {
+        self.cancel_requested = false;
+
+        if self.coding_state.is_some() {
+            self.cancel_current_coding_task();
+            return;
+        }
+
+        self.input_mode = self.mode_before_agent;
+        match self.mode_before_agent {
+            InputMode::RequirementsInput | InputMode::RequirementsRevision => {
+                let requirements = self.confirmed_requirements.clone().unwrap_or_default();
+                self.set_input(&requirements);
+            }
+            InputMode::ClarificationAnswer => {
+                if let Some(round) = self.qa_log.pop() {
+                    self.current_round_questions = round.questions;
+                    self.set_input(&round.answer);
+                }
+            }
+            InputMode::SpecFeedback | InputMode::PlanFeedback => {
+                if let Some(feedback) = self.last_submitted_feedback.take() {
+                    self.set_input(&feedback);
+                }
+            }
+            InputMode::SessionDirInput => {
+                self.resumed_session_dir = None;
+                self.resumed_has_plan = false;
+                self.pending_validation_kind = None;
+                self.approved_spec = None;
+                self.last_plan_draft = None;
+                self.session_name = None;
+                self.session_date_dir = None;
+                self.base_journal_dir = None;
+            }
+            _ => {}
+        }
+
+        self.add_system_message("작업이 취소되었습니다. 다시 입력하세요.");
+    }
+
+    fn cancel_current_coding_task(&mut self) {
+        self.review_state = None;
+        self.pending_build_test = None;
+        self.pending_coding_report = None;
+        self.concurrent_build_test = None;
+        self.pending_merge = None;
+        self.regenerate_task_requested = false;
+        self.claude_client = None;
+        self.cleanup_current_task_worktree();
+
+        self.add_system_message(
+            "현재 작업이 취소되었고 태스크 워크트리를 정리했습니다.\n\
+             Enter를 눌러 현재 작업을 다시 시작하거나, Esc를 눌러 종료하세요.",
+        );
+        self.input_mode = InputMode::CodingPaused;
+    }
+
     pub fn is_waiting_for_input(&self) -> bool {
-        matches!(
-            self.input_mode,
-            InputMode::WorkspaceConfirm
-                | InputMode::SessionDirInput
-                | InputMode::RequirementsInput
-                | InputMode::ClarificationAnswer
-                | InputMode::SpecClarificationAnswer
-                | InputMode::SpecFeedback
-                | InputMode::PlanClarificationAnswer
-                | InputMode::PlanFeedback
-                | InputMode::BuildTestCommandInput
-        )
+        self.input_mode.is_waiting_for_input()
     }
 
     pub fn is_mode_selection(&self) -> bool {
@@ -434,18 +1665,32 @@ impl App {
     }
 
     pub fn is_thinking(&self) -> bool {
-        matches!(self.input_mode, InputMode::AgentThinking | InputMode::Coding)
+        self.input_mode.is_thinking()
     }
 
     pub fn thinking_indicator(&self) -> &'static str {
         let dots = (self.thinking_started_at.elapsed().as_millis() / 500) % 4;
-        if matches!(self.input_mode, InputMode::Coding) {
+        if self.cancel_requested {
+            match dots {
+                0 => "Cancelling",
+                1 => "Cancelling.",
+                2 => "Cancelling..",
+                _ => "Cancelling...",
+            }
+        } else if matches!(self.input_mode, InputMode::Coding) {
             match dots {
                 0 => "Coding",
                 1 => "Coding.",
                 2 => "Coding..",
                 _ => "Coding...",
             }
+        } else if matches!(self.input_mode, InputMode::Publishing) {
+            match dots {
+                0 => "Publishing",
+                1 => "Publishing.",
+                2 => "Publishing..",
+                _ => "Publishing...",
+            }
         } else {
             match dots {
                 0 => "Analyzing",
@@ -456,6 +1701,57 @@ impl App {
         }
     }
 
+    /// 코딩 단계의 진행 상황. 에이전트나 빌드/테스트가 실행 중일 때 전체 태스크 중 현재 위치,
+    /// 현재 작업의 경과 시간, 진행 중인 단계를 보여준다.
+    pub fn progress_status(&self) -> Option<String> {
+        self.progress_status_at(Instant::now())
+    }
+
+    fn progress_status_at(&self, now: Instant) -> Option<String> {
+        if !self.is_thinking() {
+            return None;
+        }
+        let coding_state = self.coding_state.as_ref()?;
+        let task = coding_state.tasks.get(coding_state.current_task_index)?;
+
+        let stage = if self.pending_build_test.is_some() {
+            "빌드/테스트".to_string()
+        } else if let Some(review_state) = &self.review_state {
+            format!(
+                "리뷰 {}/{}",
+                review_state.iteration_count + 1,
+                review_state.max_iterations,
+            )
+        } else {
+            "코딩".to_string()
+        };
+        let elapsed = now.saturating_duration_since(self.thinking_started_at);
+
+        Some(format!(
+            "[{}/{}] {} — {} · {}",
+            coding_state.current_task_index + 1,
+            coding_state.tasks.len(),
+            task.task_id,
+            timing::format_duration(elapsed),
+            stage,
+        ))
+    }
+
+    /// 메시지와 입력 영역의 긴 줄을 나누는 방식. 렌더러와 커서 이동이 같은 방식을 써야 한다.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.config.wrap_mode()
+    }
+
+    /// 지금까지 누적된 에이전트 비용과 토큰 사용량, CLI 호출 수. 상태 줄에 표시한다.
+    pub fn usage_status(&self) -> Option<String> {
+        let invocations = self.invocation_counter.count();
+        let invocation_text = (invocations > 0).then(|| format!("CLI {}회", invocations));
+        match (self.usage_tracker.status_text(), invocation_text) {
+            (Some(usage), Some(invocations)) => Some(format!("{}  {}", usage, invocations)),
+            (usage, invocations) => usage.or(invocations),
+        }
+    }
+
     pub fn help_text(&self) -> &str {
         match self.input_mode {
             InputMode::WorkspaceConfirm
@@ -464,20 +1760,47 @@ impl App {
                 "[1-2] Select  [Up/Down] Navigate  [Enter] Confirm  [Esc] Quit"
             }
             InputMode::RequirementsInput
-            | InputMode::ClarificationAnswer
             | InputMode::SpecClarificationAnswer
             | InputMode::PlanClarificationAnswer => {
                 if self.keyboard_enhancement_enabled {
-                    "[Enter] Submit  [Shift+Enter] New line  [Ctrl+G] Editor  [Esc] Quit"
+                    "[Enter] Submit  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
                 } else {
-                    "[Enter] Submit  [Alt+Enter] New line  [Ctrl+G] Editor  [Esc] Quit"
+                    "[Enter] Submit  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
                 }
             }
-            InputMode::SpecFeedback | InputMode::PlanFeedback => {
+            InputMode::ClarificationAnswer => {
+                if self.keyboard_enhancement_enabled {
+                    "[Enter] Submit  [Ctrl+R] Revise requirements  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                } else {
+                    "[Enter] Submit  [Ctrl+R] Revise requirements  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                }
+            }
+            InputMode::RequirementsRevision => {
+                if self.keyboard_enhancement_enabled {
+                    "[Enter] Submit revision  [Ctrl+R] Back to answer  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                } else {
+                    "[Enter] Submit revision  [Ctrl+R] Back to answer  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                }
+            }
+            InputMode::SpecFeedback => {
+                if self.keyboard_enhancement_enabled {
+                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Ctrl+S] Review by section  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Edit draft  [Ctrl+P] Preview draft  [Esc] Quit"
+                } else {
+                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Ctrl+S] Review by section  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Edit draft  [Ctrl+P] Preview draft  [Esc] Quit"
+                }
+            }
+            InputMode::SectionReview => {
+                if self.keyboard_enhancement_enabled {
+                    "[Enter] Submit section feedback  [Ctrl+A] Approve section  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                } else {
+                    "[Enter] Submit section feedback  [Ctrl+A] Approve section  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Editor  [Esc] Quit"
+                }
+            }
+            InputMode::PlanFeedback => {
                 if self.keyboard_enhancement_enabled {
-                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Shift+Enter] New line  [Ctrl+G] Editor  [Esc] Quit"
+                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Shift+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Edit draft  [Ctrl+P] Preview draft  [Esc] Quit"
                 } else {
-                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Alt+Enter] New line  [Ctrl+G] Editor  [Esc] Quit"
+                    "[Enter] Submit feedback  [Ctrl+A] Approve  [Alt+Enter] New line  [Alt+Up/Down] History  [Ctrl+G] Edit draft  [Ctrl+P] Preview draft  [Esc] Quit"
                 }
             }
             InputMode::BuildTestCommandInput => {
@@ -487,7 +1810,29 @@ impl App {
                     "[Enter] Submit  [Alt+Enter] New line  [Ctrl+G] Editor  [Esc] Quit"
                 }
             }
-            InputMode::AgentThinking | InputMode::Coding | InputMode::Done => "[Esc] Quit",
+            InputMode::AgentThinking => {
+                "[Ctrl+C] Cancel  [Ctrl+O] Expand output  [Ctrl+E] Export  [Ctrl+L] Log  [Ctrl+T] Info  [Esc twice] Quit"
+            }
+            InputMode::Coding => {
+                "[Ctrl+C] Cancel  [Ctrl+N] Regenerate task  [Ctrl+O] Expand output  [Ctrl+E] Export  [Ctrl+L] Log  [Ctrl+T] Info  [Ctrl+U] Schedule  [Esc twice] Quit"
+            }
+            InputMode::TaskReview => {
+                "[Number + Enter] Include/exclude  [From To + Enter] Move  [Enter] Start coding  [Esc] Quit"
+            }
+            InputMode::Publishing => {
+                "[Ctrl+O] Expand output  [Ctrl+E] Export  [Ctrl+L] Log  [Ctrl+T] Info  [Esc twice] Quit"
+            }
+            InputMode::CodingPaused => "[Enter] Retry task  [Esc] Quit",
+            InputMode::ManualConflict => {
+                "[Enter] Continue rebase  [Ctrl+B] Abort rebase and block task  [Esc] Quit"
+            }
+            InputMode::MergeApproval => "[Enter] Approve merge  [Ctrl+B] Block task  [Esc] Quit",
+            InputMode::InvocationBudget => "[Enter] Continue  [Esc] Quit",
+            InputMode::BlockedTaskSelection => {
+                "[Number + Enter] Re-run blocked task  [Enter] Finish  [Esc] Quit"
+            }
+            InputMode::Done => "[Esc] Quit",
+            InputMode::FatalError => "[Enter/Esc] Quit  [Ctrl+E] Export  [Ctrl+L] Log",
         }
     }
 
@@ -496,6 +1841,11 @@ impl App {
             KeyCode::Enter => {
                 let trimmed = self.input_buffer.trim().to_string();
                 let workspace = if trimmed.is_empty() {
+                    if let Some(error_message) = bear_worktree_error(&self.current_directory) {
+                        self.add_system_message(&error_message);
+                        self.clear_input();
+                        return;
+                    }
                     self.current_directory.clone()
                 } else {
                     let path = PathBuf::from(&trimmed);
@@ -512,6 +1862,15 @@ impl App {
                     "워크스페이스가 설정되었습니다: {}",
                     workspace.display()
                 ));
+                self.load_workspace_config(&workspace);
+                // 워크스페이스 설정의 브랜치 접두사로 남은 브랜치를 찾도록 설정을 읽은 뒤에 보여준다.
+                let branch_prefix = self.config.branch_prefix();
+                if let Some(summary) =
+                    session_history::build_startup_summary(&workspace, branch_prefix)
+                {
+                    self.add_system_message(&summary);
+                }
+                self.load_input_history(&workspace);
                 self.confirmed_workspace = Some(workspace);
                 self.clear_input();
                 self.transition_to_mode_selection();
@@ -522,6 +1881,82 @@ impl App {
         }
     }
 
+    /// 워크스페이스 루트의 `.bearconfig`를 적용한다. 파일이 잘못되었으면 알리고 기존 설정으로 진행한다.
+    fn load_workspace_config(&mut self, workspace: &Path) {
+        match self.config.load_workspace_file(workspace) {
+            Ok(Some(path)) => {
+                self.add_system_message(&format!("설정 파일을 적용했습니다: {}", path.display()));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                self.add_system_message(&format!("설정 파일을 무시합니다: {}", err));
+            }
+        }
+    }
+
+    /// 워크스페이스의 `.bear`에 저장된 입력 기록을 읽는다. 읽지 못하면 알리고 빈 기록으로 진행한다.
+    fn load_input_history(&mut self, workspace: &Path) {
+        let path = workspace.join(".bear").join(INPUT_HISTORY_FILE_NAME);
+        match InputHistory::load(path.clone()) {
+            Ok(history) => self.input_history = history,
+            Err(err) => {
+                self.add_system_message(&format!(
+                    "입력 기록을 불러오지 못했습니다 ({}): {}",
+                    path.display(),
+                    err
+                ));
+            }
+        }
+    }
+
+    /// 현재 입력 모드가 기록을 공유하는 입력란 종류. 빌드/테스트 명령 입력처럼 기록하지 않는
+    /// 입력란이면 None.
+    fn history_field(&self) -> Option<InputField> {
+        match self.input_mode {
+            InputMode::RequirementsInput | InputMode::RequirementsRevision => {
+                Some(InputField::Requirements)
+            }
+            InputMode::ClarificationAnswer
+            | InputMode::SpecClarificationAnswer
+            | InputMode::PlanClarificationAnswer => Some(InputField::Answer),
+            InputMode::SpecFeedback | InputMode::SectionReview | InputMode::PlanFeedback => {
+                Some(InputField::Feedback)
+            }
+            _ => None,
+        }
+    }
+
+    /// Alt+Up/Down으로 현재 입력란의 이전 또는 다음 기록을 입력 영역에 불러온다.
+    fn recall_input_history(&mut self, older: bool) {
+        let Some(field) = self.history_field() else {
+            return;
+        };
+        let recalled = if older {
+            self.input_history.previous(field, &self.input_buffer)
+        } else {
+            self.input_history.next(field)
+        };
+        if let Some(text) = recalled {
+            self.set_input(&text);
+        }
+    }
+
+    /// 입력을 제출하고, 제출이 받아들여져 입력 영역이 바뀌었으면 제출한 입력을 기록한다.
+    fn submit_and_record_input(&mut self, submit_action: fn(&mut Self)) {
+        let field = self.history_field();
+        let submitted = self.input_buffer.clone();
+        submit_action(self);
+        let Some(field) = field else {
+            return;
+        };
+        if submitted.trim().is_empty() || self.input_buffer == submitted {
+            return;
+        }
+        if let Err(err) = self.input_history.record(field, &submitted) {
+            self.add_system_message(&format!("입력 기록을 저장하지 못했습니다: {}", err));
+        }
+    }
+
     fn handle_multiline_input(
         &mut self,
         key_event: KeyEvent,
@@ -532,7 +1967,7 @@ impl App {
                 self.insert_char_at_cursor('\n');
             }
             KeyCode::Enter => {
-                submit_action(self);
+                self.submit_and_record_input(submit_action);
             }
             KeyCode::Backspace => {
                 self.delete_char_before_cursor();
@@ -546,6 +1981,12 @@ impl App {
             KeyCode::Right => {
                 self.move_cursor_right();
             }
+            KeyCode::Up if key_event.modifiers.contains(KeyModifiers::ALT) => {
+                self.recall_input_history(true);
+            }
+            KeyCode::Down if key_event.modifiers.contains(KeyModifiers::ALT) => {
+                self.recall_input_history(false);
+            }
             KeyCode::Up => {
                 self.move_cursor_up();
             }
@@ -621,6 +2062,9 @@ impl App {
     }
 
     fn transition_to_mode_selection(&mut self) {
+        if self.require_workspace().is_none() {
+            return;
+        }
         self.selected_mode_index = 0;
         self.add_system_message(
             "작업 모드를 선택하세요:\n\
@@ -632,6 +2076,9 @@ impl App {
     }
 
     fn transition_to_session_dir_input(&mut self) {
+        if self.require_workspace().is_none() {
+            return;
+        }
         self.add_system_message(
             "이전 세션 디렉토리 경로를 입력하세요. (절대 경로 또는 상대 경로)",
         );
@@ -640,6 +2087,9 @@ impl App {
     }
 
     fn transition_to_requirements_input(&mut self) {
+        if self.require_workspace().is_none() {
+            return;
+        }
         self.add_system_message("구현할 요구사항을 입력하세요.");
         self.input_mode = InputMode::RequirementsInput;
     }
@@ -653,7 +2103,9 @@ impl App {
         self.add_user_message(&raw_path);
         self.clear_input();
 
-        let workspace = self.confirmed_workspace.clone().unwrap();
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
         let resolved_dir =
             match file_validation::validate_directory_locally(&raw_path, &workspace) {
                 Ok(dir) => dir,
@@ -688,6 +2140,15 @@ impl App {
     }
 
     fn start_file_content_validation(&mut self, path: PathBuf) {
+        if !self.config.validate_session_files() {
+            self.add_system_message("설정에 따라 파일 내용 검증을 건너뜁니다.");
+            self.handle_file_validation_result(FileValidationResponse {
+                valid: true,
+                reason: String::new(),
+            });
+            return;
+        }
+
         if let Err(error_message) = self.ensure_claude_client() {
             self.add_system_message(&format!("클라이언트 생성 실패: {}", error_message));
             self.input_mode = InputMode::Done;
@@ -696,12 +2157,15 @@ impl App {
 
         let mut client = self.claude_client.take().expect("client must be available");
         client.reset_session();
-        client.set_system_prompt(Some(file_validation::system_prompt().to_string()));
+        let system_prompt = self.system_prompt_for(PromptKind::FileValidation);
+        client.set_system_prompt(Some(system_prompt));
 
         let kind = self.pending_validation_kind.unwrap();
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Other);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
@@ -716,10 +2180,10 @@ impl App {
                 .map(AgentOutcome::FileValidation)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -786,15 +2250,26 @@ impl App {
             return;
         }
 
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
+
         let mut client = self.claude_client.take().expect("client must be available");
         client.reset_session();
 
         let has_plan = self.resumed_has_plan;
         let resumed_dir = self.resumed_session_dir.clone().unwrap();
-        let workspace = self.confirmed_workspace.clone().unwrap();
+        let system_prompt = self.system_prompt_for(if has_plan {
+            PromptKind::TaskExtraction
+        } else {
+            PromptKind::Planning
+        });
+        let (session_id, date_dir) = self.new_session_name();
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Plan);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
@@ -809,18 +2284,16 @@ impl App {
         }
 
         std::thread::spawn(move || {
-            let session_id = session_naming::generate_session_id();
-            let date_dir = session_naming::today_date_string();
             let new_journal_dir = workspace
                 .join(".bear")
                 .join(&date_dir)
                 .join(&session_id);
 
             if let Err(err) = std::fs::create_dir_all(&new_journal_dir) {
-                let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+                let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                     client,
                     outcome: Err(format!("세션 디렉토리 생성 실패: {}", err)),
-                }));
+                })));
                 return;
             }
 
@@ -833,10 +2306,10 @@ impl App {
             let source_spec = resumed_dir.join("spec.md");
             let dest_spec = new_journal_dir.join("spec.md");
             if let Err(err) = std::fs::copy(&source_spec, &dest_spec) {
-                let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+                let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                     client,
                     outcome: Err(format!("스펙 파일 복사 실패: {}", err)),
-                }));
+                })));
                 return;
             }
 
@@ -853,17 +2326,15 @@ impl App {
                 let source_plan = resumed_dir.join("plan.md");
                 let dest_plan = new_journal_dir.join("plan.md");
                 if let Err(err) = std::fs::copy(&source_plan, &dest_plan) {
-                    let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+                    let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                         client,
                         outcome: Err(format!("플랜 파일 복사 실패: {}", err)),
-                    }));
+                    })));
                     return;
                 }
 
                 // 태스크 추출 시작
-                client.set_system_prompt(
-                    Some(coding::task_extraction_system_prompt().to_string()),
-                );
+                client.set_system_prompt(Some(system_prompt));
 
                 let request = ClaudeCodeRequest {
                     user_prompt: coding::build_task_extraction_prompt(&dest_plan),
@@ -878,13 +2349,13 @@ impl App {
                     .map(AgentOutcome::TaskExtraction)
                     .map_err(|err| err.to_string());
 
-                let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+                let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                     client,
                     outcome,
-                }));
+                })));
             } else {
                 // 플랜 작성 시작
-                client.set_system_prompt(Some(planning::system_prompt().to_string()));
+                client.set_system_prompt(Some(system_prompt));
 
                 let request = ClaudeCodeRequest {
                     user_prompt: planning::build_initial_plan_prompt(
@@ -902,10 +2373,10 @@ impl App {
                     .map(AgentOutcome::Planning)
                     .map_err(|err| err.to_string());
 
-                let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+                let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                     client,
                     outcome,
-                }));
+                })));
             }
         });
     }
@@ -926,13 +2397,43 @@ impl App {
             return;
         }
 
+        if self.config.skip_clarification() {
+            self.skip_clarification();
+            return;
+        }
+
         self.add_system_message("요구사항을 분석 중입니다. 잠시만 기다려 주세요.");
+        self.emit_event(SessionEvent::PhaseStarted { phase: EventPhase::Clarification });
         self.start_clarification_query();
     }
 
-    fn submit_clarification_answer(&mut self) {
-        let answer = self.input_buffer.trim().to_string();
-        if answer.is_empty() {
+    /// 새 세션 이름과 날짜 디렉토리를 정한다. 같은 날짜의 기존 저널 디렉토리와 겹치지 않게 한다.
+    fn new_session_name(&self) -> (String, String) {
+        let style = self.config.session_name_style();
+        let name = session_naming::generate_session_name(style);
+        let date_dir = session_naming::today_date_string();
+        let name = match &self.confirmed_workspace {
+            Some(ws) => {
+                session_naming::ensure_unique_name(&ws.join(".bear").join(&date_dir), &name, style)
+            }
+            None => name,
+        };
+        (name, date_dir)
+    }
+
+    /// 명확화 질문 없이 세션 이름을 정하고 바로 스펙 작성을 시작한다.
+    fn skip_clarification(&mut self) {
+        if self.session_name.is_none() {
+            let (name, date_dir) = self.new_session_name();
+            self.handle_session_name(name, date_dir);
+        }
+        self.add_system_message("요구사항 명확화를 건너뛰고 스펙 문서를 작성합니다.");
+        self.start_spec_writing_query(true);
+    }
+
+    fn submit_clarification_answer(&mut self) {
+        let answer = self.input_buffer.trim().to_string();
+        if answer.is_empty() {
             return;
         }
 
@@ -946,17 +2447,165 @@ impl App {
         self.start_clarification_query();
     }
 
+    /// 작성 중이던 답변을 보관하고, 현재 요구사항을 입력창에 채워 수정할 수 있게 한다.
+    fn start_requirements_revision(&mut self) {
+        self.clarification_answer_draft = std::mem::take(&mut self.input_buffer);
+        let requirements = self.confirmed_requirements.clone().unwrap_or_default();
+        self.set_input(&requirements);
+        self.input_mode = InputMode::RequirementsRevision;
+        self.add_system_message(
+            "요구사항을 수정한 뒤 제출하세요. 지금까지의 답변은 유지됩니다. \
+             Ctrl+R을 누르면 수정을 취소하고 답변으로 돌아갑니다.",
+        );
+    }
+
+    fn cancel_requirements_revision(&mut self) {
+        let draft = std::mem::take(&mut self.clarification_answer_draft);
+        self.set_input(&draft);
+        self.input_mode = InputMode::ClarificationAnswer;
+        self.add_system_message("요구사항 수정을 취소했습니다. 질문에 답변해 주세요.");
+    }
+
+    /// 수정된 요구사항으로 명확화를 다시 시작한다. 이전 라운드의 질의응답은 그대로 전달하고,
+    /// 아직 답하지 않은 현재 라운드의 질문은 새 요구사항 기준으로 다시 받는다.
+    fn submit_requirements_revision(&mut self) {
+        let requirements = self.input_buffer.trim().to_string();
+        if requirements.is_empty() {
+            return;
+        }
+
+        self.add_user_message(&requirements);
+        self.clear_input();
+        self.clarification_answer_draft.clear();
+        self.current_round_questions.clear();
+
+        let journal_dir = self.workspace_journal_dir();
+        if !journal_dir.as_os_str().is_empty()
+            && let Err(err) = spec_writing::save_user_request(&journal_dir, &requirements)
+        {
+            self.add_system_message(&format!("사용자 요청 파일 저장 실패: {}", err));
+        }
+        self.confirmed_requirements = Some(requirements);
+
+        if let Err(error_message) = self.ensure_claude_client() {
+            self.add_system_message(&format!("클라이언트 생성 실패: {}", error_message));
+            self.input_mode = InputMode::Done;
+            return;
+        }
+
+        self.add_system_message("수정된 요구사항으로 다시 분석합니다. 잠시만 기다려 주세요.");
+        self.start_clarification_query();
+    }
+
+    /// `kind` 에이전트의 시스템 프롬프트. 워크스페이스나 `BEAR_PROMPT_DIR`에 오버라이드 파일이
+    /// 있으면 설정한 방식대로 적용하고, 파일을 읽지 못하면 알린 뒤 기본 프롬프트를 쓴다.
+    fn system_prompt_for(&mut self, kind: PromptKind) -> String {
+        let output_language = self.config.output_language().to_string();
+        let Some(workspace) = self.confirmed_workspace.clone() else {
+            return kind.built_in(&output_language);
+        };
+        let overrides = PromptOverrides::new(
+            &workspace,
+            self.config.prompt_dir(),
+            self.config.prompt_override_mode(),
+        );
+        overrides.resolve(kind, &output_language).unwrap_or_else(|err| {
+            self.add_system_message(&format!(
+                "시스템 프롬프트 오버라이드 파일을 읽지 못해 기본 프롬프트를 씁니다: {}",
+                err,
+            ));
+            kind.built_in(&output_language)
+        })
+    }
+
+    /// 설정의 재시도 정책을 적용한 새 클라이언트를 만든다.
+    fn new_claude_client(
+        &self,
+        working_directory: PathBuf,
+        system_prompt: Option<String>,
+    ) -> Result<ClaudeCodeClient, ClaudeCodeClientError> {
+        let api_key = self.config.api_key().to_string();
+        #[cfg(test)]
+        let mut client = match &self.claude_binary {
+            Some(binary_path) => ClaudeCodeClient::with_binary_path(
+                api_key,
+                working_directory,
+                system_prompt,
+                binary_path.clone(),
+            ),
+            None => ClaudeCodeClient::new(api_key, working_directory, system_prompt)?,
+        };
+        #[cfg(not(test))]
+        let mut client = ClaudeCodeClient::new(api_key, working_directory, system_prompt)?;
+        client.set_retry_policy(RetryPolicy {
+            max_retries: self.config.max_retries(),
+            initial_backoff: self.config.initial_backoff(),
+        });
+        client.set_model_fallbacks(self.config.model_fallbacks().to_vec());
+        client.set_debug_log_location(self.debug_log_location());
+        client.set_validate_output_schema(self.config.validate_output_schema());
+        client.set_base_url(self.config.base_url().map(str::to_string));
+        client.set_https_proxy(self.config.https_proxy().map(str::to_string));
+        client.set_verbosity(self.config.verbosity());
+        client.set_agent_semaphore(self.agent_semaphore.clone());
+        client.set_invocation_counter(Some(self.invocation_counter.clone()));
+        Ok(client)
+    }
+
+    /// 태스크 워크트리에서 일하는 코딩·리뷰 에이전트의 클라이언트. 작업 디렉토리는 워크트리로
+    /// 두고, 설정한 참고 디렉토리를 읽을 수 있도록 추가한다.
+    fn new_task_agent_client(
+        &self,
+        worktree_path: PathBuf,
+        system_prompt: Option<String>,
+    ) -> Result<ClaudeCodeClient, ClaudeCodeClientError> {
+        let mut client = self.new_claude_client(worktree_path, system_prompt)?;
+        client.set_additional_directories(self.config.context_dirs().to_vec());
+        Ok(client)
+    }
+
+    /// 쿼리별 디버그 로그를 쓸 위치. 설정한 디렉토리가 없으면 세션 저널 아래의 `logs`를 쓰고,
+    /// 세션 이름이 정해지기 전에만 임시 디렉토리를 쓴다.
+    fn debug_log_location(&self) -> DebugLogLocation {
+        if !self.config.debug_log_enabled() {
+            return DebugLogLocation::Disabled;
+        }
+        if let Some(dir) = self.config.debug_log_dir() {
+            return DebugLogLocation::Directory(dir.to_path_buf());
+        }
+        let journal_dir = self.workspace_journal_dir();
+        if journal_dir.as_os_str().is_empty() {
+            return DebugLogLocation::TempDir;
+        }
+        DebugLogLocation::Directory(journal_dir.join("logs"))
+    }
+
+    /// 단계별 설정에 따라 클라이언트가 다음 쿼리에 쓸 모델과 허용 도구를 정한다.
+    /// 파일을 고치는 코딩 단계만 모든 도구를 쓰고, 나머지 단계는 읽기 전용 도구만 쓴다.
+    fn prepare_client_for_phase(&self, client: &mut ClaudeCodeClient, phase: UsagePhase) {
+        client.set_model(self.config.model_for(phase).to_string());
+        let tool_set = match phase {
+            UsagePhase::Coding => ToolSet::Full,
+            UsagePhase::Clarification
+            | UsagePhase::Spec
+            | UsagePhase::Plan
+            | UsagePhase::Review
+            | UsagePhase::Other => ToolSet::ReadOnly,
+        };
+        client.set_tool_set(tool_set);
+    }
+
     fn ensure_claude_client(&mut self) -> Result<(), String> {
         if self.claude_client.is_some() {
             return Ok(());
         }
 
-        let workspace = self.confirmed_workspace.clone().unwrap();
-        let client = ClaudeCodeClient::new(
-            self.config.api_key().to_string(),
-            workspace,
-            Some(clarification::system_prompt().to_string()),
-        )
+        let Some(workspace) = self.require_workspace() else {
+            return Err(MISSING_WORKSPACE_MESSAGE.to_string());
+        };
+        let system_prompt = self.system_prompt_for(PromptKind::Clarification);
+        let client = self
+            .new_claude_client(workspace, Some(system_prompt))
             .map_err(|err| err.to_string())?;
 
         self.claude_client = Some(client);
@@ -967,17 +2616,17 @@ impl App {
         let mut client = self.claude_client.take().expect("client must be available");
         let original_request = self.confirmed_requirements.clone().unwrap();
         let qa_log = self.qa_log.clone();
-        let needs_session_name = self.session_name.is_none();
+        let new_session_name = self.session_name.is_none().then(|| self.new_session_name());
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Clarification);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
         std::thread::spawn(move || {
-            if needs_session_name {
-                let name = session_naming::generate_session_id();
-                let date_dir = session_naming::today_date_string();
+            if let Some((name, date_dir)) = new_session_name {
                 let _ = sender.send(AgentStreamMessage::SessionName { name, date_dir });
             }
 
@@ -994,7 +2643,7 @@ impl App {
                 .map(AgentOutcome::Clarification)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult { client, outcome }));
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult { client, outcome })));
         });
     }
 
@@ -1015,17 +2664,71 @@ impl App {
         self.input_mode = InputMode::ClarificationAnswer;
     }
 
+    /// 워크스페이스가 필요한 단계에서 확정된 워크스페이스를 반환한다. 상태 머신이 순서를
+    /// 벗어나 워크스페이스 없이 진입했다면 패닉 대신 내부 오류로 세션을 종료하고 None을 반환한다.
+    fn require_workspace(&mut self) -> Option<PathBuf> {
+        if let Some(workspace) = &self.confirmed_workspace {
+            return Some(workspace.clone());
+        }
+        self.handle_internal_error(MISSING_WORKSPACE_MESSAGE);
+        None
+    }
+
+    fn handle_internal_error(&mut self, message: &str) {
+        if self.fatal_error.is_some() {
+            return;
+        }
+        self.add_system_message(&format!("내부 오류: {}", message));
+        self.enter_fatal_error(format!("internal error: {}", message));
+    }
+
     fn handle_agent_error(&mut self, error_message: String) {
         self.add_system_message(&format!("에이전트 오류: {}", error_message));
-        self.fatal_error = Some(error_message);
-        self.should_quit = true;
+        self.enter_fatal_error(error_message);
+    }
+
+    /// 더 진행할 수 없는 오류를 기록하고 오류 화면으로 바꾼다. TUI를 바로 닫으면 오류를 읽기도
+    /// 전에 화면이 사라지므로, 사용자가 Enter나 Esc를 눌러야 종료한다.
+    fn enter_fatal_error(&mut self, fatal_error: String) {
+        self.fatal_error = Some(fatal_error);
+        self.input_mode = InputMode::FatalError;
+    }
+
+    /// 오류 화면에 보여 줄 줄들. 오류 메시지와 함께 사후 분석에 필요한 세션 저널과 디버그 로그의
+    /// 위치를 담는다. 오류 화면이 아니면 None이다.
+    pub fn fatal_error_screen(&self) -> Option<Vec<String>> {
+        if !matches!(self.input_mode, InputMode::FatalError) {
+            return None;
+        }
+        let mut lines: Vec<String> =
+            self.fatal_error.as_deref()?.lines().map(String::from).collect();
+        let journal_dir = self.workspace_journal_dir();
+        lines.push(String::new());
+        lines.push(if journal_dir.as_os_str().is_empty() {
+            "세션 저널: (세션이 시작되기 전)".to_string()
+        } else {
+            format!("세션 저널: {}", journal_dir.display())
+        });
+        lines.push(match (self.current_log_path(), self.debug_log_location()) {
+            (Some(path), _) => format!("디버그 로그: {}", path.display()),
+            (None, DebugLogLocation::Directory(dir)) => format!("디버그 로그: {}", dir.display()),
+            (None, DebugLogLocation::TempDir) => {
+                format!("디버그 로그: {}", std::env::temp_dir().display())
+            }
+            (None, DebugLogLocation::Disabled) => "디버그 로그: (꺼짐)".to_string(),
+        });
+        Some(lines)
     }
 
     fn start_spec_writing_query(&mut self, is_initial: bool) {
         let mut client = self.claude_client.take().expect("client must be available");
+        if is_initial {
+            self.emit_event(SessionEvent::PhaseStarted { phase: EventPhase::Spec });
+        }
 
         let qa_log = self.qa_log.clone();
         let user_request_path = self.journal_dir().join("user-request.md");
+        let output_language = self.config.output_language().to_string();
         let user_feedback = if is_initial {
             None
         } else {
@@ -1043,18 +2746,24 @@ impl App {
             should_send
         };
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Spec);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
         std::thread::spawn(move || {
             let user_prompt = if is_initial {
-                spec_writing::build_initial_spec_prompt(&user_request_path, &qa_log)
+                spec_writing::build_initial_spec_prompt(
+                    &user_request_path,
+                    &qa_log,
+                    &output_language,
+                )
             } else {
                 let feedback = user_feedback.unwrap_or_default();
                 if send_full_revision_instructions {
-                    spec_writing::build_revision_prompt(&feedback)
+                    spec_writing::build_revision_prompt(&feedback, &output_language)
                 } else {
                     spec_writing::build_followup_revision_prompt(&feedback)
                 }
@@ -1073,10 +2782,10 @@ impl App {
                 .map(AgentOutcome::SpecWriting)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -1086,7 +2795,7 @@ impl App {
                 let draft = response.spec_draft.unwrap_or_default();
 
                 self.add_system_message(&format!(
-                    "스펙 드래프트가 작성되었습니다:\n\n{}\n\n피드백을 입력하거나, Ctrl+A를 눌러 승인하세요.",
+                    "스펙 드래프트가 작성되었습니다:\n\n{}\n\n피드백을 입력하거나, Ctrl+A를 눌러 승인하세요. Ctrl+S를 누르면 섹션별로 검토합니다.",
                     draft
                 ));
                 self.last_spec_draft = Some(draft);
@@ -1131,12 +2840,17 @@ impl App {
 
         self.add_user_message(&feedback);
         self.clear_input();
+        self.last_submitted_feedback = Some(feedback);
 
-        self.add_system_message("피드백을 반영하여 스펙을 수정합니다.");
+        self.add_system_message(
+            "피드백을 반영하여 스펙을 수정합니다. \
+             Ctrl+C로 취소하면 피드백을 고쳐 다시 제출할 수 있습니다.",
+        );
         self.start_spec_writing_query(false);
     }
 
     fn approve_spec(&mut self) {
+        self.last_submitted_feedback = None;
         let spec = match &self.last_spec_draft {
             Some(spec) => spec.clone(),
             None => {
@@ -1152,32 +2866,192 @@ impl App {
             self.add_system_message(&format!("스펙 파일 저장 실패: {}", err));
         }
 
-        if let Some(ws) = &self.confirmed_workspace {
-            let spec_path = journal_dir.join("spec.md");
-            if let Err(err) = coding::commit_file_in_workspace(
-                ws,
-                &spec_path,
-                "Add approved specification",
-            ) {
-                self.add_system_message(&format!("스펙 파일 커밋 실패: {}", err));
+        self.commit_approved_artifact(
+            &journal_dir.join("spec.md"),
+            "Add approved specification",
+            "스펙",
+        );
+
+        if self.config.incremental_planning() {
+            self.add_system_message(
+                "스펙이 승인되었습니다. 점진적 계획 모드로 작업을 하나씩 계획하고 구현합니다.",
+            );
+            if self.enter_coding_phase(Vec::new(), true) {
+                self.start_next_coding_task();
             }
+            return;
         }
 
         self.add_system_message("스펙이 승인되었습니다. 개발 계획을 작성합니다.");
         self.start_plan_writing_query(true);
     }
 
+    /// 설정에 따라 승인된 스펙/플랜 파일을 워크스페이스 저장소에 커밋한다.
+    /// 워크스페이스가 git 저장소가 아니면 커밋을 건너뛰고 안내만 남긴다.
+    fn commit_approved_artifact(&mut self, file_path: &Path, commit_message: &str, label: &str) {
+        if !self.config.commit_spec_plan() {
+            return;
+        }
+        let Some(ws) = self.confirmed_workspace.clone() else {
+            return;
+        };
+
+        if !coding::is_git_repository(&ws) {
+            self.add_system_message(&format!(
+                "워크스페이스가 git 저장소가 아니므로 {} 파일 커밋을 건너뜁니다.",
+                label
+            ));
+            return;
+        }
+
+        let result = match self.config.spec_plan_branch() {
+            Some(branch) => coding::commit_file_to_branch(&ws, file_path, branch, commit_message),
+            None => coding::commit_file_in_workspace(&ws, file_path, commit_message),
+        };
+        if let Err(err) = result {
+            self.add_system_message(&format!("{} 파일 커밋 실패: {}", label, err));
+        }
+    }
+
+    fn start_section_review(&mut self) {
+        let Some(draft) = &self.last_spec_draft else {
+            self.add_system_message("검토할 스펙이 없습니다.");
+            return;
+        };
+
+        let sections = spec_writing::split_into_sections(draft);
+        if sections.is_empty() {
+            self.add_system_message("스펙에서 검토할 섹션을 찾을 수 없습니다.");
+            return;
+        }
+
+        self.add_system_message(&format!(
+            "섹션별 검토를 시작합니다. 총 {}개 섹션을 모두 승인하면 스펙이 확정됩니다.",
+            sections.len(),
+        ));
+        self.section_review = Some(SectionReviewState::new(sections));
+        self.clear_input();
+        self.show_current_section();
+    }
+
+    fn show_current_section(&mut self) {
+        let Some(state) = &self.section_review else {
+            return;
+        };
+        let Some(section) = state.current_section() else {
+            return;
+        };
+
+        let message = format!(
+            "[섹션 {}/{}] {}\n\n{}\n\n이 섹션에 대한 피드백을 입력하거나, Ctrl+A를 눌러 승인하세요.",
+            state.current_index() + 1,
+            state.section_count(),
+            section.title,
+            section.content,
+        );
+        self.add_system_message(&message);
+        self.input_mode = InputMode::SectionReview;
+    }
+
+    fn approve_current_section(&mut self) {
+        let Some(state) = self.section_review.as_mut() else {
+            return;
+        };
+        let title = state
+            .current_section()
+            .map(|section| section.title.clone())
+            .unwrap_or_default();
+        let all_approved = state.approve_current();
+        self.add_system_message(&format!("섹션 승인: {}", title));
+
+        if !all_approved {
+            self.show_current_section();
+            return;
+        }
+
+        let state = self.section_review.take().unwrap();
+        self.last_spec_draft = Some(state.assemble());
+        self.add_system_message("모든 섹션이 승인되었습니다.");
+        self.approve_spec();
+    }
+
+    fn submit_section_feedback(&mut self) {
+        let feedback = self.input_buffer.trim().to_string();
+        if feedback.is_empty() {
+            return;
+        }
+        let Some(section_content) = self
+            .section_review
+            .as_ref()
+            .and_then(|state| state.current_section())
+            .map(|section| section.content.clone())
+        else {
+            return;
+        };
+
+        self.add_user_message(&feedback);
+        self.clear_input();
+        self.add_system_message("피드백을 반영하여 섹션을 수정합니다.");
+        let output_language = self.config.output_language().to_string();
+
+        let mut client = self.claude_client.take().expect("client must be available");
+        self.prepare_client_for_phase(&mut client, UsagePhase::Spec);
+        client.set_cancellation_token(self.begin_cancellable_operation());
+        let (sender, receiver) = mpsc::channel();
+        self.attach_agent_receiver(receiver);
+        self.input_mode = InputMode::AgentThinking;
+        self.thinking_started_at = Instant::now();
+
+        std::thread::spawn(move || {
+            let request = ClaudeCodeRequest {
+                user_prompt: spec_writing::build_section_revision_prompt(
+                    &section_content,
+                    &feedback,
+                    &output_language,
+                ),
+                output_schema: spec_writing::section_revision_schema(),
+            };
+
+            let stream_sender = sender.clone();
+            let outcome = client
+                .query_streaming::<SectionRevisionResponse, _>(&request, |line| {
+                    let _ = stream_sender.send(AgentStreamMessage::StreamLine(line));
+                })
+                .map(AgentOutcome::SectionRevision)
+                .map_err(|err| err.to_string());
+
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
+                client,
+                outcome,
+            })));
+        });
+    }
+
+    fn handle_section_revision_response(&mut self, response: SectionRevisionResponse) {
+        let Some(state) = self.section_review.as_mut() else {
+            self.add_system_message("섹션 검토 상태를 찾을 수 없습니다.");
+            self.input_mode = InputMode::SpecFeedback;
+            return;
+        };
+        if !state.replace_current(response.section_content) {
+            self.add_system_message("이미 승인된 섹션은 수정할 수 없습니다.");
+        }
+        self.show_current_section();
+    }
+
     fn start_plan_writing_query(&mut self, is_initial: bool) {
         let mut client = self.claude_client.take().expect("client must be available");
 
         if is_initial {
             client.reset_session();
-            client.set_system_prompt(Some(planning::system_prompt().to_string()));
+            client.set_system_prompt(Some(self.system_prompt_for(PromptKind::Planning)));
+            self.emit_event(SessionEvent::PhaseStarted { phase: EventPhase::Plan });
         }
 
         let journal_dir = self.journal_dir();
         let user_request_path = journal_dir.join("user-request.md");
         let spec_path = journal_dir.join("spec.md");
+        let output_language = self.config.output_language().to_string();
         let user_feedback = if is_initial {
             None
         } else {
@@ -1188,8 +3062,10 @@ impl App {
                 .map(|m| m.content.clone())
         };
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Plan);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
@@ -1198,7 +3074,7 @@ impl App {
                 planning::build_initial_plan_prompt(&user_request_path, &spec_path)
             } else {
                 let feedback = user_feedback.unwrap_or_default();
-                planning::build_plan_revision_prompt(&feedback)
+                planning::build_plan_revision_prompt(&feedback, &output_language)
             };
 
             let request = ClaudeCodeRequest {
@@ -1214,10 +3090,10 @@ impl App {
                 .map(AgentOutcome::Planning)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -1272,12 +3148,17 @@ impl App {
 
         self.add_user_message(&feedback);
         self.clear_input();
+        self.last_submitted_feedback = Some(feedback);
 
-        self.add_system_message("피드백을 반영하여 개발 계획을 수정합니다.");
+        self.add_system_message(
+            "피드백을 반영하여 개발 계획을 수정합니다. \
+             Ctrl+C로 취소하면 피드백을 고쳐 다시 제출할 수 있습니다.",
+        );
         self.start_plan_writing_query(false);
     }
 
     fn approve_plan(&mut self) {
+        self.last_submitted_feedback = None;
         let plan = match &self.last_plan_draft {
             Some(plan) => plan.clone(),
             None => {
@@ -1291,36 +3172,40 @@ impl App {
             self.add_system_message(&format!("플랜 파일 저장 실패: {}", err));
         }
 
-        if let Some(ws) = &self.confirmed_workspace {
-            let plan_path = journal_dir.join("plan.md");
-            if let Err(err) = coding::commit_file_in_workspace(
-                ws,
-                &plan_path,
-                "Add approved development plan",
-            ) {
-                self.add_system_message(&format!("플랜 파일 커밋 실패: {}", err));
-            }
-        }
+        self.commit_approved_artifact(
+            &journal_dir.join("plan.md"),
+            "Add approved development plan",
+            "플랜",
+        );
 
         self.add_system_message("개발 계획이 승인되었습니다. 작업 목록을 추출합니다.");
-        self.start_task_extraction();
+        self.start_task_extraction(false);
     }
 
-    fn start_task_extraction(&mut self) {
+    /// 플랜에서 작업 목록을 추출한다. `is_retry`이면 플랜 전체를 `TASK-00` 하나로 보라는
+    /// 힌트를 붙인다.
+    fn start_task_extraction(&mut self, is_retry: bool) {
         let mut client = self.claude_client.take().expect("client must be available");
         client.reset_session();
-        client.set_system_prompt(Some(coding::task_extraction_system_prompt().to_string()));
+        client.set_system_prompt(Some(self.system_prompt_for(PromptKind::TaskExtraction)));
 
         let plan_path = self.journal_dir().join("plan.md");
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Plan);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::AgentThinking;
         self.thinking_started_at = Instant::now();
 
         std::thread::spawn(move || {
+            let user_prompt = if is_retry {
+                coding::build_task_extraction_retry_prompt(&plan_path)
+            } else {
+                coding::build_task_extraction_prompt(&plan_path)
+            };
             let request = ClaudeCodeRequest {
-                user_prompt: coding::build_task_extraction_prompt(&plan_path),
+                user_prompt,
                 output_schema: coding::task_extraction_schema(),
             };
 
@@ -1332,86 +3217,474 @@ impl App {
                 .map(AgentOutcome::TaskExtraction)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
     fn handle_task_extraction_response(&mut self, response: TaskExtractionResponse) {
         if response.tasks.is_empty() {
-            self.add_system_message("추출된 작업이 없습니다.");
+            if !self.task_extraction_retried {
+                self.task_extraction_retried = true;
+                self.add_system_message(
+                    "추출된 작업이 없습니다. 플랜 전체를 하나의 작업으로 보도록 다시 추출합니다.",
+                );
+                self.start_task_extraction(true);
+                return;
+            }
+            self.add_system_message("다시 추출해도 작업이 없어 종료합니다.");
             self.input_mode = InputMode::Done;
             return;
         }
+        if let Err(err) = coding::validate_task_graph(&response.tasks) {
+            self.add_system_message(&format!("추출된 작업 목록이 올바르지 않습니다: {}", err));
+            self.input_mode = InputMode::Done;
+            return;
+        }
+        let Some(tasks) = self.limit_extracted_tasks(response.tasks) else {
+            self.input_mode = InputMode::Done;
+            return;
+        };
 
-        let mut schedule_message = format!(
-            "{}개 작업이 추출되었습니다:\n",
-            response.tasks.len()
-        );
-        for (i, task) in response.tasks.iter().enumerate() {
-            schedule_message.push_str(&format!(
-                "\n{}. [{}] {}",
-                i + 1,
-                task.task_id,
-                task.title,
+        if self.headless {
+            let review = TaskReviewState::new(tasks);
+            self.add_system_message(&format!(
+                "{}개 작업이 추출되었습니다:\n\n{}",
+                review.tasks.len(),
+                review.describe(),
             ));
-            if !task.dependencies.is_empty() {
-                schedule_message.push_str(&format!(
-                    " (의존: {})",
-                    task.dependencies.join(", "),
-                ));
-            }
+            self.start_coding_with_tasks(review.selected_tasks());
+            return;
         }
-        self.add_system_message(&schedule_message);
 
-        let integration_branch = match &self.integration_branch {
-            Some(branch) => branch.clone(),
-            None => {
-                let workspace = self.confirmed_workspace.clone().unwrap();
-                let session_name = self
-                    .session_name
-                    .clone()
-                    .unwrap_or_else(|| "unnamed".to_string());
-                match coding::create_integration_branch(&workspace, &session_name) {
-                    Ok(branch) => {
-                        self.integration_branch = Some(branch.clone());
-                        branch
-                    }
-                    Err(err) => {
-                        self.add_system_message(
-                            &format!("Failed to create git branch: {}", err),
-                        );
-                        self.input_mode = InputMode::Done;
-                        return;
-                    }
-                }
+        self.task_review = Some(TaskReviewState::new(tasks));
+        self.show_task_review(true);
+        self.input_mode = InputMode::TaskReview;
+    }
+
+    /// 작업 수가 한도를 넘으면 앞의 `MAX_TASKS_PER_PLAN`개만 남기고 나머지는 후속 세션에서
+    /// 처리하도록 저널에 저장한다. 앞부분만 따로 실행할 수 없으면 None을 반환한다.
+    fn limit_extracted_tasks(&mut self, tasks: Vec<CodingTask>) -> Option<Vec<CodingTask>> {
+        let Err(err) = coding::validate_task_count(&tasks) else {
+            return Some(tasks);
+        };
+        self.add_system_message(&err);
+        let (kept, deferred) = match coding::split_at_task_limit(tasks) {
+            Ok(split) => split,
+            Err(err) => {
+                self.add_system_message(&format!(
+                    "앞의 {}개 작업만 따로 진행할 수 없어 종료합니다: {}",
+                    coding::MAX_TASKS_PER_PLAN,
+                    err,
+                ));
+                return None;
             }
         };
+        match coding::save_deferred_tasks(&self.workspace_journal_dir(), &deferred) {
+            Ok(path) => self.add_system_message(&format!(
+                "앞의 {}개 작업만 진행하고 나머지 {}개는 후속 세션으로 미룹니다. 미룬 작업 목록: {}",
+                kept.len(),
+                deferred.len(),
+                path.display(),
+            )),
+            Err(err) => {
+                self.add_system_message(&format!("미룬 작업 목록 저장 실패: {}", err));
+                return None;
+            }
+        }
+        Some(kept)
+    }
 
-        self.add_system_message(&format!(
-            "코딩 워크스페이스 준비 완료.\n통합 브랜치: {}",
-            integration_branch,
-        ));
+    /// 확인 중인 태스크 목록과 조작 방법을 보여준다.
+    fn show_task_review(&mut self, first_time: bool) {
+        let review = self.task_review.as_ref().unwrap();
+        let heading = if first_time {
+            format!("{}개 작업이 추출되었습니다:", review.tasks.len())
+        } else {
+            "변경된 작업 목록:".to_string()
+        };
+        let message = format!(
+            "{}\n\n{}\n\n번호를 입력하면 포함 여부를 바꾸고, \"옮길번호 위치\"를 입력하면 순서를 바꿉니다. \
+             Enter만 누르면 [x] 표시된 작업으로 코딩을 시작합니다.",
+            heading,
+            review.describe(),
+        );
+        self.add_system_message(&message);
+    }
 
-        self.coding_state = Some(CodingPhaseState {
-            tasks: response.tasks,
-            current_task_index: 0,
-            task_reports: Vec::new(),
-            integration_branch,
-            current_task_worktree: None,
-            build_test_commands: None,
-        });
+    fn submit_task_review(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        self.clear_input();
+        if input.is_empty() {
+            let review = self.task_review.take().unwrap();
+            self.start_coding_with_tasks(review.selected_tasks());
+            return;
+        }
+        self.add_user_message(&input);
+
+        let numbers: Option<Vec<usize>> = input
+            .split_whitespace()
+            .map(|part| part.parse::<usize>().ok().and_then(|n| n.checked_sub(1)))
+            .collect();
+        let review = self.task_review.as_mut().unwrap();
+        let result = match numbers.as_deref() {
+            Some([index]) => review.toggle(*index),
+            Some([from, to]) => review.move_task(*from, *to),
+            _ => Err(format!(
+                "잘못된 입력입니다: {}. 번호 하나 또는 \"옮길번호 위치\"를 입력하세요.",
+                input,
+            )),
+        };
+        match result {
+            Ok(()) => self.show_task_review(false),
+            Err(err) => self.add_system_message(&err),
+        }
+    }
 
-        self.start_next_coding_task();
+    /// 고른 태스크로 통합 브랜치를 준비하고 첫 태스크를 시작한다.
+    fn start_coding_with_tasks(&mut self, tasks: Vec<CodingTask>) {
+        if tasks.is_empty() {
+            self.add_system_message("선택된 작업이 없어 코딩을 시작하지 않습니다.");
+            self.input_mode = InputMode::Done;
+            return;
+        }
+        if self.enter_coding_phase(tasks, false) {
+            self.start_next_coding_task();
+        }
     }
 
-    /// 다음 코딩 태스크에 필요한 데이터를 추출한다.
-    /// 남은 태스크가 없으면 None을 반환한다.
-    fn extract_next_coding_task_data(
+    /// 자동 stash가 켜져 있으면 커밋하지 않은 변경을 먼저 치운 뒤 통합 브랜치를 만든다.
+    /// 통합 브랜치를 만들지 못하면 치운 변경을 바로 되돌린다.
+    fn prepare_integration_branch(
+        &mut self,
+        workspace: &Path,
+        session_name: &str,
+    ) -> Result<String, String> {
+        if self.config.auto_stash() {
+            self.stash_workspace_changes(workspace, session_name)?;
+        }
+        // 통합 브랜치로 전환하면 현재 브랜치가 바뀌므로 그 전에 감지해 둔다.
+        self.default_branch = coding::detect_default_branch(workspace);
+        let result = self.check_and_create_integration_branch(workspace, session_name);
+        if result.is_err() {
+            self.restore_auto_stash();
+        }
+        result
+    }
+
+    /// 워크스페이스의 커밋하지 않은 변경을 stash에 넣고, 종료 시그널을 받아도 되돌릴 수 있도록
+    /// 정리 목록에 등록한다.
+    fn stash_workspace_changes(
+        &mut self,
+        workspace: &Path,
+        session_name: &str,
+    ) -> Result<(), String> {
+        match coding::stash_working_changes(workspace, session_name) {
+            Ok(Some(stash_commit)) => {
+                self.add_system_message(&format!(
+                    "커밋하지 않은 변경을 stash에 넣었습니다({}). 코딩 단계가 끝나면 되돌립니다.",
+                    stash_commit,
+                ));
+                self.cleanup_registry.register_stash(StashedChanges {
+                    workspace: workspace.to_path_buf(),
+                    stash_commit,
+                });
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(err) => Err(format!("커밋하지 않은 변경을 stash에 넣지 못했습니다: {}", err)),
+        }
+    }
+
+    /// 자동 stash로 치워 둔 변경이 있으면 워크스페이스에 되돌린다. 되돌리지 못하면 변경이 남아
+    /// 있는 stash 위치와 직접 처리하는 방법을 알린다. 이미 되돌렸으면 아무 일도 하지 않는다.
+    pub fn restore_auto_stash(&mut self) {
+        let Some(stash) = self.cleanup_registry.take_stash() else {
+            return;
+        };
+        let err = match coding::restore_stashed_changes(&stash.workspace, &stash.stash_commit) {
+            Ok(()) => {
+                self.add_system_message("stash에 넣어 둔 변경을 워크스페이스에 되돌렸습니다.");
+                return;
+            }
+            Err(err) => err,
+        };
+        let location = coding::find_stash_entry(&stash.workspace, &stash.stash_commit)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| stash.stash_commit.clone());
+        let guidance = if matches!(err, GitError::Conflict { .. }) {
+            format!(
+                "충돌 표시와 함께 적용되었으며 stash는 {}({})에 남아 있습니다. \
+                 충돌을 해결한 뒤 `git stash drop {}`으로 지우세요.",
+                location, stash.stash_commit, location,
+            )
+        } else {
+            format!(
+                "변경은 {}({})에 남아 있습니다. `git stash pop {}`으로 직접 되돌리세요.",
+                location, stash.stash_commit, location,
+            )
+        };
+        self.add_system_message(&format!(
+            "stash에 넣어 둔 변경을 되돌리지 못했습니다: {}\n{}",
+            err, guidance,
+        ));
+    }
+
+    /// 워크스페이스 상태를 확인한 뒤 통합 브랜치를 만든다. 확인에 실패하면 사용자가 무엇을
+    /// 정리해야 하는지 알려 주는 메시지를 반환한다.
+    fn check_and_create_integration_branch(
         &self,
+        workspace: &Path,
+        session_name: &str,
+    ) -> Result<String, String> {
+        use coding::WorkspacePreflightError as Preflight;
+
+        coding::check_workspace_ready(workspace).map_err(|err| match err {
+            Preflight::NotARepository { path } => format!(
+                "{}는 git 저장소가 아닙니다. `git init` 후 첫 커밋을 만든 뒤 다시 시도하세요.",
+                path,
+            ),
+            Preflight::DirtyWorkingTree { changed_files } => format!(
+                "워크스페이스에 커밋하지 않은 변경이 있습니다: {}\n\
+                 변경을 커밋하거나 `git stash`로 치운 뒤 다시 시도하세요.",
+                changed_files.join(", "),
+            ),
+            Preflight::DetachedHead => "워크스페이스가 detached HEAD 상태입니다. \
+                 작업할 브랜치를 체크아웃한 뒤 다시 시도하세요."
+                .to_string(),
+            Preflight::GitFailed { .. } => err.to_string(),
+        })?;
+        let options = coding::IntegrationBranchOptions {
+            prefix: self.config.branch_prefix(),
+            base_branch: self.config.integration_base_branch(),
+        };
+        coding::create_integration_branch(workspace, session_name, &options)
+            .map_err(|err| err.to_string())
+    }
+
+    /// 통합 브랜치를 준비하고 코딩 단계 상태를 초기화한다.
+    /// 통합 브랜치를 만들 수 없으면 false를 반환한다.
+    fn enter_coding_phase(&mut self, tasks: Vec<CodingTask>, incremental_planning: bool) -> bool {
+        let integration_branch = match &self.integration_branch {
+            Some(branch) => branch.clone(),
+            None => {
+                let Some(workspace) = self.require_workspace() else {
+                    return false;
+                };
+                let session_name = self
+                    .session_name
+                    .clone()
+                    .unwrap_or_else(|| "unnamed".to_string());
+                match self.prepare_integration_branch(&workspace, &session_name) {
+                    Ok(branch) => {
+                        self.integration_branch = Some(branch.clone());
+                        branch
+                    }
+                    Err(err) => {
+                        self.add_system_message(&format!(
+                            "Failed to create git branch: {}\n\
+                             스펙과 플랜은 세션 저널에 남아 있으므로, 정리한 뒤 \
+                             '이전 세션 이어서'로 계속할 수 있습니다.",
+                            err,
+                        ));
+                        self.input_mode = InputMode::Done;
+                        return false;
+                    }
+                }
+            }
+        };
+
+        self.add_system_message(&format!(
+            "코딩 워크스페이스 준비 완료.\n통합 브랜치: {}",
+            integration_branch,
+        ));
+        self.emit_event(SessionEvent::PhaseStarted { phase: EventPhase::Coding });
+
+        self.coding_state = Some(CodingPhaseState {
+            tasks,
+            current_task_index: 0,
+            task_reports: Vec::new(),
+            integration_branch,
+            current_task_worktree: None,
+            build_test_commands: None,
+            build_test_subdir: None,
+            incremental_planning,
+            current_task_progress: TaskProgress::default(),
+            rerun_return_index: None,
+            retained_worktrees: Vec::new(),
+            flagged_dependency_manifests: BTreeMap::new(),
+        });
+
+        if self.config.continue_on_build_failure() {
+            self.add_system_message(
+                "⚠ 경고: continue_on_build_failure가 켜져 있습니다. 빌드/테스트가 수리 후에도 \
+                 실패한 태스크도 통합 브랜치에 머지됩니다.",
+            );
+        }
+
+        true
+    }
+
+    /// 점진적 계획 모드에서 지금까지 완료된 태스크의 리포트를 바탕으로 다음 태스크 하나를
+    /// 계획한다. 태스크 워크트리가 없는 상태에서 호출되므로 워크스페이스에서 실행된다.
+    fn start_next_task_planning(&mut self) {
+        let planned_count = self.coding_state.as_ref().unwrap().tasks.len();
+        if planned_count >= MAX_INCREMENTAL_TASKS {
+            self.add_system_message(&format!(
+                "점진적 계획: 최대 작업 수({})에 도달하여 계획을 중단합니다.",
+                MAX_INCREMENTAL_TASKS,
+            ));
+            self.finish_coding_phase();
+            return;
+        }
+
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
+        let next_task_id = format!("TASK-{:02}", planned_count);
+        let journal_dir = self.journal_dir();
+        let user_prompt = planning::build_next_task_plan_prompt(
+            &journal_dir.join("user-request.md"),
+            &journal_dir.join("spec.md"),
+            &journal_dir.join("plan.md"),
+            &self.coding_state.as_ref().unwrap().task_reports,
+            &next_task_id,
+        );
+
+        let system_prompt = self.system_prompt_for(PromptKind::IncrementalPlanning);
+        let mut client = match self.new_claude_client(
+            workspace,
+            Some(system_prompt),
+        ) {
+            Ok(c) => c,
+            Err(err) => {
+                self.add_system_message(&format!("계획 에이전트 클라이언트 생성 실패: {}", err));
+                self.finish_coding_phase();
+                return;
+            }
+        };
+
+        self.add_system_message(&format!("점진적 계획: [{}] 다음 작업을 계획합니다.", next_task_id));
+
+        self.prepare_client_for_phase(&mut client, UsagePhase::Plan);
+        client.set_cancellation_token(self.begin_cancellable_operation());
+        let (sender, receiver) = mpsc::channel();
+        self.attach_agent_receiver(receiver);
+        self.input_mode = InputMode::Coding;
+        self.thinking_started_at = Instant::now();
+
+        std::thread::spawn(move || {
+            let request = ClaudeCodeRequest {
+                user_prompt,
+                output_schema: planning::next_task_plan_schema(),
+            };
+
+            let stream_sender = sender.clone();
+            let outcome = client
+                .query_streaming::<NextTaskPlanResponse, _>(&request, |line| {
+                    let _ = stream_sender.send(AgentStreamMessage::StreamLine(line));
+                })
+                .map(AgentOutcome::NextTaskPlanned)
+                .map_err(|err| err.to_string());
+
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
+                client,
+                outcome,
+            })));
+        });
+    }
+
+    /// 계획된 다음 태스크를 태스크 목록에 추가하고 구현을 시작한다. 이전에 계획된 모든
+    /// 태스크를 의존성으로 지정하여 코딩 에이전트가 앞선 리포트를 참고하도록 한다.
+    fn handle_next_task_plan_response(&mut self, response: NextTaskPlanResponse) {
+        let (title, description) = match (response.status, response.title, response.description) {
+            (NextTaskPlanStatus::AllDone, _, _) => {
+                self.add_system_message("점진적 계획: 스펙의 모든 작업이 완료되었습니다.");
+                self.finish_coding_phase();
+                return;
+            }
+            (NextTaskPlanStatus::NextTask, Some(title), Some(description)) => (title, description),
+            (NextTaskPlanStatus::NextTask, _, _) => {
+                self.add_system_message(
+                    "점진적 계획: 다음 작업의 제목 또는 설명이 비어 있어 계획을 중단합니다.",
+                );
+                self.finish_coding_phase();
+                return;
+            }
+        };
+
+        let coding_state = self.coding_state.as_ref().unwrap();
+        let task_id = format!("TASK-{:02}", coding_state.tasks.len());
+        // 차단된 태스크에 의존하면 새 태스크가 실행되지 않고 건너뛰어지므로, 성공한 태스크에만 의존한다.
+        let dependencies = coding_state
+            .tasks
+            .iter()
+            .filter(|task| {
+                coding_state.task_status(&task.task_id)
+                    == Some(CodingTaskStatus::ImplementationSuccess)
+            })
+            .map(|task| task.task_id.clone())
+            .collect();
+
+        let journal_dir = self.journal_dir();
+        if let Err(err) =
+            planning::append_planned_task(&journal_dir, &task_id, &title, &description)
+        {
+            self.add_system_message(&format!("플랜 파일 저장 실패: {}", err));
+        }
+
+        self.add_system_message(&format!(
+            "점진적 계획: [{}] {}\n\n{}",
+            task_id, title, description,
+        ));
+
+        self.coding_state.as_mut().unwrap().tasks.push(CodingTask {
+            task_id,
+            title,
+            description,
+            dependencies,
+            files: response.files,
+            subdir: response.subdir,
+        });
+        self.start_next_coding_task();
+    }
+
+    /// 코딩 단계에서 현재 진행 중인 태스크 ID. 코딩 단계가 아니면 None.
+    fn current_task_id(&self) -> Option<String> {
+        let coding_state = self.coding_state.as_ref()?;
+        coding_state
+            .tasks
+            .get(coding_state.current_task_index)
+            .map(|task| task.task_id.clone())
+    }
+
+    /// 다음에 실행할 태스크를 꺼낸다. 의존 태스크가 차단된 태스크는 맥락 없이 실행해 봐야 실패하기
+    /// 쉬우므로 실행하지 않고 차단 리포트를 남긴 뒤 건너뛴다. 남은 태스크가 없으면 None을 반환한다.
+    fn extract_next_coding_task_data(
+        &mut self,
     ) -> Option<(CodingTask, usize, usize, Vec<PathBuf>)> {
+        while let Some(coding_state) = self.coding_state.as_ref()
+            && let Some(task) = coding_state.tasks.get(coding_state.current_task_index)
+            && let Some(blocked_dependency) = coding_state.blocked_dependency(&task.task_id)
+        {
+            let task_id = task.task_id.clone();
+            self.add_system_message(&format!(
+                "[{}] 의존 태스크 {}이(가) 차단되어 이 작업을 건너뜁니다.",
+                task_id, blocked_dependency,
+            ));
+            self.coding_state.as_mut().unwrap().current_task_progress = TaskProgress::default();
+            self.record_task_report(
+                task_id,
+                CodingTaskStatus::ImplementationBlocked,
+                format!("건너뜀: 의존 태스크 {} 차단됨", blocked_dependency),
+                PathBuf::new(),
+                false,
+            );
+        }
+
         let coding_state = self.coding_state.as_ref()?;
         if coding_state.current_task_index >= coding_state.tasks.len() {
             return None;
@@ -1427,11 +3700,18 @@ impl App {
     }
 
     fn start_next_coding_task(&mut self) {
+        if self.pause_for_invocation_budget() {
+            return;
+        }
         let extracted = self.extract_next_coding_task_data();
         let (task, total, index, upstream_report_paths) = match extracted {
             Some(data) => data,
             None => {
-                self.finish_coding_phase();
+                if self.coding_state.as_ref().is_some_and(|state| state.incremental_planning) {
+                    self.start_next_task_planning();
+                } else {
+                    self.finish_coding_phase();
+                }
                 return;
             }
         };
@@ -1443,8 +3723,24 @@ impl App {
             task.task_id,
             task.title,
         ));
+        self.emit_event(SessionEvent::TaskStarted {
+            task_id: task.task_id.clone(),
+            title: task.title.clone(),
+            position: index + 1,
+            total,
+        });
+        let coding_state = self.coding_state.as_mut().unwrap();
+        coding_state.current_task_progress = TaskProgress::default();
+        if coding_state.build_test_subdir != task.subdir {
+            // 하위 프로젝트마다 빌드 시스템이 다를 수 있으므로 빌드/테스트 명령을 다시 정한다.
+            coding_state.build_test_commands = None;
+            coding_state.build_test_subdir = task.subdir.clone();
+        }
+        self.regenerate_task_requested = false;
 
-        let workspace = self.confirmed_workspace.clone().unwrap();
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
         let integration_branch = self
             .coding_state
             .as_ref()
@@ -1466,7 +3762,13 @@ impl App {
                 }
             };
 
-        let worktree_path = match coding::create_worktree(&workspace, &task_branch) {
+        let worktree_root = self
+            .config
+            .worktree_root()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(coding::default_worktree_root);
+        let worktree_path = match coding::create_worktree(&workspace, &worktree_root, &task_branch)
+        {
             Ok(path) => path,
             Err(err) => {
                 self.add_system_message(&format!("워크트리 생성 실패: {}", err));
@@ -1486,39 +3788,52 @@ impl App {
             task_branch,
         ));
 
+        self.cleanup_registry.register(ActiveWorktree {
+            workspace: workspace.clone(),
+            worktree_path: worktree_path.clone(),
+            task_branch: task_branch.clone(),
+        });
         let coding_state = self.coding_state.as_mut().unwrap();
         coding_state.current_task_worktree = Some(TaskWorktreeInfo {
             worktree_path: worktree_path.clone(),
             task_branch,
         });
 
-        let worktree_journal = self.journal_dir();
-        let workspace_journal = self.workspace_journal_dir();
-        for err in coding::copy_artifacts_to_worktree(
-            &workspace_journal,
-            &worktree_journal,
-            &["spec.md", "plan.md"],
-        ) {
-            self.add_system_message(&err);
-        }
+        self.copy_journal_artifacts_to_worktree();
 
         let journal_dir = self.journal_dir();
         let spec_path = journal_dir.join("spec.md");
         let plan_path = journal_dir.join("plan.md");
-        let api_key = self.config.api_key().to_string();
 
-        let mut client = match ClaudeCodeClient::new(
-            api_key,
-            worktree_path,
-            Some(coding::coding_agent_system_prompt().to_string()),
+        let task_directory = self.current_task_directory().unwrap_or(worktree_path.clone());
+        if task.working_directory(&worktree_path) != task_directory {
+            self.add_system_message(&format!(
+                "[{}] 하위 디렉토리 '{}'가 워크트리에 없어 저장소 루트에서 작업합니다.",
+                task.task_id,
+                task.subdir.as_deref().unwrap_or_default(),
+            ));
+        }
+
+        let system_prompt = self.system_prompt_for(PromptKind::Coding);
+        let client = match self.new_task_agent_client(
+            task_directory.clone(),
+            Some(system_prompt),
         ) {
-            Ok(c) => c,
+            Ok(mut c) => {
+                if task_directory != worktree_path {
+                    // 하위 디렉토리에서 실행해도 워크트리 루트의 세션 저널은 읽을 수 있어야 한다.
+                    let mut directories = self.config.context_dirs().to_vec();
+                    directories.push(worktree_path);
+                    c.set_additional_directories(directories);
+                }
+                c
+            }
             Err(err) => {
                 self.add_system_message(&format!(
                     "코딩 에이전트 클라이언트 생성 실패: {}",
                     err,
                 ));
-                self.cleanup_current_task_worktree();
+                self.release_blocked_task_worktree(&task.task_id);
                 self.save_and_advance_task(
                     task.task_id.clone(),
                     CodingTaskStatus::ImplementationBlocked,
@@ -1528,8 +3843,12 @@ impl App {
             }
         };
 
+        let mut client = self.reattach_coding_session(client, &task.task_id);
+        let allow_dependency_changes = self.config.allow_dependency_changes();
+        self.prepare_client_for_phase(&mut client, UsagePhase::Coding);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
@@ -1540,6 +3859,7 @@ impl App {
                 &plan_path,
                 &upstream_report_paths,
                 &integration_branch,
+                allow_dependency_changes,
             );
 
             let request = ClaudeCodeRequest {
@@ -1555,13 +3875,81 @@ impl App {
                 .map(AgentOutcome::CodingTaskCompleted)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
+    /// 이어서 시작한 세션이면 이전 세션에서 같은 태스크의 코딩 에이전트가 쓰던 Claude 세션에
+    /// 다시 연결해 대화 맥락을 이어 간다. 기록이 없으면 클라이언트를 그대로 반환한다.
+    fn reattach_coding_session(
+        &mut self,
+        client: ClaudeCodeClient,
+        task_id: &str,
+    ) -> ClaudeCodeClient {
+        self.reattached_coding_session = None;
+        let Some(resumed_dir) = &self.resumed_session_dir else {
+            return client;
+        };
+        let session = match coding::load_coding_sessions(resumed_dir) {
+            Ok(mut sessions) => sessions.remove(task_id),
+            Err(err) => {
+                self.add_system_message(&format!("이전 코딩 세션 기록 읽기 실패: {}", err));
+                None
+            }
+        };
+        let Some(session) = session else {
+            return client;
+        };
+        if session.working_directory != client.working_directory() {
+            self.add_system_message(&format!(
+                "[{}] 이전 Claude 세션 {}은(는) 다른 작업 디렉토리({})에서 실행되어 새 세션으로 \
+                 시작합니다.",
+                task_id,
+                session.session_id,
+                session.working_directory.display(),
+            ));
+            return client;
+        }
+        self.add_system_message(&format!(
+            "[{}] 이전 Claude 세션 {}에 다시 연결합니다.",
+            task_id, session.session_id,
+        ));
+        self.reattached_coding_session = Some(session.session_id.clone());
+        client.with_session_id(session.session_id)
+    }
+
+    /// 코딩 에이전트의 Claude 세션 ID와 작업 디렉토리를 저널에 기록한다. 다시 연결하려던 세션을
+    /// 찾지 못해 새 세션으로 시작했으면 그 사실을 알린다.
+    fn record_coding_session(&mut self, task_id: &str) {
+        let Some(session) = self.claude_client.as_ref().and_then(|client| {
+            Some(coding::CodingSession {
+                session_id: client.session_id()?.to_string(),
+                working_directory: client.working_directory().to_path_buf(),
+            })
+        }) else {
+            return;
+        };
+        let session_id = &session.session_id;
+        if let Some(previous) = self.reattached_coding_session.take()
+            && previous != *session_id
+        {
+            self.add_system_message(&format!(
+                "[{}] 이전 Claude 세션 {}을(를) 찾을 수 없어 새 세션 {}으로 작업했습니다.",
+                task_id, previous, session_id,
+            ));
+        }
+        let journal_dir = self.workspace_journal_dir();
+        if journal_dir.as_os_str().is_empty() {
+            return;
+        }
+        if let Err(err) = coding::save_coding_session(&journal_dir, task_id, &session) {
+            self.add_system_message(&format!("코딩 세션 기록 저장 실패: {}", err));
+        }
+    }
+
     fn handle_coding_task_result(&mut self, result: CodingTaskResult) {
         let task_id = {
             let coding_state = self.coding_state.as_ref().unwrap();
@@ -1569,6 +3957,7 @@ impl App {
                 .task_id
                 .clone()
         };
+        self.record_coding_session(&task_id);
 
         let status_label = match &result.status {
             CodingTaskStatus::ImplementationSuccess => "SUCCESS",
@@ -1579,13 +3968,27 @@ impl App {
             task_id, status_label,
         ));
 
+        // 코딩 에이전트가 다시 작업했으면 이전에 함께 실행한 빌드/테스트 결과는 쓰지 않는다.
+        self.concurrent_build_test = None;
         if result.status == CodingTaskStatus::ImplementationBlocked {
             self.review_state = None;
-            self.cleanup_current_task_worktree();
+            self.release_blocked_task_worktree(&task_id);
             self.save_and_advance_task(task_id, result.status, result.report);
             return;
         }
 
+        let max_iterations = self.config.max_review_iterations();
+        if max_iterations == 0 {
+            self.flag_dependency_manifest_changes(&task_id);
+            self.add_system_message(&format!(
+                "[{}] 리뷰 최대 반복 횟수가 0으로 설정되어 리뷰를 건너뜁니다.",
+                task_id,
+            ));
+            self.review_state = None;
+            self.rebase_and_merge_task(task_id, result.report);
+            return;
+        }
+
         let coding_client = self.claude_client.take();
 
         match self.review_state.as_mut() {
@@ -1594,6 +3997,7 @@ impl App {
                     task_id: task_id.clone(),
                     report: result.report.clone(),
                     iteration_count: 0,
+                    max_iterations,
                     reviewer_client: None,
                     coding_client,
                 });
@@ -1607,6 +4011,52 @@ impl App {
         self.start_review();
     }
 
+    /// 의존성 변경이 허용되지 않았는데 태스크가 매니페스트나 락파일을 수정했으면
+    /// 변경된 파일 목록을 반환한다. 허용된 경우 빈 목록을 반환한다. 경고 메시지는 태스크마다
+    /// 아직 경고하지 않은 파일에 대해서만 남긴다.
+    fn flag_dependency_manifest_changes(&mut self, task_id: &str) -> Vec<String> {
+        if self.config.allow_dependency_changes() {
+            return Vec::new();
+        }
+
+        let coding_state = self.coding_state.as_mut().unwrap();
+        let Some(worktree_info) = coding_state.current_task_worktree.as_ref() else {
+            return Vec::new();
+        };
+
+        match coding::changed_dependency_manifests(
+            &worktree_info.worktree_path,
+            &coding_state.integration_branch,
+        ) {
+            Ok(changed_manifests) => {
+                let flagged = coding_state
+                    .flagged_dependency_manifests
+                    .entry(task_id.to_string())
+                    .or_default();
+                let newly_changed: Vec<&str> = changed_manifests
+                    .iter()
+                    .filter(|manifest| flagged.insert(manifest.to_string()))
+                    .map(String::as_str)
+                    .collect();
+                if !newly_changed.is_empty() {
+                    self.add_system_message(&format!(
+                        "[{}] 경고: 의존성 변경이 허용되지 않았지만 다음 파일이 수정되었습니다: {}",
+                        task_id,
+                        newly_changed.join(", "),
+                    ));
+                }
+                changed_manifests
+            }
+            Err(err) => {
+                self.add_system_message(&format!(
+                    "[{}] 의존성 파일 변경 확인 실패: {}",
+                    task_id, err,
+                ));
+                Vec::new()
+            }
+        }
+    }
+
     fn start_review(&mut self) {
         let review_state = self.review_state.as_ref().unwrap();
         let is_followup = review_state.iteration_count > 0;
@@ -1648,24 +4098,27 @@ impl App {
         let spec_path = journal_dir.join("spec.md");
         let plan_path = journal_dir.join("plan.md");
 
-        let user_prompt = if is_followup {
-            coding::build_followup_review_prompt(
-                &spec_path, &plan_path, &report_path, &git_commit_revision,
-            )
-        } else {
-            coding::build_initial_review_prompt(
-                &spec_path, &plan_path, &report_path, &git_commit_revision,
-            )
+        let changed_manifests = self.flag_dependency_manifest_changes(&task_id);
+        // 함께 실행하는 포매터가 커밋을 바꿀 수 있으므로 프롬프트는 리뷰할 커밋이 정해진 뒤 만든다.
+        let review_prompt = move |revision: &str| {
+            let mut prompt = if is_followup {
+                coding::build_followup_review_prompt(&spec_path, &plan_path, &report_path, revision)
+            } else {
+                coding::build_initial_review_prompt(&spec_path, &plan_path, &report_path, revision)
+            };
+            if !changed_manifests.is_empty() {
+                prompt.push_str(&coding::build_dependency_change_review_note(&changed_manifests));
+            }
+            prompt
         };
 
-        let api_key = self.config.api_key().to_string();
         let mut reviewer_client = match self.review_state.as_mut().unwrap().reviewer_client.take() {
             Some(client) => client,
             None => {
-                match ClaudeCodeClient::new(
-                    api_key,
+                let system_prompt = self.system_prompt_for(PromptKind::Review);
+                match self.new_task_agent_client(
                     worktree_path.clone(),
-                    Some(coding::review_agent_system_prompt().to_string()),
+                    Some(system_prompt),
                 ) {
                     Ok(c) => c,
                     Err(err) => {
@@ -1681,66 +4134,190 @@ impl App {
         };
         reviewer_client.set_working_directory(worktree_path);
 
+        let build_test_job = self.concurrent_build_test_job();
         let iteration_label = self.review_state.as_ref().unwrap().iteration_count + 1;
+        let label = if build_test_job.is_some() {
+            "코드 리뷰와 빌드/테스트 검증"
+        } else {
+            "코드 리뷰"
+        };
         self.add_system_message(&format!(
-            "[{}] 코드 리뷰 시작 (iteration {})...",
-            task_id, iteration_label,
+            "[{}] {} 시작 (iteration {})...",
+            task_id, label, iteration_label,
         ));
 
+        self.prepare_client_for_phase(&mut reviewer_client, UsagePhase::Review);
+        reviewer_client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
         std::thread::spawn(move || {
+            // 리뷰와 빌드/테스트가 같은 커밋을 보도록 포매터를 먼저 실행한 뒤 커밋을 다시 읽는다.
+            let revision = match &build_test_job {
+                Some(job) => {
+                    job.format(&sender);
+                    coding::get_latest_commit_revision(&job.worktree_path)
+                        .unwrap_or(git_commit_revision)
+                }
+                None => git_commit_revision,
+            };
+            let build_test_thread = build_test_job.map(|job| {
+                let sender = sender.clone();
+                std::thread::spawn(move || job.build_and_test(&sender))
+            });
             let request = ClaudeCodeRequest {
-                user_prompt,
+                user_prompt: review_prompt(&revision),
                 output_schema: coding::review_result_schema(),
             };
 
             let stream_sender = sender.clone();
-            let outcome = reviewer_client
+            let review = reviewer_client
                 .query_streaming::<ReviewResult, _>(&request, |line| {
                     let _ = stream_sender.send(AgentStreamMessage::StreamLine(line));
                 })
-                .map(AgentOutcome::ReviewCompleted)
                 .map_err(|err| err.to_string());
+            // 두 결과가 모두 나온 뒤에 한 번에 보내므로 UI는 둘 다 기다렸다가 다음 단계로 간다.
+            let outcome = match build_test_thread {
+                None => review.map(AgentOutcome::ReviewCompleted),
+                Some(handle) => {
+                    let build_test = handle
+                        .join()
+                        .unwrap_or_else(|_| Err("build/test thread panicked".to_string()));
+                    review.and_then(|review| {
+                        build_test.map(|outcome| AgentOutcome::ReviewWithBuildTestCompleted {
+                            review,
+                            outcome,
+                            revision,
+                        })
+                    })
+                }
+            };
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client: reviewer_client,
                 outcome,
-            }));
+            })));
         });
     }
 
-    fn handle_review_result(&mut self, result: ReviewResult) {
-        let reviewer_client = self.claude_client.take();
-        let review_state = self.review_state.as_mut().unwrap();
-        review_state.reviewer_client = reviewer_client;
-        review_state.iteration_count += 1;
+    /// `concurrent_review_build`가 켜져 있고 빌드/테스트 명령이 정해져 있으면 리뷰와 함께
+    /// 실행할 빌드/테스트 작업을 만든다. 명령을 아직 확인하지 않았으면 리뷰만 실행한다.
+    fn concurrent_build_test_job(&self) -> Option<BuildTestJob> {
+        if !self.config.concurrent_review_build() || !self.config.verify_build_test() {
+            return None;
+        }
+        let commands = self.coding_state.as_ref()?.build_test_commands.clone()?;
+        Some(self.build_test_job(self.current_task_directory()?, commands))
+    }
 
-        let task_id = review_state.task_id.clone();
+    fn build_test_job(&self, worktree_path: PathBuf, commands: BuildTestCommands) -> BuildTestJob {
+        let cache_env = self
+            .config
+            .build_cache_dir()
+            .map(|cache_dir| coding::build_cache_env(&worktree_path, cache_dir))
+            .unwrap_or_default();
+        let formatter = if !self.config.format_before_build() {
+            None
+        } else if let Some(command) = self.config.format_command() {
+            Some(FormatterCommand { program: None, command: command.to_string() })
+        } else {
+            coding::detect_formatter(&worktree_path)
+        };
+        BuildTestJob {
+            worktree_path,
+            integration_branch: self.coding_state.as_ref().unwrap().integration_branch.clone(),
+            commands,
+            timeout: self.config.build_test_timeout(),
+            cache_env,
+            formatter,
+        }
+    }
+
+    /// 리뷰와 동시에 실행한 빌드/테스트 결과를 보관한 뒤 리뷰 결과를 처리한다. 리뷰가
+    /// 승인되면 리베이스 후 `verify_build_and_test`가 이 결과를 쓰고, 변경을 요청하면
+    /// 코딩 에이전트가 코드를 고친 뒤 둘 다 다시 실행한다.
+    fn handle_review_with_build_test_result(
+        &mut self,
+        result: ReviewResult,
+        outcome: BuildTestOutcome,
+        revision: String,
+    ) {
+        let task_id = self.review_state.as_ref().unwrap().task_id.clone();
+        let outcome_label = match &outcome {
+            BuildTestOutcome::Success => "성공",
+            BuildTestOutcome::BuildFailed { .. } => "빌드 실패",
+            BuildTestOutcome::TestFailed { .. } => "테스트 실패",
+        };
+        self.add_system_message(&format!(
+            "[{}] 리뷰와 함께 실행한 빌드/테스트 결과: {}.",
+            task_id, outcome_label,
+        ));
+        self.emit_event(SessionEvent::BuildResult {
+            task_id,
+            result: BuildResultKind::from(&outcome),
+            after_repair: false,
+        });
+        self.concurrent_build_test = Some(ConcurrentBuildTest { revision, outcome });
+        self.handle_review_result(result);
+    }
+
+    fn handle_review_result(&mut self, result: ReviewResult) {
+        let reviewer_client = self.claude_client.take();
+        let review_state = self.review_state.as_mut().unwrap();
+        review_state.reviewer_client = reviewer_client;
+        review_state.iteration_count += 1;
+        let iteration_count = review_state.iteration_count;
+
+        let task_id = review_state.task_id.clone();
+        self.coding_state.as_mut().unwrap().current_task_progress.review_iterations =
+            iteration_count;
+        self.save_review_comment(&task_id, iteration_count, &result.review_comment);
+        self.emit_event(SessionEvent::ReviewResult {
+            task_id: task_id.clone(),
+            iteration: iteration_count,
+            approved: result.review_result == ReviewStatus::Approved,
+        });
 
         match result.review_result {
             ReviewStatus::Approved => {
                 self.add_system_message(&format!("[{}] 코드 리뷰 승인.", task_id));
+                self.set_review_approval(ReviewApproval::Approved);
+                self.finalize_review_and_proceed();
+            }
+            ReviewStatus::RequestChanges if result.has_only_nonblocking_findings() => {
+                self.add_system_message(&format!(
+                    "[{}] 차단하지 않는 지적 {}개만 남아 자동 승인. 지적 사항은 리포트에 남깁니다.",
+                    task_id,
+                    result.findings.len(),
+                ));
+                let review_state = self.review_state.as_mut().unwrap();
+                review_state.report =
+                    coding::append_nonblocking_findings(&review_state.report, &result.findings);
+                self.set_review_approval(ReviewApproval::AutoApproved);
                 self.finalize_review_and_proceed();
             }
             ReviewStatus::RequestChanges => {
-                let iteration_count = self.review_state.as_ref().unwrap().iteration_count;
+                if self.regenerate_task_requested {
+                    self.regenerate_current_task(&task_id);
+                    return;
+                }
+                let max_iterations = self.review_state.as_ref().unwrap().max_iterations;
 
-                if iteration_count >= MAX_REVIEW_ITERATIONS {
+                if iteration_count >= max_iterations {
                     self.add_system_message(&format!(
                         "[{}] 리뷰 최대 반복 횟수({}) 도달. 자동 승인 처리.",
-                        task_id, MAX_REVIEW_ITERATIONS,
+                        task_id, max_iterations,
                     ));
+                    self.set_review_approval(ReviewApproval::AutoApproved);
                     self.finalize_review_and_proceed();
                     return;
                 }
 
                 self.add_system_message(&format!(
                     "[{}] 리뷰어 변경 요청 (iteration {}/{}): {}",
-                    task_id, iteration_count, MAX_REVIEW_ITERATIONS,
+                    task_id, iteration_count, max_iterations,
                     result.review_comment,
                 ));
 
@@ -1749,10 +4326,59 @@ impl App {
         }
     }
 
+    /// 리뷰 반복마다 리뷰 코멘트를 저널 디렉토리에 남긴다. 저장에 실패해도 리뷰는 계속한다.
+    fn save_review_comment(&mut self, task_id: &str, iteration: usize, review_comment: &str) {
+        let journal_dir = self.journal_dir();
+        if let Err(err) =
+            coding::save_review_comment(&journal_dir, task_id, iteration, review_comment)
+        {
+            self.add_system_message(&format!("[{}] 리뷰 코멘트 저장 실패: {}", task_id, err));
+        }
+    }
+
+    fn set_review_approval(&mut self, review_approval: ReviewApproval) {
+        self.coding_state.as_mut().unwrap().current_task_progress.review_approval =
+            review_approval;
+    }
+
+    /// 리뷰 루프가 수렴하지 않을 때 쓰도록, 다음 리뷰가 변경을 요청하면 현재 태스크를 새로
+    /// 구현하도록 예약하거나 예약을 취소한다.
+    fn toggle_regenerate_task_request(&mut self) {
+        let Some(task_id) = self.current_task_id() else {
+            return;
+        };
+        self.regenerate_task_requested = !self.regenerate_task_requested;
+        let message = if self.regenerate_task_requested {
+            format!(
+                "[{}] 다음 리뷰에서 변경을 요청하면 이 태스크를 통합 브랜치에서 새로 구현합니다. \
+                 취소하려면 Ctrl+N을 다시 누르세요.",
+                task_id,
+            )
+        } else {
+            format!("[{}] 태스크 새로 구현 예약을 취소했습니다.", task_id)
+        };
+        self.add_system_message(&message);
+    }
+
+    /// 현재 태스크의 워크트리와 브랜치를 버리고, 앞선 태스크가 머지된 통합 브랜치에서 다시 만들어
+    /// 새 코딩 세션으로 처음부터 구현한다. `start_coding_revision`과 달리 이전 코딩 세션과
+    /// 리뷰 기록을 이어 가지 않는다.
+    fn regenerate_current_task(&mut self, task_id: &str) {
+        self.add_system_message(&format!(
+            "[{}] 작업 내용을 버리고 통합 브랜치에서 태스크를 새로 구현합니다.",
+            task_id,
+        ));
+        self.review_state = None;
+        self.concurrent_build_test = None;
+        self.cleanup_current_task_worktree();
+        self.start_next_coding_task();
+    }
+
     fn finalize_review_and_proceed(&mut self) {
         let review_state = self.review_state.take().unwrap();
         let task_id = review_state.task_id;
         let report = review_state.report;
+        self.regenerate_task_requested = false;
 
         self.claude_client = review_state.coding_client;
 
@@ -1782,19 +4408,22 @@ impl App {
                     "[{}] 코딩 에이전트 세션을 찾을 수 없습니다. 리뷰 자동 승인 처리.",
                     task_id,
                 ));
+                self.set_review_approval(ReviewApproval::AutoApproved);
                 self.finalize_review_and_proceed();
                 return;
             }
         };
-        client.set_working_directory(worktree_path);
+        client.set_working_directory(self.current_task_directory().unwrap_or(worktree_path));
 
         self.add_system_message(&format!(
             "[{}] 리뷰 피드백 반영을 위한 코딩 에이전트 재시작...",
             task_id,
         ));
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Coding);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
@@ -1812,10 +4441,10 @@ impl App {
                 .map(AgentOutcome::CodingTaskCompleted)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -1828,22 +4457,39 @@ impl App {
         let worktree_info = coding_state.current_task_worktree.as_ref().unwrap();
         let worktree_path = worktree_info.worktree_path.clone();
         let integration_branch = coding_state.integration_branch.clone();
+        let declared_files = coding_state.tasks[coding_state.current_task_index].files.clone();
 
+        self.warn_predicted_conflicts(
+            &task_id,
+            &worktree_path,
+            &integration_branch,
+            declared_files,
+        );
         self.add_system_message(&format!(
             "[{}] 통합 브랜치로 리베이스 시작...",
             task_id,
         ));
 
-        match coding::rebase_onto_integration(&worktree_path, &integration_branch) {
+        let rebase_result = self.timings.time(
+            OperationKind::Rebase,
+            UsagePhase::Coding,
+            Some(&task_id),
+            || coding::rebase_onto_integration(&worktree_path, &integration_branch),
+        );
+        match rebase_result {
             Ok(RebaseOutcome::Success) => {
                 self.add_system_message(&format!("[{}] 리베이스 성공.", task_id));
                 self.verify_build_and_test(task_id, report);
             }
             Ok(RebaseOutcome::Conflict { conflicted_files }) => {
+                let conflict_summary = if conflicted_files.is_empty() {
+                    "충돌 파일 목록 없음, 리베이스 중단 상태".to_string()
+                } else {
+                    format!("{}개 파일", conflicted_files.len())
+                };
                 self.add_system_message(&format!(
-                    "[{}] 리베이스 충돌 발생 ({}개 파일). 충돌 해결 에이전트 시작...",
-                    task_id,
-                    conflicted_files.len(),
+                    "[{}] 리베이스 충돌 발생 ({}). 충돌 해결 에이전트 시작...",
+                    task_id, conflict_summary,
                 ));
                 self.start_conflict_resolution(
                     task_id,
@@ -1853,7 +4499,7 @@ impl App {
             }
             Err(err) => {
                 self.add_system_message(&format!("[{}] 리베이스 실패: {}", task_id, err));
-                self.cleanup_current_task_worktree();
+                self.release_blocked_task_worktree(&task_id);
                 self.save_and_advance_task(
                     task_id,
                     CodingTaskStatus::ImplementationBlocked,
@@ -1863,40 +4509,89 @@ impl App {
         }
     }
 
+    /// 리베이스 전에 태스크가 수정한 파일(선언된 파일 포함)과 태스크 시작 이후
+    /// 통합 브랜치의 변경 파일을 비교하여 충돌이 예상되면 미리 경고한다.
+    fn warn_predicted_conflicts(
+        &mut self,
+        task_id: &str,
+        worktree_path: &Path,
+        integration_branch: &str,
+        declared_files: Vec<String>,
+    ) {
+        let integration_changed =
+            coding::changed_files_since_merge_base(worktree_path, "HEAD", integration_branch);
+        let task_changed =
+            coding::changed_files_since_merge_base(worktree_path, integration_branch, "HEAD");
+        let (integration_changed, task_changed) = match (integration_changed, task_changed) {
+            (Ok(integration_changed), Ok(task_changed)) => (integration_changed, task_changed),
+            (Err(err), _) | (_, Err(err)) => {
+                crate::cli_log!("[{}] 충돌 예측을 위한 변경 파일 조회 실패: {}", task_id, err);
+                return;
+            }
+        };
+
+        let mut task_files = declared_files;
+        task_files.extend(task_changed);
+        let predicted = coding::predict_conflicts(&task_files, &integration_changed);
+        if !predicted.is_empty() {
+            self.add_system_message(&format!(
+                "[{}] 경고: 통합 브랜치에서도 변경된 파일이 있어 리베이스 충돌이 예상됩니다: {}",
+                task_id,
+                predicted.join(", "),
+            ));
+        }
+    }
+
     fn handle_coding_task_error(&mut self, error_message: String) {
-        let task_id = {
-            let coding_state = self.coding_state.as_ref().unwrap();
-            coding_state.tasks[coding_state.current_task_index]
-                .task_id
-                .clone()
+        let Some(task_id) = self.current_task_id() else {
+            // 점진적 계획 모드에서 다음 태스크를 계획하던 중의 오류. 완료된 태스크는 그대로
+            // 두고 일시 정지하여 Enter로 계획을 다시 시도할 수 있게 한다.
+            self.add_system_message(&format!(
+                "다음 작업 계획 실패: {}\nEnter를 눌러 다시 계획하거나, Esc를 눌러 종료하세요.",
+                error_message,
+            ));
+            self.input_mode = InputMode::CodingPaused;
+            return;
         };
 
-        self.add_system_message(&format!(
-            "Task [{}] error: {}",
-            task_id, error_message,
-        ));
+        let message = format!("Task [{}] error: {}", task_id, error_message);
+        self.add_system_message(&message);
 
         self.review_state = None;
-        self.cleanup_current_task_worktree();
+        self.release_blocked_task_worktree(&task_id);
 
         let report = format!(
             "IMPLEMENTATION_BLOCKED\n---\nAgent error: {}",
             error_message,
         );
-        let message = format!("Task [{}] error: {}", task_id, error_message);
-        self.save_and_advance_task(
+        if self.config.continue_on_fatal() {
+            // 태스크 수준 오류: 구현이 차단된 것처럼 보고하고 다음 태스크로 넘어간다.
+            self.add_system_message(&format!(
+                "[{}] 작업을 차단된 것으로 처리하고 다음 작업으로 넘어갑니다.",
+                task_id,
+            ));
+            self.save_and_advance_task(task_id, CodingTaskStatus::ImplementationBlocked, report);
+            return;
+        }
+
+        // 통신 치명 오류: 보고서만 남기고 다음 태스크를 시작하지 않은 채 실행을 끝낸다.
+        self.record_task_report(
             task_id,
             CodingTaskStatus::ImplementationBlocked,
             report,
+            PathBuf::new(),
+            false,
         );
-        self.fatal_error = Some(message);
-        self.should_quit = true;
+        self.enter_fatal_error(message);
     }
 
     fn cleanup_current_task_worktree(&mut self) {
-        let workspace = self.confirmed_workspace.clone().unwrap();
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
         let coding_state = self.coding_state.as_mut().unwrap();
         if let Some(info) = coding_state.current_task_worktree.take() {
+            self.cleanup_registry.unregister(&info.worktree_path);
             if let Err(err) = coding::remove_worktree(&workspace, &info.worktree_path) {
                 self.add_system_message(&format!("워크트리 제거 실패: {}", err));
             }
@@ -1906,20 +4601,57 @@ impl App {
         }
     }
 
+    /// 차단된 태스크의 워크트리를 정리한다. `keep_failed_worktrees`가 켜져 있으면 사후 분석을
+    /// 위해 워크트리와 브랜치를 그대로 남기고 최종 요약에 표시할 목록에 추가한다.
+    fn release_blocked_task_worktree(&mut self, task_id: &str) {
+        if !self.config.keep_failed_worktrees() {
+            self.cleanup_current_task_worktree();
+            return;
+        }
+
+        let coding_state = self.coding_state.as_mut().unwrap();
+        let Some(info) = coding_state.current_task_worktree.take() else {
+            return;
+        };
+        self.cleanup_registry.unregister(&info.worktree_path);
+        self.add_system_message(&format!(
+            "[{}] 차단된 태스크의 워크트리를 남겨 둡니다: {} (브랜치: {})",
+            task_id,
+            info.worktree_path.display(),
+            info.task_branch,
+        ));
+        self.coding_state.as_mut().unwrap().retained_worktrees.push(RetainedWorktree {
+            task_id: task_id.to_string(),
+            worktree_path: info.worktree_path,
+            task_branch: info.task_branch,
+        });
+    }
+
     fn verify_build_and_test(
         &mut self,
         task_id: String,
         report: String,
     ) {
-        let worktree_path = self
-            .coding_state
-            .as_ref()
-            .unwrap()
-            .current_task_worktree
-            .as_ref()
-            .unwrap()
-            .worktree_path
-            .clone();
+        if !self.config.verify_build_test() {
+            self.add_system_message(&format!(
+                "[{}] verify_build_test 설정이 꺼져 있어 빌드/테스트 검증 없이 머지합니다.",
+                task_id,
+            ));
+            self.request_merge(task_id, report, false);
+            return;
+        }
+        if let Some(outcome) = self.take_concurrent_build_test_outcome() {
+            self.add_system_message(&format!(
+                "[{}] 리베이스로 바뀐 커밋이 없어 리뷰와 함께 실행한 빌드/테스트 결과를 사용합니다.",
+                task_id,
+            ));
+            self.pending_build_test =
+                Some(PendingBuildTest { task_id, report, repair_attempts: 0 });
+            self.handle_build_test_result(outcome);
+            return;
+        }
+
+        let task_directory = self.current_task_directory().unwrap();
 
         let already_detected = self
             .coding_state
@@ -1929,44 +4661,75 @@ impl App {
             .is_some();
 
         if !already_detected {
-            if let Some(commands) = coding::detect_build_commands(&worktree_path) {
-                self.add_system_message(&format!(
-                    "[{}] 빌드 시스템 감지: build='{}', test='{}'",
-                    task_id, commands.build, commands.test,
-                ));
-                self.coding_state.as_mut().unwrap().build_test_commands = Some(commands);
-            } else {
-                self.add_system_message(
-                    "빌드 시스템을 자동 감지할 수 없습니다. 빌드 명령어를 입력해주세요:",
-                );
-                self.ask_build_command(task_id, report);
-                return;
+            match coding::detect_build_commands(&task_directory) {
+                Some(commands) if self.headless => {
+                    self.add_system_message(&format!(
+                        "[{}] 빌드 시스템 감지: build='{}', test='{}'",
+                        task_id, commands.build, commands.test,
+                    ));
+                    self.coding_state.as_mut().unwrap().build_test_commands = Some(commands);
+                }
+                Some(commands) => {
+                    self.add_system_message(&format!(
+                        "[{}] 빌드 시스템 감지: build='{}', test='{}'\n\
+                         빌드 명령어를 확인하세요. Enter로 그대로 사용하거나, 다른 명령어로 바꿔 입력하세요:",
+                        task_id, commands.build, commands.test,
+                    ));
+                    self.ask_build_command(task_id, report, &commands.build);
+                    return;
+                }
+                None => {
+                    self.add_system_message(
+                        "빌드 시스템을 자동 감지할 수 없습니다. 빌드 명령어를 입력해주세요:",
+                    );
+                    self.ask_build_command(task_id, report, "");
+                    return;
+                }
             }
         }
 
-        self.start_build_test_execution(task_id, report, false);
+        self.start_build_test_execution(task_id, report, 0);
     }
 
+    /// 빌드 명령어 입력을 받는다. 감지된 명령어가 있으면 입력란에 미리 채워 둔다.
     fn ask_build_command(
         &mut self,
         task_id: String,
         report: String,
+        prefilled_command: &str,
     ) {
         self.pending_build_test = Some(PendingBuildTest {
             task_id,
             report,
-            is_retry: false,
+            repair_attempts: 0,
         });
         self.build_test_command_phase = BuildTestCommandPhase::BuildCommand;
-        self.input_buffer.clear();
-        self.cursor_position = 0;
+        self.prefill_input(prefilled_command);
         self.input_mode = InputMode::BuildTestCommandInput;
     }
 
+    fn prefill_input(&mut self, text: &str) {
+        self.input_buffer = text.to_string();
+        self.cursor_position = self.input_buffer.chars().count();
+    }
+
+    /// 현재 태스크 디렉토리에서 빌드 시스템을 다시 감지한다.
+    fn detect_current_worktree_commands(&self) -> Option<BuildTestCommands> {
+        coding::detect_build_commands(&self.current_task_directory()?)
+    }
+
     fn submit_build_test_command(&mut self) {
-        let command = self.input_buffer.trim().to_string();
+        let mut command = self.input_buffer.trim().to_string();
+        let detected = self.detect_current_worktree_commands();
         if command.is_empty() {
-            return;
+            // 입력란을 비우고 제출하면 다시 감지한 명령어를 사용한다. 감지할 수 없으면 계속 입력을 기다린다.
+            let Some(detected) = &detected else {
+                return;
+            };
+            command = match self.build_test_command_phase {
+                BuildTestCommandPhase::BuildCommand => detected.build.clone(),
+                BuildTestCommandPhase::TestCommand => detected.test.clone(),
+            };
         }
         self.add_user_message(&command);
         self.input_buffer.clear();
@@ -1978,13 +4741,28 @@ impl App {
                 coding_state.build_test_commands = Some(BuildTestCommands {
                     build: command,
                     test: String::new(),
+                    per_test_timeout_secs: None,
                 });
                 self.build_test_command_phase = BuildTestCommandPhase::TestCommand;
-                self.add_system_message("테스트 명령어를 입력해주세요 (예: make test):");
+                match detected {
+                    Some(detected) => {
+                        self.add_system_message(
+                            "테스트 명령어를 확인하세요. Enter로 그대로 사용하거나, 다른 명령어로 바꿔 입력하세요:",
+                        );
+                        self.prefill_input(&detected.test);
+                    }
+                    None => {
+                        self.add_system_message("테스트 명령어를 입력해주세요 (예: make test):");
+                    }
+                }
             }
             BuildTestCommandPhase::TestCommand => {
                 let coding_state = self.coding_state.as_mut().unwrap();
                 if let Some(ref mut commands) = coding_state.build_test_commands {
+                    // 감지한 명령어를 그대로 쓸 때만 감지 결과의 테스트별 제한 시간을 적용한다.
+                    commands.per_test_timeout_secs = detected
+                        .filter(|detected| detected.test == command)
+                        .and_then(|detected| detected.per_test_timeout_secs);
                     commands.test = command;
                 }
 
@@ -1992,7 +4770,7 @@ impl App {
                 self.start_build_test_execution(
                     pending.task_id,
                     pending.report,
-                    pending.is_retry,
+                    pending.repair_attempts,
                 );
             }
         }
@@ -2002,7 +4780,7 @@ impl App {
         &mut self,
         task_id: String,
         report: String,
-        is_retry: bool,
+        repair_attempts: usize,
     ) {
         let commands = self
             .coding_state
@@ -2011,15 +4789,7 @@ impl App {
             .build_test_commands
             .clone()
             .unwrap();
-        let worktree_path = self
-            .coding_state
-            .as_ref()
-            .unwrap()
-            .current_task_worktree
-            .as_ref()
-            .unwrap()
-            .worktree_path
-            .clone();
+        let task_directory = self.current_task_directory().unwrap();
 
         self.add_system_message(&format!(
             "[{}] 빌드/테스트 검증 시작...",
@@ -2029,28 +4799,44 @@ impl App {
         self.pending_build_test = Some(PendingBuildTest {
             task_id,
             report,
-            is_retry,
+            repair_attempts,
         });
 
+        let job = self.build_test_job(task_directory, commands);
         let client = self.claude_client.take().unwrap();
+        self.begin_cancellable_operation();
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
         std::thread::spawn(move || {
-            let outcome = coding::run_build_and_test(&worktree_path, &commands)
-                .map(AgentOutcome::BuildTestCompleted);
+            let outcome = job.run(&sender).map(AgentOutcome::BuildTestCompleted);
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
+    /// 리뷰와 함께 실행한 빌드/테스트 결과가 현재 워크트리 커밋에 대한 것이면 꺼내 준다.
+    /// 리베이스로 커밋이 바뀌었으면 결과를 버리고 None을 반환한다.
+    fn take_concurrent_build_test_outcome(&mut self) -> Option<BuildTestOutcome> {
+        let concurrent = self.concurrent_build_test.take()?;
+        let worktree_info = self.coding_state.as_ref()?.current_task_worktree.as_ref()?;
+        let current_revision =
+            coding::get_latest_commit_revision(&worktree_info.worktree_path).ok()?;
+        (current_revision == concurrent.revision).then_some(concurrent.outcome)
+    }
+
     fn handle_build_test_result(&mut self, outcome: BuildTestOutcome) {
         let pending = self.pending_build_test.take().unwrap();
+        self.emit_event(SessionEvent::BuildResult {
+            task_id: pending.task_id.clone(),
+            result: BuildResultKind::from(&outcome),
+            after_repair: pending.repair_attempts > 0,
+        });
 
         match outcome {
             BuildTestOutcome::Success => {
@@ -2058,10 +4844,9 @@ impl App {
                     "[{}] 빌드/테스트 검증 성공.",
                     pending.task_id,
                 ));
-                self.ff_merge_and_advance(
-                    pending.task_id,
-                    pending.report,
-                );
+                self.coding_state.as_mut().unwrap().current_task_progress.build_test_passed =
+                    Some(true);
+                self.request_merge(pending.task_id, pending.report, false);
             }
             BuildTestOutcome::BuildFailed { output } => {
                 self.handle_build_test_failure(pending, "빌드", output);
@@ -2078,40 +4863,44 @@ impl App {
         failure_type: &str,
         output: String,
     ) {
-        if pending.is_retry {
-            self.add_system_message(&format!(
-                "[{}] 수리 후 {} 재실패. 태스크 차단 처리.",
-                pending.task_id, failure_type,
-            ));
-            self.cleanup_current_task_worktree();
-            self.save_and_advance_task(
+        let max_attempts = self.config.max_build_repair_attempts();
+        if pending.repair_attempts >= max_attempts {
+            let message = if pending.repair_attempts == 0 {
+                format!("[{}] {} 실패. 수리를 시도하지 않습니다.", pending.task_id, failure_type)
+            } else {
+                format!(
+                    "[{}] 수리 {}회 후 {} 재실패.",
+                    pending.task_id, pending.repair_attempts, failure_type,
+                )
+            };
+            self.add_system_message(&message);
+            self.block_or_merge_failing_build(
                 pending.task_id,
-                CodingTaskStatus::ImplementationBlocked,
-                format!("{}\n\n---\n빌드/테스트 실패:\n{}", pending.report, output),
+                pending.report,
+                format!("빌드/테스트 실패:\n{}", output),
             );
         } else {
+            let attempt = pending.repair_attempts + 1;
             self.add_system_message(&format!(
-                "[{}] {} 실패. 수리 에이전트 시작...",
-                pending.task_id, failure_type,
+                "[{}] {} 실패. 수리 에이전트 시작 ({}/{})...",
+                pending.task_id, failure_type, attempt, max_attempts,
             ));
-            self.start_build_test_repair(
-                pending.task_id,
-                pending.report,
-                output,
-            );
+            self.start_build_test_repair(pending.task_id, pending.report, output, attempt);
         }
     }
 
+    /// 수리 에이전트를 실행한다. `attempt`는 1부터 세는 이 태스크의 수리 시도 번호다.
     fn start_build_test_repair(
         &mut self,
         task_id: String,
         report: String,
         error_output: String,
+        attempt: usize,
     ) {
         self.pending_build_test = Some(PendingBuildTest {
             task_id: task_id.clone(),
             report,
-            is_retry: true,
+            repair_attempts: attempt,
         });
 
         let commands = self
@@ -2126,6 +4915,7 @@ impl App {
             &commands.build,
             &commands.test,
             &error_output,
+            attempt,
         );
 
         let mut client = match self.claude_client.take() {
@@ -2133,21 +4923,19 @@ impl App {
             None => {
                 self.add_system_message("수리 에이전트를 위한 세션을 찾을 수 없습니다.");
                 let pending = self.pending_build_test.take().unwrap();
-                self.cleanup_current_task_worktree();
-                self.save_and_advance_task(
+                self.block_or_merge_failing_build(
                     pending.task_id,
-                    CodingTaskStatus::ImplementationBlocked,
-                    format!(
-                        "{}\n\n---\n빌드/테스트 실패 (수리 불가):\n{}",
-                        pending.report, error_output,
-                    ),
+                    pending.report,
+                    format!("빌드/테스트 실패 (수리 불가):\n{}", error_output),
                 );
                 return;
             }
         };
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Coding);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
@@ -2165,10 +4953,10 @@ impl App {
                 .map(AgentOutcome::BuildTestRepairCompleted)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -2184,7 +4972,7 @@ impl App {
                 self.start_build_test_execution(
                     pending.task_id,
                     pending.report,
-                    true,
+                    pending.repair_attempts,
                 );
             }
             BuildTestRepairStatus::FixFailed => {
@@ -2192,73 +4980,299 @@ impl App {
                     "[{}] 수리 실패: {}",
                     pending.task_id, result.report,
                 ));
-                self.cleanup_current_task_worktree();
-                self.save_and_advance_task(
+                self.block_or_merge_failing_build(
                     pending.task_id,
-                    CodingTaskStatus::ImplementationBlocked,
-                    format!(
-                        "{}\n\n---\n빌드/테스트 수리 실패: {}",
-                        pending.report, result.report,
-                    ),
+                    pending.report,
+                    format!("빌드/테스트 수리 실패: {}", result.report),
                 );
             }
         }
     }
 
-    fn ff_merge_and_advance(
-        &mut self,
-        task_id: String,
-        report: String,
-    ) {
-        let coding_state = self.coding_state.as_ref().unwrap();
-        let worktree_info = coding_state.current_task_worktree.as_ref().unwrap();
-        let worktree_path = worktree_info.worktree_path.clone();
-        let task_branch = worktree_info.task_branch.clone();
+    /// 수리 후에도 빌드/테스트가 실패한 태스크를 처리한다. 기본적으로 태스크를 차단하지만,
+    /// `continue_on_build_failure`가 켜져 있으면 눈에 띄는 표시를 남기고 그대로 머지한다.
+    fn block_or_merge_failing_build(&mut self, task_id: String, report: String, failure: String) {
+        self.coding_state.as_mut().unwrap().current_task_progress.build_test_passed = Some(false);
+        if !self.config.continue_on_build_failure() {
+            self.add_system_message(&format!("[{}] 태스크 차단 처리.", task_id));
+            self.release_blocked_task_worktree(&task_id);
+            self.save_and_advance_task(
+                task_id,
+                CodingTaskStatus::ImplementationBlocked,
+                format!("{}\n\n---\n{}", report, failure),
+            );
+            return;
+        }
+
+        self.add_system_message(&format!(
+            "[{}] ⚠ 경고: continue_on_build_failure 설정에 따라 빌드/테스트가 실패한 상태로 \
+             통합 브랜치에 머지합니다. 이후 태스크는 깨진 빌드 위에서 진행됩니다.",
+            task_id,
+        ));
+        let report = format!(
+            "{}\n\n{}\n\n---\n{}",
+            FAILING_BUILD_REPORT_MARKER, report, failure,
+        );
+        self.request_merge(task_id, report, true);
+    }
+
+    /// 머지할 태스크의 리포트를 남긴다. `commit_reports`가 켜져 있으면 태스크 브랜치의 마지막
+    /// 커밋에 포함시켜 통합 브랜치 히스토리에 남기고, 꺼져 있으면 워크스페이스의 저널 디렉토리에만
+    /// 저장한다. 어느 쪽이든 실패해도 머지는 계속한다.
+    fn store_task_report(&mut self, worktree_path: &Path, task_id: &str, report: &str) {
+        if !self.config.commit_reports() {
+            if let Err(err) =
+                coding::save_task_report(&self.workspace_journal_dir(), task_id, report)
+            {
+                self.add_system_message(&format!(
+                    "[{}] 리포트 저장 실패: {}. 리포트 없이 진행.",
+                    task_id, err,
+                ));
+            }
+            return;
+        }
 
         let date_dir = self.session_date_dir.clone().unwrap_or_default();
         let session_name = self.session_name.clone().unwrap_or_default();
-
+        let integration_branch = self.coding_state.as_ref().unwrap().integration_branch.clone();
         if let Err(err) = coding::save_and_commit_task_report_in_worktree(
-            &worktree_path, &date_dir, &session_name, &task_id, &report,
+            worktree_path,
+            &date_dir,
+            &session_name,
+            task_id,
+            report,
+            &integration_branch,
+            &self.task_commit_message(task_id),
         ) {
             self.add_system_message(&format!(
                 "[{}] 워크트리 리포트 커밋 실패: {}. 리포트 없이 진행.",
                 task_id, err,
             ));
         }
+    }
+
+    /// 설정된 템플릿으로 만든 태스크 커밋 메시지. 제목은 코딩 상태의 태스크 목록에서 찾는다.
+    fn task_commit_message(&self, task_id: &str) -> String {
+        let title = self
+            .coding_state
+            .as_ref()
+            .and_then(|state| state.tasks.iter().find(|task| task.task_id == task_id))
+            .map(|task| task.title.as_str())
+            .unwrap_or_default();
+        self.config.commit_message_template().render(task_id, title)
+    }
+
+    /// 워크스페이스 저널의 스펙과 플랜을 태스크 워크트리의 저널 디렉토리로 복사한다. 복사한 파일이
+    /// 워크트리의 `.gitignore`에 걸리면 커밋되지 않으므로 알리거나, 설정에 따라 강제로 스테이징한다.
+    fn copy_journal_artifacts_to_worktree(&mut self) {
+        let worktree_journal = self.journal_dir();
+        let outcome = coding::copy_artifacts_to_worktree(
+            &self.workspace_journal_dir(),
+            &worktree_journal,
+            &["spec.md", "plan.md"],
+            self.config.force_add_ignored_artifacts(),
+        );
+        for err in &outcome.errors {
+            self.add_system_message(err);
+        }
+        if !outcome.force_added.is_empty() {
+            self.add_system_message(&format!(
+                ".gitignore에 걸리는 파일을 git add -f로 스테이징했습니다 ({}): {}",
+                worktree_journal.display(),
+                outcome.force_added.join(", "),
+            ));
+        } else if !outcome.ignored.is_empty() {
+            self.add_system_message(&format!(
+                "복사한 파일이 .gitignore에 걸려 커밋되지 않습니다 ({}): {}\n\
+                 커밋하려면 BEAR_FORCE_ADD_IGNORED_ARTIFACTS를 켜세요.",
+                worktree_journal.display(),
+                outcome.ignored.join(", "),
+            ));
+        }
+    }
 
+    /// CLI 호출 수가 경고 한도에 처음 닿았으면 알린다. 에이전트는 멈추지 않으며, 계속할지는
+    /// 다음 태스크를 시작하기 전에 묻는다.
+    fn check_invocation_budget(&mut self) {
+        let count = self.invocation_counter.count();
+        let threshold = self.config.invocation_warning_threshold();
+        if !self.invocation_budget.take_warning(count, threshold) {
+            return;
+        }
         self.add_system_message(&format!(
-            "[{}] 통합 브랜치로 fast-forward 머지 시작...",
-            task_id,
+            "[호출 경고] 이 세션에서 CLI를 {}회 호출해 경고 한도({}회)에 닿았습니다.",
+            count,
+            threshold.unwrap_or_default(),
         ));
+    }
 
-        let report_file_path = self.workspace_journal_dir().join(format!("{}.md", task_id));
-
-        let workspace = self.confirmed_workspace.clone().unwrap();
-        match coding::fast_forward_merge_task_branch(
-            &workspace,
-            &task_branch,
+    /// CLI 호출 수가 경고 한도에 닿았으면 다음 태스크를 시작하지 않고 계속할지 묻는다.
+    /// headless 모드에서는 묻지 않고 진행한다. 멈췄으면 true를 반환한다.
+    fn pause_for_invocation_budget(&mut self) -> bool {
+        let count = self.invocation_counter.count();
+        let threshold = self.config.invocation_warning_threshold();
+        if self.headless || !self.invocation_budget.is_exhausted(count, threshold) {
+            return false;
+        }
+        self.add_system_message(&format!(
+            "이 세션에서 이미 {}회 호출했습니다. 계속하시겠습니까?\n\
+             Enter를 누르면 계속하고, Esc를 누르면 종료합니다.",
+            count,
+        ));
+        self.input_mode = InputMode::InvocationBudget;
+        true
+    }
+
+    fn continue_after_invocation_warning(&mut self) {
+        let count = self.invocation_counter.count();
+        self.invocation_budget.continue_from(count);
+        if let Some(threshold) = self.config.invocation_warning_threshold() {
+            self.add_system_message(&format!(
+                "계속합니다. {}회 더 호출하면 다시 묻습니다.",
+                threshold,
+            ));
+        }
+        self.input_mode = InputMode::Coding;
+        self.start_next_coding_task();
+    }
+
+    /// 태스크를 머지한다. `manual_merge_approval`이 켜져 있으면 바로 머지하지 않고, 태스크 변경의
+    /// `git diff --stat` 요약을 보여 준 뒤 사용자가 승인하거나 차단하기를 기다린다.
+    fn request_merge(&mut self, task_id: String, report: String, merged_with_failing_build: bool) {
+        if !self.config.manual_merge_approval() || self.headless {
+            self.merge_and_advance(task_id, report, merged_with_failing_build);
+            return;
+        }
+
+        let worktree_path = self.current_task_worktree_path();
+        let integration_branch = self.coding_state.as_ref().unwrap().integration_branch.clone();
+        let summary = match coding::diff_stat_since_merge_base(
+            &worktree_path,
+            &integration_branch,
+            "HEAD",
         ) {
-            Ok(()) => {
-                self.add_system_message(&format!("[{}] fast-forward 머지 완료.", task_id));
+            Ok(stat) if stat.is_empty() => "(변경된 파일 없음)".to_string(),
+            Ok(stat) => stat,
+            Err(err) => format!("(변경 요약을 만들지 못했습니다: {})", err),
+        };
+        self.add_system_message(&format!(
+            "[{}] 통합 브랜치({})에 머지할 변경:\n{}\n\n워크트리: {}\n\
+             Enter를 누르면 머지하고, Ctrl+B를 누르면 태스크를 차단합니다.",
+            task_id,
+            integration_branch,
+            summary,
+            worktree_path.display(),
+        ));
+        self.pending_merge = Some(PendingMerge { task_id, report, merged_with_failing_build });
+        self.input_mode = InputMode::MergeApproval;
+    }
+
+    fn approve_pending_merge(&mut self) {
+        let Some(pending) = self.pending_merge.take() else {
+            return;
+        };
+        self.add_system_message(&format!("[{}] 머지를 승인했습니다.", pending.task_id));
+        self.input_mode = InputMode::Coding;
+        self.merge_and_advance(pending.task_id, pending.report, pending.merged_with_failing_build);
+    }
+
+    fn reject_pending_merge(&mut self) {
+        let Some(pending) = self.pending_merge.take() else {
+            return;
+        };
+        self.add_system_message(&format!(
+            "[{}] 머지를 거부했습니다. 태스크 차단 처리.",
+            pending.task_id,
+        ));
+        self.input_mode = InputMode::Coding;
+        self.release_blocked_task_worktree(&pending.task_id);
+        self.save_and_advance_task(
+            pending.task_id,
+            CodingTaskStatus::ImplementationBlocked,
+            format!("{}\n\n---\n사용자가 머지를 거부함", pending.report),
+        );
+    }
+
+    /// 태스크 브랜치를 통합 브랜치에 합치고 다음 태스크로 넘어간다. 통합 브랜치는 여기서만
+    /// 갱신되며 한 번에 한 태스크씩 처리되므로 머지끼리 경쟁하지 않는다.
+    fn merge_and_advance(
+        &mut self,
+        task_id: String,
+        report: String,
+        merged_with_failing_build: bool,
+    ) {
+        let coding_state = self.coding_state.as_ref().unwrap();
+        let worktree_info = coding_state.current_task_worktree.as_ref().unwrap();
+        let worktree_path = worktree_info.worktree_path.clone();
+        let task_branch = worktree_info.task_branch.clone();
+        let integration_branch = coding_state.integration_branch.clone();
+
+        self.store_task_report(&worktree_path, &task_id, &report);
+
+        let strategy = self.config.merge_strategy();
+        self.add_system_message(&format!(
+            "[{}] 통합 브랜치로 {} 머지 시작...",
+            task_id,
+            strategy.label(),
+        ));
+
+        let report_file_path = self.workspace_journal_dir().join(format!("{}.md", task_id));
+
+        let Some(workspace) = self.require_workspace() else {
+            return;
+        };
+        let merge_result = coding::merge_task_branch_with_rebase_retry(
+            &workspace,
+            &worktree_path,
+            &integration_branch,
+            &task_branch,
+            &self.task_commit_message(&task_id),
+            strategy,
+        );
+        match merge_result {
+            Ok(rebase_retries) => {
+                if rebase_retries > 0 {
+                    self.add_system_message(&format!(
+                        "[{}] 머지 중 통합 브랜치가 앞서 나가 다시 리베이스했습니다 ({}회).",
+                        task_id, rebase_retries,
+                    ));
+                }
+                self.add_system_message(&format!(
+                    "[{}] {} 머지 완료.",
+                    task_id,
+                    strategy.label(),
+                ));
                 self.cleanup_current_task_worktree();
                 self.advance_task(
                     task_id,
                     CodingTaskStatus::ImplementationSuccess,
                     report,
                     report_file_path,
+                    merged_with_failing_build,
                 );
             }
-            Err(err) => {
+            Err(failure) => {
+                let reason = match failure {
+                    MergeFailure::RebaseConflict { conflicted_files } => format!(
+                        "앞서 나간 통합 브랜치로 다시 리베이스하다 새 충돌 발생 ({})",
+                        conflicted_files.join(", "),
+                    ),
+                    MergeFailure::Merge(err) => err.to_string(),
+                    MergeFailure::RetryRebaseFailed { merge, rebase } => {
+                        format!("{} (retry rebase failed: {})", merge, rebase)
+                    }
+                };
                 self.add_system_message(&format!(
-                    "[{}] fast-forward 머지 실패: {}",
-                    task_id, err
+                    "[{}] {} 머지 실패: {}",
+                    task_id,
+                    strategy.label(),
+                    reason,
                 ));
-                self.cleanup_current_task_worktree();
+                self.release_blocked_task_worktree(&task_id);
                 self.save_and_advance_task(
                     task_id,
                     CodingTaskStatus::ImplementationBlocked,
-                    format!("{}\n\n---\nfast-forward 머지 실패: {}", report, err),
+                    format!("{}\n\n---\n{} 머지 실패: {}", report, strategy.label(), reason),
                 );
             }
         }
@@ -2287,7 +5301,7 @@ impl App {
                         .unwrap()
                         .worktree_path,
                 );
-                self.cleanup_current_task_worktree();
+                self.release_blocked_task_worktree(&task_id);
                 self.save_and_advance_task(
                     task_id,
                     CodingTaskStatus::ImplementationBlocked,
@@ -2310,8 +5324,10 @@ impl App {
             &conflicted_files,
         );
 
+        self.prepare_client_for_phase(&mut client, UsagePhase::Coding);
+        client.set_cancellation_token(self.begin_cancellable_operation());
         let (sender, receiver) = mpsc::channel();
-        self.agent_result_receiver = Some(receiver);
+        self.attach_agent_receiver(receiver);
         self.input_mode = InputMode::Coding;
         self.thinking_started_at = Instant::now();
 
@@ -2329,10 +5345,10 @@ impl App {
                 .map(AgentOutcome::ConflictResolutionCompleted)
                 .map_err(|err| err.to_string());
 
-            let _ = sender.send(AgentStreamMessage::Completed(AgentThreadResult {
+            let _ = sender.send(AgentStreamMessage::Completed(Box::new(AgentThreadResult {
                 client,
                 outcome,
-            }));
+            })));
         });
     }
 
@@ -2358,23 +5374,108 @@ impl App {
                     "[{}] 충돌 해결 실패: {}",
                     task_id, result.report,
                 ));
-                let worktree_path = self
-                    .coding_state
-                    .as_ref()
-                    .unwrap()
-                    .current_task_worktree
-                    .as_ref()
-                    .unwrap()
-                    .worktree_path
-                    .clone();
-                let _ = coding::abort_rebase(&worktree_path);
-                self.pending_coding_report = None;
-                self.cleanup_current_task_worktree();
-                self.save_and_advance_task(
+                if self.config.manual_conflict_resolution() && !self.headless {
+                    self.start_manual_conflict_resolution(task_id);
+                } else {
+                    self.block_conflicted_task(task_id, format!("충돌 해결 실패: {}", result.report));
+                }
+            }
+        }
+    }
+
+    /// 리베이스를 중단하고 충돌난 태스크를 차단한 뒤 다음 태스크로 넘어간다.
+    fn block_conflicted_task(&mut self, task_id: String, reason: String) {
+        let _ = coding::abort_rebase(&self.current_task_worktree_path());
+        self.pending_coding_report = None;
+        self.release_blocked_task_worktree(&task_id);
+        self.save_and_advance_task(task_id, CodingTaskStatus::ImplementationBlocked, reason);
+    }
+
+    fn current_task_worktree_path(&self) -> PathBuf {
+        let coding_state = self.coding_state.as_ref().unwrap();
+        coding_state.current_task_worktree.as_ref().unwrap().worktree_path.clone()
+    }
+
+    /// 현재 태스크가 코딩하고 빌드하는 디렉토리. 태스크에 하위 디렉토리가 지정돼 있어도
+    /// 워크트리에 없으면 워크트리 루트를 쓴다. git 작업은 여전히 워크트리 루트에서 한다.
+    fn current_task_directory(&self) -> Option<PathBuf> {
+        let coding_state = self.coding_state.as_ref()?;
+        let worktree_path = &coding_state.current_task_worktree.as_ref()?.worktree_path;
+        let directory = coding_state
+            .tasks
+            .get(coding_state.current_task_index)
+            .map(|task| task.working_directory(worktree_path))
+            .filter(|directory| directory.is_dir());
+        Some(directory.unwrap_or_else(|| worktree_path.clone()))
+    }
+
+    /// 충돌난 워크트리를 남겨 두고 사용자가 직접 해결하기를 기다린다. 충돌 해결 에이전트는
+    /// 실패할 때 리베이스를 중단하므로, 리베이스가 끝나 있으면 다시 시작해 충돌 상태로 되돌린다.
+    fn start_manual_conflict_resolution(&mut self, task_id: String) {
+        let worktree_path = self.current_task_worktree_path();
+        let outcome = if coding::is_rebase_in_progress(&worktree_path) {
+            coding::list_conflicted_files(&worktree_path)
+                .map(|conflicted_files| RebaseOutcome::Conflict { conflicted_files })
+        } else {
+            let integration_branch = self.coding_state.as_ref().unwrap().integration_branch.clone();
+            coding::rebase_onto_integration(&worktree_path, &integration_branch)
+        };
+        self.handle_manual_rebase_outcome(task_id, outcome);
+    }
+
+    /// 사용자가 충돌을 해결했다고 알리면 `git rebase --continue`로 리베이스를 이어 간다. 사용자가
+    /// 이미 리베이스를 끝냈으면 그대로 검증으로 넘어간다.
+    fn continue_manual_conflict_resolution(&mut self) {
+        let Some(task_id) = self.current_task_id() else {
+            return;
+        };
+        let worktree_path = self.current_task_worktree_path();
+        let outcome = if coding::is_rebase_in_progress(&worktree_path) {
+            coding::continue_rebase(&worktree_path)
+        } else {
+            Ok(RebaseOutcome::Success)
+        };
+        self.handle_manual_rebase_outcome(task_id, outcome);
+    }
+
+    fn abandon_manual_conflict_resolution(&mut self) {
+        let Some(task_id) = self.current_task_id() else {
+            return;
+        };
+        self.add_system_message(&format!("[{}] 리베이스를 중단하고 태스크를 차단합니다.", task_id));
+        self.block_conflicted_task(task_id, "수동 충돌 해결을 포기함".to_string());
+    }
+
+    fn handle_manual_rebase_outcome(
+        &mut self,
+        task_id: String,
+        outcome: Result<RebaseOutcome, GitError>,
+    ) {
+        match outcome {
+            Ok(RebaseOutcome::Success) => {
+                self.add_system_message(&format!(
+                    "[{}] 리베이스가 끝났습니다. 빌드/테스트 검증을 이어 갑니다.",
                     task_id,
-                    CodingTaskStatus::ImplementationBlocked,
-                    format!("충돌 해결 실패: {}", result.report),
-                );
+                ));
+                self.input_mode = InputMode::Coding;
+                let report = self.pending_coding_report.take().unwrap_or_default();
+                self.verify_build_and_test(task_id, report);
+            }
+            Ok(RebaseOutcome::Conflict { conflicted_files }) => {
+                let worktree_path = self.current_task_worktree_path();
+                self.add_system_message(&manual_conflict_guidance(
+                    &task_id,
+                    &worktree_path,
+                    &conflicted_files,
+                ));
+                self.input_mode = InputMode::ManualConflict;
+            }
+            Err(err) => {
+                self.add_system_message(&format!(
+                    "[{}] 리베이스를 이어 가지 못했습니다: {}",
+                    task_id, err,
+                ));
+                self.block_conflicted_task(task_id, format!("수동 충돌 해결 실패: {}", err));
             }
         }
     }
@@ -2385,7 +5486,7 @@ impl App {
         status: CodingTaskStatus,
         report: String,
     ) {
-        self.advance_task(task_id, status, report, PathBuf::new());
+        self.advance_task(task_id, status, report, PathBuf::new(), false);
     }
 
     fn advance_task(
@@ -2394,17 +5495,47 @@ impl App {
         status: CodingTaskStatus,
         report: String,
         report_file_path: PathBuf,
+        merged_with_failing_build: bool,
+    ) {
+        self.record_task_report(
+            task_id,
+            status,
+            report,
+            report_file_path,
+            merged_with_failing_build,
+        );
+        self.start_next_coding_task();
+    }
+
+    /// 현재 태스크의 보고서를 남기고 다음 태스크 순서로 넘긴다. 다음 태스크를 시작하지는 않는다.
+    fn record_task_report(
+        &mut self,
+        task_id: String,
+        status: CodingTaskStatus,
+        report: String,
+        report_file_path: PathBuf,
+        merged_with_failing_build: bool,
     ) {
+        self.emit_event(match status {
+            CodingTaskStatus::ImplementationSuccess => {
+                SessionEvent::TaskCompleted { task_id: task_id.clone() }
+            }
+            CodingTaskStatus::ImplementationBlocked => SessionEvent::TaskBlocked {
+                task_id: task_id.clone(),
+                reason: report.clone(),
+            },
+        });
         let coding_state = self.coding_state.as_mut().unwrap();
+        let progress = std::mem::take(&mut coding_state.current_task_progress);
         coding_state.task_reports.push(TaskReport {
             task_id,
             status,
             report,
             report_file_path,
+            merged_with_failing_build,
+            progress,
         });
-        coding_state.current_task_index += 1;
-
-        self.start_next_coding_task();
+        coding_state.advance_to_next_task();
     }
 
     fn finish_coding_phase(&mut self) {
@@ -2422,126 +5553,353 @@ impl App {
             .filter(|r| r.status == CodingTaskStatus::ImplementationBlocked)
             .count();
 
+        let failing_build_tasks: Vec<String> = coding_state
+            .task_reports
+            .iter()
+            .filter(|r| r.merged_with_failing_build)
+            .map(|r| r.task_id.clone())
+            .collect();
+
         self.add_system_message(&format!(
             "코딩 단계 완료. 성공: {}, 차단: {}",
             success_count, blocked_count,
         ));
+        self.emit_event(SessionEvent::CodingFinished { success_count, blocked_count });
+
+        if !failing_build_tasks.is_empty() {
+            self.add_system_message(&format!(
+                "⚠ 경고: 빌드/테스트가 실패한 상태로 머지된 태스크 {}개: {}. \
+                 통합 브랜치의 빌드를 반드시 수정해야 합니다.",
+                failing_build_tasks.len(),
+                failing_build_tasks.join(", "),
+            ));
+        }
+
+        if !self.config.verify_build_test() {
+            self.add_system_message(
+                "⚠ 빌드/테스트 검증을 건너뛰었습니다(verify_build_test 꺼짐). \
+                 통합 브랜치의 빌드를 직접 확인하세요.",
+            );
+        }
 
         self.add_system_message(&format!(
             "통합 브랜치가 유지됩니다: {}",
             integration_branch,
         ));
 
-        self.input_mode = InputMode::Done;
-    }
-
-    pub fn open_external_editor(&mut self) {
-        self.pending_external_editor = false;
-
-        let temp_path = std::env::temp_dir().join(
-            format!("bear-input-{}.md", uuid::Uuid::new_v4()),
-        );
-
-        if let Err(err) = std::fs::File::create(&temp_path)
-            .and_then(|mut f| f.write_all(self.input_buffer.as_bytes()))
-        {
-            self.add_system_message(&format!("임시 파일 생성 실패: {}", err));
-            return;
+        let retained_worktrees = &self.coding_state.as_ref().unwrap().retained_worktrees;
+        if !retained_worktrees.is_empty() {
+            let lines: Vec<String> = retained_worktrees
+                .iter()
+                .map(|retained| {
+                    format!(
+                        "  - [{}] {} (브랜치: {})",
+                        retained.task_id,
+                        retained.worktree_path.display(),
+                        retained.task_branch,
+                    )
+                })
+                .collect();
+            self.add_system_message(&format!(
+                "사후 분석을 위해 남겨 둔 차단된 태스크의 워크트리 {}개:\n{}",
+                lines.len(),
+                lines.join("\n"),
+            ));
         }
 
-        let editor_command = std::env::var("EDITOR").unwrap_or_else(|_| "code --wait".to_string());
-        let parts: Vec<&str> = editor_command.split_whitespace().collect();
-        let (program, args) = match parts.split_first() {
-            Some((prog, rest)) => (*prog, rest),
-            None => {
-                self.add_system_message("EDITOR 환경변수가 비어 있습니다.");
-                let _ = std::fs::remove_file(&temp_path);
-                return;
-            }
-        };
-
-        let status = std::process::Command::new(program)
-            .args(args)
-            .arg(&temp_path)
-            .stdin(std::process::Stdio::inherit())
-            .stdout(std::process::Stdio::inherit())
-            .stderr(std::process::Stdio::inherit())
-            .status();
-
-        match status {
-            Ok(exit_status) if exit_status.success() => {
-                match std::fs::read_to_string(&temp_path) {
-                    Ok(content) => {
-                        self.input_buffer = content;
-                        self.cursor_position = self.input_buffer.chars().count();
-                    }
-                    Err(err) => {
-                        self.add_system_message(
-                            &format!("임시 파일 읽기 실패: {}", err),
-                        );
-                    }
-                }
-            }
-            Ok(_) => {
-                self.add_system_message("에디터가 비정상 종료되었습니다.");
+        let mut coding_summary =
+            coding::build_coding_summary(self.coding_state.as_ref().unwrap());
+        coding_summary.build_test_skipped = !self.config.verify_build_test();
+        match coding::save_coding_summary(&self.journal_dir(), &coding_summary) {
+            Ok(path) => {
+                self.add_system_message(&format!("작업 요약 저장: {}", path.display()));
             }
             Err(err) => {
-                self.add_system_message(
-                    &format!("에디터 실행 실패: {} (command: {})", err, editor_command),
-                );
+                self.add_system_message(&format!("작업 요약 저장 실패: {}", err));
             }
         }
 
-        let _ = std::fs::remove_file(&temp_path);
+        if self.config.create_pull_request() && self.publish_integration_branch(&coding_summary) {
+            return;
+        }
+        self.report_session_totals();
     }
 
-    fn is_newline_modifier(&self, modifiers: KeyModifiers) -> bool {
-        if self.keyboard_enhancement_enabled {
-            modifiers.contains(KeyModifiers::SHIFT)
-        } else {
-            modifiers.contains(KeyModifiers::ALT)
+    /// 사용량과 소요 시간을 보여준 뒤 차단된 태스크를 다시 실행할지 묻는다. 코딩 단계를 마무리하는
+    /// 마지막 단계이며, 풀 리퀘스트를 만드는 중이었으면 그 작업이 끝난 뒤에 실행한다.
+    fn report_session_totals(&mut self) {
+        if let Some(summary) = self.usage_tracker.summary_text() {
+            self.add_system_message(&summary);
+        }
+        if let Some(summary) = self.timings.summary_text() {
+            self.add_system_message(&summary);
         }
+
+        self.offer_blocked_task_rerun();
     }
 
-    fn insert_char_at_cursor(&mut self, c: char) {
-        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
-        self.input_buffer.insert(byte_pos, c);
-        self.cursor_position += 1;
+    /// 통합 브랜치를 푸시하고 세션 이름을 제목으로, 작업 요약과 리포트를 본문으로 하는 풀 리퀘스트를
+    /// 만드는 작업을 백그라운드에서 시작한다. 이미 만든 풀 리퀘스트가 있으면 푸시만 한다. 작업을
+    /// 시작했으면 true이며, 결과는 `handle_publish_result`가 받는다. `gh`가 없거나 실패해도 통합
+    /// 브랜치는 로컬에 남아 있으므로 알리기만 한다.
+    fn publish_integration_branch(&mut self, summary: &coding::CodingSummary) -> bool {
+        if summary.success_count == 0 {
+            self.add_system_message("성공한 태스크가 없어 풀 리퀘스트를 만들지 않습니다.");
+            return false;
+        }
+        let Some(gh_binary) = pull_request::find_gh_binary() else {
+            self.add_system_message(
+                "gh CLI를 찾을 수 없어 풀 리퀘스트를 만들지 않습니다. 통합 브랜치를 직접 푸시하세요.",
+            );
+            return false;
+        };
+        let Some(workspace) = self.require_workspace() else {
+            return false;
+        };
+
+        let branch = summary.integration_branch.clone();
+        let reports = &self.coding_state.as_ref().unwrap().task_reports;
+        let job = pull_request::PublishJob {
+            gh_binary,
+            workspace,
+            title: self.session_name.clone().unwrap_or_else(|| branch.clone()),
+            body: pull_request::build_pull_request_body(summary, reports),
+            base_branch: self
+                .config
+                .integration_base_branch()
+                .or(self.default_branch.as_deref())
+                .map(str::to_string),
+            branch,
+            existing_url: self.pull_request_url.clone(),
+        };
+
+        self.add_system_message("통합 브랜치를 푸시하고 풀 리퀘스트를 만드는 중...");
+        let (sender, receiver) = mpsc::channel();
+        self.publish_receiver = Some(match &self.wake_sender {
+            Some(wake_sender) => event::forward_agent_messages(receiver, wake_sender.clone()),
+            None => receiver,
+        });
+        self.input_mode = InputMode::Publishing;
+        self.thinking_started_at = Instant::now();
+
+        std::thread::spawn(move || {
+            let _ = sender.send(job.run());
+        });
+        true
     }
 
-    fn insert_text_at_cursor(&mut self, text: &str) {
-        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
-        self.input_buffer.insert_str(byte_pos, text);
-        self.cursor_position += text.chars().count();
+    fn handle_publish_result(&mut self, result: pull_request::PublishResult) {
+        match result {
+            Ok(pull_request::PublishOutcome::Updated { url }) => {
+                let message = format!("통합 브랜치를 다시 푸시해 풀 리퀘스트를 갱신했습니다: {}", url);
+                self.add_system_message(&message);
+            }
+            Ok(pull_request::PublishOutcome::Created { url }) => {
+                self.add_system_message(&format!("풀 리퀘스트 생성: {}", url));
+                self.pull_request_url = Some(url);
+            }
+            Err(err @ pull_request::PullRequestError::PushFailed { .. }) => {
+                self.add_system_message(&format!("통합 브랜치 푸시 실패: {}", err));
+            }
+            Err(err) => self.add_system_message(&format!("풀 리퀘스트 생성 실패: {}", err)),
+        }
+        self.report_session_totals();
     }
 
-    fn delete_char_before_cursor(&mut self) {
-        if self.cursor_position == 0 {
+    /// 차단된 태스크가 있으면 목록을 보여주고 다시 실행할 태스크를 고르게 한다. 없으면 세션을
+    /// 끝낸다.
+    fn offer_blocked_task_rerun(&mut self) {
+        let coding_state = self.coding_state.as_ref().unwrap();
+        let blocked_task_ids = coding_state.blocked_task_ids();
+        if blocked_task_ids.is_empty() {
+            self.complete_coding_session();
             return;
         }
-        self.cursor_position -= 1;
-        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
-        self.input_buffer.remove(byte_pos);
+
+        let mut lines = vec![
+            "차단된 작업을 처음부터 다시 실행할 수 있습니다. \
+             번호를 입력하세요. (Enter만 누르면 종료)"
+                .to_string(),
+        ];
+        for (i, task_id) in blocked_task_ids.iter().enumerate() {
+            let title = coding_state
+                .tasks
+                .iter()
+                .find(|task| &task.task_id == task_id)
+                .map(|task| task.title.as_str())
+                .unwrap_or_default();
+            lines.push(format!("  {}. [{}] {}", i + 1, task_id, title));
+        }
+        self.add_system_message(&lines.join("\n"));
+        self.input_mode = InputMode::BlockedTaskSelection;
     }
 
-    fn delete_char_at_cursor(&mut self) {
-        let char_count = self.input_buffer.chars().count();
-        if self.cursor_position >= char_count {
+    fn submit_blocked_task_selection(&mut self) {
+        let selection = self.input_buffer.trim().to_string();
+        self.clear_input();
+        if selection.is_empty() {
+            self.complete_coding_session();
             return;
         }
-        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
-        self.input_buffer.remove(byte_pos);
-    }
+        self.add_user_message(&selection);
 
-    fn clear_input(&mut self) {
-        self.input_buffer.clear();
-        self.cursor_position = 0;
+        let coding_state = self.coding_state.as_mut().unwrap();
+        let selected_task_id = selection
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .and_then(|index| coding_state.blocked_task_ids().get(index).cloned());
+        let Some(task_id) = selected_task_id else {
+            self.add_system_message(&format!(
+                "잘못된 선택입니다: {}. 목록의 번호를 입력하세요.",
+                selection,
+            ));
+            return;
+        };
+
+        coding_state.reopen_blocked_task(&task_id);
+        self.add_system_message(&format!("[{}] 작업을 처음부터 다시 실행합니다.", task_id));
+        self.start_next_coding_task();
     }
 
-    fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-        }
+    /// 자동 stash로 치워 둔 변경을 되돌리고 세션을 끝낸다. 차단된 태스크를 다시 실행하면
+    /// 워크스페이스에 체크아웃된 통합 브랜치에 머지하므로, 그 전에는 되돌리지 않는다.
+    fn complete_coding_session(&mut self) {
+        self.restore_auto_stash();
+        self.input_mode = InputMode::Done;
+    }
+
+    pub fn open_external_editor(&mut self) {
+        self.pending_external_editor = false;
+
+        let editor_command = std::env::var("EDITOR").unwrap_or_else(|_| "code --wait".to_string());
+        match self.external_edit_target() {
+            ExternalEditTarget::InputBuffer => self.edit_input_in_external_editor(&editor_command),
+            target => self.edit_draft_in_external_editor(&editor_command, target),
+        }
+    }
+
+    /// 스펙/플랜 피드백 단계에서는 피드백 입력 대신 초안 자체를 편집한다.
+    fn external_edit_target(&self) -> ExternalEditTarget {
+        match self.input_mode {
+            InputMode::SpecFeedback if self.last_spec_draft.is_some() => {
+                ExternalEditTarget::SpecDraft
+            }
+            InputMode::PlanFeedback if self.last_plan_draft.is_some() => {
+                ExternalEditTarget::PlanDraft
+            }
+            _ => ExternalEditTarget::InputBuffer,
+        }
+    }
+
+    /// 입력 버퍼를 임시 파일에 쓰고 에디터로 편집한 뒤 결과를 입력 버퍼에 반영한다.
+    /// 에디터가 비정상 종료하면 편집 전 입력 버퍼와 커서 위치를 그대로 복원한다.
+    fn edit_input_in_external_editor(&mut self, editor_command: &str) {
+        let original_buffer = self.input_buffer.clone();
+        let original_cursor_position = self.cursor_position;
+
+        match run_external_editor(editor_command, "bear-input", &original_buffer) {
+            Ok(content) if content == original_buffer => {
+                self.add_system_message("에디터에서 변경된 내용이 없습니다.");
+            }
+            Ok(content) => {
+                self.input_buffer = content;
+                self.cursor_position = self.input_buffer.chars().count();
+            }
+            Err(error_message) => {
+                // 에디터가 임시 파일을 일부만 쓰고 끝났을 수 있으므로 편집 전 입력을 복원한다.
+                self.input_buffer = original_buffer;
+                self.cursor_position = original_cursor_position;
+                self.add_system_message(&format!("{} 편집 전 입력을 유지합니다.", error_message));
+            }
+        }
+    }
+
+    /// 스펙/플랜 초안을 에디터로 편집한다. 내용을 바꿔 저장하면 편집한 초안을 그대로 승인하고,
+    /// 비우거나 바꾸지 않았거나 에디터가 비정상 종료하면 기존 초안을 유지한다.
+    fn edit_draft_in_external_editor(&mut self, editor_command: &str, target: ExternalEditTarget) {
+        let (original_draft, label) = match target {
+            ExternalEditTarget::SpecDraft => (self.last_spec_draft.clone(), "스펙"),
+            ExternalEditTarget::PlanDraft => (self.last_plan_draft.clone(), "개발 계획"),
+            ExternalEditTarget::InputBuffer => return,
+        };
+        let original_draft = original_draft.unwrap_or_default();
+
+        match run_external_editor(editor_command, "bear-draft", &original_draft) {
+            Ok(content) if content.trim().is_empty() || content == original_draft => {
+                self.add_system_message(&format!("{} 초안에 변경된 내용이 없습니다.", label));
+            }
+            Ok(content) => {
+                self.add_system_message(&format!("편집한 {} 초안을 승인합니다.", label));
+                if target == ExternalEditTarget::SpecDraft {
+                    self.last_spec_draft = Some(content);
+                    self.approve_spec();
+                } else {
+                    self.last_plan_draft = Some(content);
+                    self.approve_plan();
+                }
+            }
+            Err(error_message) => {
+                self.add_system_message(&format!(
+                    "{} 편집한 내용을 버리고 기존 {} 초안을 유지합니다.",
+                    error_message, label,
+                ));
+            }
+        }
+    }
+
+    fn is_newline_modifier(&self, modifiers: KeyModifiers) -> bool {
+        if self.keyboard_enhancement_enabled {
+            modifiers.contains(KeyModifiers::SHIFT)
+        } else {
+            modifiers.contains(KeyModifiers::ALT)
+        }
+    }
+
+    fn insert_char_at_cursor(&mut self, c: char) {
+        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
+        self.input_buffer.insert(byte_pos, c);
+        self.cursor_position += 1;
+    }
+
+    fn insert_text_at_cursor(&mut self, text: &str) {
+        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
+        self.input_buffer.insert_str(byte_pos, text);
+        self.cursor_position += text.chars().count();
+    }
+
+    fn delete_char_before_cursor(&mut self) {
+        if self.cursor_position == 0 {
+            return;
+        }
+        self.cursor_position -= 1;
+        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
+        self.input_buffer.remove(byte_pos);
+    }
+
+    fn delete_char_at_cursor(&mut self) {
+        let char_count = self.input_buffer.chars().count();
+        if self.cursor_position >= char_count {
+            return;
+        }
+        let byte_pos = char_to_byte_index(&self.input_buffer, self.cursor_position);
+        self.input_buffer.remove(byte_pos);
+    }
+
+    fn clear_input(&mut self) {
+        self.input_buffer.clear();
+        self.cursor_position = 0;
+    }
+
+    fn set_input(&mut self, text: &str) {
+        self.input_buffer = text.to_string();
+        self.cursor_position = self.input_buffer.chars().count();
+    }
+
+    fn move_cursor_left(&mut self) {
+        if self.cursor_position > 0 {
+            self.cursor_position -= 1;
+        }
     }
 
     fn move_cursor_right(&mut self) {
@@ -2599,7 +5957,7 @@ impl App {
         let mut global_char_offset = 0;
 
         for (logical_idx, logical_line) in logical_lines.iter().enumerate() {
-            let wrapped = wrap_text_by_char_width(logical_line, text_width);
+            let wrapped = wrap_text(logical_line, text_width, self.wrap_mode());
             let wrap_count = wrapped.len();
             let mut line_char_offset = 0;
 
@@ -2623,17 +5981,53 @@ impl App {
     }
 
     fn add_system_message(&mut self, content: &str) {
-        self.messages.push(ChatMessage {
-            role: MessageRole::System,
-            content: content.to_string(),
-        });
+        self.push_message(MessageRole::System, content);
     }
 
     fn add_user_message(&mut self, content: &str) {
+        self.push_message(MessageRole::User, content);
+    }
+
+    fn add_stream_message(&mut self, message: StreamMessage) {
+        self.push_message(MessageRole::System, &message.content);
+        if let Some(last) = self.messages.last_mut() {
+            last.full_content = message.full_content;
+        }
+    }
+
+    /// 잘린 스트리밍 메시지의 원문을 대화 기록 끝에 추가한다. 이미 출력된 메시지는 터미널
+    /// 스크롤백에 남아 고칠 수 없으므로 새 메시지로 덧붙인다. `all`이 false이면 가장 최근
+    /// 메시지 하나만 펼치고, 펼친 메시지가 없으면 false를 반환한다.
+    fn expand_truncated_messages(&mut self, all: bool) -> bool {
+        let mut expanded = Vec::new();
+        for message in self.messages.iter_mut().rev() {
+            if let Some(full_content) = message.full_content.take() {
+                expanded.push(full_content);
+                if !all {
+                    break;
+                }
+            }
+        }
+        if expanded.is_empty() {
+            return false;
+        }
+
+        for full_content in expanded.into_iter().rev() {
+            self.push_message(MessageRole::System, &format!("[전체 내용]\n{}", full_content));
+        }
+        true
+    }
+
+    fn push_message(&mut self, role: MessageRole, content: &str) {
         self.messages.push(ChatMessage {
-            role: MessageRole::User,
+            role,
             content: content.to_string(),
+            full_content: None,
         });
+        // 위로 스크롤한 상태에서는 보고 있던 위치가 유지되도록 오프셋을 함께 늘린다.
+        if !self.follow_tail {
+            self.scroll_offset += 1;
+        }
     }
 }
 
@@ -2643,6 +6037,44 @@ struct VisualLineInfo {
     is_last_of_logical: bool,
 }
 
+/// `content`를 임시 파일에 쓰고 에디터로 연 뒤, 에디터가 정상 종료하면 편집된 내용을 반환한다.
+fn run_external_editor(
+    editor_command: &str,
+    file_prefix: &str,
+    content: &str,
+) -> Result<String, String> {
+    let temp_path = std::env::temp_dir().join(
+        format!("{}-{}.md", file_prefix, uuid::Uuid::new_v4()),
+    );
+
+    std::fs::File::create(&temp_path)
+        .and_then(|mut f| f.write_all(content.as_bytes()))
+        .map_err(|err| format!("임시 파일 생성 실패: {}.", err))?;
+
+    let parts: Vec<&str> = editor_command.split_whitespace().collect();
+    let Some((program, args)) = parts.split_first() else {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err("EDITOR 환경변수가 비어 있습니다.".to_string());
+    };
+
+    let status = std::process::Command::new(program)
+        .args(args)
+        .arg(&temp_path)
+        .stdin(std::process::Stdio::inherit())
+        .stdout(std::process::Stdio::inherit())
+        .stderr(std::process::Stdio::inherit())
+        .status();
+
+    let edited_content = match status {
+        Ok(exit_status) if exit_status.success() => std::fs::read_to_string(&temp_path)
+            .map_err(|err| format!("임시 파일 읽기 실패: {}", err)),
+        Ok(_) => Err("에디터가 비정상 종료되었습니다.".to_string()),
+        Err(err) => Err(format!("에디터 실행 실패: {} (command: {})", err, editor_command)),
+    };
+    let _ = std::fs::remove_file(&temp_path);
+    edited_content
+}
+
 fn char_to_byte_index(s: &str, char_idx: usize) -> usize {
     s.char_indices()
         .nth(char_idx)
@@ -2684,5 +6116,3130 @@ fn validate_workspace_path(path: &Path) -> Option<String> {
             path.display()
         ));
     }
-    None
+    bear_worktree_error(path)
+}
+
+/// 이전 실행이 남긴 워크트리를 워크스페이스로 고른 경우 원래 저장소 루트를 안내한다.
+fn bear_worktree_error(path: &Path) -> Option<String> {
+    if !coding::is_bear_worktree(path) {
+        return None;
+    }
+    let suggestion = match coding::main_worktree_root(path) {
+        Some(root) => format!("원래 저장소 루트를 사용하세요: {}", root.display()),
+        None => "원래 저장소 루트를 사용하세요.".to_string(),
+    };
+    Some(format!(
+        "이전 Bear 실행이 만든 워크트리(또는 연결된 워크트리)는 워크스페이스로 사용할 수 없습니다: {}\n{}\n새로운 워크스페이스 절대 경로를 입력하세요.",
+        path.display(),
+        suggestion,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use crate::ui::coding::{FindingSeverity, ReviewFinding};
+    use crate::config::PromptOverrideMode;
+    use crate::ui::test_support::{coding_task, run_git, setup_workspace, write_script};
+    use tempfile::TempDir;
+
+    /// 첫 번째 태스크의 워크트리가 준비된 코딩 단계 상태의 App을 만든다.
+    fn app_in_coding_phase(config: Config, workspace: &Path) -> App {
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.to_path_buf());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+
+        let integration_branch = coding::create_integration_branch(
+            workspace,
+            "test-session",
+            &coding::IntegrationBranchOptions::default(),
+        )
+        .unwrap();
+        let task_branch =
+            coding::create_task_branch(workspace, &integration_branch, "TASK-00").unwrap();
+        let worktree_root = workspace.parent().unwrap();
+        let worktree_path =
+            coding::create_worktree(workspace, worktree_root, &task_branch).unwrap();
+
+        app.coding_state = Some(CodingPhaseState {
+            tasks: vec![coding_task("TASK-00", &[])],
+            current_task_index: 0,
+            task_reports: Vec::new(),
+            integration_branch,
+            current_task_worktree: Some(TaskWorktreeInfo {
+                worktree_path,
+                task_branch,
+            }),
+            build_test_commands: None,
+            build_test_subdir: None,
+            incremental_planning: false,
+            current_task_progress: TaskProgress::default(),
+            rerun_return_index: None,
+            retained_worktrees: Vec::new(),
+            flagged_dependency_manifests: BTreeMap::new(),
+        });
+        app.input_mode = InputMode::Coding;
+        app
+    }
+
+    fn has_message_containing(app: &App, needle: &str) -> bool {
+        app.messages.iter().any(|m| m.content.contains(needle))
+    }
+
+    fn finish_blocked_task(
+        keep_failed_worktrees: bool,
+    ) -> (TempDir, PathBuf, TaskWorktreeInfo, App) {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_keep_failed_worktrees(keep_failed_worktrees);
+        let mut app = app_in_coding_phase(config, &workspace);
+        let coding_state = app.coding_state.as_ref().unwrap();
+        let worktree_info = coding_state.current_task_worktree.as_ref().unwrap();
+        let worktree_info = TaskWorktreeInfo {
+            worktree_path: worktree_info.worktree_path.clone(),
+            task_branch: worktree_info.task_branch.clone(),
+        };
+
+        app.handle_coding_task_result(CodingTaskResult {
+            status: CodingTaskStatus::ImplementationBlocked,
+            report: "blocked".to_string(),
+        });
+
+        (root, workspace, worktree_info, app)
+    }
+
+    #[test]
+    fn coding_agent_error_is_fatal_by_default() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+
+        app.handle_coding_task_error("connection reset".to_string());
+
+        assert!(matches!(app.input_mode, InputMode::FatalError));
+        assert!(!app.should_quit);
+        assert_eq!(app.fatal_error(), Some("Task [TASK-00] error: connection reset"));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.task_reports.len(), 1);
+        assert_eq!(coding_state.task_reports[0].status, CodingTaskStatus::ImplementationBlocked);
+        assert!(coding_state.current_task_worktree.is_none());
+    }
+
+    #[test]
+    fn coding_agent_error_blocks_only_the_task_when_continuing_on_fatal() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_continue_on_fatal(true);
+        let mut app = app_in_coding_phase(config, &workspace);
+
+        app.handle_coding_task_error("connection reset".to_string());
+
+        assert!(!app.should_quit);
+        assert!(app.fatal_error().is_none());
+        assert!(has_message_containing(&app, "차단된 것으로 처리하고 다음 작업으로 넘어갑니다."));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.task_reports.len(), 1);
+        assert!(coding_state.task_reports[0].report.contains("Agent error: connection reset"));
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+    }
+
+    fn branch_exists(workspace: &Path, branch: &str) -> bool {
+        std::process::Command::new("git")
+            .current_dir(workspace)
+            .args(["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)])
+            .status()
+            .unwrap()
+            .success()
+    }
+
+    #[test]
+    fn blocked_task_worktree_is_retained_when_configured() {
+        let (_root, workspace, worktree_info, app) = finish_blocked_task(true);
+
+        assert!(worktree_info.worktree_path.is_dir());
+        assert!(branch_exists(&workspace, &worktree_info.task_branch));
+        let retained = &app.coding_state.as_ref().unwrap().retained_worktrees;
+        assert_eq!(retained.len(), 1);
+        assert_eq!(retained[0].task_id, "TASK-00");
+        assert!(has_message_containing(&app, "남겨 둔 차단된 태스크의 워크트리 1개"));
+        assert!(has_message_containing(&app, &worktree_info.worktree_path.display().to_string()));
+    }
+
+    #[test]
+    fn blocked_task_worktree_is_removed_by_default() {
+        let (_root, workspace, worktree_info, app) = finish_blocked_task(false);
+
+        assert!(!worktree_info.worktree_path.exists());
+        assert!(!branch_exists(&workspace, &worktree_info.task_branch));
+        assert!(app.coding_state.as_ref().unwrap().retained_worktrees.is_empty());
+        assert!(!has_message_containing(&app, "남겨 둔 차단된 태스크의 워크트리"));
+    }
+
+    fn progress_status_after(app: &App, elapsed: std::time::Duration) -> Option<String> {
+        app.progress_status_at(app.thinking_started_at + elapsed)
+    }
+
+    #[test]
+    fn progress_status_shows_task_position_and_elapsed_time_while_coding() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let coding_state = app.coding_state.as_mut().unwrap();
+        coding_state.tasks.push(coding_task("TASK-01", &[]));
+        coding_state.tasks.push(coding_task("TASK-02", &[]));
+
+        assert_eq!(
+            progress_status_after(&app, std::time::Duration::from_secs(65)).as_deref(),
+            Some("[1/3] TASK-00 — 1m 05s · 코딩")
+        );
+    }
+
+    #[test]
+    fn progress_status_shows_review_iteration() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        app.review_state = Some(ReviewState {
+            task_id: "TASK-00".to_string(),
+            report: "done".to_string(),
+            iteration_count: 1,
+            max_iterations: 3,
+            reviewer_client: None,
+            coding_client: None,
+        });
+
+        assert_eq!(
+            progress_status_after(&app, std::time::Duration::from_millis(12_300)).as_deref(),
+            Some("[1/1] TASK-00 — 12.3s · 리뷰 2/3")
+        );
+    }
+
+    #[test]
+    fn progress_status_shows_build_phase_and_hides_when_idle() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        app.pending_build_test = Some(PendingBuildTest {
+            task_id: "TASK-00".to_string(),
+            report: "done".to_string(),
+            repair_attempts: 0,
+        });
+
+        assert_eq!(
+            progress_status_after(&app, std::time::Duration::from_secs(3)).as_deref(),
+            Some("[1/1] TASK-00 — 3.0s · 빌드/테스트")
+        );
+
+        app.input_mode = InputMode::BuildTestCommandInput;
+        assert!(app.progress_status().is_none());
+    }
+
+    #[test]
+    fn zero_max_review_iterations_skips_review_and_goes_to_rebase() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(0);
+        let mut app = app_in_coding_phase(config, &workspace);
+
+        app.handle_coding_task_result(CodingTaskResult {
+            status: CodingTaskStatus::ImplementationSuccess,
+            report: "done".to_string(),
+        });
+
+        assert!(app.review_state.is_none());
+        assert!(!has_message_containing(&app, "코드 리뷰 시작"));
+        assert!(has_message_containing(&app, "리뷰를 건너뜁니다"));
+        assert!(has_message_containing(&app, "리베이스 성공"));
+        // 빌드 시스템이 없는 저장소이므로 빌드 명령어 입력 단계에 도달해야 한다.
+        assert!(matches!(app.input_mode, InputMode::BuildTestCommandInput));
+    }
+
+    /// 코딩 단계 App의 태스크 워크트리에 build/test 타깃이 있는 Makefile을 둔다.
+    fn app_with_detectable_build(workspace: &Path) -> App {
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), workspace);
+        let coding_state = app.coding_state.as_ref().unwrap();
+        let worktree_path = &coding_state.current_task_worktree.as_ref().unwrap().worktree_path;
+        std::fs::write(worktree_path.join("Makefile"), "build:\n\ttrue\ntest:\n\ttrue\n")
+            .unwrap();
+        app.claude_client = Some(ClaudeCodeClient::with_binary_path(
+            "test-key".to_string(),
+            workspace.to_path_buf(),
+            None,
+            PathBuf::from("claude"),
+        ));
+        app
+    }
+
+    fn build_test_commands(app: &App) -> (String, String) {
+        let commands = app.coding_state.as_ref().unwrap().build_test_commands.as_ref().unwrap();
+        (commands.build.clone(), commands.test.clone())
+    }
+
+    #[test]
+    fn detected_build_commands_are_prefilled_and_accepted_with_enter() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_with_detectable_build(&workspace);
+
+        app.verify_build_and_test("TASK-00".to_string(), "done".to_string());
+
+        assert!(matches!(app.input_mode, InputMode::BuildTestCommandInput));
+        assert_eq!(app.input_buffer, "make build");
+        assert_eq!(app.cursor_position, "make build".len());
+
+        type_and_submit(&mut app, "");
+        assert_eq!(app.input_buffer, "make test");
+
+        type_and_submit(&mut app, "");
+
+        assert_eq!(
+            build_test_commands(&app),
+            ("make build".to_string(), "make test".to_string()),
+        );
+        assert!(matches!(app.input_mode, InputMode::Coding));
+        assert!(has_message_containing(&app, "[TASK-00] 빌드/테스트 검증 시작..."));
+    }
+
+    #[test]
+    fn detected_build_commands_can_be_replaced_or_cleared_to_redetect() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_with_detectable_build(&workspace);
+        app.verify_build_and_test("TASK-00".to_string(), "done".to_string());
+
+        app.input_buffer.clear();
+        app.cursor_position = 0;
+        type_and_submit(&mut app, "make -C server build");
+        app.input_buffer.clear();
+        app.cursor_position = 0;
+        type_and_submit(&mut app, "");
+
+        assert_eq!(
+            build_test_commands(&app),
+            ("make -C server build".to_string(), "make test".to_string()),
+        );
+        assert!(matches!(app.input_mode, InputMode::Coding));
+    }
+
+    fn review_state_for(task_id: &str, max_iterations: usize) -> ReviewState {
+        ReviewState {
+            task_id: task_id.to_string(),
+            report: "done".to_string(),
+            iteration_count: 0,
+            max_iterations,
+            reviewer_client: None,
+            coding_client: None,
+        }
+    }
+
+    #[test]
+    fn regenerate_request_recreates_the_task_worktree_from_the_integration_branch() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace)
+            .with_claude_binary(hanging_agent_binary(root.path()));
+        app.input_mode = InputMode::Coding;
+        commit_in_current_worktree(&app, "attempt.txt");
+        let current_worktree = |app: &App| {
+            let info = app.coding_state.as_ref().unwrap().current_task_worktree.as_ref().unwrap();
+            (info.worktree_path.clone(), info.task_branch.clone())
+        };
+        let (old_path, old_branch) = current_worktree(&app);
+        app.review_state = Some(review_state_for("TASK-00", 5));
+
+        app.handle_key_event(ctrl('n'));
+        assert!(has_message_containing(&app, "[TASK-00] 다음 리뷰에서 변경을 요청하면"));
+        app.handle_review_result(ReviewResult {
+            review_result: ReviewStatus::RequestChanges,
+            review_comment: "still wrong".to_string(),
+            findings: Vec::new(),
+        });
+
+        assert!(has_message_containing(&app, "[TASK-00] 작업 내용을 버리고"));
+        assert!(app.review_state.is_none());
+        assert!(!app.regenerate_task_requested);
+        assert!(!old_path.exists());
+        let branches = coding::list_bear_branches(&workspace, "bear/integration").unwrap();
+        assert!(!branches.contains(&old_branch), "{:?}", branches);
+        let (new_path, new_branch) = current_worktree(&app);
+        assert_ne!(new_branch, old_branch);
+        assert!(branches.contains(&new_branch), "{:?}", branches);
+        assert!(new_path.join("README.md").is_file());
+        assert!(!new_path.join("attempt.txt").exists());
+        assert!(matches!(app.input_mode, InputMode::Coding));
+
+        app.handle_key_event(ctrl('c'));
+        tick_until_idle(&mut app);
+    }
+
+    #[test]
+    fn regenerate_request_can_be_withdrawn_before_the_review_ends() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(1);
+        let mut app = app_in_coding_phase(config, &workspace);
+        app.input_mode = InputMode::Coding;
+        app.review_state = Some(review_state_for("TASK-00", 1));
+
+        app.handle_key_event(ctrl('n'));
+        app.handle_key_event(ctrl('n'));
+        assert!(has_message_containing(&app, "[TASK-00] 태스크 새로 구현 예약을 취소했습니다."));
+        app.handle_review_result(ReviewResult {
+            review_result: ReviewStatus::RequestChanges,
+            review_comment: "fix it".to_string(),
+            findings: Vec::new(),
+        });
+
+        assert!(!has_message_containing(&app, "작업 내용을 버리고"));
+        assert!(has_message_containing(&app, "리뷰 최대 반복 횟수(1) 도달. 자동 승인 처리."));
+    }
+
+    #[test]
+    fn review_limit_message_reports_configured_limit() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(1);
+        let mut app = app_in_coding_phase(config, &workspace);
+        app.review_state = Some(ReviewState {
+            task_id: "TASK-00".to_string(),
+            report: "done".to_string(),
+            iteration_count: 0,
+            max_iterations: 1,
+            reviewer_client: None,
+            coding_client: None,
+        });
+
+        app.handle_review_result(ReviewResult {
+            review_result: ReviewStatus::RequestChanges,
+            review_comment: "fix it".to_string(),
+            findings: Vec::new(),
+        });
+
+        assert!(has_message_containing(&app, "리뷰 최대 반복 횟수(1) 도달. 자동 승인 처리."));
+        assert!(app.review_state.is_none());
+    }
+
+    #[test]
+    fn each_review_iteration_saves_its_comment_and_approval_is_recorded() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(5);
+        let mut app = app_in_coding_phase(config, &workspace);
+        app.review_state = Some(ReviewState {
+            task_id: "TASK-00".to_string(),
+            report: "done".to_string(),
+            iteration_count: 0,
+            max_iterations: 5,
+            reviewer_client: None,
+            coding_client: None,
+        });
+        let reviews = [
+            (ReviewStatus::RequestChanges, "# Review 1\nfix naming"),
+            (ReviewStatus::RequestChanges, "# Review 2\nadd tests"),
+            (ReviewStatus::Approved, "# Review 3\nlooks good"),
+        ];
+
+        for (review_result, review_comment) in reviews.clone() {
+            let coding_client = ClaudeCodeClient::with_binary_path(
+                "test-key".to_string(),
+                workspace.clone(),
+                None,
+                PathBuf::from("/nonexistent/bear-test-claude"),
+            );
+            app.review_state.as_mut().unwrap().coding_client = Some(coding_client);
+            app.handle_review_result(ReviewResult {
+                review_result,
+                review_comment: review_comment.to_string(),
+                findings: Vec::new(),
+            });
+        }
+
+        for (iteration, (_, review_comment)) in reviews.iter().enumerate() {
+            let path = app.journal_dir().join(format!("TASK-00.review-{}.md", iteration + 1));
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), *review_comment);
+        }
+        assert!(!app.journal_dir().join("TASK-00.review-4.md").exists());
+        let coding_state = app.coding_state.as_ref().unwrap();
+        // 승인 후 빌드/테스트 검증을 기다리는 중이므로 아직 현재 태스크의 진행 상태에 있다.
+        assert!(matches!(app.input_mode, InputMode::BuildTestCommandInput));
+        assert!(coding_state.task_reports.is_empty());
+        let progress = coding_state.current_task_progress;
+        assert_eq!(progress.review_iterations, 3);
+        assert_eq!(progress.review_approval, ReviewApproval::Approved);
+    }
+
+    fn finding(severity: FindingSeverity, description: &str) -> ReviewFinding {
+        ReviewFinding { severity, description: description.to_string() }
+    }
+
+    #[test]
+    fn review_with_only_nonblocking_findings_is_auto_approved_and_recorded() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(5);
+        let mut app = app_in_coding_phase(config, &workspace);
+        app.review_state = Some(review_state_for("TASK-00", 5));
+
+        app.handle_review_result(ReviewResult {
+            review_result: ReviewStatus::RequestChanges,
+            review_comment: "nits only".to_string(),
+            findings: vec![
+                finding(FindingSeverity::Nonblocking, "Rename `tmp` to `buffer`"),
+                finding(FindingSeverity::Nonblocking, "Add a doc comment"),
+            ],
+        });
+
+        assert!(has_message_containing(&app, "[TASK-00] 차단하지 않는 지적 2개만 남아 자동 승인."));
+        assert!(app.review_state.is_none());
+        let progress = app.coding_state.as_ref().unwrap().current_task_progress;
+        assert_eq!(progress.review_approval, ReviewApproval::AutoApproved);
+        let report = &app.pending_build_test.as_ref().unwrap().report;
+        assert!(report.starts_with("done\n\n## 나중에 처리할 리뷰 지적 사항"), "{}", report);
+        assert!(report.contains("- Rename `tmp` to `buffer`\n- Add a doc comment"), "{}", report);
+    }
+
+    #[test]
+    fn review_with_a_blocking_finding_still_requests_changes() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_review_iterations(1);
+        let mut app = app_in_coding_phase(config, &workspace);
+        app.review_state = Some(review_state_for("TASK-00", 1));
+
+        app.handle_review_result(ReviewResult {
+            review_result: ReviewStatus::RequestChanges,
+            review_comment: "fix it".to_string(),
+            findings: vec![
+                finding(FindingSeverity::Nonblocking, "Add a doc comment"),
+                finding(FindingSeverity::Blocking, "Overflow on empty input"),
+            ],
+        });
+
+        assert!(!has_message_containing(&app, "차단하지 않는 지적"));
+        assert!(has_message_containing(&app, "리뷰 최대 반복 횟수(1) 도달. 자동 승인 처리."));
+        let report = &app.pending_build_test.as_ref().unwrap().report;
+        assert_eq!(report, "done");
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    /// 실제 CLI 없이 에이전트 작업이 진행 중인 상태를 만든다.
+    /// 반환된 송신측을 drop 하면 에이전트 스레드가 종료된 것과 같다.
+    fn start_fake_agent_operation(
+        app: &mut App,
+        mode: InputMode,
+    ) -> (mpsc::Sender<AgentStreamMessage>, CancellationToken) {
+        let token = app.begin_cancellable_operation();
+        let (sender, receiver) = mpsc::channel();
+        app.attach_agent_receiver(receiver);
+        app.input_mode = mode;
+        (sender, token)
+    }
+
+    #[test]
+    fn phase_deadline_is_reported_once_after_the_ceiling() {
+        let config = Config::new("test-key".to_string())
+            .with_max_phase_duration(Some(Duration::from_secs(600)))
+            .with_notify_bell(false);
+        let mut app = App::new(config).unwrap();
+        let (_sender, _token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+        let started = app.thinking_started_at;
+        let warning = "에이전트 작업이 최대 시간(10m 00s)을 넘었습니다.";
+
+        app.check_phase_deadline_at(started + Duration::from_secs(599));
+        assert!(!has_message_containing(&app, warning));
+
+        app.check_phase_deadline_at(started + Duration::from_secs(600));
+        app.check_phase_deadline_at(started + Duration::from_secs(1200));
+        let warnings = app.messages.iter().filter(|m| m.content.contains(warning)).count();
+        assert_eq!(warnings, 1);
+
+        // 다음 작업이 시작되면 다시 시간을 잰다.
+        app.thinking_started_at = started + Duration::from_secs(1200);
+        app.check_phase_deadline_at(started + Duration::from_secs(1300));
+        assert_eq!(app.messages.iter().filter(|m| m.content.contains(warning)).count(), 1);
+        app.check_phase_deadline_at(started + Duration::from_secs(1800));
+        assert_eq!(app.messages.iter().filter(|m| m.content.contains(warning)).count(), 2);
+    }
+
+    #[test]
+    fn phase_deadline_is_ignored_when_disabled_or_idle() {
+        let config = Config::new("test-key".to_string()).with_max_phase_duration(None);
+        let mut app = App::new(config).unwrap();
+        let (_sender, _token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+        app.check_phase_deadline_at(app.thinking_started_at + Duration::from_secs(86_400));
+        assert!(!has_message_containing(&app, "최대 시간"));
+
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.check_phase_deadline_at(app.thinking_started_at + Duration::from_secs(86_400));
+        assert!(!has_message_containing(&app, "최대 시간"));
+    }
+
+    #[test]
+    fn cancel_during_requirements_analysis_returns_to_requirements_input() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::RequirementsInput;
+        app.confirmed_requirements = Some("build a thing".to_string());
+        let (sender, token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+
+        app.handle_key_event(ctrl('c'));
+        assert!(token.is_cancelled());
+        assert!(!app.should_quit);
+        assert!(app.is_thinking());
+
+        drop(sender);
+        app.tick();
+
+        assert!(matches!(app.input_mode, InputMode::RequirementsInput));
+        assert_eq!(app.input_buffer, "build a thing");
+        assert!(app.fatal_error().is_none());
+    }
+
+    #[test]
+    fn cancel_after_clarification_answer_rolls_back_last_round() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::ClarificationAnswer;
+        app.qa_log.push(QaRound {
+            questions: vec!["which db?".to_string()],
+            answer: "postgres".to_string(),
+        });
+        let (sender, _token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+
+        app.handle_key_event(ctrl('c'));
+        drop(sender);
+        app.tick();
+
+        assert!(matches!(app.input_mode, InputMode::ClarificationAnswer));
+        assert!(app.qa_log.is_empty());
+        assert_eq!(app.current_round_questions, vec!["which db?".to_string()]);
+        assert_eq!(app.input_buffer, "postgres");
+    }
+
+    #[test]
+    fn revising_requirements_restarts_clarification_with_prior_answers() {
+        let root = TempDir::new().unwrap();
+        let prompt_path = root.path().join("captured-prompt");
+        let script = format!(
+            r#"#!/bin/sh
+cat > '{}'
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":{{"questions":["두 번째 질문입니다"]}}}}\n'
+"#,
+            prompt_path.display(),
+        );
+        let binary = write_script(root.path(), "fake-clarifier", &script);
+        let config = Config::new("test-key".to_string());
+        let mut app = App::new(config).unwrap().with_claude_binary(binary);
+        app.confirmed_workspace = Some(root.path().to_path_buf());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        app.confirmed_requirements = Some("build a thing".to_string());
+        app.qa_log.push(QaRound {
+            questions: vec!["which db?".to_string()],
+            answer: "postgres".to_string(),
+        });
+        app.current_round_questions = vec!["which cache?".to_string()];
+        app.input_mode = InputMode::ClarificationAnswer;
+        app.set_input("redis");
+
+        app.handle_key_event(ctrl('r'));
+        assert!(matches!(app.input_mode, InputMode::RequirementsRevision));
+        assert_eq!(app.input_buffer, "build a thing");
+        app.handle_key_event(ctrl('r'));
+        assert!(matches!(app.input_mode, InputMode::ClarificationAnswer));
+        assert_eq!(app.input_buffer, "redis");
+
+        app.handle_key_event(ctrl('r'));
+        type_and_submit(&mut app, " with login");
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::ClarificationAnswer));
+        assert_eq!(app.confirmed_requirements.as_deref(), Some("build a thing with login"));
+        assert_eq!(app.qa_log.len(), 1);
+        assert_eq!(app.current_round_questions, vec!["두 번째 질문입니다".to_string()]);
+        let prompt = std::fs::read_to_string(&prompt_path).unwrap();
+        assert!(prompt.contains("build a thing with login"), "{}", prompt);
+        assert!(prompt.contains("postgres"), "{}", prompt);
+        assert!(!prompt.contains("which cache?"), "{}", prompt);
+        let saved_request =
+            std::fs::read_to_string(app.workspace_journal_dir().join("user-request.md")).unwrap();
+        assert_eq!(saved_request, "build a thing with login");
+    }
+
+    /// 응답하지 않고 취소될 때까지 기다리는 가짜 CLI.
+    fn hanging_agent_binary(root: &Path) -> PathBuf {
+        write_script(root, "fake-hanging-claude", "#!/bin/sh\nexec sleep 30\n")
+    }
+
+    /// 응답하지 않고 취소될 때까지 기다리는 가짜 CLI를 쓰는 App을 만든다.
+    fn app_with_hanging_agent(root: &Path, mode: InputMode) -> App {
+        let mut app = App::new(Config::new("test-key".to_string()))
+            .unwrap()
+            .with_claude_binary(hanging_agent_binary(root));
+        app.confirmed_workspace = Some(root.to_path_buf());
+        // 승인하면 저널 디렉토리에 파일을 쓰므로, 작업 디렉토리가 아닌 임시 디렉토리를 가리키게 한다.
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        app.claude_client = Some(app.new_claude_client(root.to_path_buf(), None).unwrap());
+        app.input_mode = mode;
+        app
+    }
+
+    #[test]
+    fn cancelling_spec_revision_restores_feedback_and_previous_draft() {
+        let root = TempDir::new().unwrap();
+        let mut app = app_with_hanging_agent(root.path(), InputMode::SpecFeedback);
+        app.last_spec_draft = Some("draft v1".to_string());
+
+        type_and_submit(&mut app, "typo feedbak");
+        assert!(app.is_thinking());
+        assert_eq!(app.input_buffer, "");
+
+        app.handle_key_event(ctrl('c'));
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::SpecFeedback));
+        assert_eq!(app.input_buffer, "typo feedbak");
+        assert_eq!(app.last_spec_draft.as_deref(), Some("draft v1"));
+        assert!(app.last_submitted_feedback.is_none());
+        assert!(app.fatal_error().is_none());
+
+        // 승인으로 시작한 작업을 취소하면 이미 되돌린 피드백을 다시 채우지 않는다.
+        app.clear_input();
+        app.handle_key_event(ctrl('a'));
+        assert!(app.is_thinking());
+        assert!(app.workspace_journal_dir().starts_with(root.path()));
+        assert!(app.workspace_journal_dir().join("spec.md").exists());
+        app.handle_key_event(ctrl('c'));
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::SpecFeedback));
+        assert_eq!(app.input_buffer, "");
+    }
+
+    #[test]
+    fn cancelling_plan_revision_restores_feedback_and_previous_draft() {
+        let root = TempDir::new().unwrap();
+        let mut app = app_with_hanging_agent(root.path(), InputMode::PlanFeedback);
+        app.last_plan_draft = Some("plan v1".to_string());
+
+        type_and_submit(&mut app, "wrong plan feedback");
+        app.handle_key_event(ctrl('c'));
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::PlanFeedback));
+        assert_eq!(app.input_buffer, "wrong plan feedback");
+        assert_eq!(app.last_plan_draft.as_deref(), Some("plan v1"));
+    }
+
+    #[test]
+    fn publish_result_arrives_on_its_own_channel_and_cannot_be_cancelled() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let (sender, receiver) = mpsc::channel();
+        app.publish_receiver = Some(receiver);
+        app.input_mode = InputMode::Publishing;
+
+        app.handle_key_event(ctrl('c'));
+        app.tick();
+
+        assert!(!app.cancel_requested);
+        assert!(matches!(app.input_mode, InputMode::Publishing));
+        assert!(app.claude_client.is_none());
+
+        let url = "https://example.com/pull/3".to_string();
+        sender.send(Ok(pull_request::PublishOutcome::Created { url: url.clone() })).unwrap();
+        app.tick();
+
+        assert!(has_message_containing(&app, "풀 리퀘스트 생성: https://example.com/pull/3"));
+        assert_eq!(app.pull_request_url, Some(url));
+        assert!(app.publish_receiver.is_none());
+        assert!(matches!(app.input_mode, InputMode::Done));
+    }
+
+    #[test]
+    fn cancel_during_coding_cleans_up_worktree_and_pauses() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let worktree_path = app
+            .coding_state
+            .as_ref()
+            .unwrap()
+            .current_task_worktree
+            .as_ref()
+            .unwrap()
+            .worktree_path
+            .clone();
+        app.concurrent_build_test = Some(ConcurrentBuildTest {
+            revision: "stale".to_string(),
+            outcome: BuildTestOutcome::Success,
+        });
+        app.pending_merge = Some(PendingMerge {
+            task_id: "TASK-00".to_string(),
+            report: "stale".to_string(),
+            merged_with_failing_build: false,
+        });
+        app.regenerate_task_requested = true;
+        let (sender, _token) = start_fake_agent_operation(&mut app, InputMode::Coding);
+
+        app.handle_key_event(ctrl('c'));
+        drop(sender);
+        app.tick();
+
+        assert!(matches!(app.input_mode, InputMode::CodingPaused));
+        assert!(app.concurrent_build_test.is_none());
+        assert!(app.pending_merge.is_none());
+        assert!(!app.regenerate_task_requested);
+        assert!(!worktree_path.exists());
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert!(coding_state.current_task_worktree.is_none());
+        assert_eq!(coding_state.current_task_index, 0);
+        assert!(coding_state.task_reports.is_empty());
+        assert!(!app.should_quit);
+    }
+
+    fn esc() -> KeyEvent {
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn esc_while_agent_is_running_quits_only_when_pressed_twice() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        let (_sender, token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+
+        app.handle_key_event(esc());
+        assert!(!app.should_quit);
+        assert!(!token.is_cancelled());
+        assert!(has_message_containing(&app, "작업이 진행 중입니다. 종료하려면 다시 Esc."));
+
+        app.handle_key_event(esc());
+        assert!(app.should_quit);
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn esc_after_the_confirm_window_starts_over() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        let (_sender, token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+        app.pending_quit = Instant::now().checked_sub(QUIT_CONFIRM_WINDOW * 2);
+
+        app.handle_key_event(esc());
+
+        assert!(!app.should_quit);
+        assert!(!token.is_cancelled());
+        assert!(app.pending_quit.is_some());
+        app.handle_key_event(esc());
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn confirmed_quit_during_coding_removes_the_task_worktree() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let coding_state = app.coding_state.as_ref().unwrap();
+        let worktree_path =
+            coding_state.current_task_worktree.as_ref().unwrap().worktree_path.clone();
+
+        app.handle_key_event(esc());
+        assert!(worktree_path.exists());
+        app.handle_key_event(esc());
+
+        assert!(app.should_quit);
+        assert!(!worktree_path.exists());
+        assert!(app.coding_state.as_ref().unwrap().current_task_worktree.is_none());
+    }
+
+    #[test]
+    fn esc_quits_immediately_while_waiting_for_input() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::RequirementsInput;
+
+        app.handle_key_event(esc());
+
+        assert!(app.should_quit);
+    }
+
+    fn app_with_messages(count: usize) -> App {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        for i in 1..count {
+            app.add_system_message(&format!("message {}", i));
+        }
+        app
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn truncated_stream_message(full_text: &str) -> StreamMessage {
+        StreamMessage {
+            content: format!("{}...", &full_text[..4]),
+            full_content: Some(full_text.to_string()),
+        }
+    }
+
+    #[test]
+    fn copy_target_is_the_last_message_with_its_full_text() {
+        let mut app = app_with_messages(3);
+        app.input_mode = InputMode::AgentThinking;
+        app.add_stream_message(truncated_stream_message("full agent output"));
+
+        let (label, content) = app.copy_target().unwrap();
+
+        assert_eq!(label, "마지막 메시지");
+        assert_eq!(content, "full agent output");
+    }
+
+    #[test]
+    fn copy_target_is_the_top_message_when_scrolled_up() {
+        let mut app = app_with_messages(6);
+        app.handle_key_event(key(KeyCode::PageUp));
+        let view_start = app.scrolled_view_start().unwrap();
+
+        let (label, content) = app.copy_target().unwrap();
+
+        assert_eq!(label, format!("메시지 {}", view_start + 1));
+        assert_eq!(content, app.messages[view_start].content);
+    }
+
+    #[test]
+    fn copy_target_prefers_draft_then_latest_task_report() {
+        let mut app = app_with_messages(3);
+        app.input_mode = InputMode::PlanFeedback;
+        app.last_plan_draft = Some("# Plan".to_string());
+        assert_eq!(app.copy_target(), Some(("개발 계획 초안".to_string(), "# Plan".to_string())));
+
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        app.record_task_report(
+            "TASK-00".to_string(),
+            CodingTaskStatus::ImplementationSuccess,
+            "# Report".to_string(),
+            PathBuf::new(),
+            false,
+        );
+        app.add_system_message("다음 태스크를 시작합니다.");
+
+        assert_eq!(
+            app.copy_target(),
+            Some(("[TASK-00] 리포트".to_string(), "# Report".to_string())),
+        );
+    }
+
+    #[test]
+    fn copying_without_any_content_says_so() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.messages.clear();
+
+        app.handle_key_event(ctrl('y'));
+
+        assert_eq!(app.messages.len(), 1);
+        assert_eq!(app.messages[0].content, "복사할 내용이 없습니다.");
+    }
+
+    #[test]
+    fn stream_message_renders_truncated_and_ctrl_o_appends_full_text() {
+        let mut app = app_with_messages(1);
+        app.input_mode = InputMode::AgentThinking;
+        app.add_stream_message(truncated_stream_message("first full output"));
+        app.add_stream_message(truncated_stream_message("second full output"));
+        let before = app.messages.len();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL));
+
+        assert_eq!(app.messages[before - 1].content, "seco...");
+        assert_eq!(app.messages.len(), before + 1);
+        assert_eq!(app.messages[before].content, "[전체 내용]\nsecond full output");
+        assert!(app.messages[before - 1].full_content.is_none());
+        assert!(app.messages[before - 2].full_content.is_some());
+    }
+
+    #[test]
+    fn alt_o_expands_all_remaining_truncated_messages_in_order() {
+        let mut app = app_with_messages(1);
+        app.input_mode = InputMode::Coding;
+        app.add_stream_message(truncated_stream_message("first full output"));
+        app.add_stream_message(StreamMessage {
+            content: "short".to_string(),
+            full_content: None,
+        });
+        app.add_stream_message(truncated_stream_message("second full output"));
+        let before = app.messages.len();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::ALT));
+
+        let expanded: Vec<&str> =
+            app.messages[before..].iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(
+            expanded,
+            vec!["[전체 내용]\nfirst full output", "[전체 내용]\nsecond full output"],
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL));
+
+        assert_eq!(app.messages.last().unwrap().content, "펼칠 수 있는 잘린 출력이 없습니다.");
+    }
+
+    #[test]
+    fn ctrl_e_exports_transcript_to_workspace_journal() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let journal_dir = app.workspace_journal_dir();
+        std::fs::create_dir_all(&journal_dir).unwrap();
+        std::fs::write(journal_dir.join("spec.md"), "# Exported spec").unwrap();
+        app.add_system_message("코딩을 시작합니다.");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL));
+
+        let transcript_path = journal_dir.join(transcript::TRANSCRIPT_FILE_NAME);
+        let exported = std::fs::read_to_string(&transcript_path).unwrap();
+        assert!(exported.contains("## Spec\n\n# Exported spec"), "{}", exported);
+        assert!(exported.contains("### [TASK-00]"), "{}", exported);
+        assert!(exported.contains("코딩을 시작합니다."), "{}", exported);
+        assert!(has_message_containing(&app, "대화 기록을 내보냈습니다"));
+    }
+
+    #[test]
+    fn attention_event_fires_only_when_leaving_agent_work() {
+        assert_eq!(
+            attention_event(InputMode::AgentThinking, InputMode::SpecFeedback),
+            Some(AttentionEvent::InputNeeded),
+        );
+        assert_eq!(
+            attention_event(InputMode::Coding, InputMode::BuildTestCommandInput),
+            Some(AttentionEvent::InputNeeded),
+        );
+        assert_eq!(
+            attention_event(InputMode::Coding, InputMode::MergeApproval),
+            Some(AttentionEvent::InputNeeded),
+        );
+        assert_eq!(
+            attention_event(InputMode::Coding, InputMode::Done),
+            Some(AttentionEvent::Finished),
+        );
+        assert_eq!(
+            attention_event(InputMode::Coding, InputMode::FatalError),
+            Some(AttentionEvent::Finished),
+        );
+        assert_eq!(attention_event(InputMode::AgentThinking, InputMode::Coding), None);
+        assert_eq!(attention_event(InputMode::Coding, InputMode::CodingPaused), None);
+        assert_eq!(attention_event(InputMode::RequirementsInput, InputMode::SpecFeedback), None);
+        assert_eq!(attention_event(InputMode::ModeSelection, InputMode::Done), None);
+    }
+
+    #[test]
+    fn attention_rings_bell_once_unless_disabled() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.notify_attention(AttentionEvent::Finished);
+
+        assert!(app.take_pending_bell());
+        assert!(!app.take_pending_bell());
+
+        let mut quiet = App::new(Config::new("test-key".to_string()).with_notify_bell(false))
+            .unwrap();
+        quiet.notify_attention(AttentionEvent::InputNeeded);
+
+        assert!(!quiet.take_pending_bell());
+    }
+
+    #[test]
+    fn attention_runs_notify_command_with_event_name() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let marker = temp_dir.path().join("notified");
+        let command = format!("printf %s \"$BEAR_NOTIFY_EVENT\" > '{}'", marker.display());
+        let config = Config::new("test-key".to_string()).with_notify_command(Some(command));
+        let mut app = App::new(config).unwrap();
+
+        app.notify_attention(AttentionEvent::InputNeeded);
+
+        let deadline = Instant::now() + std::time::Duration::from_secs(5);
+        while std::fs::read_to_string(&marker).unwrap_or_default() != "input" {
+            assert!(Instant::now() < deadline, "notify command did not run");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn workspace_confirm_refuses_bear_worktree_and_suggests_repo_root() {
+        let (_root, workspace) = setup_workspace();
+        let branch = coding::create_task_branch(&workspace, "master", "TASK-01").unwrap();
+        let worktree_path =
+            coding::create_worktree(&workspace, workspace.parent().unwrap(), &branch).unwrap();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+
+        type_and_submit(&mut app, &worktree_path.display().to_string());
+
+        assert!(matches!(app.input_mode, InputMode::WorkspaceConfirm));
+        assert!(app.confirmed_workspace.is_none());
+        assert!(has_message_containing(&app, "워크스페이스로 사용할 수 없습니다"));
+        let repo_root = workspace.canonicalize().unwrap();
+        assert!(has_message_containing(
+            &app,
+            &format!("원래 저장소 루트를 사용하세요: {}", repo_root.display()),
+        ));
+
+        coding::remove_worktree(&workspace, &worktree_path).unwrap();
+    }
+
+    /// Writes an editor script that replaces the edited file with `content` and exits with
+    /// `exit_code`.
+    fn write_fake_editor(dir: &Path, content: &str, exit_code: i32) -> String {
+        let script = format!("#!/bin/sh\nprintf '{}' > \"$1\"\nexit {}\n", content, exit_code);
+        write_script(dir, "fake-editor", &script).display().to_string()
+    }
+
+    fn app_with_input(text: &str, cursor_position: usize) -> App {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::RequirementsInput;
+        app.input_buffer = text.to_string();
+        app.cursor_position = cursor_position;
+        app
+    }
+
+    #[test]
+    fn editor_failure_restores_original_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let editor = write_fake_editor(temp_dir.path(), "partial", 1);
+        let mut app = app_with_input("원래 입력", 2);
+
+        app.edit_input_in_external_editor(&editor);
+
+        assert_eq!(app.input_buffer, "원래 입력");
+        assert_eq!(app.cursor_position, 2);
+        assert!(has_message_containing(
+            &app,
+            "에디터가 비정상 종료되었습니다. 편집 전 입력을 유지합니다.",
+        ));
+    }
+
+    #[test]
+    fn editor_launch_failure_restores_original_input() {
+        let mut app = app_with_input("원래 입력", 1);
+
+        app.edit_input_in_external_editor("/nonexistent/bear-test-editor");
+
+        assert_eq!(app.input_buffer, "원래 입력");
+        assert_eq!(app.cursor_position, 1);
+        assert!(has_message_containing(&app, "에디터 실행 실패"));
+    }
+
+    #[test]
+    fn editor_success_replaces_input_and_no_op_keeps_cursor() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = app_with_input("before", 3);
+
+        app.edit_input_in_external_editor(&write_fake_editor(temp_dir.path(), "before", 0));
+
+        assert_eq!(app.input_buffer, "before");
+        assert_eq!(app.cursor_position, 3);
+        assert!(has_message_containing(&app, "에디터에서 변경된 내용이 없습니다."));
+
+        app.edit_input_in_external_editor(&write_fake_editor(temp_dir.path(), "after edit", 0));
+
+        assert_eq!(app.input_buffer, "after edit");
+        assert_eq!(app.cursor_position, 10);
+    }
+
+    #[test]
+    fn external_editor_loads_draft_only_in_draft_feedback_modes() {
+        let mut app = app_with_input("feedback", 0);
+        app.last_spec_draft = Some("# Spec".to_string());
+        app.last_plan_draft = Some("# Plan".to_string());
+
+        assert_eq!(app.external_edit_target(), ExternalEditTarget::InputBuffer);
+        app.input_mode = InputMode::SpecFeedback;
+        assert_eq!(app.external_edit_target(), ExternalEditTarget::SpecDraft);
+        app.input_mode = InputMode::PlanFeedback;
+        assert_eq!(app.external_edit_target(), ExternalEditTarget::PlanDraft);
+        app.input_mode = InputMode::SectionReview;
+        assert_eq!(app.external_edit_target(), ExternalEditTarget::InputBuffer);
+
+        app.last_plan_draft = None;
+        app.input_mode = InputMode::PlanFeedback;
+        assert_eq!(app.external_edit_target(), ExternalEditTarget::InputBuffer);
+    }
+
+    #[test]
+    fn unchanged_empty_or_failed_draft_edit_keeps_draft() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = app_with_input("feedback", 0);
+        app.input_mode = InputMode::SpecFeedback;
+        app.last_spec_draft = Some("# Spec".to_string());
+
+        for (content, exit_code) in [("# Spec", 0), ("  \\n", 0), ("# Edited", 1)] {
+            let editor = write_fake_editor(temp_dir.path(), content, exit_code);
+            app.edit_draft_in_external_editor(&editor, ExternalEditTarget::SpecDraft);
+        }
+
+        assert_eq!(app.last_spec_draft.as_deref(), Some("# Spec"));
+        assert!(app.approved_spec.is_none());
+        assert_eq!(app.input_buffer, "feedback");
+        assert!(matches!(app.input_mode, InputMode::SpecFeedback));
+        assert!(has_message_containing(&app, "스펙 초안에 변경된 내용이 없습니다."));
+        assert!(has_message_containing(&app, "편집한 내용을 버리고 기존 스펙 초안을 유지합니다."));
+    }
+
+    #[test]
+    fn edited_spec_draft_is_approved_directly() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_incremental_planner(root.path(), &workspace, 0);
+        app.input_mode = InputMode::SpecFeedback;
+        let editor = write_fake_editor(root.path(), "# Edited spec", 0);
+
+        app.edit_draft_in_external_editor(&editor, ExternalEditTarget::SpecDraft);
+
+        assert_eq!(app.approved_spec.as_deref(), Some("# Edited spec"));
+        let saved = std::fs::read_to_string(app.journal_dir().join("spec.md")).unwrap();
+        assert_eq!(saved, "# Edited spec");
+        tick_until_idle(&mut app);
+    }
+
+    #[test]
+    fn prepare_client_uses_phase_model_and_tool_set() {
+        let config = Config::new("test-key".to_string())
+            .with_default_model("default-model".to_string())
+            .with_phase_model(UsagePhase::Clarification, "cheap-model".to_string());
+        let app = App::new(config).unwrap();
+        let mut client = ClaudeCodeClient::with_binary_path(
+            "test-key".to_string(),
+            std::env::temp_dir(),
+            None,
+            PathBuf::from("claude"),
+        );
+
+        app.prepare_client_for_phase(&mut client, UsagePhase::Clarification);
+        assert_eq!(client.model(), "cheap-model");
+        assert_eq!(client.tool_set(), ToolSet::ReadOnly);
+
+        app.prepare_client_for_phase(&mut client, UsagePhase::Coding);
+        assert_eq!(client.model(), "default-model");
+        assert_eq!(client.tool_set(), ToolSet::Full);
+
+        app.prepare_client_for_phase(&mut client, UsagePhase::Review);
+        assert_eq!(client.tool_set(), ToolSet::ReadOnly);
+    }
+
+    #[test]
+    fn page_up_stops_following_tail_and_new_messages_keep_view() {
+        let mut app = app_with_messages(20);
+        let (_sender, _token) = start_fake_agent_operation(&mut app, InputMode::AgentThinking);
+
+        app.handle_key_event(key(KeyCode::PageUp));
+
+        assert!(!app.follow_tail);
+        assert_eq!(app.scroll_offset, SCROLL_PAGE_MESSAGES);
+        assert_eq!(app.scrolled_view_start(), Some(15));
+
+        app.add_system_message("new message");
+
+        assert_eq!(app.scrolled_view_start(), Some(15));
+        assert!(app.is_thinking());
+    }
+
+    #[test]
+    fn page_down_to_bottom_resumes_following_tail() {
+        let mut app = app_with_messages(20);
+        app.input_mode = InputMode::RequirementsInput;
+
+        app.handle_key_event(key(KeyCode::PageUp));
+        app.handle_key_event(key(KeyCode::PageUp));
+        app.handle_key_event(key(KeyCode::PageDown));
+        assert!(!app.follow_tail);
+        assert_eq!(app.scroll_offset, SCROLL_PAGE_MESSAGES);
+
+        app.handle_key_event(key(KeyCode::PageDown));
+
+        assert!(app.follow_tail);
+        assert_eq!(app.scrolled_view_start(), None);
+        app.add_system_message("new message");
+        assert_eq!(app.scroll_offset, 0);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    fn wheel(kind: MouseEventKind) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn mouse_wheel_scrolls_one_message_and_clamps_at_top() {
+        let mut app = app_with_messages(3);
+        app.input_mode = InputMode::RequirementsInput;
+
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+
+        assert!(!app.follow_tail);
+        assert_eq!(app.scrolled_view_start(), Some(2));
+
+        for _ in 0..5 {
+            app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+        }
+
+        assert_eq!(app.scroll_offset, 3);
+        assert_eq!(app.scrolled_view_start(), Some(0));
+    }
+
+    #[test]
+    fn mouse_wheel_down_clamps_at_bottom_and_resumes_following_tail() {
+        let mut app = app_with_messages(20);
+        app.input_mode = InputMode::RequirementsInput;
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollUp));
+
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollDown));
+        assert_eq!(app.scroll_offset, 1);
+
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollDown));
+        app.handle_mouse_event(wheel(MouseEventKind::ScrollDown));
+
+        assert!(app.follow_tail);
+        assert_eq!(app.scroll_offset, 0);
+        assert_eq!(app.scrolled_view_start(), None);
+    }
+
+    #[test]
+    fn mouse_clicks_do_not_scroll_or_edit_input() {
+        let mut app = app_with_messages(20);
+        app.input_mode = InputMode::RequirementsInput;
+
+        app.handle_mouse_event(wheel(MouseEventKind::Down(crossterm::event::MouseButton::Left)));
+
+        assert!(app.follow_tail);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    fn alt(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::ALT)
+    }
+
+    #[test]
+    fn alt_up_and_down_recall_history_of_the_current_field_only() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_history.record(InputField::Requirements, "첫 요구사항").unwrap();
+        app.input_history.record(InputField::Requirements, "두 번째 요구사항").unwrap();
+        app.input_history.record(InputField::Feedback, "피드백").unwrap();
+        app.input_mode = InputMode::RequirementsInput;
+        app.set_input("작성 중");
+
+        app.handle_key_event(alt(KeyCode::Up));
+        assert_eq!(app.input_buffer, "두 번째 요구사항");
+        app.handle_key_event(alt(KeyCode::Up));
+        assert_eq!(app.input_buffer, "첫 요구사항");
+        assert_eq!(app.cursor_position, "첫 요구사항".chars().count());
+        app.handle_key_event(alt(KeyCode::Down));
+        app.handle_key_event(alt(KeyCode::Down));
+        assert_eq!(app.input_buffer, "작성 중");
+
+        app.input_mode = InputMode::BuildTestCommandInput;
+        app.handle_key_event(alt(KeyCode::Up));
+        assert_eq!(app.input_buffer, "작성 중");
+    }
+
+    #[test]
+    fn input_history_is_loaded_from_workspace_on_confirm() {
+        let (_root, workspace) = setup_workspace();
+        let path = workspace.join(".bear").join(INPUT_HISTORY_FILE_NAME);
+        let mut saved = InputHistory::load(path).unwrap();
+        saved.record(InputField::Feedback, "지난 세션의 피드백").unwrap();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.set_input(&workspace.display().to_string());
+        app.handle_key_event(key(KeyCode::Enter));
+
+        app.input_mode = InputMode::PlanFeedback;
+        app.handle_key_event(alt(KeyCode::Up));
+
+        assert_eq!(app.input_buffer, "지난 세션의 피드백");
+    }
+
+    #[test]
+    fn ctrl_home_and_ctrl_end_jump_to_top_and_bottom() {
+        let mut app = app_with_messages(20);
+        app.input_mode = InputMode::Done;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL));
+
+        assert_eq!(app.scrolled_view_start(), Some(0));
+
+        app.handle_key_event(key(KeyCode::PageUp));
+        assert_eq!(app.scrolled_view_start(), Some(0));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::End, KeyModifiers::CONTROL));
+
+        assert!(app.follow_tail);
+        assert_eq!(app.scrolled_view_start(), None);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn entering_requirements_input_without_workspace_fails_gracefully() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::ModeSelection;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE));
+
+        assert!(matches!(app.input_mode, InputMode::FatalError));
+        assert!(!app.should_quit);
+        assert!(app.fatal_error().unwrap().contains("internal error"));
+        assert!(has_message_containing(&app, "워크스페이스가 설정되지 않았습니다"));
+    }
+
+    #[test]
+    fn submitting_session_dir_without_workspace_fails_gracefully() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::SessionDirInput;
+        app.set_input("/tmp/some-session");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(matches!(app.input_mode, InputMode::FatalError));
+        assert!(app.fatal_error().is_some());
+        assert!(app.resumed_session_dir.is_none());
+    }
+
+    #[test]
+    fn fatal_error_stays_on_error_screen_until_dismissed() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+
+        app.handle_coding_task_error("connection reset".to_string());
+
+        let screen = app.fatal_error_screen().unwrap();
+        assert_eq!(screen[0], "Task [TASK-00] error: connection reset");
+        assert!(screen.iter().any(|line| line.starts_with("세션 저널: ")), "{:?}", screen);
+        assert!(screen.iter().any(|line| line.starts_with("디버그 로그: ")), "{:?}", screen);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert!(!app.should_quit);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(app.should_quit);
+        assert_eq!(app.fatal_error(), Some("Task [TASK-00] error: connection reset"));
+    }
+
+    #[test]
+    fn section_review_walks_sections_from_spec_feedback() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.last_spec_draft = Some("## A\n\na\n\n## B\n\nb\n\n## C\n\nc".to_string());
+        app.input_mode = InputMode::SpecFeedback;
+
+        app.handle_key_event(ctrl('s'));
+        assert!(matches!(app.input_mode, InputMode::SectionReview));
+        assert_eq!(app.section_review.as_ref().unwrap().current_index(), 0);
+        assert!(has_message_containing(&app, "[섹션 1/3] A"));
+
+        app.handle_key_event(ctrl('a'));
+        assert!(matches!(app.input_mode, InputMode::SectionReview));
+        assert_eq!(app.section_review.as_ref().unwrap().current_index(), 1);
+        assert!(has_message_containing(&app, "[섹션 2/3] B"));
+        assert!(app.approved_spec.is_none());
+    }
+
+    #[test]
+    fn section_revision_replaces_only_current_section() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.last_spec_draft = Some("## A\n\na\n\n## B\n\nb".to_string());
+        app.input_mode = InputMode::SpecFeedback;
+        app.handle_key_event(ctrl('s'));
+        app.handle_key_event(ctrl('a'));
+        app.input_mode = InputMode::AgentThinking;
+
+        app.handle_section_revision_response(SectionRevisionResponse {
+            section_content: "## B\n\nb revised".to_string(),
+        });
+
+        let state = app.section_review.as_ref().unwrap();
+        assert!(matches!(app.input_mode, InputMode::SectionReview));
+        assert_eq!(state.current_index(), 1);
+        assert!(!state.is_approved(1));
+        assert_eq!(state.assemble(), "## A\n\na\n\n## B\n\nb revised");
+    }
+
+    #[test]
+    fn section_review_without_draft_stays_in_spec_feedback() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::SpecFeedback;
+
+        app.handle_key_event(ctrl('s'));
+
+        assert!(matches!(app.input_mode, InputMode::SpecFeedback));
+        assert!(app.section_review.is_none());
+    }
+
+    fn git_stdout(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn write_journal_file(workspace: &Path, file_name: &str) -> PathBuf {
+        let journal_dir = workspace.join(".bear").join("20260101").join("test-session");
+        std::fs::create_dir_all(&journal_dir).unwrap();
+        let file_path = journal_dir.join(file_name);
+        std::fs::write(&file_path, "# Approved").unwrap();
+        file_path
+    }
+
+    #[test]
+    fn approved_spec_is_committed_to_current_branch_when_enabled() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        let spec_path = write_journal_file(&workspace, "spec.md");
+
+        app.commit_approved_artifact(&spec_path, "Add approved specification", "스펙");
+
+        assert_eq!(
+            git_stdout(&workspace, &["log", "-1", "--format=%s"]),
+            "Add approved specification"
+        );
+        assert_eq!(git_stdout(&workspace, &["status", "--porcelain"]), "");
+    }
+
+    #[test]
+    fn approved_plan_is_committed_to_configured_branch() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_spec_plan_branch(Some("bear/docs".to_string()));
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        let plan_path = write_journal_file(&workspace, "plan.md");
+
+        app.commit_approved_artifact(&plan_path, "Add approved development plan", "플랜");
+
+        assert_eq!(
+            git_stdout(&workspace, &["log", "-1", "--format=%s", "bear/docs"]),
+            "Add approved development plan"
+        );
+        assert_eq!(
+            git_stdout(&workspace, &["log", "-1", "--format=%s"]),
+            "initial commit"
+        );
+    }
+
+    #[test]
+    fn approved_artifact_is_not_committed_when_disabled() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_commit_spec_plan(false);
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        let spec_path = write_journal_file(&workspace, "spec.md");
+
+        app.commit_approved_artifact(&spec_path, "Add approved specification", "스펙");
+
+        assert_eq!(
+            git_stdout(&workspace, &["log", "-1", "--format=%s"]),
+            "initial commit"
+        );
+    }
+
+    #[test]
+    fn approved_artifact_commit_is_skipped_outside_git_repository() {
+        let root = TempDir::new().unwrap();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.confirmed_workspace = Some(root.path().to_path_buf());
+        let spec_path = write_journal_file(root.path(), "spec.md");
+
+        app.commit_approved_artifact(&spec_path, "Add approved specification", "스펙");
+
+        assert!(has_message_containing(&app, "git 저장소가 아니므로 스펙 파일 커밋을 건너뜁니다"));
+        assert!(!has_message_containing(&app, "커밋 실패"));
+    }
+
+    /// 통합 브랜치에 워크스페이스 파일 하나를 커밋하여 다른 태스크가 먼저 병합된 상황을 만든다.
+    fn advance_integration_branch(app: &App, workspace: &Path, file_name: &str) {
+        let integration_branch = app.coding_state.as_ref().unwrap().integration_branch.clone();
+        let file_path = workspace.join(file_name);
+        std::fs::write(&file_path, "changed on integration").unwrap();
+        coding::commit_file_to_branch(workspace, &file_path, &integration_branch, "Other task")
+            .unwrap();
+    }
+
+    fn commit_in_current_worktree(app: &App, file_name: &str) {
+        let worktree_path = app
+            .coding_state
+            .as_ref()
+            .unwrap()
+            .current_task_worktree
+            .as_ref()
+            .unwrap()
+            .worktree_path
+            .clone();
+        std::fs::write(worktree_path.join(file_name), "changed by task").unwrap();
+        run_git(&worktree_path, &["add", "."]);
+        run_git(&worktree_path, &["commit", "-m", "Task change"]);
+    }
+
+    #[test]
+    fn dependency_manifest_change_is_warned_once_per_task() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let warning = "의존성 변경이 허용되지 않았지만";
+        commit_in_current_worktree(&app, "Cargo.toml");
+
+        assert_eq!(app.flag_dependency_manifest_changes("TASK-00"), vec!["Cargo.toml"]);
+        assert_eq!(app.flag_dependency_manifest_changes("TASK-00"), vec!["Cargo.toml"]);
+        let warnings = app.messages.iter().filter(|m| m.content.contains(warning)).count();
+        assert_eq!(warnings, 1);
+
+        commit_in_current_worktree(&app, "package.json");
+        let changed = app.flag_dependency_manifest_changes("TASK-00");
+
+        assert_eq!(changed, vec!["Cargo.toml", "package.json"]);
+        let last_warning = app.messages.iter().rev().find(|m| m.content.contains(warning));
+        assert!(last_warning.unwrap().content.ends_with(": package.json"));
+    }
+
+    fn warn_for_current_task(app: &mut App) {
+        let coding_state = app.coding_state.as_ref().unwrap();
+        let worktree_path =
+            coding_state.current_task_worktree.as_ref().unwrap().worktree_path.clone();
+        let integration_branch = coding_state.integration_branch.clone();
+        let declared_files = coding_state.tasks[0].files.clone();
+        app.warn_predicted_conflicts(
+            "TASK-00",
+            &worktree_path,
+            &integration_branch,
+            declared_files,
+        );
+    }
+
+    /// 태스크와 통합 브랜치가 같은 파일을 고쳐 리베이스가 충돌하고, 충돌 해결 에이전트가 리베이스를
+    /// 중단한 뒤 실패를 보고한 상태를 만든다.
+    fn fail_conflict_resolution(config: Config) -> (TempDir, PathBuf, App) {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(config.with_verify_build_test(false), &workspace);
+        commit_in_current_worktree(&app, "README.md");
+        advance_integration_branch(&app, &workspace, "README.md");
+        let worktree_path = current_worktree_path(&app);
+        let integration_branch = app.coding_state.as_ref().unwrap().integration_branch.clone();
+        let outcome = coding::rebase_onto_integration(&worktree_path, &integration_branch);
+        assert!(matches!(outcome, Ok(RebaseOutcome::Conflict { .. })));
+        coding::abort_rebase(&worktree_path).unwrap();
+        app.pending_coding_report = Some("task report".to_string());
+
+        app.handle_conflict_resolution_result(ConflictResolutionResult {
+            status: ConflictResolutionStatus::ConflictResolutionFailed,
+            report: "irreconcilable".to_string(),
+        });
+        (root, workspace, app)
+    }
+
+    #[test]
+    fn failed_conflict_resolution_blocks_task_by_default() {
+        let (_root, _workspace, app) = fail_conflict_resolution(Config::new("test-key".into()));
+
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.task_reports[0].status, CodingTaskStatus::ImplementationBlocked);
+        assert!(!matches!(app.input_mode, InputMode::ManualConflict));
+    }
+
+    #[test]
+    fn manual_conflict_resolution_resumes_after_user_resolves() {
+        let config = Config::new("test-key".to_string()).with_manual_conflict_resolution(true);
+        let (_root, workspace, mut app) = fail_conflict_resolution(config);
+        let worktree_path = current_worktree_path(&app);
+
+        assert!(matches!(app.input_mode, InputMode::ManualConflict));
+        assert!(coding::is_rebase_in_progress(&worktree_path));
+        assert!(has_message_containing(&app, "  - README.md"));
+        assert!(has_message_containing(&app, &worktree_path.display().to_string()));
+        assert!(app.coding_state.as_ref().unwrap().task_reports.is_empty());
+
+        // 아직 해결하지 않았으면 같은 상태로 남는다.
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(app.input_mode, InputMode::ManualConflict));
+        assert!(coding::is_rebase_in_progress(&worktree_path));
+
+        std::fs::write(worktree_path.join("README.md"), "resolved by hand").unwrap();
+        run_git(&worktree_path, &["add", "README.md"]);
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(!coding::is_rebase_in_progress(&worktree_path));
+        assert!(has_message_containing(&app, "리베이스가 끝났습니다"));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.task_reports[0].status, CodingTaskStatus::ImplementationSuccess);
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("README.md")).unwrap(),
+            "resolved by hand",
+        );
+    }
+
+    #[test]
+    fn abandoning_manual_conflict_resolution_blocks_task() {
+        let config = Config::new("test-key".to_string()).with_manual_conflict_resolution(true);
+        let (_root, workspace, mut app) = fail_conflict_resolution(config);
+
+        app.handle_key_event(ctrl('b'));
+
+        assert!(!matches!(app.input_mode, InputMode::ManualConflict));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.task_reports[0].status, CodingTaskStatus::ImplementationBlocked);
+        assert_eq!(coding_state.task_reports[0].report, "수동 충돌 해결을 포기함");
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("README.md")).unwrap(),
+            "changed on integration",
+        );
+    }
+
+    #[test]
+    fn conflict_warning_when_task_and_integration_touch_same_file() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        commit_in_current_worktree(&app, "README.md");
+        advance_integration_branch(&app, &workspace, "README.md");
+
+        warn_for_current_task(&mut app);
+
+        assert!(has_message_containing(&app, "리베이스 충돌이 예상됩니다: README.md"));
+    }
+
+    #[test]
+    fn conflict_warning_uses_declared_task_files() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        app.coding_state.as_mut().unwrap().tasks[0].files = vec!["./shared.rs".to_string()];
+        advance_integration_branch(&app, &workspace, "shared.rs");
+
+        warn_for_current_task(&mut app);
+
+        assert!(has_message_containing(&app, "리베이스 충돌이 예상됩니다: shared.rs"));
+    }
+
+    #[test]
+    fn no_conflict_warning_for_disjoint_changes() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+        advance_integration_branch(&app, &workspace, "other.rs");
+
+        warn_for_current_task(&mut app);
+
+        assert!(!has_message_containing(&app, "리베이스 충돌이 예상됩니다"));
+    }
+
+    fn fail_build_after_repair(app: &mut App) {
+        app.pending_build_test = Some(PendingBuildTest {
+            task_id: "TASK-00".to_string(),
+            report: "구현 완료".to_string(),
+            repair_attempts: 1,
+        });
+        app.handle_build_test_result(BuildTestOutcome::BuildFailed {
+            output: "error[E0308]: mismatched types".to_string(),
+        });
+    }
+
+    #[test]
+    fn build_failure_merges_task_when_continue_on_build_failure_is_set() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_continue_on_build_failure(true);
+        let mut app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        fail_build_after_repair(&mut app);
+
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationSuccess);
+        assert!(report.merged_with_failing_build);
+        assert!(report.report.contains("MERGED WITH FAILING BUILD"));
+        assert!(report.report.contains("mismatched types"));
+        assert!(workspace.join("task.rs").exists());
+        assert!(has_message_containing(&app, "실패한 상태로 머지된 태스크 1개: TASK-00"));
+    }
+
+    #[test]
+    fn build_failure_blocks_task_by_default() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        fail_build_after_repair(&mut app);
+
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+        assert!(!report.merged_with_failing_build);
+        assert!(!workspace.join("task.rs").exists());
+        assert!(!has_message_containing(&app, "실패한 상태로 머지된 태스크"));
+    }
+
+    fn fail_build(app: &mut App, repair_attempts: usize, output: &str) {
+        app.pending_build_test = Some(PendingBuildTest {
+            task_id: "TASK-00".to_string(),
+            report: "구현 완료".to_string(),
+            repair_attempts,
+        });
+        app.handle_build_test_result(BuildTestOutcome::BuildFailed { output: output.to_string() });
+    }
+
+    #[test]
+    fn build_repair_repeats_until_the_attempt_limit_then_blocks() {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0)
+            .with_max_build_repair_attempts(2);
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        commit_in_current_worktree(&app, "task.rs");
+        app.coding_state.as_mut().unwrap().build_test_commands = Some(BuildTestCommands {
+            build: "make build".to_string(),
+            test: "make test".to_string(),
+            per_test_timeout_secs: None,
+        });
+
+        for (repair_attempts, output) in [(0, "first error"), (1, "second error")] {
+            app.claude_client = Some(app.new_claude_client(workspace.clone(), None).unwrap());
+            fail_build(&mut app, repair_attempts, output);
+
+            let pending = app.pending_build_test.as_ref().unwrap();
+            assert_eq!(pending.repair_attempts, repair_attempts + 1);
+            assert!(has_message_containing(
+                &app,
+                &format!("빌드 실패. 수리 에이전트 시작 ({}/2)", repair_attempts + 1),
+            ));
+            assert!(app.coding_state.as_ref().unwrap().task_reports.is_empty());
+        }
+
+        fail_build(&mut app, 2, "third error");
+
+        assert!(app.pending_build_test.is_none());
+        assert!(has_message_containing(&app, "수리 2회 후 빌드 재실패."));
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+        assert!(report.report.contains("third error"));
+    }
+
+    #[test]
+    fn zero_build_repair_attempts_blocks_on_first_failure() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_max_build_repair_attempts(0);
+        let mut app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        fail_build(&mut app, 0, "error[E0308]: mismatched types");
+
+        assert!(has_message_containing(&app, "빌드 실패. 수리를 시도하지 않습니다."));
+        assert!(!has_message_containing(&app, "수리 에이전트 시작"));
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+    }
+
+    fn current_worktree_path(app: &App) -> PathBuf {
+        let coding_state = app.coding_state.as_ref().unwrap();
+        coding_state.current_task_worktree.as_ref().unwrap().worktree_path.clone()
+    }
+
+    #[test]
+    fn task_directory_uses_existing_subdir_of_current_worktree() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string());
+        let mut app = app_in_coding_phase(config, &workspace);
+        let worktree_path = current_worktree_path(&app);
+        app.coding_state.as_mut().unwrap().tasks[0].subdir = Some("services/api".to_string());
+
+        assert_eq!(app.current_task_directory(), Some(worktree_path.clone()));
+
+        std::fs::create_dir_all(worktree_path.join("services/api")).unwrap();
+        assert_eq!(app.current_task_directory(), Some(worktree_path.join("services/api")));
+    }
+
+    fn run_build_test_job_in_current_worktree(app: &App) -> (BuildTestOutcome, Vec<String>) {
+        let worktree_path = current_worktree_path(app);
+        let commands = BuildTestCommands {
+            build: "true".to_string(),
+            test: "true".to_string(),
+            per_test_timeout_secs: None,
+        };
+        let (sender, receiver) = mpsc::channel();
+        let outcome = app.build_test_job(worktree_path, commands).run(&sender).unwrap();
+        drop(sender);
+        let lines = receiver
+            .iter()
+            .filter_map(|message| match message {
+                AgentStreamMessage::StreamLine(line) => Some(line.content),
+                _ => None,
+            })
+            .collect();
+        (outcome, lines)
+    }
+
+    #[test]
+    fn formatter_runs_before_build_and_folds_its_changes_into_the_task_commit() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_format_before_build(true)
+            .with_format_command(Some("printf 'formatted\\n' > task.rs".to_string()));
+        let app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        let (outcome, lines) = run_build_test_job_in_current_worktree(&app);
+
+        assert!(matches!(outcome, BuildTestOutcome::Success));
+        assert!(lines[0].contains("바뀐 파일을 커밋했습니다"), "{:?}", lines);
+        let worktree_path = current_worktree_path(&app);
+        let subject = Command::new("git")
+            .args(["log", "-1", "--format=%s"])
+            .current_dir(&worktree_path)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&subject.stdout).trim(), "Task change");
+        let content = std::fs::read_to_string(worktree_path.join("task.rs")).unwrap();
+        assert_eq!(content, "formatted\n");
+        run_git(&worktree_path, &["diff", "--quiet", "HEAD"]);
+    }
+
+    #[test]
+    fn formatter_is_not_run_unless_enabled() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_format_command(Some("printf 'formatted\\n' > task.rs".to_string()));
+        let app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        let (_, lines) = run_build_test_job_in_current_worktree(&app);
+
+        assert!(lines.iter().all(|line| !line.contains("[포매터]")), "{:?}", lines);
+        let worktree_path = current_worktree_path(&app);
+        let content = std::fs::read_to_string(worktree_path.join("task.rs")).unwrap();
+        assert_eq!(content, "changed by task");
+    }
+
+    #[test]
+    fn rebase_duration_is_recorded_for_current_task() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        app.rebase_and_merge_task("TASK-00".to_string(), "구현 완료".to_string());
+
+        let summary = app.timings.summary_text().unwrap();
+        assert!(summary.contains("- 리베이스: 1회"));
+        assert!(summary.contains("- [TASK-00] 리베이스"));
+    }
+
+    fn merged_files_on_integration_branch(app: &App, workspace: &Path) -> String {
+        let integration_branch = &app.coding_state.as_ref().unwrap().integration_branch;
+        let output = Command::new("git")
+            .args(["log", "--name-only", "--format=", integration_branch])
+            .current_dir(workspace)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    #[test]
+    fn dirty_workspace_stops_before_creating_the_integration_branch() {
+        let (_root, workspace) = setup_workspace();
+        std::fs::write(workspace.join("README.md"), "uncommitted edit").unwrap();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        app.session_name = Some("test-session".to_string());
+
+        assert!(!app.enter_coding_phase(vec![coding_task("TASK-00", &[])], false));
+
+        assert!(has_message_containing(&app, "커밋하지 않은 변경이 있습니다: README.md"));
+        assert!(matches!(app.input_mode, InputMode::Done));
+        assert!(app.integration_branch.is_none());
+        let output = Command::new("git")
+            .args(["branch", "--list", "bear/integration/*"])
+            .current_dir(&workspace)
+            .output()
+            .unwrap();
+        assert!(String::from_utf8_lossy(&output.stdout).trim().is_empty());
+    }
+
+    #[test]
+    fn auto_stash_clears_dirty_workspace_and_restores_it_once() {
+        let (_root, workspace) = setup_workspace();
+        std::fs::write(workspace.join("README.md"), "uncommitted edit").unwrap();
+        let config = Config::new("test-key".to_string()).with_auto_stash(true);
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        app.session_name = Some("test-session".to_string());
+
+        assert!(app.enter_coding_phase(vec![coding_task("TASK-00", &[])], false));
+        assert!(has_message_containing(&app, "커밋하지 않은 변경을 stash에 넣었습니다"));
+        assert_eq!(std::fs::read_to_string(workspace.join("README.md")).unwrap(), "init");
+
+        app.restore_auto_stash();
+        app.restore_auto_stash();
+
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("README.md")).unwrap(),
+            "uncommitted edit",
+        );
+        let restored = app
+            .messages
+            .iter()
+            .filter(|m| m.content.contains("변경을 워크스페이스에 되돌렸습니다"))
+            .count();
+        assert_eq!(restored, 1);
+    }
+
+    #[test]
+    fn auto_stash_is_restored_when_integration_branch_cannot_be_created() {
+        let (_root, workspace) = setup_workspace();
+        std::fs::write(workspace.join("README.md"), "uncommitted edit").unwrap();
+        let config = Config::new("test-key".to_string())
+            .with_auto_stash(true)
+            .with_integration_base_branch(Some("missing".to_string()));
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.clone());
+        app.session_name = Some("test-session".to_string());
+
+        assert!(!app.enter_coding_phase(vec![coding_task("TASK-00", &[])], false));
+
+        assert!(has_message_containing(&app, "변경을 워크스페이스에 되돌렸습니다"));
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("README.md")).unwrap(),
+            "uncommitted edit",
+        );
+        assert!(app.cleanup_registry.take_stash().is_none());
+    }
+
+    #[test]
+    fn task_report_is_committed_with_the_task_by_default() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        app.merge_and_advance("TASK-00".to_string(), "구현 완료".to_string(), false);
+
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+        assert!(
+            merged_files.contains(".bear/20260101/test-session/TASK-00.md"),
+            "{}",
+            merged_files,
+        );
+    }
+
+    #[test]
+    fn task_report_stays_in_journal_when_commit_reports_is_off() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_commit_reports(false);
+        let mut app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        app.merge_and_advance("TASK-00".to_string(), "구현 완료".to_string(), false);
+
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+        assert!(!merged_files.contains(".bear/"), "{}", merged_files);
+        let report_path = app.workspace_journal_dir().join("TASK-00.md");
+        assert_eq!(std::fs::read_to_string(&report_path).unwrap(), "구현 완료");
+        let task_report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(task_report.report_file_path, report_path);
+    }
+
+    #[test]
+    fn merges_without_running_build_test_when_verification_is_off() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_verify_build_test(false);
+        let mut app = app_in_coding_phase(config, &workspace);
+        commit_in_current_worktree(&app, "task.rs");
+        let marker = workspace.join("build-ran");
+        app.coding_state.as_mut().unwrap().build_test_commands = Some(BuildTestCommands {
+            build: format!("touch {}", marker.display()),
+            test: format!("touch {}", marker.display()),
+            per_test_timeout_secs: None,
+        });
+
+        app.rebase_and_merge_task("TASK-00".to_string(), "구현 완료".to_string());
+        tick_until_idle(&mut app);
+
+        assert!(!marker.exists());
+        assert!(app.pending_build_test.is_none());
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+        assert!(has_message_containing(&app, "빌드/테스트 검증 없이 머지합니다"));
+    }
+
+    fn app_waiting_for_merge_approval(workspace: &Path) -> App {
+        let config = Config::new("test-key".to_string())
+            .with_verify_build_test(false)
+            .with_manual_merge_approval(true);
+        let mut app = app_in_coding_phase(config, workspace);
+        commit_in_current_worktree(&app, "task.rs");
+
+        app.rebase_and_merge_task("TASK-00".to_string(), "구현 완료".to_string());
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::MergeApproval));
+        assert!(has_message_containing(&app, "task.rs | 1 +"));
+        let merged_files = merged_files_on_integration_branch(&app, workspace);
+        assert!(!merged_files.contains("task.rs"), "{}", merged_files);
+        app
+    }
+
+    #[test]
+    fn approving_merge_merges_task_into_integration_branch() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_waiting_for_merge_approval(&workspace);
+
+        app.handle_key_event(key(KeyCode::Enter));
+
+        assert!(app.pending_merge.is_none());
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationSuccess);
+    }
+
+    #[test]
+    fn rejecting_merge_blocks_task_without_merging() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_waiting_for_merge_approval(&workspace);
+
+        app.handle_key_event(ctrl('b'));
+
+        assert!(app.pending_merge.is_none());
+        assert!(!matches!(app.input_mode, InputMode::MergeApproval));
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(!merged_files.contains("task.rs"), "{}", merged_files);
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+        assert!(report.report.contains("사용자가 머지를 거부함"));
+    }
+
+    /// 리뷰 요청에는 `review_status`로, 빌드/테스트 수리 요청에는 실패로, 코딩 요청에는
+    /// IMPLEMENTATION_BLOCKED로 응답하는 가짜 CLI와 `build_command`를 쓰는 App을 만들고,
+    /// 리뷰와 빌드/테스트를 동시에 실행한다. 빌드/테스트가 실행될 때마다 기록하는 파일의
+    /// 경로를 함께 반환한다. `format_command`가 있으면 빌드/테스트 전에 포매터로 실행한다.
+    fn run_concurrent_review_and_build(
+        root: &Path,
+        workspace: &Path,
+        review_status: &str,
+        build_command: &str,
+        format_command: Option<&str>,
+    ) -> (App, PathBuf) {
+        let script = format!(
+            r#"#!/bin/sh
+prompt=$(cat)
+case "$prompt" in
+  *"Build/Test Failure Resolution"*) out='{{"status":"BUILD_TEST_FIX_FAILED","report":"no fix"}}' ;;
+  *"Code Review"*) out='{{"review_result":"{review_status}","review_comment":"review"}}' ;;
+  *) out='{{"status":"IMPLEMENTATION_BLOCKED","report":"gave up"}}' ;;
+esac
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":%s}}\n' "$out"
+"#,
+        );
+        let binary = write_script(root, "fake-claude", &script);
+        let config = Config::new("test-key".to_string())
+            .with_concurrent_review_build(true)
+            .with_format_before_build(format_command.is_some())
+            .with_format_command(format_command.map(str::to_string));
+        let mut app = app_in_coding_phase(config, workspace).with_claude_binary(binary);
+        commit_in_current_worktree(&app, "task.rs");
+
+        let runs = root.join("build-runs");
+        app.coding_state.as_mut().unwrap().build_test_commands = Some(BuildTestCommands {
+            build: format!("echo build >> {}; {}", runs.display(), build_command),
+            test: format!("echo test >> {}", runs.display()),
+            per_test_timeout_secs: None,
+        });
+        let coding_client = app.new_claude_client(workspace.to_path_buf(), None).unwrap();
+        app.review_state = Some(ReviewState {
+            task_id: "TASK-00".to_string(),
+            report: "구현 완료".to_string(),
+            iteration_count: 0,
+            max_iterations: 3,
+            reviewer_client: None,
+            coding_client: Some(coding_client),
+        });
+
+        app.start_review();
+        tick_until_idle(&mut app);
+        (app, runs)
+    }
+
+    #[test]
+    fn concurrent_review_and_build_merge_when_both_pass() {
+        let (root, workspace) = setup_workspace();
+
+        let (app, runs) =
+            run_concurrent_review_and_build(root.path(), &workspace, "APPROVED", "true", None);
+
+        assert!(has_message_containing(&app, "코드 리뷰와 빌드/테스트 검증 시작"));
+        assert!(has_message_containing(&app, "리뷰와 함께 실행한 빌드/테스트 결과: 성공."));
+        assert!(has_message_containing(&app, "리뷰와 함께 실행한 빌드/테스트 결과를 사용합니다"));
+        // 리베이스로 커밋이 바뀌지 않았으므로 빌드/테스트는 리뷰와 함께 한 번만 실행된다.
+        assert_eq!(std::fs::read_to_string(&runs).unwrap(), "build\ntest\n");
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationSuccess);
+        assert_eq!(report.progress.build_test_passed, Some(true));
+    }
+
+    #[test]
+    fn concurrent_review_requesting_changes_does_not_merge_a_passing_build() {
+        let (root, workspace) = setup_workspace();
+
+        let (app, runs) = run_concurrent_review_and_build(
+            root.path(),
+            &workspace,
+            "REQUEST_CHANGES",
+            "true",
+            None,
+        );
+
+        assert!(has_message_containing(&app, "리뷰와 함께 실행한 빌드/테스트 결과: 성공."));
+        assert!(has_message_containing(&app, "리뷰어 변경 요청"));
+        assert_eq!(std::fs::read_to_string(&runs).unwrap(), "build\ntest\n");
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(!merged_files.contains("task.rs"), "{}", merged_files);
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+    }
+
+    #[test]
+    fn concurrent_formatter_commit_does_not_invalidate_the_build_result() {
+        let (root, workspace) = setup_workspace();
+        let format_command = "printf 'formatted\\n' > task.rs";
+
+        let (app, runs) = run_concurrent_review_and_build(
+            root.path(),
+            &workspace,
+            "APPROVED",
+            "true",
+            Some(format_command),
+        );
+
+        assert!(has_message_containing(&app, "[포매터]"));
+        assert!(has_message_containing(&app, "리뷰와 함께 실행한 빌드/테스트 결과를 사용합니다"));
+        assert_eq!(std::fs::read_to_string(&runs).unwrap(), "build\ntest\n");
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(merged_files.contains("task.rs"), "{}", merged_files);
+    }
+
+    #[test]
+    fn concurrent_build_failure_blocks_an_approved_task_without_rebuilding() {
+        let (root, workspace) = setup_workspace();
+
+        let (app, runs) =
+            run_concurrent_review_and_build(root.path(), &workspace, "APPROVED", "false", None);
+
+        assert!(has_message_containing(&app, "리뷰와 함께 실행한 빌드/테스트 결과: 빌드 실패."));
+        assert!(has_message_containing(&app, "코드 리뷰 승인."));
+        assert!(has_message_containing(&app, "수리 실패: no fix"));
+        assert_eq!(std::fs::read_to_string(&runs).unwrap(), "build\n");
+        let merged_files = merged_files_on_integration_branch(&app, &workspace);
+        assert!(!merged_files.contains("task.rs"), "{}", merged_files);
+        let report = &app.coding_state.as_ref().unwrap().task_reports[0];
+        assert_eq!(report.status, CodingTaskStatus::ImplementationBlocked);
+        assert_eq!(report.progress.build_test_passed, Some(false));
+    }
+
+    /// 표준 입력으로 받은 프롬프트에 따라 미리 정해진 결과를 돌려주는 가짜 Claude Code CLI.
+    /// 다음 작업 계획 요청에는 태스크 하나를 계획한 뒤 TASK-00 리포트가 보이면 완료를
+    /// 응답하고, 코딩 요청에는 항상 IMPLEMENTATION_BLOCKED를 응답한다.
+    fn write_fake_claude_binary(dir: &Path, planner_exit_code: i32) -> PathBuf {
+        write_fake_planning_claude_binary(dir, planner_exit_code, 1)
+    }
+
+    /// `write_fake_claude_binary`와 같지만, 마지막 태스크의 리포트가 보일 때까지 태스크를
+    /// `planned_tasks`개 계획한다.
+    fn write_fake_planning_claude_binary(
+        dir: &Path,
+        planner_exit_code: i32,
+        planned_tasks: usize,
+    ) -> PathBuf {
+        let script = format!(
+            r#"#!/bin/sh
+prompt=$(cat)
+case "$prompt" in
+  *"Plan the next single"*)
+    if [ {exit_code} -ne 0 ]; then echo "planner crashed" >&2; exit {exit_code}; fi
+    case "$prompt" in
+      *"[TASK-{last_task:02}] IMPLEMENTATION_BLOCKED"*) out='{{"status":"ALL_DONE"}}' ;;
+      *) out='{{"status":"NEXT_TASK","title":"첫 작업","description":"설명","files":["a.rs"]}}' ;;
+    esac ;;
+  *) out='{{"status":"IMPLEMENTATION_BLOCKED","report":"fake agent report"}}' ;;
+esac
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":%s}}\n' "$out"
+"#,
+            exit_code = planner_exit_code,
+            last_task = planned_tasks - 1,
+        );
+        write_script(dir, "fake-claude", &script)
+    }
+
+    /// 첫 태스크 추출에는 빈 목록을, 다시 추출할 때는 `retry_output`을 돌려주는 가짜 CLI를 쓰는
+    /// App을 만든다.
+    fn app_with_fake_task_extractor(root: &Path, workspace: &Path, retry_output: &str) -> App {
+        let script = format!(
+            r#"#!/bin/sh
+prompt=$(cat)
+case "$prompt" in
+  *"returned no tasks"*) out='{}' ;;
+  *) out='{{"tasks":[]}}' ;;
+esac
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":%s}}\n' "$out"
+"#,
+            retry_output,
+        );
+        let path = write_script(root, "fake-extractor", &script);
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0);
+        let mut app = App::new(config).unwrap().with_claude_binary(path);
+        app.confirmed_workspace = Some(workspace.to_path_buf());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        app.ensure_claude_client().unwrap();
+        app
+    }
+
+    #[test]
+    fn empty_task_extraction_is_retried_once_with_single_task_hint() {
+        let (root, workspace) = setup_workspace();
+        let retry_output = r#"{"tasks":[{"task_id":"TASK-00","title":"전체 플랜","description":"d","dependencies":[]}]}"#;
+        let mut app = app_with_fake_task_extractor(root.path(), &workspace, retry_output);
+
+        app.start_task_extraction(false);
+        tick_until_idle(&mut app);
+
+        assert!(has_message_containing(&app, "플랜 전체를 하나의 작업으로 보도록 다시 추출합니다."));
+        assert!(matches!(app.input_mode, InputMode::TaskReview));
+        let review = app.task_review.as_ref().unwrap();
+        assert_eq!(review.tasks.len(), 1);
+        assert_eq!(review.tasks[0].task_id, "TASK-00");
+    }
+
+    #[test]
+    fn empty_task_extraction_ends_when_retry_is_also_empty() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_task_extractor(root.path(), &workspace, r#"{"tasks":[]}"#);
+
+        app.start_task_extraction(false);
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::Done));
+        assert!(has_message_containing(&app, "다시 추출해도 작업이 없어 종료합니다."));
+        assert!(app.task_review.is_none());
+    }
+
+    /// 스펙 승인 직전 상태에서 가짜 CLI를 쓰는 점진적 계획 모드 App을 만든다.
+    fn app_with_fake_incremental_planner(root: &Path, workspace: &Path, exit_code: i32) -> App {
+        let config = Config::new("test-key".to_string())
+            .with_incremental_planning(true)
+            .with_max_retries(0);
+        let binary = write_fake_claude_binary(root, exit_code);
+        app_with_approvable_spec(config, workspace).with_claude_binary(binary)
+    }
+
+    fn app_with_approvable_spec(config: Config, workspace: &Path) -> App {
+        let mut app = App::new(config).unwrap();
+        app.confirmed_workspace = Some(workspace.to_path_buf());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        app.last_spec_draft = Some("# Spec".to_string());
+        app
+    }
+
+    fn tick_until_idle(app: &mut App) {
+        let deadline = Instant::now() + std::time::Duration::from_secs(30);
+        while app.is_thinking() {
+            assert!(Instant::now() < deadline, "agent operation did not finish");
+            app.tick();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn incremental_planning_interleaves_planning_and_coding_until_all_done() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_incremental_planner(root.path(), &workspace, 0);
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.tasks.len(), 1);
+        assert_eq!(coding_state.tasks[0].task_id, "TASK-00");
+        assert_eq!(coding_state.tasks[0].files, vec!["a.rs"]);
+        assert_eq!(coding_state.task_reports.len(), 1);
+        assert_eq!(coding_state.task_reports[0].report, "fake agent report");
+        assert!(has_message_containing(&app, "점진적 계획: [TASK-00] 다음 작업을 계획합니다."));
+        assert!(has_message_containing(&app, "점진적 계획: [TASK-00] 첫 작업"));
+        assert!(has_message_containing(&app, "점진적 계획: 스펙의 모든 작업이 완료되었습니다."));
+        assert!(has_message_containing(&app, "코딩 단계 완료. 성공: 0, 차단: 1"));
+
+        let plan = std::fs::read_to_string(app.workspace_journal_dir().join("plan.md")).unwrap();
+        assert!(plan.contains("## [TASK-00] 첫 작업"));
+    }
+
+    #[test]
+    fn incremental_planning_keeps_running_new_tasks_after_a_blocked_task() {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_incremental_planning(true)
+            .with_max_retries(0);
+        let binary = write_fake_planning_claude_binary(root.path(), 0, 2);
+        let mut app = app_with_approvable_spec(config, &workspace).with_claude_binary(binary);
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.tasks.len(), 2);
+        assert_eq!(coding_state.tasks[1].task_id, "TASK-01");
+        assert!(coding_state.tasks[1].dependencies.is_empty());
+        let reports: Vec<_> =
+            coding_state.task_reports.iter().map(|report| report.report.as_str()).collect();
+        assert_eq!(reports, vec!["fake agent report", "fake agent report"]);
+        assert!(!has_message_containing(&app, "의존 태스크"));
+        assert!(has_message_containing(&app, "점진적 계획: 스펙의 모든 작업이 완료되었습니다."));
+    }
+
+    #[test]
+    fn incremental_planning_failure_pauses_and_enter_plans_again() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_incremental_planner(root.path(), &workspace, 1);
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::CodingPaused));
+        assert!(app.fatal_error().is_none());
+        assert!(has_message_containing(&app, "다음 작업 계획 실패"));
+        assert!(app.coding_state.as_ref().unwrap().tasks.is_empty());
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(app.input_mode, InputMode::Coding));
+        tick_until_idle(&mut app);
+        assert!(matches!(app.input_mode, InputMode::CodingPaused));
+    }
+
+    fn app_with_invocation_warning_threshold(
+        root: &Path,
+        workspace: &Path,
+        threshold: usize,
+    ) -> App {
+        let config = Config::new("test-key".to_string())
+            .with_incremental_planning(true)
+            .with_max_retries(0)
+            .with_invocation_warning_threshold(Some(threshold));
+        let binary = write_fake_claude_binary(root, 0);
+        app_with_approvable_spec(config, workspace).with_claude_binary(binary)
+    }
+
+    #[test]
+    fn invocation_warning_pauses_before_next_task_and_enter_resumes() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_invocation_warning_threshold(root.path(), &workspace, 1);
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::InvocationBudget));
+        assert!(has_message_containing(&app, "이 세션에서 이미 1회 호출했습니다"));
+        assert!(has_message_containing(&app, "[호출 경고]"));
+        assert_eq!(app.usage_status().as_deref(), Some("CLI 1회"));
+        assert!(app.coding_state.as_ref().unwrap().task_reports.is_empty());
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(app.input_mode, InputMode::Coding));
+        tick_until_idle(&mut app);
+
+        assert_eq!(app.coding_state.as_ref().unwrap().task_reports.len(), 1);
+        assert!(matches!(app.input_mode, InputMode::InvocationBudget));
+    }
+
+    #[test]
+    fn invocation_warning_does_not_pause_headless_runs() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_invocation_warning_threshold(root.path(), &workspace, 1);
+        app.headless = true;
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        assert!(has_message_containing(&app, "[호출 경고]"));
+    }
+
+    fn type_and_submit(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn pasting_a_path_with_a_trailing_newline_inserts_the_trimmed_path() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::SessionDirInput;
+
+        app.handle_paste("  /work/.bear/20260101/session\r\n".to_string());
+
+        assert_eq!(app.input_buffer, "/work/.bear/20260101/session");
+        assert_eq!(app.cursor_position, app.input_buffer.chars().count());
+
+        app.input_mode = InputMode::WorkspaceConfirm;
+        app.clear_input();
+        app.handle_paste("\n/my workspace\n\n".to_string());
+        assert_eq!(app.input_buffer, "/my workspace");
+    }
+
+    #[test]
+    fn pasting_multiple_lines_into_a_path_input_is_rejected() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::SessionDirInput;
+
+        app.handle_paste("/first/path\n/second/path\n".to_string());
+
+        assert!(app.input_buffer.is_empty());
+        assert!(has_message_containing(&app, "경로 입력란에는 한 줄만 붙여넣을 수 있습니다."));
+
+        app.input_mode = InputMode::TaskReview;
+        app.handle_paste("1\n2".to_string());
+        assert_eq!(app.input_buffer, "1 2");
+    }
+
+    #[test]
+    fn debug_log_location_follows_config_and_session() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        assert_eq!(app.debug_log_location(), DebugLogLocation::TempDir);
+
+        app.confirmed_workspace = Some(PathBuf::from("/workspace"));
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        assert_eq!(
+            app.debug_log_location(),
+            DebugLogLocation::Directory(PathBuf::from("/workspace/.bear/20260101/test-session/logs")),
+        );
+
+        app.config = Config::new("test-key".to_string())
+            .with_debug_log_dir(Some(PathBuf::from("/var/log/bear")));
+        assert_eq!(
+            app.debug_log_location(),
+            DebugLogLocation::Directory(PathBuf::from("/var/log/bear")),
+        );
+
+        app.config = Config::new("test-key".to_string()).with_debug_log_enabled(false);
+        assert_eq!(app.debug_log_location(), DebugLogLocation::Disabled);
+    }
+
+    #[test]
+    fn current_log_path_follows_the_session_through_phases() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        assert_eq!(app.current_log_path(), None);
+
+        // 세션 이름이 정해지기 전에는 마지막 CLI 세션의 임시 디렉토리 로그를 본다.
+        app.last_cli_session_id = Some("cli-1".to_string());
+        assert_eq!(app.current_log_path(), Some(std::env::temp_dir().join("bear-cli-1.log")));
+
+        app.confirmed_workspace = Some(workspace.clone());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        let logs_dir = workspace.join(".bear/20260101/test-session/logs");
+        assert_eq!(app.current_log_path(), Some(logs_dir.join("bear-cli-1.log")));
+
+        std::fs::create_dir_all(&logs_dir).unwrap();
+        std::fs::write(logs_dir.join("bear-cli-2.log"), "spec query").unwrap();
+        assert_eq!(app.current_log_path(), Some(logs_dir.join("bear-cli-2.log")));
+
+        app.config = Config::new("test-key".to_string()).with_debug_log_enabled(false);
+        assert_eq!(app.current_log_path(), None);
+    }
+
+    #[test]
+    fn current_log_path_during_coding_uses_the_workspace_session_logs() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        app.last_cli_session_id = Some("coder".to_string());
+
+        assert_eq!(
+            app.current_log_path(),
+            Some(workspace.join(".bear/20260101/test-session/logs/bear-coder.log")),
+        );
+    }
+
+    #[test]
+    fn ctrl_l_toggles_the_log_viewer_in_any_mode() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        assert_eq!(app.overlay(), None);
+
+        app.handle_key_event(ctrl('l'));
+        assert_eq!(app.overlay(), Some(Overlay::DebugLog));
+        assert!(app.input_buffer.is_empty());
+
+        app.input_mode = InputMode::AgentThinking;
+        app.handle_key_event(ctrl('l'));
+        assert_eq!(app.overlay(), None);
+    }
+
+    #[test]
+    fn ctrl_t_shows_session_info_in_place_of_the_log_viewer() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+
+        app.handle_key_event(ctrl('l'));
+        app.handle_key_event(ctrl('t'));
+        assert_eq!(app.overlay(), Some(Overlay::SessionInfo));
+        app.handle_key_event(ctrl('t'));
+        assert_eq!(app.overlay(), None);
+    }
+
+    #[test]
+    fn ctrl_u_shows_the_remaining_task_schedule() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        assert_eq!(app.task_schedule_lines().len(), 1);
+
+        app.handle_key_event(ctrl('u'));
+        assert_eq!(app.overlay(), Some(Overlay::TaskSchedule));
+        app.handle_key_event(ctrl('u'));
+        assert_eq!(app.overlay(), None);
+
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let mut dependent = coding_task("TASK-01", &[]);
+        dependent.dependencies = vec!["TASK-00".to_string()];
+        app.coding_state.as_mut().unwrap().tasks.push(dependent);
+
+        assert_eq!(
+            app.task_schedule_lines(),
+            vec![
+                "실행 가능  [TASK-00] TASK-00 title".to_string(),
+                "대기 중    [TASK-01] TASK-01 title".to_string(),
+            ],
+        );
+    }
+
+    #[test]
+    fn session_info_reflects_the_current_state() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        assert_eq!(app.session_info(), SessionInfo::default());
+        assert!(app.session_info().lines().iter().all(|line| line.ends_with("(없음)")));
+
+        app.confirmed_workspace = Some(workspace.clone());
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        app.integration_branch = Some("bear/integration/test-session".to_string());
+        app.last_cli_session_id = Some("cli-1".to_string());
+        let journal_dir = workspace.join(".bear/20260101/test-session");
+        assert_eq!(
+            app.session_info(),
+            SessionInfo {
+                session_name: Some("test-session".to_string()),
+                session_date_dir: Some("20260101".to_string()),
+                journal_dir: Some(journal_dir.clone()),
+                integration_branch: Some("bear/integration/test-session".to_string()),
+                claude_session_id: Some("cli-1".to_string()),
+                log_path: Some(journal_dir.join("logs/bear-cli-1.log")),
+            },
+        );
+        assert!(app.session_info().lines().contains(&"Claude 세션 ID: cli-1".to_string()));
+
+        let app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+        let info = app.session_info();
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(info.integration_branch, Some(coding_state.integration_branch.clone()));
+        let worktree_path = &coding_state.current_task_worktree.as_ref().unwrap().worktree_path;
+        assert!(info.journal_dir.unwrap().starts_with(worktree_path));
+    }
+
+    #[test]
+    fn task_agent_clients_add_context_dirs_but_work_in_the_worktree() {
+        let (root, workspace) = setup_workspace();
+        let invocation = root.path().join("invocation");
+        let script = format!(
+            "#!/bin/sh\ncat > /dev/null\npwd > {0}\necho \"$@\" >> {0}\n\
+             printf '{{\"type\":\"result\",\"is_error\":false,\
+             \"session_id\":\"fake\",\"structured_output\":{{}}}}\\n'\n",
+            invocation.display(),
+        );
+        let binary = write_script(root.path(), "recording-claude", &script);
+        let shared_docs = root.path().join("shared-docs");
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0)
+            .with_context_dirs(vec![shared_docs.clone()]);
+        let app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        let worktree_info = app.coding_state.as_ref().unwrap().current_task_worktree.as_ref();
+        let worktree_path = worktree_info.unwrap().worktree_path.clone();
+
+        let mut client = app.new_task_agent_client(worktree_path.clone(), None).unwrap();
+        let request = ClaudeCodeRequest {
+            user_prompt: "implement".to_string(),
+            output_schema: serde_json::json!({}),
+        };
+        let _: serde_json::Value = client.query(&request).unwrap();
+
+        let recorded = std::fs::read_to_string(&invocation).unwrap();
+        let (working_directory, args) = recorded.split_once('\n').unwrap();
+        assert_eq!(
+            std::fs::canonicalize(working_directory).unwrap(),
+            std::fs::canonicalize(&worktree_path).unwrap(),
+        );
+        assert!(args.contains(&format!("--add-dir {}", shared_docs.display())), "{}", args);
+    }
+
+    #[test]
+    fn resumed_session_reattaches_recorded_coding_session_or_starts_fresh() {
+        let (root, workspace) = setup_workspace();
+        let script = r#"#!/bin/sh
+cat > /dev/null
+case "$*" in
+  *--resume*)
+    echo 'No conversation found with session ID: sess-earlier' >&2
+    exit 1 ;;
+esac
+printf '{"type":"result","is_error":false,"session_id":"sess-fresh","structured_output":{}}\n'
+"#;
+        let binary = write_script(root.path(), "resume-claude", script);
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0);
+        let mut app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        let previous_session_dir = root.path().join("previous-session");
+        let worktree_info = app.coding_state.as_ref().unwrap().current_task_worktree.as_ref();
+        let worktree_path = worktree_info.unwrap().worktree_path.clone();
+        let earlier_session = coding::CodingSession {
+            session_id: "sess-earlier".to_string(),
+            working_directory: worktree_path.clone(),
+        };
+        coding::save_coding_session(&previous_session_dir, "TASK-00", &earlier_session).unwrap();
+        app.resumed_session_dir = Some(previous_session_dir);
+
+        let client = app.new_task_agent_client(worktree_path.clone(), None).unwrap();
+        let mut client = app.reattach_coding_session(client, "TASK-00");
+        assert_eq!(client.session_id(), Some("sess-earlier"));
+        assert!(has_message_containing(&app, "이전 Claude 세션 sess-earlier에 다시 연결합니다."));
+
+        let request = ClaudeCodeRequest {
+            user_prompt: "implement".to_string(),
+            output_schema: serde_json::json!({}),
+        };
+        let _: serde_json::Value = client.query(&request).unwrap();
+        app.claude_client = Some(client);
+        app.record_coding_session("TASK-00");
+
+        assert!(has_message_containing(&app, "새 세션 sess-fresh으로 작업했습니다."));
+        let recorded = coding::load_coding_sessions(&app.workspace_journal_dir()).unwrap();
+        assert_eq!(recorded["TASK-00"].session_id, "sess-fresh");
+        assert_eq!(recorded["TASK-00"].working_directory, worktree_path);
+    }
+
+    #[test]
+    fn resumed_session_does_not_reattach_a_session_from_another_working_directory() {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0);
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        let previous_session_dir = root.path().join("previous-session");
+        let earlier_session = coding::CodingSession {
+            session_id: "sess-earlier".to_string(),
+            working_directory: root.path().join("old-worktree"),
+        };
+        coding::save_coding_session(&previous_session_dir, "TASK-00", &earlier_session).unwrap();
+        app.resumed_session_dir = Some(previous_session_dir);
+        let worktree_info = app.coding_state.as_ref().unwrap().current_task_worktree.as_ref();
+        let worktree_path = worktree_info.unwrap().worktree_path.clone();
+
+        let client = app.new_task_agent_client(worktree_path, None).unwrap();
+        let client = app.reattach_coding_session(client, "TASK-00");
+
+        assert_eq!(client.session_id(), None);
+        assert!(has_message_containing(&app, "다른 작업 디렉토리"));
+        assert!(app.reattached_coding_session.is_none());
+    }
+
+    #[test]
+    fn skip_clarification_starts_spec_writing_with_a_session_name() {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_preset(crate::config::Preset::Quick)
+            .with_max_retries(0);
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = App::new(config).unwrap().with_claude_binary(binary);
+        app.confirmed_workspace = Some(workspace);
+        app.input_mode = InputMode::RequirementsInput;
+
+        type_and_submit(&mut app, "build a thing");
+
+        assert!(has_message_containing(&app, "요구사항 명확화를 건너뛰고 스펙 문서를 작성합니다."));
+        assert!(!has_message_containing(&app, "요구사항을 분석 중입니다"));
+        assert!(app.session_name.is_some());
+        assert!(app.integration_branch.is_some());
+        assert!(app.workspace_journal_dir().join("user-request.md").is_file());
+        assert!(app.is_thinking());
+    }
+
+    #[test]
+    fn more_than_the_task_limit_reviews_the_first_tasks_and_defers_the_rest() {
+        let (_root, workspace) = setup_workspace();
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.confirmed_workspace = Some(workspace);
+        app.session_name = Some("test-session".to_string());
+        app.session_date_dir = Some("20260101".to_string());
+        let tasks = (0..coding::MAX_TASKS_PER_PLAN + 1)
+            .map(|index| coding_task(&format!("TASK-{:03}", index), &[]))
+            .collect();
+
+        app.handle_task_extraction_response(TaskExtractionResponse { tasks });
+
+        assert!(matches!(app.input_mode, InputMode::TaskReview));
+        assert_eq!(app.task_review.as_ref().unwrap().tasks.len(), coding::MAX_TASKS_PER_PLAN);
+        assert!(has_message_containing(&app, "나머지 1개는 후속 세션으로 미룹니다."));
+        let deferred = std::fs::read_to_string(
+            app.workspace_journal_dir().join("deferred-tasks.json"),
+        )
+        .unwrap();
+        assert!(deferred.contains("TASK-100"), "{}", deferred);
+        assert!(!deferred.contains("TASK-099"), "{}", deferred);
+    }
+
+    #[test]
+    fn exactly_the_task_limit_is_reviewed_without_deferring() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        let tasks = (0..coding::MAX_TASKS_PER_PLAN)
+            .map(|index| coding_task(&format!("TASK-{:02}", index), &[]))
+            .collect();
+
+        app.handle_task_extraction_response(TaskExtractionResponse { tasks });
+
+        assert!(matches!(app.input_mode, InputMode::TaskReview));
+        assert_eq!(app.task_review.as_ref().unwrap().tasks.len(), coding::MAX_TASKS_PER_PLAN);
+        assert!(!has_message_containing(&app, "후속 세션"));
+    }
+
+    #[test]
+    fn task_review_rejects_deselecting_dependency_and_finishes_without_tasks() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        let mut dependent = coding_task("TASK-01", &[]);
+        dependent.dependencies = vec!["TASK-00".to_string()];
+
+        app.handle_task_extraction_response(TaskExtractionResponse {
+            tasks: vec![coding_task("TASK-00", &[]), dependent],
+        });
+
+        assert!(matches!(app.input_mode, InputMode::TaskReview));
+        assert!(has_message_containing(&app, "1. [x] [TASK-00] TASK-00 title"));
+
+        type_and_submit(&mut app, "1");
+        assert!(has_message_containing(&app, "TASK-00을(를) 제외할 수 없습니다."));
+        type_and_submit(&mut app, "x");
+        assert!(has_message_containing(&app, "잘못된 입력입니다: x."));
+
+        type_and_submit(&mut app, "2");
+        type_and_submit(&mut app, "1");
+        assert!(has_message_containing(&app, "1. [ ] [TASK-00] TASK-00 title"));
+        type_and_submit(&mut app, "");
+
+        assert!(matches!(app.input_mode, InputMode::Done));
+        assert!(app.coding_state.is_none());
+        assert!(has_message_containing(&app, "선택된 작업이 없어"));
+    }
+
+    #[test]
+    fn finishing_with_blocked_tasks_offers_rerun_and_empty_enter_finishes() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_incremental_planner(root.path(), &workspace, 0);
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        assert!(app.is_waiting_for_input());
+        assert!(has_message_containing(&app, "1. [TASK-00] 첫 작업"));
+        assert_eq!(app.headless_exit(), Some(HeadlessExit::TasksBlocked));
+
+        type_and_submit(&mut app, "7");
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        assert!(has_message_containing(&app, "잘못된 선택입니다: 7."));
+
+        type_and_submit(&mut app, "");
+
+        assert!(matches!(app.input_mode, InputMode::Done));
+        assert_eq!(app.coding_state.as_ref().unwrap().task_reports.len(), 1);
+    }
+
+    #[test]
+    fn auto_stash_is_restored_only_after_the_blocked_task_rerun_offer_is_declined() {
+        let (root, workspace) = setup_workspace();
+        std::fs::write(workspace.join("README.md"), "uncommitted edit").unwrap();
+        let config = Config::new("test-key".to_string())
+            .with_incremental_planning(true)
+            .with_max_retries(0)
+            .with_auto_stash(true);
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = app_with_approvable_spec(config, &workspace).with_claude_binary(binary);
+        let readme = || std::fs::read_to_string(workspace.join("README.md")).unwrap();
+
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        assert_eq!(readme(), "init");
+
+        type_and_submit(&mut app, "1");
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        assert_eq!(readme(), "init");
+        assert!(!has_message_containing(&app, "변경을 워크스페이스에 되돌렸습니다"));
+
+        type_and_submit(&mut app, "");
+
+        assert!(matches!(app.input_mode, InputMode::Done));
+        assert_eq!(readme(), "uncommitted edit");
+        assert!(has_message_containing(&app, "변경을 워크스페이스에 되돌렸습니다"));
+    }
+
+    #[test]
+    fn selecting_blocked_task_reruns_it_from_scratch() {
+        let (root, workspace) = setup_workspace();
+        let mut app = app_with_fake_incremental_planner(root.path(), &workspace, 0);
+        app.approve_spec();
+        tick_until_idle(&mut app);
+
+        type_and_submit(&mut app, "1");
+
+        assert!(matches!(app.input_mode, InputMode::Coding));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert!(coding_state.task_reports.is_empty());
+        assert_eq!(coding_state.current_task_index, 0);
+        assert_eq!(coding_state.rerun_return_index, Some(1));
+        assert!(has_message_containing(&app, "[TASK-00] 작업을 처음부터 다시 실행합니다."));
+
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::BlockedTaskSelection));
+        let coding_state = app.coding_state.as_ref().unwrap();
+        assert_eq!(coding_state.tasks.len(), 1);
+        assert_eq!(coding_state.task_reports.len(), 1);
+        assert_eq!(coding_state.current_task_index, 1);
+        let task_starts = app
+            .messages
+            .iter()
+            .filter(|m| m.content.contains("작업 1/1 시작: [TASK-00]"))
+            .count();
+        assert_eq!(task_starts, 2);
+    }
+
+    #[test]
+    fn clarification_agent_uses_workspace_prompt_override() {
+        let root = TempDir::new().unwrap();
+        let prompt_dir = root.path().join(".bear/prompts");
+        std::fs::create_dir_all(&prompt_dir).unwrap();
+        std::fs::write(prompt_dir.join("clarification.md"), "Ask about our house style.\n")
+            .unwrap();
+        let captured_path = root.path().join("captured-system-prompt");
+        let script = format!(
+            r#"#!/bin/sh
+while [ $# -gt 0 ]; do
+  if [ "$1" = "--append-system-prompt-file" ]; then cp "$2" '{}'; fi
+  shift
+done
+cat > /dev/null
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":{{"questions":["질문입니다"]}}}}\n'
+"#,
+            captured_path.display(),
+        );
+        let binary = write_script(root.path(), "fake-clarifier", &script);
+        let config = Config::new("test-key".to_string())
+            .with_prompt_override_mode(PromptOverrideMode::Replace);
+        let mut app = App::new(config).unwrap().with_claude_binary(binary);
+        app.confirmed_workspace = Some(root.path().to_path_buf());
+        app.input_mode = InputMode::RequirementsInput;
+
+        type_and_submit(&mut app, "build a thing");
+        tick_until_idle(&mut app);
+
+        assert!(matches!(app.input_mode, InputMode::ClarificationAnswer));
+        let system_prompt = std::fs::read_to_string(&captured_path).unwrap();
+        assert_eq!(system_prompt, "Ask about our house style.\n");
+    }
+
+    #[test]
+    fn system_prompts_follow_configured_output_language() {
+        let (_root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string()).with_output_language("English".into());
+        let mut app = app_in_coding_phase(config, &workspace);
+
+        let system_prompt = app.system_prompt_for(PromptKind::Coding);
+
+        assert_eq!(system_prompt, coding::coding_agent_system_prompt("English"));
+        assert!(system_prompt.contains("MUST be English unless"));
+        assert!(!system_prompt.contains("Korean"));
+    }
+
+    #[test]
+    fn unreadable_prompt_override_falls_back_to_built_in_prompt() {
+        let (_root, workspace) = setup_workspace();
+        std::fs::create_dir_all(workspace.join(".bear/prompts/coding.md")).unwrap();
+        let mut app = app_in_coding_phase(Config::new("test-key".to_string()), &workspace);
+
+        let system_prompt = app.system_prompt_for(PromptKind::Coding);
+
+        assert_eq!(system_prompt, coding::coding_agent_system_prompt("Korean"));
+        assert!(has_message_containing(&app, "시스템 프롬프트 오버라이드 파일을 읽지 못해"));
+        assert_eq!(
+            app.system_prompt_for(PromptKind::Review),
+            coding::review_agent_system_prompt(),
+        );
+    }
+
+    #[test]
+    fn coding_progress_is_appended_to_the_events_file() {
+        let (root, workspace) = setup_workspace();
+        let events_file = root.path().join("events.jsonl");
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0)
+            .with_events_file(Some(events_file.clone()));
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        app.coding_state.as_mut().unwrap().tasks.push(coding_task("TASK-01", &[]));
+
+        app.save_and_advance_task(
+            "TASK-00".to_string(),
+            CodingTaskStatus::ImplementationBlocked,
+            "차단".to_string(),
+        );
+        tick_until_idle(&mut app);
+
+        let events: Vec<serde_json::Value> = std::fs::read_to_string(&events_file)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let summary: Vec<(&str, &str)> = events
+            .iter()
+            .map(|event| {
+                let task_id = event["task_id"].as_str().unwrap_or("");
+                (event["event"].as_str().unwrap(), task_id)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("task_blocked", "TASK-00"),
+                ("task_started", "TASK-01"),
+                ("task_blocked", "TASK-01"),
+                ("coding_finished", ""),
+            ],
+        );
+        assert_eq!(events[1]["position"], 2);
+        assert_eq!(events[2]["reason"], "fake agent report");
+        assert_eq!(events[3]["blocked_count"], 2);
+        assert!(events.iter().all(|event| event["timestamp"].is_string()));
+    }
+
+    #[test]
+    fn tasks_downstream_of_a_blocked_task_are_skipped_and_coding_continues() {
+        let (root, workspace) = setup_workspace();
+        let config = Config::new("test-key".to_string())
+            .with_max_retries(0);
+        let binary = write_fake_claude_binary(root.path(), 0);
+        let mut app = app_in_coding_phase(config, &workspace).with_claude_binary(binary);
+        let mut direct = coding_task("TASK-01", &[]);
+        direct.dependencies = vec!["TASK-00".to_string()];
+        let mut indirect = coding_task("TASK-02", &[]);
+        indirect.dependencies = vec!["TASK-01".to_string()];
+        let unrelated = coding_task("TASK-03", &[]);
+        app.coding_state.as_mut().unwrap().tasks.extend([direct, indirect, unrelated]);
+
+        app.save_and_advance_task(
+            "TASK-00".to_string(),
+            CodingTaskStatus::ImplementationBlocked,
+            "차단".to_string(),
+        );
+        tick_until_idle(&mut app);
+
+        let reports: Vec<(&str, &str)> = app
+            .coding_state
+            .as_ref()
+            .unwrap()
+            .task_reports
+            .iter()
+            .map(|report| (report.task_id.as_str(), report.report.as_str()))
+            .collect();
+        assert_eq!(
+            reports,
+            vec![
+                ("TASK-00", "차단"),
+                ("TASK-01", "건너뜀: 의존 태스크 TASK-00 차단됨"),
+                ("TASK-02", "건너뜀: 의존 태스크 TASK-01 차단됨"),
+                ("TASK-03", "fake agent report"),
+            ],
+        );
+        assert!(has_message_containing(&app, "[TASK-01] 의존 태스크 TASK-00이(가) 차단되어"));
+        assert!(!has_message_containing(&app, "시작: [TASK-01]"));
+        assert!(has_message_containing(&app, "시작: [TASK-03]"));
+        assert!(has_message_containing(&app, "코딩 단계 완료. 성공: 0, 차단: 4"));
+    }
+
+    fn app_with_spec_draft(line_count: usize) -> App {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        let draft: Vec<String> = (0..line_count).map(|index| format!("line {}", index)).collect();
+        app.last_spec_draft = Some(draft.join("\n"));
+        app.input_mode = InputMode::SpecFeedback;
+        app.terminal_height = 10;
+        app
+    }
+
+    #[test]
+    fn ctrl_p_previews_the_draft_only_during_feedback() {
+        let mut app = App::new(Config::new("test-key".to_string())).unwrap();
+        app.input_mode = InputMode::RequirementsInput;
+
+        app.handle_key_event(ctrl('p'));
+        assert_eq!(app.overlay(), None);
+        assert!(has_message_containing(&app, "초안 미리보기는 스펙/플랜 피드백 단계에서만"));
+
+        let mut app = app_with_spec_draft(3);
+        app.handle_key_event(ctrl('p'));
+        assert_eq!(app.overlay(), Some(Overlay::DraftPreview));
+        for c in "more tests".chars() {
+            app.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.input_buffer, "more tests");
+        assert_eq!(
+            app.draft_preview(),
+            Some(DraftPreview {
+                title: "스펙",
+                lines: vec!["line 0".to_string(), "line 1".to_string(), "line 2".to_string()],
+                first_line: 0,
+                total_lines: 3,
+            }),
+        );
+
+        app.handle_key_event(ctrl('p'));
+        assert_eq!(app.overlay(), None);
+    }
+
+    #[test]
+    fn draft_scroll_stays_within_the_draft() {
+        // 높이 10이면 미리보기 창에 4줄이 보이므로 20줄짜리 초안의 최대 스크롤 위치는 16이다.
+        let mut app = app_with_spec_draft(20);
+        app.handle_key_event(ctrl('p'));
+
+        app.handle_key_event(key(KeyCode::PageUp));
+        assert_eq!(app.draft_preview().unwrap().first_line, 0);
+        app.handle_key_event(key(KeyCode::PageDown));
+        assert_eq!(app.draft_preview().unwrap().first_line, 4);
+        app.handle_key_event(KeyEvent::new(KeyCode::End, KeyModifiers::CONTROL));
+        app.handle_key_event(key(KeyCode::PageDown));
+        let preview = app.draft_preview().unwrap();
+        assert_eq!(preview.first_line, 16);
+        assert_eq!(preview.lines.last().map(String::as_str), Some("line 19"));
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        });
+        assert_eq!(app.draft_preview().unwrap().first_line, 13);
+        assert_eq!(app.scroll_offset, 0);
+
+        // 터미널이 커지면 보이는 줄이 늘어나 스크롤 위치가 초안 끝을 넘지 않도록 당겨진다.
+        app.terminal_height = 40;
+        assert_eq!(app.draft_preview().unwrap().first_line, 1);
+        app.handle_key_event(KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL));
+        assert_eq!(app.draft_preview().unwrap().first_line, 0);
+
+        let mut short = app_with_spec_draft(2);
+        short.handle_key_event(ctrl('p'));
+        short.handle_key_event(key(KeyCode::PageDown));
+        assert_eq!(short.draft_preview().unwrap().first_line, 0);
+    }
 }
@@ -0,0 +1,18 @@
+/// 시스템 클립보드. 리눅스에서는 클립보드를 연 프로세스가 살아 있는 동안 내용을 제공하므로,
+/// 복사할 때마다 새로 열지 않고 처음 연 클립보드를 계속 쓴다.
+#[derive(Default)]
+pub struct SystemClipboard {
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl SystemClipboard {
+    /// `text`를 클립보드에 넣는다. 디스플레이가 없는 SSH 세션처럼 클립보드를 쓸 수 없는
+    /// 환경이면 오류를 반환한다.
+    pub fn copy(&mut self, text: &str) -> Result<(), arboard::Error> {
+        let clipboard = match &mut self.clipboard {
+            Some(clipboard) => clipboard,
+            empty => empty.insert(arboard::Clipboard::new()?),
+        };
+        clipboard.set_text(text)
+    }
+}
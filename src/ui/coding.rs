@@ -1,11 +1,17 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::io;
-use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::git_error::GitError;
+use crate::config::{DEFAULT_INTEGRATION_BRANCH_PREFIX, MergeStrategy};
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
@@ -21,6 +27,39 @@ pub struct CodingTask {
     pub title: String,
     pub description: String,
     pub dependencies: Vec<String>,
+    /// 태스크가 생성하거나 수정할 것으로 예상되는 파일 경로(저장소 기준 상대 경로).
+    /// 플랜에 명시된 경우에만 채워지며 리베이스 충돌 예측에 사용한다.
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// 모노레포에서 태스크가 작업할 하위 디렉토리(저장소 기준 상대 경로). 없으면 워크트리
+    /// 루트에서 코딩하고 빌드한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdir: Option<String>,
+}
+
+impl CodingTask {
+    /// 코딩 에이전트와 빌드/테스트를 실행할 디렉토리. `subdir`이 없거나 워크트리 밖을
+    /// 가리키면 워크트리 루트를 쓴다.
+    pub fn working_directory(&self, worktree_path: &Path) -> PathBuf {
+        match normalized_subdir(self.subdir.as_deref()) {
+            Some(subdir) => worktree_path.join(subdir),
+            None => worktree_path.to_path_buf(),
+        }
+    }
+}
+
+/// 하위 디렉토리 경로에서 `.`과 앞뒤 공백을 걷어 낸다. 비어 있거나 절대 경로이거나 `..`로
+/// 워크트리 밖으로 나갈 수 있으면 None.
+fn normalized_subdir(subdir: Option<&str>) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in Path::new(subdir?.trim()).components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (!normalized.as_os_str().is_empty()).then_some(normalized)
 }
 
 #[derive(Debug, Deserialize)]
@@ -29,7 +68,7 @@ pub struct CodingTaskResult {
     pub report: String,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum CodingTaskStatus {
     #[serde(rename = "IMPLEMENTATION_SUCCESS")]
     ImplementationSuccess,
@@ -37,6 +76,16 @@ pub enum CodingTaskStatus {
     ImplementationBlocked,
 }
 
+impl CodingTaskStatus {
+    /// 요약과 리포트에 보여줄 상태 이름.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CodingTaskStatus::ImplementationSuccess => "성공",
+            CodingTaskStatus::ImplementationBlocked => "차단",
+        }
+    }
+}
+
 pub struct CodingPhaseState {
     pub tasks: Vec<CodingTask>,
     pub current_task_index: usize,
@@ -44,6 +93,403 @@ pub struct CodingPhaseState {
     pub integration_branch: String,
     pub current_task_worktree: Option<TaskWorktreeInfo>,
     pub build_test_commands: Option<BuildTestCommands>,
+    /// `build_test_commands`를 정한 태스크의 하위 디렉토리.
+    pub build_test_subdir: Option<String>,
+    /// 점진적 계획 모드 여부. 켜져 있으면 남은 태스크가 없을 때 다음 태스크를 계획한다.
+    pub incremental_planning: bool,
+    /// 현재 태스크의 리뷰/빌드 진행 기록. 태스크가 끝나면 리포트로 옮겨진다.
+    pub current_task_progress: TaskProgress,
+    /// 차단된 태스크를 다시 실행하는 중이면, 그 태스크가 끝난 뒤 돌아갈 태스크 인덱스.
+    pub rerun_return_index: Option<usize>,
+    /// 사후 분석을 위해 제거하지 않고 남겨 둔 차단된 태스크의 워크트리들.
+    pub retained_worktrees: Vec<RetainedWorktree>,
+    /// 태스크 ID별로 이미 경고한 의존성 파일. 리뷰를 반복해도 같은 파일은 다시 경고하지 않는다.
+    pub flagged_dependency_manifests: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CodingPhaseState {
+    /// `ImplementationBlocked`로 끝난 태스크 ID를 완료된 순서대로 반환한다.
+    pub fn blocked_task_ids(&self) -> Vec<String> {
+        self.task_reports
+            .iter()
+            .filter(|report| report.status == CodingTaskStatus::ImplementationBlocked)
+            .map(|report| report.task_id.clone())
+            .collect()
+    }
+
+    /// 차단된 태스크의 리포트를 지우고 그 태스크를 현재 태스크로 되돌린다.
+    /// 다시 실행한 태스크가 끝나면 `advance_to_next_task`가 원래 위치로 돌아간다.
+    /// 해당 태스크가 없거나 차단 상태가 아니면 false를 반환한다.
+    pub fn reopen_blocked_task(&mut self, task_id: &str) -> bool {
+        let Some(report_index) = self.task_reports.iter().position(|report| {
+            report.task_id == task_id && report.status == CodingTaskStatus::ImplementationBlocked
+        }) else {
+            return false;
+        };
+        let Some(task_index) = self.tasks.iter().position(|task| task.task_id == task_id) else {
+            return false;
+        };
+
+        self.task_reports.remove(report_index);
+        self.rerun_return_index = Some(self.current_task_index);
+        self.current_task_index = task_index;
+        self.current_task_progress = TaskProgress::default();
+        true
+    }
+
+    /// 현재 태스크를 끝내고 다음에 실행할 태스크로 이동한다.
+    pub fn advance_to_next_task(&mut self) {
+        match self.rerun_return_index.take() {
+            Some(return_index) => self.current_task_index = return_index,
+            None => self.current_task_index += 1,
+        }
+    }
+
+    /// 아직 끝나지 않은 태스크를 의존성 상태에 따라 나눈다. 순서는 태스크 목록의 순서를 따른다.
+    pub fn task_schedule(&self) -> TaskSchedule {
+        let mut schedule = TaskSchedule::default();
+        for task in &self.tasks {
+            if self.task_status(&task.task_id).is_some() {
+                continue;
+            }
+            let task_id = task.task_id.clone();
+            if let Some(blocked_dependency) = self.blocked_dependency(&task.task_id) {
+                schedule.blocked.push(UnrunnableTask { task_id, blocked_dependency });
+            } else if task.dependencies.iter().all(|dependency| {
+                self.task_status(dependency) == Some(CodingTaskStatus::ImplementationSuccess)
+                    || !self.tasks.iter().any(|task| &task.task_id == dependency)
+            }) {
+                schedule.ready.push(task_id);
+            } else {
+                schedule.waiting.push(task_id);
+            }
+        }
+        schedule
+    }
+
+    /// `task_id`가 직접 또는 간접으로 의존하는 태스크 중 `ImplementationBlocked`로 끝난 첫
+    /// 태스크의 ID. 그런 태스크가 없으면 None이다.
+    pub fn blocked_dependency(&self, task_id: &str) -> Option<String> {
+        self.find_blocked_dependency(task_id, &mut Vec::new())
+    }
+
+    fn find_blocked_dependency(&self, task_id: &str, visited: &mut Vec<String>) -> Option<String> {
+        // 의존성 순환은 추출 단계에서 걸러지지만, 만약을 대비해 같은 태스크를 두 번 보지 않는다.
+        if visited.iter().any(|visited_id| visited_id == task_id) {
+            return None;
+        }
+        visited.push(task_id.to_string());
+        let task = self.tasks.iter().find(|task| task.task_id == task_id)?;
+        for dependency in &task.dependencies {
+            let found = match self.task_status(dependency) {
+                Some(CodingTaskStatus::ImplementationBlocked) => Some(dependency.clone()),
+                Some(CodingTaskStatus::ImplementationSuccess) => None,
+                None => self.find_blocked_dependency(dependency, visited),
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+
+    /// 태스크의 마지막 리포트 상태. 아직 끝나지 않았으면 None이다.
+    pub fn task_status(&self, task_id: &str) -> Option<CodingTaskStatus> {
+        self.task_reports
+            .iter()
+            .rev()
+            .find(|report| report.task_id == task_id)
+            .map(|report| report.status.clone())
+    }
+}
+
+/// 아직 끝나지 않은 태스크의 실행 예정표.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TaskSchedule {
+    /// 의존하는 태스크가 모두 성공해 바로 실행할 수 있는 태스크.
+    pub ready: Vec<String>,
+    /// 아직 끝나지 않은 태스크를 기다리는 태스크.
+    pub waiting: Vec<String>,
+    /// 의존하는 태스크가 차단되어 실행할 수 없는 태스크.
+    pub blocked: Vec<UnrunnableTask>,
+}
+
+impl TaskSchedule {
+    /// 실행 예정 창에 한 줄씩 보여 줄 항목. 제목은 `tasks`에서 찾는다.
+    pub fn lines(&self, tasks: &[CodingTask]) -> Vec<String> {
+        let label = |task_id: &str| match tasks.iter().find(|task| task.task_id == task_id) {
+            Some(task) => format!("[{}] {}", task_id, task.title),
+            None => format!("[{}]", task_id),
+        };
+        let mut lines = Vec::new();
+        lines.extend(self.ready.iter().map(|task_id| format!("실행 가능  {}", label(task_id))));
+        lines.extend(self.waiting.iter().map(|task_id| format!("대기 중    {}", label(task_id))));
+        lines.extend(self.blocked.iter().map(|task| {
+            format!(
+                "실행 불가  {} — 의존 태스크 {} 차단됨",
+                label(&task.task_id),
+                task.blocked_dependency,
+            )
+        }));
+        if lines.is_empty() {
+            lines.push("남은 태스크가 없습니다.".to_string());
+        }
+        lines
+    }
+}
+
+/// 의존하는 태스크가 차단되어 실행할 수 없는 태스크.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrunnableTask {
+    pub task_id: String,
+    /// 차단으로 끝난 의존 태스크. 간접 의존이면 체인에서 처음 차단된 태스크다.
+    pub blocked_dependency: String,
+}
+
+/// 코딩을 시작하기 전에 사용자가 추출된 태스크의 순서와 포함 여부를 고르는 단계의 상태.
+pub struct TaskReviewState {
+    pub tasks: Vec<CodingTask>,
+    pub included: Vec<bool>,
+}
+
+impl TaskReviewState {
+    pub fn new(tasks: Vec<CodingTask>) -> Self {
+        let included = vec![true; tasks.len()];
+        Self { tasks, included }
+    }
+
+    /// `index`번째 태스크의 포함 여부를 바꾼다. 의존성이 깨지면 바꾸지 않고 이유를 반환한다.
+    pub fn toggle(&mut self, index: usize) -> Result<(), String> {
+        if index >= self.tasks.len() {
+            return Err(format!("태스크 번호는 1부터 {}까지입니다.", self.tasks.len()));
+        }
+        let mut included = self.included.clone();
+        included[index] = !included[index];
+        validate_task_selection(&self.tasks, &included)?;
+        self.included = included;
+        Ok(())
+    }
+
+    /// `from`번째 태스크를 `to`번째 위치로 옮긴다. 의존성이 깨지면 옮기지 않고 이유를 반환한다.
+    pub fn move_task(&mut self, from: usize, to: usize) -> Result<(), String> {
+        let count = self.tasks.len();
+        if from >= count || to >= count {
+            return Err(format!("태스크 번호는 1부터 {}까지입니다.", count));
+        }
+        let mut tasks = self.tasks.clone();
+        let mut included = self.included.clone();
+        let task = tasks.remove(from);
+        let is_included = included.remove(from);
+        tasks.insert(to, task);
+        included.insert(to, is_included);
+        validate_task_selection(&tasks, &included)?;
+        self.tasks = tasks;
+        self.included = included;
+        Ok(())
+    }
+
+    /// 포함된 태스크를 현재 순서대로 반환한다.
+    pub fn selected_tasks(self) -> Vec<CodingTask> {
+        self.tasks
+            .into_iter()
+            .zip(self.included)
+            .filter(|(_, included)| *included)
+            .map(|(task, _)| task)
+            .collect()
+    }
+
+    /// 번호, 포함 여부, 의존성을 담은 태스크 목록.
+    pub fn describe(&self) -> String {
+        let mut lines = Vec::new();
+        for (i, (task, included)) in self.tasks.iter().zip(&self.included).enumerate() {
+            let mark = if *included { "[x]" } else { "[ ]" };
+            let mut line = format!("{}. {} [{}] {}", i + 1, mark, task.task_id, task.title);
+            if !task.dependencies.is_empty() {
+                line.push_str(&format!(" (의존: {})", task.dependencies.join(", ")));
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+/// 한 세션에서 처리할 수 있는 최대 태스크 수. 점진적 계획 모드도 이 수에서 계획을 멈춘다.
+/// 추출 프롬프트는 이에 맞춰 ID를 `TASK-00`부터 `TASK-99`까지 매기게 하지만, ID 형식에는
+/// 자릿수 제한이 없으므로 한도는 ID 충돌이 아니라 한 세션의 크기를 제한하기 위한 것이다.
+pub const MAX_TASKS_PER_PLAN: usize = 100;
+
+/// 태스크 수가 `MAX_TASKS_PER_PLAN` 이하인지 확인한다.
+pub fn validate_task_count(tasks: &[CodingTask]) -> Result<(), String> {
+    if tasks.len() > MAX_TASKS_PER_PLAN {
+        return Err(format!(
+            "추출된 작업이 {}개로 한 세션에서 처리할 수 있는 최대 {}개를 넘습니다. \
+             플랜을 여러 세션으로 나누는 것을 권장합니다.",
+            tasks.len(),
+            MAX_TASKS_PER_PLAN,
+        ));
+    }
+    Ok(())
+}
+
+/// 앞의 `MAX_TASKS_PER_PLAN`개 태스크와 후속 세션으로 미룰 나머지로 나눈다.
+/// 남기는 태스크가 미루는 태스크에 의존하면 앞부분만 따로 실행할 수 없으므로 오류를 반환한다.
+pub fn split_at_task_limit(
+    mut tasks: Vec<CodingTask>,
+) -> Result<(Vec<CodingTask>, Vec<CodingTask>), String> {
+    let deferred = tasks.split_off(tasks.len().min(MAX_TASKS_PER_PLAN));
+    for task in &tasks {
+        if let Some(dependency) = task
+            .dependencies
+            .iter()
+            .find(|dependency| deferred.iter().any(|other| &other.task_id == *dependency))
+        {
+            return Err(format!(
+                "{}이(가) 뒤로 미뤄질 태스크 {}에 의존합니다.",
+                task.task_id, dependency,
+            ));
+        }
+    }
+    Ok((tasks, deferred))
+}
+
+/// 후속 세션으로 미룬 태스크 목록을 저널 디렉토리에 저장한다.
+pub fn save_deferred_tasks(dir: &Path, tasks: &[CodingTask]) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let file_path = dir.join("deferred-tasks.json");
+    let json = serde_json::to_string_pretty(tasks).map_err(io::Error::other)?;
+    fs::write(&file_path, json)?;
+
+    Ok(file_path)
+}
+
+/// 추출된 태스크 목록이 일관된지 확인한다. 태스크 ID가 겹치거나, 없는 태스크에 의존하거나,
+/// 의존성이 순환하면 브랜치를 만들기 전에 알릴 수 있도록 이유를 담은 오류를 반환한다.
+pub fn validate_task_graph(tasks: &[CodingTask]) -> Result<(), String> {
+    let mut seen_ids = std::collections::HashSet::new();
+    for task in tasks {
+        if !seen_ids.insert(task.task_id.as_str()) {
+            return Err(format!("태스크 ID {}가 중복되었습니다.", task.task_id));
+        }
+    }
+    for task in tasks {
+        if let Some(missing) = task
+            .dependencies
+            .iter()
+            .find(|dependency| !seen_ids.contains(dependency.as_str()))
+        {
+            return Err(format!(
+                "{}이(가) 존재하지 않는 태스크 {}에 의존합니다.",
+                task.task_id, missing,
+            ));
+        }
+    }
+    match find_dependency_cycle(tasks) {
+        Some(cycle) => Err(format!("태스크 의존성이 순환합니다: {}", cycle.join(" -> "))),
+        None => Ok(()),
+    }
+}
+
+/// 의존성 그래프를 깊이 우선으로 훑어 순환이 있으면 순환 경로를 반환한다.
+/// 모든 의존 ID가 목록에 있다고 가정한다.
+fn find_dependency_cycle(tasks: &[CodingTask]) -> Option<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum VisitState {
+        Unvisited,
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        tasks: &[CodingTask],
+        states: &mut [VisitState],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<String>> {
+        states[index] = VisitState::InProgress;
+        path.push(index);
+        for dependency in &tasks[index].dependencies {
+            let next = tasks.iter().position(|task| &task.task_id == dependency)?;
+            match states[next] {
+                VisitState::InProgress => {
+                    let start = path.iter().position(|&i| i == next).unwrap_or(0);
+                    let mut cycle: Vec<String> =
+                        path[start..].iter().map(|&i| tasks[i].task_id.clone()).collect();
+                    cycle.push(tasks[next].task_id.clone());
+                    return Some(cycle);
+                }
+                VisitState::Unvisited => {
+                    if let Some(cycle) = visit(next, tasks, states, path) {
+                        return Some(cycle);
+                    }
+                }
+                VisitState::Done => {}
+            }
+        }
+        path.pop();
+        states[index] = VisitState::Done;
+        None
+    }
+
+    let mut states = vec![VisitState::Unvisited; tasks.len()];
+    for index in 0..tasks.len() {
+        if states[index] == VisitState::Unvisited
+            && let Some(cycle) = visit(index, tasks, &mut states, &mut Vec::new())
+        {
+            return Some(cycle);
+        }
+    }
+    None
+}
+
+/// 포함된 태스크가 의존하는 태스크도 포함되어 있고 목록에서 더 앞에 있는지 확인한다.
+/// 목록에 없는 태스크 ID에 대한 의존성은 `validate_task_graph`에서 미리 걸러지므로 무시한다.
+pub fn validate_task_selection(tasks: &[CodingTask], included: &[bool]) -> Result<(), String> {
+    for (index, task) in tasks.iter().enumerate() {
+        if !included[index] {
+            continue;
+        }
+        for dependency in &task.dependencies {
+            let Some(dependency_index) = tasks.iter().position(|t| &t.task_id == dependency)
+            else {
+                continue;
+            };
+            if !included[dependency_index] {
+                return Err(format!(
+                    "{}이(가) {}에 의존하므로 {}을(를) 제외할 수 없습니다.",
+                    task.task_id, dependency, dependency,
+                ));
+            }
+            if dependency_index > index {
+                return Err(format!(
+                    "{}은(는) 의존하는 {}보다 앞에 올 수 없습니다.",
+                    task.task_id, dependency,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 태스크의 코드 리뷰가 어떻게 끝났는지.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewApproval {
+    /// 리뷰를 받지 않았다. 리뷰 최대 반복 횟수가 0이거나 리뷰 전에 차단된 경우.
+    #[default]
+    NotReviewed,
+    /// 리뷰어가 승인했다.
+    Approved,
+    /// 리뷰 최대 반복 횟수에 도달하는 등의 이유로 리뷰어 승인 없이 넘어갔다.
+    AutoApproved,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TaskProgress {
+    pub review_iterations: usize,
+    pub review_approval: ReviewApproval,
+    /// 빌드/테스트 검증 결과. 검증 단계까지 가지 못했으면 None.
+    pub build_test_passed: Option<bool>,
 }
 
 pub struct TaskWorktreeInfo {
@@ -51,6 +497,13 @@ pub struct TaskWorktreeInfo {
     pub task_branch: String,
 }
 
+/// `keep_failed_worktrees` 설정으로 남겨 둔 차단된 태스크의 워크트리와 브랜치.
+pub struct RetainedWorktree {
+    pub task_id: String,
+    pub worktree_path: PathBuf,
+    pub task_branch: String,
+}
+
 pub enum RebaseOutcome {
     Success,
     Conflict { conflicted_files: Vec<String> },
@@ -75,12 +528,76 @@ pub struct TaskReport {
     pub status: CodingTaskStatus,
     pub report: String,
     pub report_file_path: PathBuf,
+    /// `continue_on_build_failure` 설정으로 빌드/테스트가 실패한 채 머지되었는지 여부.
+    pub merged_with_failing_build: bool,
+    pub progress: TaskProgress,
+}
+
+/// 코딩 단계 종료 시 저널 디렉토리에 기록하는 `summary.json`의 내용.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CodingSummary {
+    pub integration_branch: String,
+    pub success_count: usize,
+    pub blocked_count: usize,
+    pub tasks: Vec<TaskSummary>,
+    /// `verify_build_test` 설정이 꺼져 머지 전 빌드/테스트 검증을 건너뛰었는지 여부.
+    #[serde(default)]
+    pub build_test_skipped: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskSummary {
+    pub task_id: String,
+    pub title: String,
+    pub status: CodingTaskStatus,
+    pub report_file_path: Option<PathBuf>,
+    pub review_iterations: usize,
+    /// 이 필드가 생기기 전에 저장된 `summary.json`도 읽을 수 있도록 없으면 기본값을 쓴다.
+    #[serde(default)]
+    pub review_approval: ReviewApproval,
+    pub build_test_passed: Option<bool>,
 }
 
 #[derive(Clone)]
 pub struct BuildTestCommands {
     pub build: String,
     pub test: String,
+    /// 테스트 하나(또는 테스트 바이너리 하나)에 적용할 제한 시간(초). 테스트 프레임워크가
+    /// 지원할 때만 설정되며, 하나의 테스트가 멈춰도 전체 스위트의 결과를 잃지 않게 한다.
+    pub per_test_timeout_secs: Option<u32>,
+}
+
+impl BuildTestCommands {
+    /// 실제로 실행할 테스트 명령어. 테스트별 제한 시간이 있으면 프레임워크에 맞는 플래그를 넣는다.
+    pub fn test_command(&self) -> String {
+        match self.per_test_timeout_secs {
+            Some(timeout_secs) => inject_per_test_timeout(&self.test, timeout_secs),
+            None => self.test.clone(),
+        }
+    }
+}
+
+/// 테스트별 제한 시간을 지원하는 프레임워크의 명령어에만 제한 시간 플래그를 넣는다.
+/// 이미 제한 시간 플래그가 있거나 지원하지 않는 프레임워크면 그대로 반환한다.
+/// - ctest: `--timeout <초>` (테스트마다)
+/// - go test: `-timeout <초>s` (패키지 테스트 바이너리마다)
+/// - pytest: `--timeout=<초>` (pytest-timeout 플러그인이 있을 때만 설정됨)
+fn inject_per_test_timeout(test_command: &str, timeout_secs: u32) -> String {
+    if test_command.contains("-timeout") {
+        return test_command.to_string();
+    }
+
+    if test_command.starts_with("ctest") {
+        return format!("{} --timeout {}", test_command, timeout_secs);
+    }
+    if let Some(arguments) = test_command.strip_prefix("go test") {
+        return format!("go test -timeout {}s{}", timeout_secs, arguments);
+    }
+    if test_command.starts_with("pytest") || test_command.starts_with("python -m pytest") {
+        return format!("{} --timeout={}", test_command, timeout_secs);
+    }
+
+    test_command.to_string()
 }
 
 pub enum BuildTestOutcome {
@@ -107,6 +624,34 @@ pub enum BuildTestRepairStatus {
 pub struct ReviewResult {
     pub review_result: ReviewStatus,
     pub review_comment: String,
+    /// 심각도를 표시한 지적 사항. 예전 형식의 리뷰처럼 없으면 비어 있다.
+    #[serde(default)]
+    pub findings: Vec<ReviewFinding>,
+}
+
+impl ReviewResult {
+    /// 변경을 요청했지만 지적 사항이 모두 차단하지 않는 것이라 고치지 않고 넘어가도 되는지
+    /// 판단한다. 심각도를 표시한 지적이 하나도 없으면 변경 요청을 그대로 따른다.
+    pub fn has_only_nonblocking_findings(&self) -> bool {
+        self.review_result == ReviewStatus::RequestChanges
+            && !self.findings.is_empty()
+            && self.findings.iter().all(|finding| finding.severity == FindingSeverity::Nonblocking)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewFinding {
+    pub severity: FindingSeverity,
+    pub description: String,
+}
+
+/// 리뷰 지적 사항의 심각도. 차단하는 지적이 있을 때만 변경을 요청한다.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub enum FindingSeverity {
+    #[serde(rename = "BLOCKING")]
+    Blocking,
+    #[serde(rename = "NONBLOCKING")]
+    Nonblocking,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -136,12 +681,16 @@ pub fn task_extraction_schema() -> serde_json::Value {
                         "dependencies": {
                             "type": "array",
                             "items": { "type": "string" }
-                        }
+                        },
+                        "files": {
+                            "type": "array",
+                            "items": { "type": "string" }
+                        },
+                        "subdir": { "type": "string" }
                     },
                     "required": ["task_id", "title", "description", "dependencies"],
                     "additionalProperties": false
-                },
-                "minItems": 1
+                }
             }
         },
         "required": ["tasks"],
@@ -210,6 +759,21 @@ pub fn review_result_schema() -> serde_json::Value {
             },
             "review_comment": {
                 "type": "string"
+            },
+            "findings": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "severity": {
+                            "type": "string",
+                            "enum": ["BLOCKING", "NONBLOCKING"]
+                        },
+                        "description": { "type": "string" }
+                    },
+                    "required": ["severity", "description"],
+                    "additionalProperties": false
+                }
             }
         },
         "required": ["review_result", "review_comment"],
@@ -221,7 +785,7 @@ pub fn review_result_schema() -> serde_json::Value {
 // Prompts – Task Extraction
 // ---------------------------------------------------------------------------
 
-pub fn task_extraction_system_prompt() -> &'static str {
+pub fn task_extraction_system_prompt(output_language: &str) -> String {
     r#"You are a task extraction assistant. Your job is to parse an approved implementation plan and extract individual tasks with their dependency relationships.
 
 Rules:
@@ -230,11 +794,14 @@ Rules:
 - The maximum number of tasks allowed in a single plan is 100 (i.e., "TASK-00" through "TASK-99").
 - For each task, provide the title and a comprehensive description containing ALL implementation details from the plan: file paths, new symbols, edit intent, pseudocode, acceptance criteria.
 - List direct dependency task_ids in the "dependencies" array. If a task has no dependencies, use an empty array.
+- If the plan names the files a task creates or modifies, list them in the "files" array as repository-relative paths. Omit the field when the plan does not say.
+- If the plan scopes a task to one project inside a monorepo (e.g., "services/api"), set "subdir" to that repository-relative directory so the task is coded, built, and tested there. Omit the field for tasks that work at the repository root or span several projects.
 - Return tasks in topological order: tasks with no dependencies first, followed by tasks whose dependencies all appear earlier in the list.
 - If the plan contains no explicit task decomposition section, treat the entire plan as a single task with task id "TASK-00".
-- Output MUST be Korean for titles and descriptions, preserving code identifiers as-is.
+- Output MUST be {{OUTPUT_LANGUAGE}} for titles and descriptions, preserving code identifiers as-is.
 
 Output MUST be valid JSON conforming to the provided JSON Schema."#
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
 }
 
 const TASK_EXTRACTION_PROMPT_TEMPLATE: &str = r#"Extract all implementation tasks from the approved development plan.
@@ -247,16 +814,28 @@ Output MUST be valid JSON conforming to the provided JSON Schema.
 You MUST read the plan file below before extracting tasks:
 - {{PLAN_PATH}}"#;
 
+const TASK_EXTRACTION_RETRY_HINT: &str = r#"
+
+---
+
+The previous extraction from this plan returned no tasks, which usually means the plan has no explicit task decomposition section.
+In that case you MUST treat the entire plan as a single task with task id "TASK-00" and return exactly that one task."#;
+
 pub fn build_task_extraction_prompt(plan_path: &Path) -> String {
     TASK_EXTRACTION_PROMPT_TEMPLATE
         .replace("{{PLAN_PATH}}", &plan_path.display().to_string())
 }
 
+/// 첫 추출 결과가 비었을 때 다시 추출하는 프롬프트. 플랜 전체를 `TASK-00` 하나로 보라고 알린다.
+pub fn build_task_extraction_retry_prompt(plan_path: &Path) -> String {
+    build_task_extraction_prompt(plan_path) + TASK_EXTRACTION_RETRY_HINT
+}
+
 // ---------------------------------------------------------------------------
 // Prompts – Coding Agent
 // ---------------------------------------------------------------------------
 
-pub fn coding_agent_system_prompt() -> &'static str {
+pub fn coding_agent_system_prompt(output_language: &str) -> String {
     r#"# Role
 
 You are the **coding** assistant. Your job is to implement the approved plan by creating and modifying code based on the provided specification.
@@ -317,17 +896,17 @@ You MUST implement code against the plan and the specification by checking the f
 
 # Output Language
 
-Your default output language MUST be Korean unless explicitly requested otherwise.
+Your default output language MUST be {{OUTPUT_LANGUAGE}} unless explicitly requested otherwise.
 
 - Code content rule:
   - Code identifiers (symbol names, file paths, configuration keys, command names) MUST follow the repository's established conventions and MUST NOT be translated or localized.
-  - Do NOT force Korean into identifiers. Keep identifiers idiomatic for the language and consistent with the codebase.
+  - Do NOT force {{OUTPUT_LANGUAGE}} into identifiers. Keep identifiers idiomatic for the language and consistent with the codebase.
 
 - Comments and documentation rule:
-  - Write developer-facing comments in Korean by default.
-  - Write developer-facing documentation in Korean by default.
-  - If a comment or documentation sentence would lose precision or become ambiguous in Korean, you MAY use English for that specific sentence only. Keep such English minimal and continue in Korean immediately afterward.
-  - Preserve exact technical tokens unchanged (e.g., `NULL`, `RAII`, error messages, CLI output, config keys), even inside Korean comments.
+  - Write developer-facing comments in {{OUTPUT_LANGUAGE}} by default.
+  - Write developer-facing documentation in {{OUTPUT_LANGUAGE}} by default.
+  - If a comment or documentation sentence would lose precision or become ambiguous in {{OUTPUT_LANGUAGE}}, you MAY use English for that specific sentence only. Keep such English minimal and continue in {{OUTPUT_LANGUAGE}} immediately afterward.
+  - Preserve exact technical tokens unchanged (e.g., `NULL`, `RAII`, error messages, CLI output, config keys), even inside comments written in {{OUTPUT_LANGUAGE}}.
 
 - User override:
   - If the user explicitly requests English output or English documentation, follow the user's request.
@@ -622,6 +1201,7 @@ Include if the task is incomplete:
 Git commit created during this session, including the commit hash and subject line:
 - `<commit_hash>`: `<subject line>`
 >>>"#
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
 }
 
 const CODING_USER_PROMPT_TEMPLATE: &str = r#"Based on the given specification and plan:
@@ -653,12 +1233,22 @@ You MUST read following files for context before writing code:
 Worktree context:
 - Integration Branch: {{INTEGRATION_BRANCH}}"#;
 
+const DEPENDENCY_CHANGE_PROHIBITION: &str = r#"
+
+---
+
+Dependency policy:
+- You MUST NOT add, remove, or upgrade any dependency (e.g. `cargo add`, `npm install <pkg>`, `pip install`, `go get`).
+- You MUST NOT modify dependency manifests or lockfiles such as `Cargo.toml`, `Cargo.lock`, `package.json`, `package-lock.json`, `go.mod`, `go.sum`, `requirements.txt`, or `pyproject.toml`.
+- If the task cannot be completed without a dependency change, report IMPLEMENTATION_BLOCKED and name the required dependency in the report."#;
+
 pub fn build_coding_task_prompt(
     task: &CodingTask,
     spec_path: &Path,
     plan_path: &Path,
     upstream_report_paths: &[PathBuf],
     integration_branch: &str,
+    allow_dependency_changes: bool,
 ) -> String {
     let upstream_section = if upstream_report_paths.is_empty() {
         "  - N/A".to_string()
@@ -670,14 +1260,20 @@ pub fn build_coding_task_prompt(
             .join("\n")
     };
 
-    CODING_USER_PROMPT_TEMPLATE
+    let prompt = CODING_USER_PROMPT_TEMPLATE
         .replace("{{TASK_ID}}", &task.task_id)
         .replace("{{TASK_TITLE}}", &task.title)
         .replace("{{TASK_DESCRIPTION}}", &task.description)
         .replace("{{SPEC_PATH}}", &spec_path.display().to_string())
         .replace("{{PLAN_PATH}}", &plan_path.display().to_string())
         .replace("{{UPSTREAM_REPORT_PATHS}}", &upstream_section)
-        .replace("{{INTEGRATION_BRANCH}}", integration_branch)
+        .replace("{{INTEGRATION_BRANCH}}", integration_branch);
+
+    if allow_dependency_changes {
+        prompt
+    } else {
+        prompt + DEPENDENCY_CHANGE_PROHIBITION
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -751,11 +1347,16 @@ pub fn build_conflict_resolution_prompt(
     integration_branch: &str,
     conflicted_files: &[String],
 ) -> String {
-    let files_section = conflicted_files
-        .iter()
-        .map(|f| format!("  - {}", f))
-        .collect::<Vec<_>>()
-        .join("\n");
+    let files_section = if conflicted_files.is_empty() {
+        "  - (no unmerged files were listed; run `git status` to see where the rebase stopped)"
+            .to_string()
+    } else {
+        conflicted_files
+            .iter()
+            .map(|f| format!("  - {}", f))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
 
     CONFLICT_RESOLUTION_PROMPT_TEMPLATE
         .replace("{{INTEGRATION_BRANCH}}", integration_branch)
@@ -776,7 +1377,7 @@ Test command: {{TEST_COMMAND}}
 
 Error output:
 {{ERROR_OUTPUT}}
-
+{{PREVIOUS_ATTEMPTS}}
 Hard requirement (do this before changing code):
 You MUST determine whether the failure is caused by (a) integration branch changes, (b) this task's changes, or (c) an interaction between them. Do NOT start by patching files directly based only on the error text.
 
@@ -835,17 +1436,32 @@ If you cannot fix the issue without changing requirements or introducing a risky
 Output requirements:
 - Output MUST be valid JSON conforming to the provided JSON Schema."#;
 
+/// 빌드/테스트 수리 프롬프트. `attempt`는 1부터 세는 수리 시도 번호이며, 두 번째 시도부터는
+/// 이전 수리가 실패를 고치지 못했고 오류 출력이 그 뒤에 다시 실행한 결과라는 안내를 붙인다.
 pub fn build_build_test_repair_prompt(
     task_id: &str,
     build_command: &str,
     test_command: &str,
     error_output: &str,
+    attempt: usize,
 ) -> String {
+    let previous_attempts = if attempt > 1 {
+        format!(
+            "\nThis is repair attempt {}. The previous {} repair attempt(s) in this session \
+             did not fix the failure; the error output above is from the build/test run after \
+             the latest attempt. Do not repeat a fix that already failed.\n",
+            attempt,
+            attempt - 1,
+        )
+    } else {
+        String::new()
+    };
     BUILD_TEST_REPAIR_PROMPT_TEMPLATE
         .replace("{{TASK_ID}}", task_id)
         .replace("{{BUILD_COMMAND}}", build_command)
         .replace("{{TEST_COMMAND}}", test_command)
         .replace("{{ERROR_OUTPUT}}", error_output)
+        .replace("{{PREVIOUS_ATTEMPTS}}", &previous_attempts)
 }
 
 // ---------------------------------------------------------------------------
@@ -915,6 +1531,14 @@ Your default output language MUST be English.
 - `APPROVED`: the implementation is sound, complete, and meets all requirements.
 - `REQUEST_CHANGES`: the implementation has issues that must be addressed before approval.
 
+# Finding severity (mandatory)
+
+List every finding in `findings` with a severity:
+- `BLOCKING`: a defect that must be fixed before merging (incorrect behavior, missing requirement, failing or missing tests for required behavior, security or data-loss risk).
+- `NONBLOCKING`: a nit or suggestion that can be addressed later (naming, style, minor refactoring, optional improvements).
+
+Use `REQUEST_CHANGES` only when at least one finding is `BLOCKING`. If only `NONBLOCKING` findings remain, the implementation is approved and those findings are recorded in the task report for later.
+
 ---
 
 # Output
@@ -925,6 +1549,9 @@ Output MUST be valid JSON conforming to the provided JSON Schema.
 ## Review result
 `APPROVED` or `REQUEST_CHANGES`
 
+## Findings
+Each finding with its `severity` (`BLOCKING` or `NONBLOCKING`) and a one-line `description`. Use an empty list when there are no findings.
+
 ## Review comment (Markdown)
 ```markdown
 # Review Result
@@ -1083,38 +1710,208 @@ pub fn build_coding_revision_prompt(
 // Git Operations
 // ---------------------------------------------------------------------------
 
+/// 통합 브랜치 이름 규칙과 분기할 기준 브랜치.
+#[derive(Debug, Clone, Copy)]
+pub struct IntegrationBranchOptions<'a> {
+    /// 브랜치 이름 `<prefix>/<세션 이름>-<UUID>`의 접두사.
+    pub prefix: &'a str,
+    /// 지정하면 현재 HEAD 대신 이 브랜치에서 분기한다.
+    pub base_branch: Option<&'a str>,
+}
+
+impl Default for IntegrationBranchOptions<'_> {
+    fn default() -> Self {
+        Self {
+            prefix: DEFAULT_INTEGRATION_BRANCH_PREFIX,
+            base_branch: None,
+        }
+    }
+}
+
+/// 저장소의 기본 브랜치 이름. `origin/HEAD`가 가리키는 원격 기본 브랜치가 있으면 그 이름을,
+/// 없으면 현재 체크아웃한 브랜치 이름을 반환한다. `main`이나 `master`를 가정하지 않으며,
+/// detached HEAD이거나 git 저장소가 아니면 None.
+pub fn detect_default_branch(workspace: &Path) -> Option<String> {
+    let remote_head = ["symbolic-ref", "--quiet", "--short", "refs/remotes/origin/HEAD"];
+    if let Ok(remote_head) = git_stdout(workspace, &remote_head, None)
+        && let Some(branch) = remote_head.strip_prefix("origin/")
+    {
+        return Some(branch.to_string());
+    }
+    git_stdout(workspace, &["symbolic-ref", "--quiet", "--short", "HEAD"], None)
+        .ok()
+        .filter(|branch| !branch.is_empty())
+}
+
 pub fn create_integration_branch(
     workspace: &Path,
     session_name: &str,
-) -> Result<String, String> {
-    let branch_name = format!("bear/integration/{}-{}", session_name, Uuid::new_v4());
+    options: &IntegrationBranchOptions,
+) -> Result<String, GitError> {
+    let branch_name = format!(
+        "{}/{}-{}",
+        options.prefix.trim_end_matches('/'),
+        session_name,
+        Uuid::new_v4(),
+    );
+    let mut args = vec!["checkout", "-b", &branch_name];
+    if let Some(base_branch) = options.base_branch {
+        let commit = format!("{}^{{commit}}", base_branch);
+        if git_stdout(workspace, &["rev-parse", "--verify", "--quiet", &commit], None).is_err() {
+            return Err(GitError::BaseBranchNotFound { branch: base_branch.to_string() });
+        }
+        args.push(base_branch);
+    }
 
     let output = Command::new("git")
         .current_dir(workspace)
-        .args(["checkout", "-b", &branch_name])
+        .args(&args)
         .output()
-        .map_err(|e| format!("failed to execute git checkout -b: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git checkout -b", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to create integration branch: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to create integration branch", &output));
     }
 
     Ok(branch_name)
 }
 
+/// 통합 브랜치를 만들 수 없는 워크스페이스 상태.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum WorkspacePreflightError {
+    #[error("{path} is not a git repository")]
+    NotARepository { path: String },
+
+    #[error("workspace has uncommitted changes: {}", changed_files.join(", "))]
+    DirtyWorkingTree { changed_files: Vec<String> },
+
+    #[error("workspace HEAD is detached")]
+    DetachedHead,
+
+    #[error("failed to inspect workspace: {message}")]
+    GitFailed { message: String },
+}
+
+/// 통합 브랜치를 만들기 전에 워크스페이스가 git 저장소이고, 브랜치를 체크아웃한 상태이며,
+/// 추적 중인 파일에 커밋하지 않은 변경이 없는지 확인한다. 추적하지 않는 파일(저널 디렉토리 포함)은
+/// 브랜치에 섞여 들어가지 않으므로 검사하지 않는다.
+pub fn check_workspace_ready(workspace: &Path) -> Result<(), WorkspacePreflightError> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .current_dir(workspace)
+            .args(args)
+            .output()
+            .map_err(|e| WorkspacePreflightError::GitFailed {
+                message: format!("failed to execute git {}: {}", args[0], e),
+            })
+    };
+
+    let inside = git(&["rev-parse", "--is-inside-work-tree"])?;
+    if !inside.status.success() || String::from_utf8_lossy(&inside.stdout).trim() != "true" {
+        return Err(WorkspacePreflightError::NotARepository {
+            path: workspace.display().to_string(),
+        });
+    }
+
+    if !git(&["symbolic-ref", "-q", "HEAD"])?.status.success() {
+        return Err(WorkspacePreflightError::DetachedHead);
+    }
+
+    // `-z`를 쓰면 경로를 따옴표로 감싸지 않고, 이름 변경은 "old -> new" 대신 새 경로와 원래
+    // 경로를 NUL로 나눠 출력한다.
+    let status = git(&["status", "--porcelain", "-z", "--untracked-files=no"])?;
+    if !status.status.success() {
+        return Err(WorkspacePreflightError::GitFailed {
+            message: String::from_utf8_lossy(&status.stderr).trim().to_string(),
+        });
+    }
+    let stdout = String::from_utf8_lossy(&status.stdout);
+    let mut entries = stdout.split('\0').filter(|entry| !entry.is_empty());
+    let mut changed_files = Vec::new();
+    while let Some(entry) = entries.next() {
+        let (code, path) = (entry.get(..2).unwrap_or_default(), entry.get(3..).unwrap_or_default());
+        changed_files.push(path.to_string());
+        if code.contains(['R', 'C']) {
+            entries.next();
+        }
+    }
+    if !changed_files.is_empty() {
+        return Err(WorkspacePreflightError::DirtyWorkingTree { changed_files });
+    }
+
+    Ok(())
+}
+
+/// 자동 stash에서 제외하는 경로. 세션 저널은 실행 내내 워크스페이스에 써야 하므로 stash에 넣지 않는다.
+const AUTO_STASH_PATHSPEC: [&str; 2] = [".", ":(exclude).bear"];
+
+/// 워크스페이스의 커밋하지 않은 변경을 추적하지 않는 파일까지 stash에 넣고 그 stash 커밋의
+/// 해시를 반환한다. stash 목록의 번호(`stash@{n}`)는 사용자가 다른 stash를 만들면 바뀌므로
+/// 해시로 기억한다. 넣을 변경이 없으면 stash를 만들지 않고 None을 반환한다.
+pub fn stash_working_changes(
+    workspace: &Path,
+    session_name: &str,
+) -> Result<Option<String>, GitError> {
+    let mut status_args = vec!["status", "--porcelain", "--"];
+    status_args.extend(AUTO_STASH_PATHSPEC);
+    if git_stdout(workspace, &status_args, None)?.is_empty() {
+        return Ok(None);
+    }
+
+    let message = format!("bear auto-stash: {}", session_name);
+    let mut stash_args = vec!["stash", "push", "--include-untracked", "-m", &message, "--"];
+    stash_args.extend(AUTO_STASH_PATHSPEC);
+    git_stdout(workspace, &stash_args, None)
+        .map_err(|err| err.within("failed to stash working changes"))?;
+
+    git_stdout(workspace, &["rev-parse", "--verify", "stash@{0}"], None).map(Some)
+}
+
+/// `stash_commit`이 지금 stash 목록에서 몇 번째(`stash@{n}`)인지 찾는다. 목록에 없으면 None.
+pub fn find_stash_entry(workspace: &Path, stash_commit: &str) -> Result<Option<String>, GitError> {
+    let list = git_stdout(workspace, &["stash", "list", "--format=%H"], None)?;
+    Ok(list
+        .lines()
+        .position(|commit| commit == stash_commit)
+        .map(|index| format!("stash@{{{}}}", index)))
+}
+
+/// `stash_working_changes`로 넣은 변경을 워크스페이스에 되돌리고 stash 목록에서 지운다.
+/// 되돌리다 충돌하면 git이 stash를 목록에 남겨 두므로 `find_stash_entry`로 위치를 찾아 알릴 수 있다.
+pub fn restore_stashed_changes(workspace: &Path, stash_commit: &str) -> Result<(), GitError> {
+    let Some(entry) = find_stash_entry(workspace, stash_commit)? else {
+        return Err(GitError::UnknownRevision {
+            context: "failed to restore auto-stash".to_string(),
+            message: format!("stash {} is no longer in the stash list", stash_commit),
+        });
+    };
+    git_stdout(workspace, &["stash", "pop", &entry], None)
+        .map(|_| ())
+        .map_err(|err| err.within("failed to restore auto-stash"))
+}
+
+/// 워크트리 위치를 따로 정하지 않았을 때 쓰는 디렉토리. 워크스페이스의 부모 디렉토리를
+/// 어지럽히지 않고, 부모에 쓸 권한이 없어도 워크트리를 만들 수 있도록 시스템 임시 디렉토리를 쓴다.
+pub fn default_worktree_root() -> PathBuf {
+    std::env::temp_dir().join("bear-worktrees")
+}
+
+/// `worktree_root` 아래에 `<워크스페이스 이름>-bear-worktree-<uuid>` 워크트리를 만든다.
+/// 상대 경로인 `worktree_root`는 워크스페이스 기준이며, 없으면 만든다.
 pub fn create_worktree(
     workspace: &Path,
+    worktree_root: &Path,
     integration_branch: &str,
-) -> Result<PathBuf, String> {
+) -> Result<PathBuf, GitError> {
     let workspace_dir_name = workspace
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("workspace");
 
-    let worktree_path = workspace
-        .parent()
-        .unwrap_or(workspace)
+    let worktree_root = workspace.join(worktree_root);
+    fs::create_dir_all(&worktree_root)
+        .map_err(|e| GitError::execution("failed to create worktree root directory", e))?;
+    let worktree_path = worktree_root
         .join(format!("{}-bear-worktree-{}", workspace_dir_name, Uuid::new_v4()));
 
     let output = Command::new("git")
@@ -1126,11 +1923,10 @@ pub fn create_worktree(
             integration_branch,
         ])
         .output()
-        .map_err(|e| format!("failed to execute git worktree add: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git worktree add", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to create worktree: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to create worktree", &output));
     }
 
     Ok(worktree_path)
@@ -1139,7 +1935,7 @@ pub fn create_worktree(
 pub fn remove_worktree(
     workspace: &Path,
     worktree_path: &Path,
-) -> Result<(), String> {
+) -> Result<(), GitError> {
     let output = Command::new("git")
         .current_dir(workspace)
         .args([
@@ -1149,32 +1945,62 @@ pub fn remove_worktree(
             &worktree_path.display().to_string(),
         ])
         .output()
-        .map_err(|e| format!("failed to execute git worktree remove: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git worktree remove", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to remove worktree: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to remove worktree", &output));
     }
 
     Ok(())
 }
 
-pub fn create_task_branch(
+/// 워크스페이스에 남아 있는 bear 브랜치 목록을 반환한다. 태스크 브랜치의 `bear/` 접두사와
+/// 설정한 통합 브랜치 접두사 `integration_prefix` 아래의 로컬 브랜치를 모두 찾는다.
+pub fn list_bear_branches(
     workspace: &Path,
-    integration_branch: &str,
-    task_id: &str,
-) -> Result<String, String> {
+    integration_prefix: &str,
+) -> Result<Vec<String>, GitError> {
+    let integration_refs = format!("refs/heads/{}/", integration_prefix.trim_end_matches('/'));
+    let stdout = git_stdout(
+        workspace,
+        &["for-each-ref", "--format=%(refname:short)", "refs/heads/bear/", &integration_refs],
+        None,
+    )?;
+
+    Ok(stdout.lines().map(|line| line.to_string()).collect())
+}
+
+/// `create_worktree`로 만들어진 뒤 제거되지 않은 워크트리 경로 목록을 반환한다.
+pub fn list_bear_worktrees(workspace: &Path) -> Result<Vec<PathBuf>, GitError> {
+    let stdout = git_stdout(workspace, &["worktree", "list", "--porcelain"], None)?;
+
+    Ok(stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("worktree "))
+        .map(PathBuf::from)
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.contains("-bear-worktree-"))
+        })
+        .collect())
+}
+
+pub fn create_task_branch(
+    workspace: &Path,
+    integration_branch: &str,
+    task_id: &str,
+) -> Result<String, GitError> {
     let branch_name = format!("bear/task/{}-{}", task_id, Uuid::new_v4());
 
     let output = Command::new("git")
         .current_dir(workspace)
         .args(["branch", &branch_name, integration_branch])
         .output()
-        .map_err(|e| format!("failed to execute git branch: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git branch", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to create task branch: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to create task branch", &output));
     }
 
     Ok(branch_name)
@@ -1183,34 +2009,76 @@ pub fn create_task_branch(
 pub fn rebase_onto_integration(
     worktree_path: &Path,
     integration_branch: &str,
-) -> Result<RebaseOutcome, String> {
+) -> Result<RebaseOutcome, GitError> {
+    let args = ["rebase", integration_branch];
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(args)
+        .output()
+        .map_err(|e| GitError::execution("failed to execute git rebase", e))?;
+
+    rebase_outcome(worktree_path, &args, &output)
+}
+
+/// 사용자가 충돌을 해결하고 stage한 뒤 멈춘 리베이스를 이어 간다. 커밋 메시지 편집기가 뜨지
+/// 않도록 `GIT_EDITOR`를 `true`로 둔다. 해결하지 않은 파일이 남았거나 다음 커밋에서 다시
+/// 충돌하면 `Conflict`를 반환한다.
+pub fn continue_rebase(worktree_path: &Path) -> Result<RebaseOutcome, GitError> {
+    let args = ["rebase", "--continue"];
     let output = Command::new("git")
         .current_dir(worktree_path)
-        .args(["rebase", integration_branch])
+        .env("GIT_EDITOR", "true")
+        .args(args)
         .output()
-        .map_err(|e| format!("failed to execute git rebase: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git rebase --continue", e))?;
+
+    rebase_outcome(worktree_path, &args, &output)
+}
 
+fn rebase_outcome(
+    worktree_path: &Path,
+    args: &[&str],
+    output: &std::process::Output,
+) -> Result<RebaseOutcome, GitError> {
     if output.status.success() {
         return Ok(RebaseOutcome::Success);
     }
 
+    // 충돌 메시지는 git 버전과 로케일에 따라 달라지므로, 리베이스가 멈춘 상태로 남았는지도 확인한다.
     let stderr = String::from_utf8_lossy(&output.stderr);
-    if stderr.contains("CONFLICT") || stderr.contains("could not apply") {
+    if stderr.contains("CONFLICT")
+        || stderr.contains("could not apply")
+        || is_rebase_in_progress(worktree_path)
+    {
         let conflicted_files = list_conflicted_files(worktree_path)?;
         return Ok(RebaseOutcome::Conflict { conflicted_files });
     }
 
-    Err(format!("git rebase failed: {}", stderr.trim()))
+    Err(describe_git_failure(worktree_path, args, output))
+}
+
+/// 워크트리에서 리베이스가 진행 중(충돌 등으로 멈춘 상태 포함)인지 확인한다.
+/// 워크트리마다 git 디렉토리가 다르므로 `rebase-merge`/`rebase-apply`의 경로를 git에 묻는다.
+pub fn is_rebase_in_progress(worktree_path: &Path) -> bool {
+    ["rebase-merge", "rebase-apply"].iter().any(|state_dir| {
+        let args = ["rev-parse", "--path-format=absolute", "--git-path", state_dir];
+        git_stdout(worktree_path, &args, None).is_ok_and(|path| Path::new(&path).exists())
+    })
 }
 
 pub fn list_conflicted_files(
     worktree_path: &Path,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, GitError> {
+    let args = ["diff", "--name-only", "--diff-filter=U"];
     let output = Command::new("git")
         .current_dir(worktree_path)
-        .args(["diff", "--name-only", "--diff-filter=U"])
+        .args(args)
         .output()
-        .map_err(|e| format!("failed to execute git diff: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git diff", e))?;
+
+    if !output.status.success() {
+        return Err(describe_git_failure(worktree_path, &args, &output));
+    }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let files: Vec<String> = stdout
@@ -1222,16 +2090,146 @@ pub fn list_conflicted_files(
     Ok(files)
 }
 
-pub fn abort_rebase(worktree_path: &Path) -> Result<(), String> {
+/// 실패한 git 명령의 전체 인자, 실행 디렉토리, 종료 상태를 context로 담은 오류를 만든다.
+fn describe_git_failure(dir: &Path, args: &[&str], output: &std::process::Output) -> GitError {
+    let context = format!(
+        "`git {}` failed in {} ({})",
+        args.join(" "),
+        dir.display(),
+        output.status,
+    );
+    GitError::from_git_output(context, output)
+}
+
+/// `base`와 `head`의 merge-base 이후 `head` 쪽에서 변경된 파일 목록을 반환한다.
+pub fn changed_files_since_merge_base(
+    worktree_path: &Path,
+    base: &str,
+    head: &str,
+) -> Result<Vec<String>, GitError> {
+    let range = format!("{}...{}", base, head);
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["diff", "--name-only", &range])
+        .output()
+        .map_err(|e| GitError::execution("failed to execute git diff", e))?;
+
+    if !output.status.success() {
+        return Err(GitError::from_git_output("failed to list changed files", &output));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// `base`와 `head`의 merge-base 이후 `head` 쪽 변경의 `git diff --stat` 요약을 반환한다.
+pub fn diff_stat_since_merge_base(
+    worktree_path: &Path,
+    base: &str,
+    head: &str,
+) -> Result<String, GitError> {
+    let range = format!("{}...{}", base, head);
+    git_stdout(worktree_path, &["diff", "--stat", &range], None)
+        .map_err(|err| err.within("failed to summarize task diff"))
+}
+
+/// 의존성 매니페스트와 락파일의 파일 이름. 디렉토리와 관계없이 이름으로 판별한다.
+const DEPENDENCY_MANIFEST_FILE_NAMES: &[&str] = &[
+    "Cargo.toml",
+    "Cargo.lock",
+    "package.json",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "go.mod",
+    "go.sum",
+    "requirements.txt",
+    "pyproject.toml",
+    "poetry.lock",
+    "Pipfile",
+    "Pipfile.lock",
+    "Gemfile",
+    "Gemfile.lock",
+    "pom.xml",
+    "build.gradle",
+    "build.gradle.kts",
+    "composer.json",
+    "composer.lock",
+];
+
+fn is_dependency_manifest(path: &str) -> bool {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    DEPENDENCY_MANIFEST_FILE_NAMES.contains(&file_name)
+}
+
+/// 통합 브랜치에서 갈라진 이후 태스크가 수정한 의존성 매니페스트/락파일 목록을 반환한다.
+pub fn changed_dependency_manifests(
+    worktree_path: &Path,
+    integration_branch: &str,
+) -> Result<Vec<String>, GitError> {
+    let changed = changed_files_since_merge_base(worktree_path, integration_branch, "HEAD")?;
+    Ok(changed
+        .into_iter()
+        .filter(|path| is_dependency_manifest(path))
+        .collect())
+}
+
+/// 리뷰 프롬프트 뒤에 붙여 리뷰어가 의존성 변경을 반드시 검토하도록 하는 안내문.
+pub fn build_dependency_change_review_note(changed_manifests: &[String]) -> String {
+    let file_list = changed_manifests
+        .iter()
+        .map(|path| format!("  - {}", path))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "\n\n---\n\nDependency changes are NOT allowed for this task, but the following \
+         dependency manifests or lockfiles were modified:\n{}\n\
+         You MUST request changes unless every modification is strictly required by the \
+         specification and does not add, remove, or upgrade any dependency.",
+        file_list,
+    )
+}
+
+/// 태스크가 수정하는 파일과 태스크 시작 이후 통합 브랜치에서 변경된 파일이 겹치면
+/// 리베이스 충돌 가능성이 높다. 겹치는 파일 목록을 정렬하여 반환한다.
+pub fn predict_conflicts(
+    task_files: &[String],
+    integration_changed_files: &[String],
+) -> Vec<String> {
+    fn normalize(path: &str) -> &str {
+        let path = path.trim();
+        path.strip_prefix("./").unwrap_or(path)
+    }
+
+    let integration: std::collections::BTreeSet<&str> = integration_changed_files
+        .iter()
+        .map(|path| normalize(path))
+        .filter(|path| !path.is_empty())
+        .collect();
+
+    let overlapping: std::collections::BTreeSet<&str> = task_files
+        .iter()
+        .map(|path| normalize(path))
+        .filter(|path| integration.contains(path))
+        .collect();
+
+    overlapping.into_iter().map(String::from).collect()
+}
+
+pub fn abort_rebase(worktree_path: &Path) -> Result<(), GitError> {
     let output = Command::new("git")
         .current_dir(worktree_path)
         .args(["rebase", "--abort"])
         .output()
-        .map_err(|e| format!("failed to execute git rebase --abort: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git rebase --abort", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to abort rebase: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to abort rebase", &output));
     }
 
     Ok(())
@@ -1248,6 +2246,7 @@ pub fn detect_build_commands(worktree_path: &Path) -> Option<BuildTestCommands>
             return Some(BuildTestCommands {
                 build: "make build".to_string(),
                 test: "make test".to_string(),
+                per_test_timeout_secs: None,
             });
         }
     }
@@ -1256,6 +2255,7 @@ pub fn detect_build_commands(worktree_path: &Path) -> Option<BuildTestCommands>
         return Some(BuildTestCommands {
             build: "cargo build".to_string(),
             test: "cargo test".to_string(),
+            per_test_timeout_secs: None,
         });
     }
 
@@ -1267,12 +2267,66 @@ pub fn detect_build_commands(worktree_path: &Path) -> Option<BuildTestCommands>
         return Some(BuildTestCommands {
             build: "go build ./...".to_string(),
             test: "go test ./...".to_string(),
+            per_test_timeout_secs: Some(DEFAULT_PER_TEST_TIMEOUT_SECS),
         });
     }
 
+    if let Some(commands) = detect_gradle_commands(worktree_path) {
+        return Some(commands);
+    }
+
+    if let Some(commands) = detect_maven_commands(worktree_path) {
+        return Some(commands);
+    }
+
+    if let Some(commands) = detect_python_commands(worktree_path) {
+        return Some(commands);
+    }
+
+    if let Some(commands) = detect_cmake_commands(worktree_path) {
+        return Some(commands);
+    }
+
     None
 }
 
+/// 프로젝트에 포함된 래퍼 스크립트가 있으면 래퍼를, 없으면 전역 설치된 도구를 사용한다.
+fn jvm_tool_command(worktree_path: &Path, wrapper: &str, global: &str) -> String {
+    if worktree_path.join(wrapper).exists() {
+        format!("./{}", wrapper)
+    } else {
+        global.to_string()
+    }
+}
+
+fn detect_gradle_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
+    let has_build_script = worktree_path.join("build.gradle").exists()
+        || worktree_path.join("build.gradle.kts").exists();
+    if !has_build_script {
+        return None;
+    }
+
+    let gradle = jvm_tool_command(worktree_path, "gradlew", "gradle");
+    Some(BuildTestCommands {
+        build: format!("{} build", gradle),
+        test: format!("{} test", gradle),
+        per_test_timeout_secs: None,
+    })
+}
+
+fn detect_maven_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
+    if !worktree_path.join("pom.xml").exists() {
+        return None;
+    }
+
+    let maven = jvm_tool_command(worktree_path, "mvnw", "mvn");
+    Some(BuildTestCommands {
+        build: format!("{} -q compile", maven),
+        test: format!("{} -q test", maven),
+        per_test_timeout_secs: None,
+    })
+}
+
 fn detect_npm_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
     let package_json_path = worktree_path.join("package.json");
     let content = fs::read_to_string(&package_json_path).ok()?;
@@ -1286,24 +2340,193 @@ fn detect_npm_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
         Some(BuildTestCommands {
             build: "npm run build".to_string(),
             test: "npm test".to_string(),
+            per_test_timeout_secs: None,
         })
     } else {
         None
     }
 }
 
+fn detect_python_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
+    let has_project_file = worktree_path.join("pyproject.toml").exists()
+        || worktree_path.join("setup.py").exists();
+    if !has_project_file || !worktree_path.join("tests").is_dir() {
+        return None;
+    }
+
+    Some(BuildTestCommands {
+        build: "pip install -e .".to_string(),
+        test: "pytest".to_string(),
+        per_test_timeout_secs: declares_pytest_timeout_plugin(worktree_path)
+            .then_some(DEFAULT_PER_TEST_TIMEOUT_SECS),
+    })
+}
+
+/// pytest의 `--timeout` 옵션은 pytest-timeout 플러그인이 있어야 쓸 수 있으므로,
+/// 프로젝트 의존성 파일에 플러그인이 선언되어 있는지 확인한다.
+fn declares_pytest_timeout_plugin(worktree_path: &Path) -> bool {
+    [
+        "pyproject.toml",
+        "setup.py",
+        "setup.cfg",
+        "requirements.txt",
+        "requirements-dev.txt",
+        "tox.ini",
+    ]
+    .iter()
+    .filter_map(|file_name| fs::read_to_string(worktree_path.join(file_name)).ok())
+    .any(|content| content.contains("pytest-timeout"))
+}
+
+/// 코딩 에이전트 시스템 프롬프트의 타임아웃 정책과 같은 테스트 단계 soft timeout(초).
+/// 지원하는 테스트 프레임워크에 테스트별 타임아웃으로 전달한다.
+const DEFAULT_PER_TEST_TIMEOUT_SECS: u32 = 90;
+
+/// CMakePresets.json의 configure 프리셋을 기준으로 명령어를 만든다. `debug` 프리셋이
+/// 있으면 우선 사용하고, 없으면 첫 번째 공개(hidden이 아닌) 프리셋을 사용한다.
+/// build/test 프리셋은 해당 configure 프리셋에 연결된 것이 있으면 그것을 사용한다.
+fn detect_cmake_commands(worktree_path: &Path) -> Option<BuildTestCommands> {
+    if !worktree_path.join("CMakeLists.txt").exists() {
+        return None;
+    }
+    let content = fs::read_to_string(worktree_path.join("CMakePresets.json")).ok()?;
+    let presets: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    let visible_presets = |kind: &str| -> Vec<serde_json::Value> {
+        presets
+            .get(kind)
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter(|p| !p.get("hidden").and_then(|h| h.as_bool()).unwrap_or(false))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let preset_name = |preset: &serde_json::Value| {
+        preset.get("name").and_then(|n| n.as_str()).map(String::from)
+    };
+
+    let configure_presets = visible_presets("configurePresets");
+    let configure = configure_presets
+        .iter()
+        .filter_map(preset_name)
+        .find(|name| name == "debug")
+        .or_else(|| configure_presets.iter().find_map(preset_name))?;
+
+    let linked_preset = |kind: &str| {
+        visible_presets(kind)
+            .iter()
+            .filter(|p| p.get("configurePreset").and_then(|c| c.as_str()) == Some(&configure))
+            .find_map(preset_name)
+    };
+    // 구성 프리셋 이름은 빌드/테스트 프리셋으로 쓸 수 없으므로, 연결된 프리셋이 없으면 구성
+    // 프리셋의 빌드 디렉토리를 직접 지정한다.
+    let binary_dir = || cmake_binary_dir(&presets, &configure, worktree_path);
+    let build = match linked_preset("buildPresets") {
+        Some(build_preset) => format!("cmake --build --preset {}", build_preset),
+        None => format!("cmake --build {}", binary_dir()?),
+    };
+    let test = match linked_preset("testPresets") {
+        Some(test_preset) => format!("ctest --preset {} --output-on-failure", test_preset),
+        None => format!("ctest --test-dir {} --output-on-failure", binary_dir()?),
+    };
+
+    Some(BuildTestCommands {
+        build: format!("cmake --preset {} && {}", configure, build),
+        test,
+        per_test_timeout_secs: Some(DEFAULT_PER_TEST_TIMEOUT_SECS),
+    })
+}
+
+/// 구성 프리셋 `name`의 `binaryDir`을 `inherits`를 따라가며 찾아 워크트리 기준 경로로 바꾼다.
+/// 지원하지 않는 매크로(`$env{...}` 등)가 남거나 `binaryDir`이 없으면 None.
+fn cmake_binary_dir(
+    presets: &serde_json::Value,
+    name: &str,
+    worktree_path: &Path,
+) -> Option<String> {
+    let configure_presets = presets.get("configurePresets")?.as_array()?;
+    let mut pending = vec![name.to_string()];
+    let mut visited = Vec::new();
+    let binary_dir = loop {
+        let current = pending.pop()?;
+        if visited.contains(&current) {
+            continue;
+        }
+        let preset = configure_presets
+            .iter()
+            .find(|p| p.get("name").and_then(|n| n.as_str()) == Some(current.as_str()))?;
+        if let Some(dir) = preset.get("binaryDir").and_then(|d| d.as_str()) {
+            break dir.to_string();
+        }
+        // 여러 부모를 상속하면 앞의 부모가 우선하므로 뒤에서부터 쌓는다.
+        match preset.get("inherits") {
+            Some(serde_json::Value::String(parent)) => pending.push(parent.clone()),
+            Some(serde_json::Value::Array(parents)) => pending.extend(
+                parents.iter().rev().filter_map(|p| p.as_str()).map(String::from),
+            ),
+            _ => {}
+        }
+        visited.push(current);
+    };
+
+    let source_dir_name = worktree_path.file_name()?.to_string_lossy();
+    let expanded = binary_dir
+        .replace("${sourceDir}", ".")
+        .replace("${sourceDirName}", &source_dir_name)
+        .replace("${presetName}", name);
+    (!expanded.contains('$')).then_some(expanded)
+}
+
+/// 빌드 캐시를 공유할 때 프레임워크별로 설정하는 환경 변수. (프로젝트 표시 파일, 환경 변수,
+/// 공유 캐시 디렉토리 아래의 하위 디렉토리) 순이다. 여러 태스크가 동시에 빌드해도 안전하도록
+/// 도구가 스스로 잠금을 걸거나(cargo, npm, pip) 동시 접근을 전제로 설계된(go) 캐시만 고른다.
+const BUILD_CACHE_VARIABLES: &[(&str, &str, &str)] = &[
+    ("Cargo.toml", "CARGO_TARGET_DIR", "cargo-target"),
+    ("go.mod", "GOCACHE", "go-build"),
+    ("package.json", "npm_config_cache", "npm"),
+    ("pyproject.toml", "PIP_CACHE_DIR", "pip"),
+    ("setup.py", "PIP_CACHE_DIR", "pip"),
+];
+
+/// 워크트리에서 감지한 프레임워크의 빌드 출력/캐시 디렉토리를 워크트리 밖의 `cache_dir` 아래로
+/// 돌리는 환경 변수를 만든다. 태스크마다 새 워크트리를 만들어도 이전 빌드 결과를 재사용할 수
+/// 있게 한다. 여러 프레임워크를 쓰는 저장소면 해당하는 변수를 모두 설정한다.
+pub fn build_cache_env(worktree_path: &Path, cache_dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut env: Vec<(String, PathBuf)> = Vec::new();
+    for (marker, variable, subdirectory) in BUILD_CACHE_VARIABLES {
+        let already_set = env.iter().any(|(name, _)| name == variable);
+        if !already_set && worktree_path.join(marker).exists() {
+            env.push((variable.to_string(), cache_dir.join(subdirectory)));
+        }
+    }
+    env
+}
+
+/// 빌드와 테스트 명령을 차례로 실행한다. `cache_env`는 두 명령 모두에 추가로 설정할 환경
+/// 변수다. 두 명령의 stdout/stderr는 한 줄씩 읽히는 대로 `on_output_line`에 넘겨 진행 상황을
+/// 보여 주고, 실패한 단계의 전체 출력은 결과에 담는다.
 pub fn run_build_and_test(
     worktree_path: &Path,
     commands: &BuildTestCommands,
+    timeout: Duration,
+    cache_env: &[(String, PathBuf)],
+    on_output_line: &dyn Fn(String),
 ) -> Result<BuildTestOutcome, String> {
-    let build_outcome = run_shell_command(worktree_path, &commands.build)?;
+    let build_outcome =
+        run_shell_command(worktree_path, &commands.build, timeout, cache_env, on_output_line)?;
     if !build_outcome.success {
         return Ok(BuildTestOutcome::BuildFailed {
             output: build_outcome.combined_output,
         });
     }
 
-    let test_outcome = run_shell_command(worktree_path, &commands.test)?;
+    let test_command = commands.test_command();
+    let test_outcome =
+        run_shell_command(worktree_path, &test_command, timeout, cache_env, on_output_line)?;
     if !test_outcome.success {
         return Ok(BuildTestOutcome::TestFailed {
             output: test_outcome.combined_output,
@@ -1313,44 +2536,309 @@ pub fn run_build_and_test(
     Ok(BuildTestOutcome::Success)
 }
 
+/// 태스크를 구현한 뒤 빌드/테스트 전에 워크트리에서 실행할 포매터.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatterCommand {
+    /// 설치되어 있는지 확인할 실행 파일. 사용자가 직접 지정한 명령은 확인하지 않는다.
+    pub program: Option<String>,
+    pub command: String,
+}
+
+/// 포매터를 실행한 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatterOutcome {
+    /// 포매터가 설치되어 있지 않아 실행하지 않았다.
+    Skipped { program: String },
+    Unchanged,
+    /// 포매터가 바꾼 파일을 커밋했다. 태스크 커밋이 있으면 그 커밋에 합친다.
+    Committed,
+    Failed { output: String },
+}
+
+/// 태스크 커밋이 없어 포매터가 바꾼 파일을 따로 커밋할 때의 메시지.
+const FORMATTER_COMMIT_MESSAGE: &str = "Apply code formatter";
+
+/// clang-format으로 정리하는 C/C++ 소스 파일 패턴.
+const CLANG_FORMAT_PATHSPECS: &str = "'*.c' '*.cc' '*.cpp' '*.cxx' '*.h' '*.hh' '*.hpp'";
+
+/// 워크트리의 언어에 맞는 포매터를 고른다. clang-format은 스타일 설정 없이 실행하면 프로젝트
+/// 전체를 기본 스타일로 바꿔 버리므로 `.clang-format`이 있을 때만 고른다.
+pub fn detect_formatter(worktree_path: &Path) -> Option<FormatterCommand> {
+    let formatter = |program: &str, command: String| FormatterCommand {
+        program: Some(program.to_string()),
+        command,
+    };
+    if worktree_path.join("Cargo.toml").exists() {
+        return Some(formatter("cargo-fmt", "cargo fmt --all".to_string()));
+    }
+    if worktree_path.join("go.mod").exists() {
+        return Some(formatter("gofmt", "gofmt -w .".to_string()));
+    }
+    if worktree_path.join(".clang-format").exists() {
+        let command = format!(
+            "git ls-files -z -- {} | xargs -0 -r clang-format -i",
+            CLANG_FORMAT_PATHSPECS,
+        );
+        return Some(formatter("clang-format", command));
+    }
+    None
+}
+
+/// 포매터를 실행하고 추적 중인 파일이 바뀌었으면 커밋한다. `base_branch` 이후의 태스크 커밋이
+/// 있으면 리포트 커밋처럼 마지막 커밋에 합쳐 태스크가 한 커밋으로 남게 한다. 포매터가 설치되어
+/// 있지 않거나 실패해도 빌드/테스트는 계속할 수 있으므로 결과로만 알린다.
+pub fn run_formatter(
+    worktree_path: &Path,
+    base_branch: &str,
+    formatter: &FormatterCommand,
+    timeout: Duration,
+    on_output_line: &dyn Fn(String),
+) -> Result<FormatterOutcome, String> {
+    if let Some(program) = &formatter.program
+        && which::which(program).is_err()
+    {
+        return Ok(FormatterOutcome::Skipped { program: program.clone() });
+    }
+
+    let result =
+        run_shell_command(worktree_path, &formatter.command, timeout, &[], on_output_line)?;
+    if !result.success {
+        return Ok(FormatterOutcome::Failed { output: result.combined_output });
+    }
+
+    let status_args = ["status", "--porcelain", "--untracked-files=no"];
+    let changed = git_stdout(worktree_path, &status_args, None).map_err(|err| err.to_string())?;
+    if changed.is_empty() {
+        return Ok(FormatterOutcome::Unchanged);
+    }
+    let range = format!("{}..HEAD", base_branch);
+    let task_commit_count = git_stdout(worktree_path, &["rev-list", "--count", &range], None)
+        .map_err(|err| err.within("failed to count task commits").to_string())?;
+    let commit_args: &[&str] = if task_commit_count == "0" {
+        &["commit", "-a", "-m", FORMATTER_COMMIT_MESSAGE]
+    } else {
+        &["commit", "-a", "--amend", "--no-edit"]
+    };
+    git_stdout(worktree_path, commit_args, None)
+        .map_err(|err| err.within("failed to commit formatter changes").to_string())?;
+    Ok(FormatterOutcome::Committed)
+}
+
 struct ShellCommandResult {
     success: bool,
     combined_output: String,
 }
 
+/// 명령의 어느 출력에서 읽은 줄인지 구분한다.
+#[derive(Clone, Copy)]
+enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
 fn run_shell_command(
     working_dir: &Path,
     command: &str,
+    timeout: Duration,
+    env: &[(String, PathBuf)],
+    on_output_line: &dyn Fn(String),
 ) -> Result<ShellCommandResult, String> {
-    let output = Command::new("timeout")
+    let timeout_arg = format!("{}s", timeout.as_secs());
+    let mut child = Command::new("timeout")
         .current_dir(working_dir)
-        .args(["--signal=TERM", "--kill-after=15s", "180s", "sh", "-c", command])
-        .output()
+        .args(["--signal=TERM", "--kill-after=15s", &timeout_arg, "sh", "-c", command])
+        .envs(env.iter().map(|(name, value)| (name, value)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
         .map_err(|e| format!("failed to execute '{}': {}", command, e))?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let combined_output = format!("--- stdout ---\n{}\n--- stderr ---\n{}", stdout, stderr);
+    // 파이프 버퍼 데드락을 막기 위해 두 출력을 각각 별도 스레드에서 읽어 한 채널로 모은다.
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take().expect("stdout must be piped");
+    let stderr = child.stderr.take().expect("stderr must be piped");
+    let stdout_thread = spawn_line_reader(stdout, OutputStream::Stdout, sender.clone());
+    let stderr_thread = spawn_line_reader(stderr, OutputStream::Stderr, sender);
+
+    let mut stdout_lines = Vec::new();
+    let mut stderr_lines = Vec::new();
+    for (stream, line) in receiver {
+        on_output_line(line.clone());
+        match stream {
+            OutputStream::Stdout => stdout_lines.push(line),
+            OutputStream::Stderr => stderr_lines.push(line),
+        }
+    }
+    let _ = stdout_thread.join();
+    let _ = stderr_thread.join();
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("failed to wait for '{}': {}", command, e))?;
+    let combined_output = format!(
+        "--- stdout ---\n{}\n--- stderr ---\n{}",
+        stdout_lines.join("\n"),
+        stderr_lines.join("\n"),
+    );
 
     Ok(ShellCommandResult {
-        success: output.status.success(),
+        success: status.success(),
         combined_output,
     })
 }
 
+/// UTF-8이 아닌 출력도 멈추지 않고 끝까지 읽도록 바이트 단위로 줄을 나눠 보낸다.
+fn spawn_line_reader(
+    source: impl Read + Send + 'static,
+    stream: OutputStream,
+    sender: mpsc::Sender<(OutputStream, String)>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        for line in BufReader::new(source).split(b'\n').map_while(Result::ok) {
+            let line = String::from_utf8_lossy(&line).trim_end_matches('\r').to_string();
+            if sender.send((stream, line)).is_err() {
+                break;
+            }
+        }
+    })
+}
+
 pub fn fast_forward_merge_task_branch(
     workspace: &Path,
     task_branch: &str,
-) -> Result<(), String> {
+) -> Result<(), GitError> {
     let merge_output = Command::new("git")
         .current_dir(workspace)
         .args(["merge", "--ff-only", task_branch])
         .output()
-        .map_err(|e| format!("failed to execute git merge --ff-only: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git merge --ff-only", e))?;
 
     if !merge_output.status.success() {
-        let stderr = String::from_utf8_lossy(&merge_output.stderr);
-        return Err(format!("failed to fast-forward merge: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to fast-forward merge", &merge_output));
+    }
+
+    Ok(())
+}
+
+/// 설정된 전략으로 태스크 브랜치를 현재 체크아웃된 통합 브랜치에 머지한다. squash와 머지 커밋은
+/// `commit_message`로 커밋을 만들며, 실패하면 워크스페이스를 머지 이전 상태로 되돌린다.
+pub fn merge_task_branch(
+    workspace: &Path,
+    task_branch: &str,
+    commit_message: &str,
+    strategy: MergeStrategy,
+) -> Result<(), GitError> {
+    match strategy {
+        MergeStrategy::FastForwardOnly => fast_forward_merge_task_branch(workspace, task_branch),
+        MergeStrategy::Squash => squash_merge_task_branch(workspace, task_branch, commit_message),
+        MergeStrategy::MergeCommit => run_merge_or_reset(
+            workspace,
+            &["merge", "--no-ff", "-m", commit_message, task_branch],
+            "merge --no-ff",
+        ),
+    }
+}
+
+/// 머지가 실패했을 때 통합 브랜치 위로 다시 리베이스하고 머지를 재시도하는 최대 횟수.
+pub const MAX_MERGE_REBASE_RETRIES: usize = 2;
+
+/// `merge_task_branch_with_rebase_retry`가 끝내 머지하지 못한 원인.
+pub enum MergeFailure {
+    /// 앞서 나간 통합 브랜치 위로 다시 리베이스하다가 새 충돌이 났다. 리베이스는 중단해 둔다.
+    RebaseConflict { conflicted_files: Vec<String> },
+    /// 재시도를 모두 쓰고도 머지가 실패했다.
+    Merge(GitError),
+    /// 머지가 실패해 다시 리베이스하려 했지만 리베이스 자체가 실패했다.
+    RetryRebaseFailed { merge: GitError, rebase: GitError },
+}
+
+/// 태스크 브랜치를 머지하되, 그 사이 통합 브랜치가 앞서 나가 머지가 실패하면 워크트리에서
+/// 통합 브랜치 위로 다시 리베이스한 뒤 최대 `MAX_MERGE_REBASE_RETRIES`번 재시도한다.
+/// 성공하면 재시도한 리베이스 횟수를 반환한다.
+pub fn merge_task_branch_with_rebase_retry(
+    workspace: &Path,
+    worktree_path: &Path,
+    integration_branch: &str,
+    task_branch: &str,
+    commit_message: &str,
+    strategy: MergeStrategy,
+) -> Result<usize, MergeFailure> {
+    let mut rebase_retries = 0;
+    loop {
+        let err = match merge_task_branch(workspace, task_branch, commit_message, strategy) {
+            Ok(()) => return Ok(rebase_retries),
+            Err(err) => err,
+        };
+        if rebase_retries == MAX_MERGE_REBASE_RETRIES {
+            return Err(MergeFailure::Merge(err));
+        }
+
+        match rebase_onto_integration(worktree_path, integration_branch) {
+            Ok(RebaseOutcome::Success) => rebase_retries += 1,
+            Ok(RebaseOutcome::Conflict { conflicted_files }) => {
+                let _ = abort_rebase(worktree_path);
+                return Err(MergeFailure::RebaseConflict { conflicted_files });
+            }
+            Err(rebase) => {
+                return Err(MergeFailure::RetryRebaseFailed { merge: err, rebase });
+            }
+        }
+    }
+}
+
+fn squash_merge_task_branch(
+    workspace: &Path,
+    task_branch: &str,
+    commit_message: &str,
+) -> Result<(), GitError> {
+    run_merge_or_reset(workspace, &["merge", "--squash", task_branch], "merge --squash")?;
+
+    // 태스크 브랜치에 통합 브랜치 대비 변경이 없으면 만들 커밋이 없다.
+    let staged = Command::new("git")
+        .current_dir(workspace)
+        .args(["diff", "--cached", "--quiet"])
+        .status()
+        .map_err(|e| GitError::execution("failed to execute git diff --cached", e))?;
+    if staged.success() {
+        return Ok(());
+    }
+
+    let commit_output = Command::new("git")
+        .current_dir(workspace)
+        .args(["commit", "-m", commit_message])
+        .output()
+        .map_err(|e| GitError::execution("failed to execute git commit", e))?;
+
+    if !commit_output.status.success() {
+        let _ = Command::new("git")
+            .current_dir(workspace)
+            .args(["reset", "--merge"])
+            .output();
+        return Err(GitError::from_git_output("failed to commit squash merge", &commit_output));
+    }
+
+    Ok(())
+}
+
+/// git 머지 명령을 실행하고, 실패하면 `git reset --merge`로 머지 이전 상태로 되돌린다.
+fn run_merge_or_reset(
+    workspace: &Path,
+    args: &[&str],
+    description: &str,
+) -> Result<(), GitError> {
+    let output = Command::new("git")
+        .current_dir(workspace)
+        .args(args)
+        .output()
+        .map_err(|e| GitError::execution(format!("failed to execute git {}", description), e))?;
+
+    if !output.status.success() {
+        let _ = Command::new("git")
+            .current_dir(workspace)
+            .args(["reset", "--merge"])
+            .output();
+        return Err(GitError::from_git_output(format!("failed to {}", description), &output));
     }
 
     Ok(())
@@ -1359,31 +2847,29 @@ pub fn fast_forward_merge_task_branch(
 pub fn delete_branch(
     workspace: &Path,
     branch_name: &str,
-) -> Result<(), String> {
+) -> Result<(), GitError> {
     let output = Command::new("git")
         .current_dir(workspace)
         .args(["branch", "-D", branch_name])
         .output()
-        .map_err(|e| format!("failed to execute git branch -D: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git branch -D", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to delete branch: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to delete branch", &output));
     }
 
     Ok(())
 }
 
-pub fn get_latest_commit_revision(worktree_path: &Path) -> Result<String, String> {
+pub fn get_latest_commit_revision(worktree_path: &Path) -> Result<String, GitError> {
     let output = Command::new("git")
         .current_dir(worktree_path)
         .args(["rev-parse", "HEAD"])
         .output()
-        .map_err(|e| format!("failed to execute git rev-parse: {}", e))?;
+        .map_err(|e| GitError::execution("failed to execute git rev-parse", e))?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("failed to get latest commit: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to get latest commit", &output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -1393,25 +2879,74 @@ pub fn get_latest_commit_revision(worktree_path: &Path) -> Result<String, String
 // Report Management
 // ---------------------------------------------------------------------------
 
+/// 워크트리에 복사한 아티팩트의 결과.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ArtifactCopyOutcome {
+    /// 복사했지만 대상 저장소의 `.gitignore` 규칙에 걸려 커밋되지 않는 파일 이름.
+    pub ignored: Vec<String>,
+    /// 무시 규칙에 걸리지만 `git add -f`로 스테이징한 파일 이름.
+    pub force_added: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// `source_dir`에 있는 `file_names`를 `target_dir`로 복사하고, 복사한 파일 중 대상 저장소의
+/// `.gitignore`에 걸리는 파일을 알려 준다. `force_add_ignored`가 켜져 있으면 그 파일을
+/// `git add -f`로 스테이징해 다음 커밋에 들어가게 한다. 원본에 없는 파일은 건너뛴다.
 pub fn copy_artifacts_to_worktree(
     source_dir: &Path,
     target_dir: &Path,
     file_names: &[&str],
-) -> Vec<String> {
-    let mut errors = Vec::new();
+    force_add_ignored: bool,
+) -> ArtifactCopyOutcome {
+    let mut outcome = ArtifactCopyOutcome::default();
     if let Err(err) = fs::create_dir_all(target_dir) {
-        errors.push(format!("디렉토리 생성 실패: {}", err));
-        return errors;
+        outcome.errors.push(format!("디렉토리 생성 실패: {}", err));
+        return outcome;
     }
+    let mut copied = Vec::new();
     for name in file_names {
         let src = source_dir.join(name);
-        if src.exists()
-            && let Err(err) = fs::copy(&src, target_dir.join(name))
-        {
-            errors.push(format!("{} 복사 실패: {}", name, err));
+        if !src.exists() {
+            continue;
+        }
+        match fs::copy(&src, target_dir.join(name)) {
+            Ok(_) => copied.push(*name),
+            Err(err) => outcome.errors.push(format!("{} 복사 실패: {}", name, err)),
+        }
+    }
+
+    match gitignored_paths(target_dir, &copied) {
+        Ok(ignored) => outcome.ignored = ignored,
+        Err(err) => outcome.errors.push(format!("gitignore 확인 실패: {}", err)),
+    }
+    if force_add_ignored && !outcome.ignored.is_empty() {
+        let mut args = vec!["add", "-f", "--"];
+        args.extend(outcome.ignored.iter().map(String::as_str));
+        match git_stdout(target_dir, &args, None) {
+            Ok(_) => outcome.force_added = outcome.ignored.clone(),
+            Err(err) => outcome.errors.push(format!("강제 추가 실패: {}", err)),
         }
     }
-    errors
+    outcome
+}
+
+/// `dir` 기준 상대 경로 `paths` 중 저장소의 `.gitignore` 규칙에 걸리는 경로를 반환한다.
+fn gitignored_paths(dir: &Path, paths: &[&str]) -> Result<Vec<String>, GitError> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["check-ignore", "--"])
+        .args(paths)
+        .output()
+        .map_err(|e| GitError::execution("failed to execute git check-ignore", e))?;
+    // 종료 코드 1은 무시 규칙에 걸리는 경로가 하나도 없다는 뜻이다.
+    if !output.status.success() && output.status.code() != Some(1) {
+        return Err(GitError::from_git_output("failed to check ignored files", &output));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect())
 }
 
 pub fn save_task_report(
@@ -1427,58 +2962,315 @@ pub fn save_task_report(
     Ok(file_path)
 }
 
-pub fn collect_upstream_report_paths(
-    task: &CodingTask,
-    completed_reports: &[TaskReport],
-) -> Vec<PathBuf> {
-    task.dependencies
+/// 고치지 않고 넘어간 차단하지 않는 리뷰 지적 사항을 나중에 처리할 수 있도록 태스크 리포트
+/// 끝에 붙인다.
+pub fn append_nonblocking_findings(report: &str, findings: &[ReviewFinding]) -> String {
+    let items: Vec<String> = findings
         .iter()
-        .filter_map(|dep_id| {
-            completed_reports
-                .iter()
-                .find(|r| &r.task_id == dep_id)
-                .map(|r| r.report_file_path.clone())
-        })
-        .collect()
+        .filter(|finding| finding.severity == FindingSeverity::Nonblocking)
+        .map(|finding| format!("- {}", finding.description.trim()))
+        .collect();
+    if items.is_empty() {
+        return report.to_string();
+    }
+    format!(
+        "{}\n\n## 나중에 처리할 리뷰 지적 사항 (차단하지 않음)\n{}\n",
+        report.trim_end(),
+        items.join("\n"),
+    )
 }
 
-pub fn commit_file_in_workspace(
-    workspace: &Path,
-    file_path: &Path,
+/// 리뷰 반복 한 번의 리뷰 코멘트를 `<task_id>.review-<n>.md`로 저장한다.
+pub fn save_review_comment(
+    dir: &Path,
+    task_id: &str,
+    iteration: usize,
+    review_comment: &str,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let file_path = dir.join(format!("{}.review-{}.md", task_id, iteration));
+    fs::write(&file_path, review_comment)?;
+
+    Ok(file_path)
+}
+
+pub fn build_coding_summary(coding_state: &CodingPhaseState) -> CodingSummary {
+    let tasks: Vec<TaskSummary> = coding_state
+        .task_reports
+        .iter()
+        .map(|report| {
+            let title = coding_state
+                .tasks
+                .iter()
+                .find(|task| task.task_id == report.task_id)
+                .map(|task| task.title.clone())
+                .unwrap_or_default();
+            let report_file_path = if report.report_file_path.as_os_str().is_empty() {
+                None
+            } else {
+                Some(report.report_file_path.clone())
+            };
+            TaskSummary {
+                task_id: report.task_id.clone(),
+                title,
+                status: report.status.clone(),
+                report_file_path,
+                review_iterations: report.progress.review_iterations,
+                review_approval: report.progress.review_approval,
+                build_test_passed: report.progress.build_test_passed,
+            }
+        })
+        .collect();
+
+    let success_count = tasks
+        .iter()
+        .filter(|t| t.status == CodingTaskStatus::ImplementationSuccess)
+        .count();
+
+    CodingSummary {
+        integration_branch: coding_state.integration_branch.clone(),
+        success_count,
+        blocked_count: tasks.len() - success_count,
+        tasks,
+        build_test_skipped: false,
+    }
+}
+
+pub fn save_coding_summary(dir: &Path, summary: &CodingSummary) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let file_path = dir.join("summary.json");
+    let json = serde_json::to_string_pretty(summary).map_err(io::Error::other)?;
+    fs::write(&file_path, json)?;
+
+    Ok(file_path)
+}
+
+pub fn load_coding_summary(dir: &Path) -> io::Result<CodingSummary> {
+    let json = fs::read_to_string(dir.join("summary.json"))?;
+    serde_json::from_str(&json).map_err(io::Error::other)
+}
+
+/// 태스크별 코딩 에이전트의 Claude 세션 ID를 기록하는 저널 파일 이름.
+const CODING_SESSIONS_FILE_NAME: &str = "coding-sessions.json";
+
+/// 코딩 에이전트가 쓴 Claude 세션. CLI는 세션을 작업 디렉토리별로 저장하므로 같은 디렉토리에서
+/// 실행할 때만 다시 연결할 수 있다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodingSession {
+    pub session_id: String,
+    pub working_directory: PathBuf,
+}
+
+/// 태스크의 코딩 에이전트가 쓴 Claude 세션을 저널 디렉토리에 기록한다. 같은 태스크의 기존
+/// 기록은 덮어쓴다. 세션을 이어서 시작할 때 이 기록으로 코딩 에이전트의 대화를 다시 연결한다.
+pub fn save_coding_session(dir: &Path, task_id: &str, session: &CodingSession) -> io::Result<()> {
+    let mut sessions = load_coding_sessions(dir)?;
+    sessions.insert(task_id.to_string(), session.clone());
+
+    fs::create_dir_all(dir)?;
+    let json = serde_json::to_string_pretty(&sessions).map_err(io::Error::other)?;
+    fs::write(dir.join(CODING_SESSIONS_FILE_NAME), json)
+}
+
+/// 저널 디렉토리에 기록된 태스크 ID별 코딩 에이전트 세션. 기록이 없으면 빈 맵이다.
+pub fn load_coding_sessions(dir: &Path) -> io::Result<BTreeMap<String, CodingSession>> {
+    match fs::read_to_string(dir.join(CODING_SESSIONS_FILE_NAME)) {
+        Ok(json) => serde_json::from_str(&json).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn collect_upstream_report_paths(
+    task: &CodingTask,
+    completed_reports: &[TaskReport],
+) -> Vec<PathBuf> {
+    task.dependencies
+        .iter()
+        .filter_map(|dep_id| {
+            completed_reports
+                .iter()
+                .find(|r| &r.task_id == dep_id)
+                .map(|r| r.report_file_path.clone())
+        })
+        .collect()
+}
+
+pub fn commit_file_in_workspace(
+    workspace: &Path,
+    file_path: &Path,
     commit_message: &str,
-) -> Result<(), String> {
+) -> Result<(), GitError> {
     let add_output = Command::new("git")
         .current_dir(workspace)
         .args(["add", &file_path.display().to_string()])
         .output()
-        .map_err(|e| format!("failed to git add: {}", e))?;
+        .map_err(|e| GitError::execution("failed to git add", e))?;
 
     if !add_output.status.success() {
-        let stderr = String::from_utf8_lossy(&add_output.stderr);
-        return Err(format!("failed to git add: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to git add", &add_output));
     }
 
     let commit_output = Command::new("git")
         .current_dir(workspace)
         .args(["commit", "-m", commit_message])
         .output()
-        .map_err(|e| format!("failed to git commit: {}", e))?;
+        .map_err(|e| GitError::execution("failed to git commit", e))?;
 
     if !commit_output.status.success() {
-        let stderr = String::from_utf8_lossy(&commit_output.stderr);
-        return Err(format!("failed to git commit: {}", stderr.trim()));
+        return Err(GitError::from_git_output("failed to git commit", &commit_output));
     }
 
     Ok(())
 }
 
+pub fn is_git_repository(path: &Path) -> bool {
+    Command::new("git")
+        .current_dir(path)
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// 경로가 이전 실행에서 `create_worktree`로 만든 워크트리이거나, 다른 저장소에 연결된
+/// 워크트리인지 판단한다. 이런 경로를 워크스페이스로 쓰면 브랜치/워크트리 조작이 꼬인다.
+pub fn is_bear_worktree(path: &Path) -> bool {
+    let has_worktree_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.contains("-bear-worktree-"));
+
+    has_worktree_name || main_worktree_root(path).is_some()
+}
+
+/// 연결된 워크트리이면 그 워크트리가 속한 원래 저장소의 루트 경로를 반환한다.
+/// 일반 저장소이거나 git 저장소가 아니면 None.
+pub fn main_worktree_root(path: &Path) -> Option<PathBuf> {
+    let git_dir = git_stdout(path, &["rev-parse", "--path-format=absolute", "--git-dir"], None)
+        .ok()?;
+    let common_dir = git_stdout(
+        path,
+        &["rev-parse", "--path-format=absolute", "--git-common-dir"],
+        None,
+    )
+    .ok()?;
+
+    if git_dir == common_dir {
+        return None;
+    }
+    Path::new(&common_dir).parent().map(Path::to_path_buf)
+}
+
+/// 워크스페이스의 작업 트리와 인덱스를 건드리지 않고 지정한 브랜치에 파일 하나를 커밋한다.
+/// 브랜치가 없으면 현재 HEAD에서 새로 만든다. 브랜치가 현재 체크아웃된 브랜치이면
+/// 작업 트리와 어긋나지 않도록 일반 커밋을 사용한다.
+pub fn commit_file_to_branch(
+    workspace: &Path,
+    file_path: &Path,
+    branch: &str,
+    commit_message: &str,
+) -> Result<(), GitError> {
+    let current_branch = git_stdout(workspace, &["rev-parse", "--abbrev-ref", "HEAD"], None)?;
+    if current_branch == branch {
+        return commit_file_in_workspace(workspace, file_path, commit_message);
+    }
+
+    let toplevel = git_stdout(workspace, &["rev-parse", "--show-toplevel"], None)?;
+    let absolute_file = fs::canonicalize(file_path)
+        .map_err(|e| GitError::Other {
+            context: format!("failed to resolve {}", file_path.display()),
+            message: e.to_string(),
+        })?;
+    let toplevel = fs::canonicalize(&toplevel).map_err(|e| GitError::Other {
+        context: "failed to resolve repository root".to_string(),
+        message: e.to_string(),
+    })?;
+    let relative_path = absolute_file
+        .strip_prefix(&toplevel)
+        .map_err(|_| GitError::PathOutsideRepository {
+            path: file_path.display().to_string(),
+        })?
+        .display()
+        .to_string();
+
+    let branch_ref = format!("refs/heads/{}", branch);
+    let existing_tip =
+        git_stdout(workspace, &["rev-parse", "--verify", "--quiet", &branch_ref], None).ok();
+    let parent = match &existing_tip {
+        Some(tip) => tip.clone(),
+        None => git_stdout(workspace, &["rev-parse", "HEAD"], None)?,
+    };
+
+    let temp_index = std::env::temp_dir().join(format!("bear-index-{}", uuid::Uuid::new_v4()));
+    let result = (|| {
+        let index = Some(temp_index.as_path());
+        git_stdout(workspace, &["read-tree", &parent], index)?;
+        let blob = git_stdout(
+            workspace,
+            &["hash-object", "-w", &absolute_file.display().to_string()],
+            None,
+        )?;
+        let cache_info = format!("100644,{},{}", blob, relative_path);
+        git_stdout(workspace, &["update-index", "--add", "--cacheinfo", &cache_info], index)?;
+        let tree = git_stdout(workspace, &["write-tree"], index)?;
+        let commit = git_stdout(
+            workspace,
+            &["commit-tree", &tree, "-p", &parent, "-m", commit_message],
+            None,
+        )?;
+        let expected_old = existing_tip.clone().unwrap_or_default();
+        git_stdout(workspace, &["update-ref", &branch_ref, &commit, &expected_old], None)
+    })();
+    let _ = fs::remove_file(&temp_index);
+
+    result
+        .map(|_| ())
+        .map_err(|e| e.within(&format!("failed to commit to branch {}", branch)))
+}
+
+fn git_stdout(
+    workspace: &Path,
+    args: &[&str],
+    index_file: Option<&Path>,
+) -> Result<String, GitError> {
+    let mut command = Command::new("git");
+    command.current_dir(workspace).args(args);
+    if let Some(index_file) = index_file {
+        command.env("GIT_INDEX_FILE", index_file);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| GitError::execution(format!("failed to execute git {}", args[0]), e))?;
+    if !output.status.success() {
+        return Err(GitError::from_git_output(format!("git {} failed", args[0]), &output));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 태스크 리포트를 워크트리의 저널 디렉토리에 쓰고 직전 코드 커밋에 포함시킨다. 태스크 브랜치에
+/// `base_branch` 이후의 커밋이 없으면 통합 브랜치의 커밋을 고치지 않도록 `commit_message`로 새
+/// 커밋을 만든다.
+///
+/// 태스크 브랜치가 통합 브랜치에 합쳐질 때 저널 디렉토리에서 충돌이 나지 않도록 다음 불변식을 지킨다.
+/// - 각 태스크는 자기 `<task_id>.md` 파일 하나만 추가하며, 여러 태스크가 함께 고치는
+///   인덱스나 목록 파일은 커밋하지 않는다. (`summary.json`, 점진적 계획의 `plan.md`는
+///   메인 워크스페이스에만 쓰고 태스크 브랜치에는 커밋하지 않는다.)
+/// - 통합 브랜치 갱신(리베이스 후 머지)은 App이 태스크 하나씩 순서대로 처리한다.
 pub fn save_and_commit_task_report_in_worktree(
     worktree_path: &Path,
     date_dir: &str,
     session_name: &str,
     task_id: &str,
     report: &str,
+    base_branch: &str,
+    commit_message: &str,
 ) -> Result<PathBuf, String> {
+    let report_file_name = task_report_file_name(task_id)?;
     let report_dir = worktree_path
         .join(".bear")
         .join(date_dir)
@@ -1486,7 +3278,7 @@ pub fn save_and_commit_task_report_in_worktree(
     fs::create_dir_all(&report_dir)
         .map_err(|e| format!("failed to create report directory: {}", e))?;
 
-    let file_path = report_dir.join(format!("{}.md", task_id));
+    let file_path = report_dir.join(report_file_name);
     fs::write(&file_path, report)
         .map_err(|e| format!("failed to write report file: {}", e))?;
 
@@ -1497,26 +3289,47 @@ pub fn save_and_commit_task_report_in_worktree(
         .map_err(|e| format!("failed to git add report: {}", e))?;
 
     if !add_output.status.success() {
-        let stderr = String::from_utf8_lossy(&add_output.stderr);
-        return Err(format!("failed to git add report: {}", stderr.trim()));
+        let err = GitError::from_git_output("failed to git add report", &add_output);
+        return Err(err.to_string());
     }
 
     // Amend the previous commit (code changes) to include the task report,
     // so they are recorded as a single commit.
+    let range = format!("{}..HEAD", base_branch);
+    let task_commit_count = git_stdout(worktree_path, &["rev-list", "--count", &range], None)
+        .map_err(|e| format!("failed to count task commits: {}", e))?;
+    let commit_args: &[&str] = if task_commit_count == "0" {
+        &["commit", "-m", commit_message]
+    } else {
+        &["commit", "--amend", "--no-edit"]
+    };
     let commit_output = Command::new("git")
         .current_dir(worktree_path)
-        .args(["commit", "--amend", "--no-edit"])
+        .args(commit_args)
         .output()
         .map_err(|e| format!("failed to git commit report: {}", e))?;
 
     if !commit_output.status.success() {
-        let stderr = String::from_utf8_lossy(&commit_output.stderr);
-        return Err(format!("failed to commit report: {}", stderr.trim()));
+        let err = GitError::from_git_output("failed to commit report", &commit_output);
+        return Err(err.to_string());
     }
 
     Ok(file_path)
 }
 
+/// 태스크 리포트 파일 이름. 다른 태스크의 파일이나 저널 디렉토리 밖을 가리키지 않도록
+/// 경로 구분자나 `..`가 들어간 태스크 ID는 거부한다.
+fn task_report_file_name(task_id: &str) -> Result<String, String> {
+    let is_single_component = !task_id.is_empty()
+        && task_id != "."
+        && task_id != ".."
+        && !task_id.contains(['/', '\\']);
+    if !is_single_component {
+        return Err(format!("invalid task id for report file name: {:?}", task_id));
+    }
+    Ok(format!("{}.md", task_id))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1524,6 +3337,8 @@ pub fn save_and_commit_task_report_in_worktree(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{CommitMessageTemplate, DEFAULT_BUILD_TEST_TIMEOUT};
+    use crate::ui::test_support::coding_task;
     use tempfile::TempDir;
 
     #[test]
@@ -1579,6 +3394,63 @@ mod tests {
         assert_eq!(response.tasks[1].dependencies, vec!["TASK-00"]);
     }
 
+    fn task_with_subdir(subdir: Option<&str>) -> CodingTask {
+        CodingTask {
+            task_id: "TASK-00".to_string(),
+            title: "API 서버 수정".to_string(),
+            description: "설명".to_string(),
+            dependencies: Vec::new(),
+            files: Vec::new(),
+            subdir: subdir.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn deserialize_task_with_subdir() {
+        let json = serde_json::json!({
+            "task_id": "TASK-00",
+            "title": "API 서버 수정",
+            "description": "설명",
+            "dependencies": [],
+            "subdir": "services/api"
+        });
+
+        let task: CodingTask = serde_json::from_value(json).unwrap();
+
+        assert_eq!(task.subdir.as_deref(), Some("services/api"));
+        let without_subdir = serde_json::to_value(task_with_subdir(None)).unwrap();
+        assert!(without_subdir.get("subdir").is_none());
+    }
+
+    #[test]
+    fn working_directory_joins_subdir_to_worktree() {
+        let worktree = Path::new("/repo-bear-worktree-TASK-00");
+
+        assert_eq!(task_with_subdir(None).working_directory(worktree), worktree);
+        assert_eq!(
+            task_with_subdir(Some("services/api")).working_directory(worktree),
+            worktree.join("services/api"),
+        );
+        assert_eq!(
+            task_with_subdir(Some(" ./services/api/ ")).working_directory(worktree),
+            worktree.join("services/api"),
+        );
+    }
+
+    #[test]
+    fn working_directory_falls_back_to_root_for_paths_outside_worktree() {
+        let worktree = Path::new("/repo-bear-worktree-TASK-00");
+
+        for subdir in ["", ".", "/etc", "../other", "services/../../other"] {
+            assert_eq!(
+                task_with_subdir(Some(subdir)).working_directory(worktree),
+                worktree,
+                "{}",
+                subdir,
+            );
+        }
+    }
+
     #[test]
     fn deserialize_coding_task_result_success() {
         let json = serde_json::json!({
@@ -1613,6 +3485,16 @@ mod tests {
         assert!(prompt.contains("topological order"));
     }
 
+    #[test]
+    fn task_extraction_retry_prompt_asks_for_single_task() {
+        let plan_path = Path::new("/workspace/.bear/20260215/session/plan.md");
+        let prompt = build_task_extraction_retry_prompt(plan_path);
+
+        assert!(prompt.starts_with(&build_task_extraction_prompt(plan_path)));
+        assert!(prompt.contains("returned no tasks"));
+        assert!(prompt.contains("single task with task id \"TASK-00\""));
+    }
+
     #[test]
     fn coding_task_prompt_contains_all_fields() {
         let task = CodingTask {
@@ -1620,6 +3502,8 @@ mod tests {
             title: "기본 타입 정의".to_string(),
             description: "핵심 타입을 정의합니다.".to_string(),
             dependencies: vec!["TASK-01".to_string()],
+            files: Vec::new(),
+            subdir: None,
         };
 
         let spec_path = Path::new("/workspace/.bear/20260215/session/spec.md");
@@ -1633,6 +3517,7 @@ mod tests {
             plan_path,
             &upstream_paths,
             integration_branch,
+            true,
         );
 
         assert!(prompt.contains("TASK-00"));
@@ -1651,14 +3536,49 @@ mod tests {
             title: "독립 작업".to_string(),
             description: "의존성 없는 작업".to_string(),
             dependencies: vec![],
+            files: Vec::new(),
+            subdir: None,
         };
 
         let spec_path = Path::new("/workspace/.bear/spec.md");
         let plan_path = Path::new("/workspace/.bear/plan.md");
-        let prompt =
-            build_coding_task_prompt(&task, spec_path, plan_path, &[], "bear/integration/test");
+        let prompt = build_coding_task_prompt(
+            &task,
+            spec_path,
+            plan_path,
+            &[],
+            "bear/integration/test",
+            true,
+        );
 
         assert!(prompt.contains("N/A"));
+        assert!(!prompt.contains("Dependency policy"));
+    }
+
+    #[test]
+    fn coding_task_prompt_forbids_dependency_changes_unless_allowed() {
+        let task = CodingTask {
+            task_id: "TASK-00".to_string(),
+            title: "작업".to_string(),
+            description: "설명".to_string(),
+            dependencies: vec![],
+            files: Vec::new(),
+            subdir: None,
+        };
+        let spec_path = Path::new("/workspace/.bear/spec.md");
+        let plan_path = Path::new("/workspace/.bear/plan.md");
+
+        let prompt = build_coding_task_prompt(
+            &task,
+            spec_path,
+            plan_path,
+            &[],
+            "bear/integration/test",
+            false,
+        );
+
+        assert!(prompt.contains("Dependency policy"));
+        assert!(prompt.contains("You MUST NOT add, remove, or upgrade any dependency"));
     }
 
     #[test]
@@ -1675,6 +3595,203 @@ mod tests {
         assert_eq!(content, report_content);
     }
 
+    /// Builds a finished coding phase with one succeeded task and one blocked task.
+    fn mixed_status_coding_state() -> CodingPhaseState {
+        CodingPhaseState {
+            tasks: vec![
+                CodingTask {
+                    task_id: "TASK-00".to_string(),
+                    title: "첫 작업".to_string(),
+                    description: String::new(),
+                    dependencies: Vec::new(),
+                    files: Vec::new(),
+                    subdir: None,
+                },
+                CodingTask {
+                    task_id: "TASK-01".to_string(),
+                    title: "둘째 작업".to_string(),
+                    description: String::new(),
+                    dependencies: Vec::new(),
+                    files: Vec::new(),
+                    subdir: None,
+                },
+            ],
+            current_task_index: 2,
+            task_reports: vec![
+                TaskReport {
+                    task_id: "TASK-00".to_string(),
+                    status: CodingTaskStatus::ImplementationSuccess,
+                    report: "완료".to_string(),
+                    report_file_path: PathBuf::from("/ws/.bear/TASK-00.md"),
+                    merged_with_failing_build: false,
+                    progress: TaskProgress {
+                        review_iterations: 2,
+                        review_approval: ReviewApproval::Approved,
+                        build_test_passed: Some(true),
+                    },
+                },
+                TaskReport {
+                    task_id: "TASK-01".to_string(),
+                    status: CodingTaskStatus::ImplementationBlocked,
+                    report: "차단".to_string(),
+                    report_file_path: PathBuf::new(),
+                    merged_with_failing_build: false,
+                    progress: TaskProgress {
+                        review_iterations: 1,
+                        review_approval: ReviewApproval::NotReviewed,
+                        build_test_passed: Some(false),
+                    },
+                },
+            ],
+            integration_branch: "bear/integration/test".to_string(),
+            current_task_worktree: None,
+            build_test_commands: None,
+            build_test_subdir: None,
+            incremental_planning: false,
+            current_task_progress: TaskProgress::default(),
+            rerun_return_index: None,
+            retained_worktrees: Vec::new(),
+            flagged_dependency_manifests: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn reopening_blocked_task_clears_its_report_and_returns_afterwards() {
+        let mut coding_state = mixed_status_coding_state();
+
+        assert_eq!(coding_state.blocked_task_ids(), vec!["TASK-01"]);
+        assert!(!coding_state.reopen_blocked_task("TASK-00"));
+        assert!(coding_state.reopen_blocked_task("TASK-01"));
+
+        assert_eq!(coding_state.current_task_index, 1);
+        assert_eq!(coding_state.task_reports.len(), 1);
+        assert!(coding_state.blocked_task_ids().is_empty());
+
+        coding_state.advance_to_next_task();
+
+        assert_eq!(coding_state.current_task_index, 2);
+        assert!(coding_state.rerun_return_index.is_none());
+
+        coding_state.advance_to_next_task();
+
+        assert_eq!(coding_state.current_task_index, 3);
+    }
+
+    #[test]
+    fn task_schedule_partitions_remaining_tasks_by_dependency_state() {
+        // TASK-00 성공, TASK-01 차단으로 끝난 상태에서 남은 태스크의 의존 관계:
+        // TASK-02 -> 00, TASK-03 -> 01, TASK-04 -> 03, TASK-05 -> 02, TASK-06 -> 00, 05
+        let mut coding_state = mixed_status_coding_state();
+        coding_state.tasks.extend([
+            coding_task("TASK-02", &["TASK-00"]),
+            coding_task("TASK-03", &["TASK-01"]),
+            coding_task("TASK-04", &["TASK-03"]),
+            coding_task("TASK-05", &["TASK-02"]),
+            coding_task("TASK-06", &["TASK-00", "TASK-05"]),
+        ]);
+
+        let schedule = coding_state.task_schedule();
+
+        assert_eq!(schedule.ready, vec!["TASK-02"]);
+        assert_eq!(schedule.waiting, vec!["TASK-05", "TASK-06"]);
+        assert_eq!(
+            schedule.blocked,
+            vec![
+                UnrunnableTask {
+                    task_id: "TASK-03".to_string(),
+                    blocked_dependency: "TASK-01".to_string(),
+                },
+                UnrunnableTask {
+                    task_id: "TASK-04".to_string(),
+                    blocked_dependency: "TASK-01".to_string(),
+                },
+            ],
+        );
+        assert_eq!(coding_state.blocked_dependency("TASK-06"), None);
+        assert_eq!(
+            schedule.lines(&coding_state.tasks),
+            vec![
+                "실행 가능  [TASK-02] TASK-02 title",
+                "대기 중    [TASK-05] TASK-05 title",
+                "대기 중    [TASK-06] TASK-06 title",
+                "실행 불가  [TASK-03] TASK-03 title — 의존 태스크 TASK-01 차단됨",
+                "실행 불가  [TASK-04] TASK-04 title — 의존 태스크 TASK-01 차단됨",
+            ],
+        );
+    }
+
+    #[test]
+    fn task_schedule_is_empty_when_every_task_finished() {
+        let coding_state = mixed_status_coding_state();
+
+        let schedule = coding_state.task_schedule();
+
+        assert_eq!(schedule, TaskSchedule::default());
+        assert_eq!(schedule.lines(&coding_state.tasks), vec!["남은 태스크가 없습니다."]);
+    }
+
+    #[test]
+    fn coding_sessions_are_recorded_per_task() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("journal");
+        assert!(load_coding_sessions(&dir).unwrap().is_empty());
+        let session = |session_id: &str, working_directory: &str| CodingSession {
+            session_id: session_id.to_string(),
+            working_directory: PathBuf::from(working_directory),
+        };
+
+        save_coding_session(&dir, "TASK-00", &session("sess-a", "/work/a")).unwrap();
+        save_coding_session(&dir, "TASK-01", &session("sess-b", "/work/b")).unwrap();
+        save_coding_session(&dir, "TASK-00", &session("sess-c", "/work/c")).unwrap();
+
+        let sessions = load_coding_sessions(&dir).unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions["TASK-00"], session("sess-c", "/work/c"));
+        assert_eq!(sessions["TASK-01"], session("sess-b", "/work/b"));
+    }
+
+    #[test]
+    fn coding_summary_json_has_expected_shape() {
+        let coding_state = mixed_status_coding_state();
+        let temp_dir = TempDir::new().unwrap();
+
+        let path =
+            save_coding_summary(temp_dir.path(), &build_coding_summary(&coding_state)).unwrap();
+
+        assert_eq!(path, temp_dir.path().join("summary.json"));
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "integration_branch": "bear/integration/test",
+                "success_count": 1,
+                "blocked_count": 1,
+                "tasks": [
+                    {
+                        "task_id": "TASK-00",
+                        "title": "첫 작업",
+                        "status": "IMPLEMENTATION_SUCCESS",
+                        "report_file_path": "/ws/.bear/TASK-00.md",
+                        "review_iterations": 2,
+                        "review_approval": "APPROVED",
+                        "build_test_passed": true
+                    },
+                    {
+                        "task_id": "TASK-01",
+                        "title": "둘째 작업",
+                        "status": "IMPLEMENTATION_BLOCKED",
+                        "report_file_path": null,
+                        "review_iterations": 1,
+                        "review_approval": "NOT_REVIEWED",
+                        "build_test_passed": false
+                    }
+                ],
+                "build_test_skipped": false
+            }),
+        );
+    }
+
     #[test]
     fn collect_upstream_report_paths_with_dependencies() {
         let task = CodingTask {
@@ -1682,6 +3799,8 @@ mod tests {
             title: "후속 작업".to_string(),
             description: "TASK-00, TASK-01에 의존".to_string(),
             dependencies: vec!["TASK-00".to_string(), "TASK-01".to_string()],
+            files: Vec::new(),
+            subdir: None,
         };
 
         let reports = vec![
@@ -1690,12 +3809,16 @@ mod tests {
                 status: CodingTaskStatus::ImplementationSuccess,
                 report: "TASK-00 완료".to_string(),
                 report_file_path: PathBuf::from("/tmp/TASK-00.md"),
+                merged_with_failing_build: false,
+                progress: TaskProgress::default(),
             },
             TaskReport {
                 task_id: "TASK-01".to_string(),
                 status: CodingTaskStatus::ImplementationSuccess,
                 report: "TASK-01 완료".to_string(),
                 report_file_path: PathBuf::from("/tmp/TASK-01.md"),
+                merged_with_failing_build: false,
+                progress: TaskProgress::default(),
             },
         ];
 
@@ -1713,6 +3836,8 @@ mod tests {
             title: "독립 작업".to_string(),
             description: "의존성 없음".to_string(),
             dependencies: vec![],
+            files: Vec::new(),
+            subdir: None,
         };
 
         let paths = collect_upstream_report_paths(&task, &[]);
@@ -1769,6 +3894,120 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn workspace_preflight_accepts_clean_repository_with_untracked_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        fs::create_dir_all(workspace.join(".bear")).unwrap();
+        fs::write(workspace.join(".bear").join("user-request.md"), "request").unwrap();
+
+        assert_eq!(check_workspace_ready(workspace), Ok(()));
+    }
+
+    #[test]
+    fn workspace_preflight_distinguishes_failure_kinds() {
+        let not_a_repo = TempDir::new().unwrap();
+        assert!(matches!(
+            check_workspace_ready(not_a_repo.path()),
+            Err(WorkspacePreflightError::NotARepository { .. })
+        ));
+
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        make_commit(workspace, "staged.txt", "one", "second commit");
+        make_commit(workspace, "old name.txt", "renamed", "third commit");
+        fs::write(workspace.join("init.txt"), "modified").unwrap();
+        fs::write(workspace.join("staged.txt"), "two").unwrap();
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["add", "staged.txt"])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["mv", "old name.txt", "new name.txt"])
+            .output()
+            .unwrap();
+        assert_eq!(
+            check_workspace_ready(workspace),
+            Err(WorkspacePreflightError::DirtyWorkingTree {
+                changed_files: vec![
+                    "init.txt".to_string(),
+                    "new name.txt".to_string(),
+                    "staged.txt".to_string(),
+                ],
+            }),
+        );
+
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["reset", "--hard"])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["checkout", "--detach"])
+            .output()
+            .unwrap();
+        assert_eq!(check_workspace_ready(workspace), Err(WorkspacePreflightError::DetachedHead));
+    }
+
+    #[test]
+    fn auto_stash_round_trip_keeps_journal_and_restores_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        assert_eq!(stash_working_changes(workspace, "session").unwrap(), None);
+
+        fs::write(workspace.join("init.txt"), "edited").unwrap();
+        fs::write(workspace.join("scratch.txt"), "untracked").unwrap();
+        fs::create_dir_all(workspace.join(".bear")).unwrap();
+        fs::write(workspace.join(".bear").join("user-request.md"), "request").unwrap();
+
+        let stash_commit = stash_working_changes(workspace, "session").unwrap().unwrap();
+        assert_eq!(check_workspace_ready(workspace), Ok(()));
+        assert_eq!(fs::read_to_string(workspace.join("init.txt")).unwrap(), "init");
+        assert!(!workspace.join("scratch.txt").exists());
+        assert!(workspace.join(".bear").join("user-request.md").exists());
+        assert_eq!(
+            find_stash_entry(workspace, &stash_commit).unwrap().as_deref(),
+            Some("stash@{0}"),
+        );
+
+        restore_stashed_changes(workspace, &stash_commit).unwrap();
+        assert_eq!(fs::read_to_string(workspace.join("init.txt")).unwrap(), "edited");
+        assert_eq!(fs::read_to_string(workspace.join("scratch.txt")).unwrap(), "untracked");
+        assert_eq!(find_stash_entry(workspace, &stash_commit).unwrap(), None);
+        assert!(matches!(
+            restore_stashed_changes(workspace, &stash_commit),
+            Err(GitError::UnknownRevision { .. })
+        ));
+    }
+
+    #[test]
+    fn auto_stash_restore_conflict_keeps_stash_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        fs::write(workspace.join("init.txt"), "user edit").unwrap();
+        let stash_commit = stash_working_changes(workspace, "session").unwrap().unwrap();
+
+        make_commit(workspace, "init.txt", "agent edit", "conflicting commit");
+
+        let err = restore_stashed_changes(workspace, &stash_commit).unwrap_err();
+        assert!(matches!(err, GitError::Conflict { .. }), "{:?}", err);
+        assert_eq!(
+            find_stash_entry(workspace, &stash_commit).unwrap().as_deref(),
+            Some("stash@{0}"),
+        );
+    }
+
     #[test]
     fn create_task_branch_from_integration() {
         let temp_dir = TempDir::new().unwrap();
@@ -1776,7 +4015,8 @@ mod tests {
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
 
-        let integration = create_integration_branch(workspace, "test-session").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test-session", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
 
         assert!(task_branch.starts_with("bear/task/TASK-00-"));
@@ -1790,6 +4030,99 @@ mod tests {
         assert!(!stdout.trim().is_empty());
     }
 
+    #[test]
+    fn integration_branch_uses_custom_prefix_and_base() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        git_stdout(workspace, &["checkout", "-b", "develop"], None).unwrap();
+        make_commit(workspace, "develop.txt", "develop", "develop commit");
+        let develop_head = git_stdout(workspace, &["rev-parse", "develop"], None).unwrap();
+        git_stdout(workspace, &["checkout", "-"], None).unwrap();
+        let options = IntegrationBranchOptions {
+            prefix: "team/ai/",
+            base_branch: Some("develop"),
+        };
+
+        let integration = create_integration_branch(workspace, "login", &options).unwrap();
+
+        assert!(integration.starts_with("team/ai/login-"), "{}", integration);
+        let head = git_stdout(workspace, &["rev-parse", "HEAD"], None).unwrap();
+        assert_eq!(head, develop_head);
+        let current = git_stdout(workspace, &["symbolic-ref", "--short", "HEAD"], None).unwrap();
+        assert_eq!(current, integration);
+    }
+
+    #[test]
+    fn integration_branch_fails_when_base_is_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        let options = IntegrationBranchOptions {
+            base_branch: Some("develop"),
+            ..Default::default()
+        };
+
+        let err = create_integration_branch(workspace, "login", &options).unwrap_err();
+
+        assert_eq!(err, GitError::BaseBranchNotFound { branch: "develop".to_string() });
+        assert_eq!(err.to_string(), "integration base branch `develop` does not exist");
+        let branches = git_stdout(workspace, &["branch", "--list", "bear/*"], None).unwrap();
+        assert!(branches.is_empty(), "{}", branches);
+    }
+
+    #[test]
+    fn created_worktree_is_detected_as_bear_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path().join("repo");
+        fs::create_dir(&workspace).unwrap();
+        init_git_repo(&workspace);
+        make_commit(&workspace, "init.txt", "init", "initial commit");
+
+        let integration =
+            create_integration_branch(&workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(&workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(&workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+
+        assert!(is_bear_worktree(&worktree_path));
+        assert_eq!(
+            main_worktree_root(&worktree_path).unwrap(),
+            workspace.canonicalize().unwrap(),
+        );
+        assert!(!is_bear_worktree(&workspace));
+        assert!(main_worktree_root(&workspace).is_none());
+
+        remove_worktree(&workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn worktree_is_created_under_configured_root_and_removed() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path().join("repo");
+        fs::create_dir(&workspace).unwrap();
+        init_git_repo(&workspace);
+        make_commit(&workspace, "init.txt", "init", "initial commit");
+        let absolute_root = temp_dir.path().join("worktrees").join("nested");
+
+        for root in [absolute_root.clone(), PathBuf::from("../relative-worktrees")] {
+            let branch = create_task_branch(&workspace, "master", "TASK-00").unwrap();
+            let worktree_path = create_worktree(&workspace, &root, &branch).unwrap();
+
+            assert_eq!(worktree_path.parent().unwrap(), workspace.join(&root));
+            assert!(worktree_path.join("init.txt").exists());
+            assert!(is_bear_worktree(&worktree_path));
+            assert_eq!(list_bear_worktrees(&workspace).unwrap().len(), 1);
+
+            remove_worktree(&workspace, &worktree_path).unwrap();
+            assert!(!worktree_path.exists());
+            assert!(list_bear_worktrees(&workspace).unwrap().is_empty());
+        }
+        assert!(temp_dir.path().join("relative-worktrees").is_dir());
+    }
+
     #[test]
     fn rebase_onto_integration_success() {
         let temp_dir = TempDir::new().unwrap();
@@ -1797,9 +4130,11 @@ mod tests {
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
         make_commit(&worktree_path, "task.txt", "task content", "task commit");
 
         let result = rebase_onto_integration(&worktree_path, &integration).unwrap();
@@ -1815,10 +4150,13 @@ mod tests {
         let workspace = temp_dir.path();
         init_git_repo(workspace);
         make_commit(workspace, "shared.txt", "original", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
 
         // 통합 브랜치에서 같은 파일 수정 (메인 워크스페이스에서 체크아웃해서 커밋)
         Command::new("git")
@@ -1829,7 +4167,7 @@ mod tests {
         make_commit(workspace, "shared.txt", "integration change", "integration commit");
         Command::new("git")
             .current_dir(workspace)
-            .args(["checkout", "main"])
+            .args(["checkout", &default_branch])
             .output()
             .unwrap();
 
@@ -1847,16 +4185,111 @@ mod tests {
         remove_worktree(workspace, &worktree_path).unwrap();
     }
 
+    #[test]
+    fn continue_rebase_finishes_after_conflict_is_resolved_by_hand() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "shared.txt", "original", "initial commit");
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+        git_stdout(workspace, &["checkout", &integration], None).unwrap();
+        make_commit(workspace, "shared.txt", "integration change", "integration commit");
+        make_commit(&worktree_path, "shared.txt", "task change", "task commit");
+        rebase_onto_integration(&worktree_path, &integration).unwrap();
+
+        let unresolved = continue_rebase(&worktree_path).unwrap();
+        fs::write(worktree_path.join("shared.txt"), "merged change").unwrap();
+        git_stdout(&worktree_path, &["add", "shared.txt"], None).unwrap();
+        let resolved = continue_rebase(&worktree_path).unwrap();
+
+        assert!(matches!(
+            unresolved,
+            RebaseOutcome::Conflict { conflicted_files } if conflicted_files == ["shared.txt"]
+        ));
+        assert!(matches!(resolved, RebaseOutcome::Success));
+        assert!(!is_rebase_in_progress(&worktree_path));
+        let subject = git_stdout(&worktree_path, &["log", "-1", "--format=%s"], None).unwrap();
+        assert_eq!(subject, "task commit");
+    }
+
+    #[test]
+    fn rebase_reports_conflict_when_stopped_without_unmerged_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "shared.txt", "original", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
+
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["checkout", &integration])
+            .output()
+            .unwrap();
+        make_commit(workspace, "shared.txt", "integration change", "integration commit");
+        Command::new("git")
+            .current_dir(workspace)
+            .args(["checkout", &default_branch])
+            .output()
+            .unwrap();
+        make_commit(&worktree_path, "shared.txt", "task change", "task commit");
+        rebase_onto_integration(&worktree_path, &integration).unwrap();
+
+        // 충돌 파일을 해결된 것으로 표시해 두면 리베이스는 멈춰 있지만 충돌 파일 목록은 비어 있다.
+        Command::new("git")
+            .current_dir(&worktree_path)
+            .args(["add", "shared.txt"])
+            .output()
+            .unwrap();
+        assert!(is_rebase_in_progress(&worktree_path));
+
+        let result = rebase_onto_integration(&worktree_path, &integration).unwrap();
+
+        match result {
+            RebaseOutcome::Conflict { conflicted_files } => assert!(conflicted_files.is_empty()),
+            RebaseOutcome::Success => panic!("stopped rebase must be reported as a conflict"),
+        }
+
+        abort_rebase(&worktree_path).unwrap();
+        assert!(!is_rebase_in_progress(&worktree_path));
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn rebase_error_includes_attempted_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+
+        let err = rebase_onto_integration(workspace, "bear/integration/missing").err().unwrap();
+
+        assert!(matches!(err, GitError::UnknownRevision { .. }), "{:?}", err);
+        let message = err.to_string();
+        assert!(message.contains("`git rebase bear/integration/missing` failed in"), "{}", message);
+    }
+
     #[test]
     fn abort_rebase_restores_clean_state() {
         let temp_dir = TempDir::new().unwrap();
         let workspace = temp_dir.path();
         init_git_repo(workspace);
         make_commit(workspace, "shared.txt", "original", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
 
         Command::new("git")
             .current_dir(workspace)
@@ -1866,7 +4299,7 @@ mod tests {
         make_commit(workspace, "shared.txt", "integration", "integration commit");
         Command::new("git")
             .current_dir(workspace)
-            .args(["checkout", "main"])
+            .args(["checkout", &default_branch])
             .output()
             .unwrap();
 
@@ -1892,33 +4325,262 @@ mod tests {
         let workspace = temp_dir.path();
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+
+        make_commit(&worktree_path, "feature.txt", "feature", "feature commit");
+        make_commit(&worktree_path, "feature2.txt", "feature2", "feature2 commit");
+
+        rebase_onto_integration(&worktree_path, &integration).unwrap();
+
+        fast_forward_merge_task_branch(
+            workspace,
+            &task_branch,
+        )
+        .unwrap();
+
+        // fast-forward 머지 후 태스크 브랜치의 커밋들이 그대로 통합 브랜치에 존재하는지 확인
+        let log_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["log", "--oneline", &format!("{}..HEAD", default_branch)])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&log_output.stdout);
+        let commit_lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(commit_lines.len(), 2);
+        assert!(commit_lines[0].contains("feature2 commit"));
+        assert!(commit_lines[1].contains("feature commit"));
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn detect_default_branch_uses_current_branch_without_remote() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        git_stdout(workspace, &["symbolic-ref", "HEAD", "refs/heads/trunk"], None).unwrap();
+        make_commit(workspace, "init.txt", "init", "initial commit");
+
+        assert_eq!(detect_default_branch(workspace).as_deref(), Some("trunk"));
+
+        let head = git_stdout(workspace, &["rev-parse", "HEAD"], None).unwrap();
+        git_stdout(workspace, &["checkout", "--detach", &head], None).unwrap();
+        assert_eq!(detect_default_branch(workspace), None);
+    }
+
+    #[test]
+    fn detect_default_branch_prefers_remote_head() {
+        let temp_dir = TempDir::new().unwrap();
+        let origin = temp_dir.path().join("origin");
+        fs::create_dir(&origin).unwrap();
+        init_git_repo(&origin);
+        git_stdout(&origin, &["symbolic-ref", "HEAD", "refs/heads/develop"], None).unwrap();
+        make_commit(&origin, "init.txt", "init", "initial commit");
+        let clone = temp_dir.path().join("clone");
+        git_stdout(
+            temp_dir.path(),
+            &["clone", "--quiet", &origin.display().to_string(), "clone"],
+            None,
+        )
+        .unwrap();
+        git_stdout(&clone, &["checkout", "-b", "feature"], None).unwrap();
+
+        assert_eq!(detect_default_branch(&clone).as_deref(), Some("develop"));
+    }
+
+    #[test]
+    fn task_merges_into_integration_branch_of_repo_without_main() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        git_stdout(workspace, &["symbolic-ref", "HEAD", "refs/heads/trunk"], None).unwrap();
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
+
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+        make_commit(&worktree_path, "feature.txt", "feature", "feature commit");
+        rebase_onto_integration(&worktree_path, &integration).unwrap();
+        fast_forward_merge_task_branch(workspace, &task_branch).unwrap();
+
+        let range = format!("{}..{}", default_branch, integration);
+        let log = git_stdout(workspace, &["log", "--oneline", &range], None).unwrap();
+        assert_eq!(log.lines().count(), 1);
+        assert!(log.contains("feature commit"));
+        assert!(git_stdout(workspace, &["rev-parse", "--verify", "main"], None).is_err());
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    /// Sets up an integration branch checked out in `workspace` and a rebased task
+    /// branch worktree with two commits. Returns (integration, task_branch, worktree_path).
+    fn prepare_task_branch_with_two_commits(workspace: &Path) -> (String, String, PathBuf) {
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(workspace, &workspace.join("worktrees"), &task_branch).unwrap();
+
+        make_commit(&worktree_path, "feature.txt", "feature", "feature commit");
+        make_commit(&worktree_path, "feature2.txt", "feature2", "feature2 commit");
+        rebase_onto_integration(&worktree_path, &integration).unwrap();
+
+        (integration, task_branch, worktree_path)
+    }
+
+    fn git_log_subjects(dir: &Path, range: &str) -> Vec<String> {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(["log", "--format=%s", range])
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn merge_task_branch_fast_forward_only_keeps_task_commits() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (_, task_branch, worktree_path) = prepare_task_branch_with_two_commits(workspace);
+
+        merge_task_branch(workspace, &task_branch, "TASK-00", MergeStrategy::FastForwardOnly)
+            .unwrap();
+
+        assert_eq!(
+            git_log_subjects(workspace, "master..HEAD"),
+            vec!["feature2 commit", "feature commit"],
+        );
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn merge_task_branch_fast_forward_only_fails_when_integration_advanced() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (_, task_branch, worktree_path) = prepare_task_branch_with_two_commits(workspace);
+        make_commit(workspace, "other.txt", "other", "other task commit");
+
+        let result =
+            merge_task_branch(workspace, &task_branch, "TASK-00", MergeStrategy::FastForwardOnly);
+
+        assert!(matches!(result, Err(GitError::NotFastForward { .. })), "{:?}", result);
+        assert_eq!(git_log_subjects(workspace, "-1"), vec!["other task commit"]);
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn merge_retry_rebases_when_integration_advanced_after_rebase() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (integration, task_branch, worktree_path) =
+            prepare_task_branch_with_two_commits(workspace);
+        // 리베이스와 머지 사이에 다른 태스크가 통합 브랜치에 머지된 상황.
+        make_commit(workspace, "other.txt", "other", "other task commit");
+
+        let rebase_retries = merge_task_branch_with_rebase_retry(
+            workspace,
+            &worktree_path,
+            &integration,
+            &task_branch,
+            "[TASK-00] Add feature",
+            MergeStrategy::FastForwardOnly,
+        )
+        .ok()
+        .unwrap();
+
+        assert_eq!(rebase_retries, 1);
+        assert_eq!(
+            git_log_subjects(workspace, "master..HEAD"),
+            vec!["feature2 commit", "feature commit", "other task commit"],
+        );
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn merge_retry_reports_new_conflict_and_aborts_rebase() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (integration, task_branch, worktree_path) =
+            prepare_task_branch_with_two_commits(workspace);
+        make_commit(workspace, "feature.txt", "conflicting", "other task commit");
+
+        let result = merge_task_branch_with_rebase_retry(
+            workspace,
+            &worktree_path,
+            &integration,
+            &task_branch,
+            "[TASK-00] Add feature",
+            MergeStrategy::FastForwardOnly,
+        );
+
+        match result {
+            Err(MergeFailure::RebaseConflict { conflicted_files }) => {
+                assert_eq!(conflicted_files, vec!["feature.txt".to_string()]);
+            }
+            _ => panic!("expected a rebase conflict"),
+        }
+        assert!(!is_rebase_in_progress(&worktree_path));
+        assert_eq!(git_log_subjects(workspace, "-1"), vec!["other task commit"]);
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn merge_task_branch_squash_creates_single_commit_labeled_with_task_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (_, task_branch, worktree_path) = prepare_task_branch_with_two_commits(workspace);
+
+        let message = CommitMessageTemplate::default().render("TASK-00", "Add feature");
+        merge_task_branch(workspace, &task_branch, &message, MergeStrategy::Squash).unwrap();
+
+        let subjects = git_log_subjects(workspace, "master..HEAD");
+        assert_eq!(subjects, vec!["[TASK-00] Add feature"]);
+        assert!(workspace.join("feature.txt").exists());
+        assert!(workspace.join("feature2.txt").exists());
 
-        make_commit(&worktree_path, "feature.txt", "feature", "feature commit");
-        make_commit(&worktree_path, "feature2.txt", "feature2", "feature2 commit");
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
 
-        rebase_onto_integration(&worktree_path, &integration).unwrap();
+    #[test]
+    fn merge_task_branch_merge_commit_succeeds_when_integration_advanced() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let (_, task_branch, worktree_path) = prepare_task_branch_with_two_commits(workspace);
+        make_commit(workspace, "other.txt", "other", "other task commit");
 
-        fast_forward_merge_task_branch(
-            workspace,
-            &task_branch,
-        )
-        .unwrap();
+        let template: CommitMessageTemplate = "Merge {task_id}: {title}".parse().unwrap();
+        let message = template.render("TASK-00", "Add feature");
+        merge_task_branch(workspace, &task_branch, &message, MergeStrategy::MergeCommit).unwrap();
 
-        // fast-forward 머지 후 태스크 브랜치의 커밋들이 그대로 통합 브랜치에 존재하는지 확인
-        let log_output = Command::new("git")
+        let parents = Command::new("git")
             .current_dir(workspace)
-            .args(["log", "--oneline", &format!("{}..HEAD", "master")])
+            .args(["rev-list", "--parents", "-n", "1", "HEAD"])
             .output()
             .unwrap();
-        let stdout = String::from_utf8_lossy(&log_output.stdout);
-        let commit_lines: Vec<&str> = stdout.lines().collect();
-        assert_eq!(commit_lines.len(), 2);
-        assert!(commit_lines[0].contains("feature2 commit"));
-        assert!(commit_lines[1].contains("feature commit"));
+        let parents = String::from_utf8_lossy(&parents.stdout);
+        assert_eq!(parents.split_whitespace().count(), 3);
+        assert_eq!(git_log_subjects(workspace, "-1"), vec!["Merge TASK-00: Add feature"]);
+        assert!(workspace.join("feature2.txt").exists());
+        assert!(workspace.join("other.txt").exists());
 
         remove_worktree(workspace, &worktree_path).unwrap();
     }
@@ -1930,7 +4592,8 @@ mod tests {
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
 
         delete_branch(workspace, &task_branch).unwrap();
@@ -1950,10 +4613,13 @@ mod tests {
         let workspace = temp_dir.path();
         init_git_repo(workspace);
         make_commit(workspace, "shared.txt", "original", "initial commit");
+        let default_branch = detect_default_branch(workspace).unwrap();
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
 
         Command::new("git")
             .current_dir(workspace)
@@ -1963,7 +4629,7 @@ mod tests {
         make_commit(workspace, "shared.txt", "integration", "integration commit");
         Command::new("git")
             .current_dir(workspace)
-            .args(["checkout", "main"])
+            .args(["checkout", &default_branch])
             .output()
             .unwrap();
 
@@ -2116,6 +4782,206 @@ mod tests {
         assert_eq!(commands.test, "go test ./...");
     }
 
+    #[test]
+    fn detect_build_commands_with_gradle_wrapper() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("build.gradle"), "plugins { id 'java' }\n").unwrap();
+        fs::write(temp_dir.path().join("gradlew"), "#!/bin/sh\n").unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "./gradlew build");
+        assert_eq!(commands.test, "./gradlew test");
+    }
+
+    #[test]
+    fn detect_build_commands_with_gradle_kts_without_wrapper() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("build.gradle.kts"),
+            "plugins { java }\n",
+        )
+        .unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "gradle build");
+        assert_eq!(commands.test, "gradle test");
+    }
+
+    #[test]
+    fn detect_build_commands_with_maven_wrapper() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("pom.xml"), "<project></project>\n").unwrap();
+        fs::write(temp_dir.path().join("mvnw"), "#!/bin/sh\n").unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "./mvnw -q compile");
+        assert_eq!(commands.test, "./mvnw -q test");
+    }
+
+    #[test]
+    fn detect_build_commands_with_maven_without_wrapper() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("pom.xml"), "<project></project>\n").unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "mvn -q compile");
+        assert_eq!(commands.test, "mvn -q test");
+    }
+
+    #[test]
+    fn detect_build_commands_with_pyproject_and_tests_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"test\"\n",
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join("tests")).unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "pip install -e .");
+        assert_eq!(commands.test, "pytest");
+    }
+
+    #[test]
+    fn detect_build_commands_python_without_tests_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("setup.py"), "from setuptools import setup\n").unwrap();
+
+        // tests 디렉토리가 없으면 None
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_none());
+    }
+
+    fn write_cmake_project(dir: &Path, presets: serde_json::Value) {
+        fs::write(dir.join("CMakeLists.txt"), "cmake_minimum_required(VERSION 3.21)\n").unwrap();
+        fs::write(
+            dir.join("CMakePresets.json"),
+            serde_json::to_string(&presets).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn detect_build_commands_with_cmake_prefers_debug_preset() {
+        let temp_dir = TempDir::new().unwrap();
+        write_cmake_project(
+            temp_dir.path(),
+            serde_json::json!({
+                "version": 3,
+                "configurePresets": [
+                    { "name": "base", "hidden": true },
+                    { "name": "release", "inherits": "base" },
+                    { "name": "debug", "inherits": "base" }
+                ],
+                "buildPresets": [
+                    { "name": "build-debug", "configurePreset": "debug" }
+                ],
+                "testPresets": [
+                    { "name": "test-debug", "configurePreset": "debug" }
+                ]
+            }),
+        );
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(
+            commands.build,
+            "cmake --preset debug && cmake --build --preset build-debug"
+        );
+        assert_eq!(
+            commands.test_command(),
+            "ctest --preset test-debug --output-on-failure --timeout 90"
+        );
+    }
+
+    #[test]
+    fn detect_build_commands_with_cmake_falls_back_to_first_visible_preset() {
+        let temp_dir = TempDir::new().unwrap();
+        write_cmake_project(
+            temp_dir.path(),
+            serde_json::json!({
+                "version": 3,
+                "configurePresets": [
+                    {
+                        "name": "base",
+                        "hidden": true,
+                        "binaryDir": "${sourceDir}/out/${presetName}"
+                    },
+                    { "name": "ninja", "inherits": ["base"] }
+                ]
+            }),
+        );
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "cmake --preset ninja && cmake --build ./out/ninja");
+        assert_eq!(
+            commands.test_command(),
+            "ctest --test-dir ./out/ninja --output-on-failure --timeout 90"
+        );
+    }
+
+    #[test]
+    fn detect_build_commands_cmake_without_build_presets_or_known_binary_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        write_cmake_project(
+            temp_dir.path(),
+            serde_json::json!({
+                "version": 3,
+                "configurePresets": [
+                    { "name": "ci", "binaryDir": "$env{BUILD_ROOT}/ci" }
+                ]
+            }),
+        );
+
+        assert!(detect_build_commands(temp_dir.path()).is_none());
+    }
+
+    #[test]
+    fn detect_build_commands_cmake_without_presets() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("CMakeLists.txt"),
+            "cmake_minimum_required(VERSION 3.21)\n",
+        )
+        .unwrap();
+
+        // CMakePresets.json이 없으면 None
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn detect_build_commands_uses_task_subdir() {
+        let temp_dir = TempDir::new().unwrap();
+        let api_dir = temp_dir.path().join("services").join("api");
+        fs::create_dir_all(&api_dir).unwrap();
+        fs::write(api_dir.join("Cargo.toml"), "[package]\nname = \"api\"\n").unwrap();
+        fs::write(temp_dir.path().join("Makefile"), "build:\n\tmake all\n\ntest:\n\tmake check\n")
+            .unwrap();
+
+        let task = task_with_subdir(Some("services/api"));
+        let commands = detect_build_commands(&task.working_directory(temp_dir.path())).unwrap();
+        assert_eq!(commands.build, "cargo build");
+        assert_eq!(commands.test, "cargo test");
+
+        let root = task_with_subdir(None).working_directory(temp_dir.path());
+        assert_eq!(detect_build_commands(&root).unwrap().build, "make build");
+    }
+
     #[test]
     fn detect_build_commands_returns_none_for_empty_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -2144,19 +5010,215 @@ mod tests {
         assert_eq!(commands.test, "make test");
     }
 
+    #[test]
+    fn detect_build_commands_makefile_has_priority_over_cmake() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Makefile"),
+            "build:\n\tcmake --build build\n\ntest:\n\tctest --test-dir build\n",
+        )
+        .unwrap();
+        write_cmake_project(
+            temp_dir.path(),
+            serde_json::json!({
+                "version": 3,
+                "configurePresets": [{ "name": "debug" }]
+            }),
+        );
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "make build");
+        assert_eq!(commands.test, "make test");
+    }
+
+    #[test]
+    fn detect_build_commands_makefile_has_priority_over_gradle_and_maven() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Makefile"),
+            "build:\n\t./gradlew build\n\ntest:\n\t./gradlew test\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("build.gradle"), "plugins { id 'java' }\n").unwrap();
+        fs::write(temp_dir.path().join("pom.xml"), "<project></project>\n").unwrap();
+
+        let result = detect_build_commands(temp_dir.path());
+        assert!(result.is_some());
+        let commands = result.unwrap();
+        assert_eq!(commands.build, "make build");
+        assert_eq!(commands.test, "make test");
+    }
+
+    // -----------------------------------------------------------------------
+    // Formatter tests
+    // -----------------------------------------------------------------------
+
+    fn detected_formatter(files: &[&str]) -> Option<FormatterCommand> {
+        let temp_dir = TempDir::new().unwrap();
+        for file in files {
+            fs::write(temp_dir.path().join(file), "").unwrap();
+        }
+        detect_formatter(temp_dir.path())
+    }
+
+    #[test]
+    fn detect_formatter_selects_tool_per_ecosystem() {
+        let rust = detected_formatter(&["Cargo.toml"]).unwrap();
+        assert_eq!(rust.program.as_deref(), Some("cargo-fmt"));
+        assert_eq!(rust.command, "cargo fmt --all");
+
+        let go = detected_formatter(&["go.mod"]).unwrap();
+        assert_eq!(go.program.as_deref(), Some("gofmt"));
+        assert_eq!(go.command, "gofmt -w .");
+
+        let clang = detected_formatter(&["CMakeLists.txt", ".clang-format"]).unwrap();
+        assert_eq!(clang.program.as_deref(), Some("clang-format"));
+        assert!(clang.command.ends_with("| xargs -0 -r clang-format -i"), "{}", clang.command);
+        assert!(clang.command.contains("'*.cpp'"), "{}", clang.command);
+    }
+
+    #[test]
+    fn detect_formatter_skips_c_without_style_file_and_unknown_projects() {
+        assert_eq!(detected_formatter(&["CMakeLists.txt"]), None);
+        assert_eq!(detected_formatter(&["package.json"]), None);
+        assert_eq!(detected_formatter(&[]), None);
+    }
+
+    #[test]
+    fn run_formatter_commits_changes_and_reports_each_outcome() {
+        let temp_dir = TempDir::new().unwrap();
+        let worktree = temp_dir.path();
+        init_git_repo(worktree);
+        make_commit(worktree, "main.rs", "fn main(){}", "initial commit");
+        let custom = |command: &str| FormatterCommand {
+            program: None,
+            command: command.to_string(),
+        };
+        let run = |formatter: &FormatterCommand| {
+            run_formatter(worktree, "HEAD", formatter, DEFAULT_BUILD_TEST_TIMEOUT, &|_| {})
+                .unwrap()
+        };
+
+        let rewrite = custom("printf 'fn main() {}\\n' > main.rs");
+        assert_eq!(run(&rewrite), FormatterOutcome::Committed);
+        assert_eq!(
+            git_stdout(worktree, &["log", "-1", "--format=%s"], None).unwrap(),
+            FORMATTER_COMMIT_MESSAGE,
+        );
+        assert_eq!(run(&rewrite), FormatterOutcome::Unchanged);
+
+        let failed = run(&custom("echo bad style >&2; exit 1"));
+        assert!(
+            matches!(&failed, FormatterOutcome::Failed { output } if output.contains("bad style")),
+            "{:?}",
+            failed,
+        );
+        let missing = FormatterCommand {
+            program: Some("bear-formatter-that-does-not-exist".to_string()),
+            command: "rm main.rs".to_string(),
+        };
+        assert_eq!(
+            run(&missing),
+            FormatterOutcome::Skipped { program: "bear-formatter-that-does-not-exist".to_string() },
+        );
+        assert!(worktree.join("main.rs").exists());
+    }
+
+    #[test]
+    fn run_formatter_folds_changes_into_the_task_commit() {
+        let temp_dir = TempDir::new().unwrap();
+        let worktree = temp_dir.path();
+        init_git_repo(worktree);
+        make_commit(worktree, "main.rs", "fn main(){}", "initial commit");
+        let base = git_stdout(worktree, &["rev-parse", "HEAD"], None).unwrap();
+        make_commit(worktree, "lib.rs", "fn lib(){}", "task commit");
+        let formatter = FormatterCommand {
+            program: None,
+            command: "printf 'fn lib() {}\\n' > lib.rs".to_string(),
+        };
+
+        let outcome =
+            run_formatter(worktree, &base, &formatter, DEFAULT_BUILD_TEST_TIMEOUT, &|_| {});
+
+        assert_eq!(outcome.unwrap(), FormatterOutcome::Committed);
+        let range = format!("{}..HEAD", base);
+        let subjects = git_stdout(worktree, &["log", "--format=%s", &range], None).unwrap();
+        assert_eq!(subjects, "task commit");
+        let content = git_stdout(worktree, &["show", "HEAD:lib.rs"], None).unwrap();
+        assert_eq!(content, "fn lib() {}");
+    }
+
     // -----------------------------------------------------------------------
     // Build/test execution tests
     // -----------------------------------------------------------------------
 
+    fn commands_with_per_test_timeout(test: &str) -> BuildTestCommands {
+        BuildTestCommands {
+            build: "true".to_string(),
+            test: test.to_string(),
+            per_test_timeout_secs: Some(30),
+        }
+    }
+
+    #[test]
+    fn per_test_timeout_is_injected_for_ctest() {
+        assert_eq!(
+            commands_with_per_test_timeout("ctest --preset debug").test_command(),
+            "ctest --preset debug --timeout 30"
+        );
+    }
+
+    #[test]
+    fn per_test_timeout_is_injected_for_go_test() {
+        assert_eq!(
+            commands_with_per_test_timeout("go test ./...").test_command(),
+            "go test -timeout 30s ./..."
+        );
+    }
+
+    #[test]
+    fn per_test_timeout_is_injected_for_pytest() {
+        assert_eq!(commands_with_per_test_timeout("pytest").test_command(), "pytest --timeout=30");
+        assert_eq!(
+            commands_with_per_test_timeout("python -m pytest tests").test_command(),
+            "python -m pytest tests --timeout=30"
+        );
+    }
+
+    #[test]
+    fn per_test_timeout_leaves_unsupported_or_explicit_commands_unchanged() {
+        for command in ["cargo test", "npm test", "make test", "ctest --timeout 5"] {
+            assert_eq!(commands_with_per_test_timeout(command).test_command(), command);
+        }
+    }
+
+    #[test]
+    fn pytest_per_test_timeout_requires_the_timeout_plugin() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("tests")).unwrap();
+        fs::write(temp_dir.path().join("pyproject.toml"), "[project]\nname = \"app\"\n").unwrap();
+        let without_plugin = detect_build_commands(temp_dir.path()).unwrap();
+        assert_eq!(without_plugin.test_command(), "pytest");
+
+        fs::write(temp_dir.path().join("requirements-dev.txt"), "pytest-timeout\n").unwrap();
+        let with_plugin = detect_build_commands(temp_dir.path()).unwrap();
+        assert_eq!(with_plugin.test_command(), "pytest --timeout=90");
+    }
+
     #[test]
     fn run_build_and_test_success() {
         let temp_dir = TempDir::new().unwrap();
         let commands = BuildTestCommands {
             build: "true".to_string(),
             test: "true".to_string(),
+            per_test_timeout_secs: None,
         };
 
-        let result = run_build_and_test(temp_dir.path(), &commands).unwrap();
+        let result =
+            run_build_and_test(temp_dir.path(), &commands, DEFAULT_BUILD_TEST_TIMEOUT, &[], &|_| {})
+                .unwrap();
         assert!(matches!(result, BuildTestOutcome::Success));
     }
 
@@ -2166,38 +5228,139 @@ mod tests {
         let commands = BuildTestCommands {
             build: "false".to_string(),
             test: "true".to_string(),
+            per_test_timeout_secs: None,
+        };
+
+        let result =
+            run_build_and_test(temp_dir.path(), &commands, DEFAULT_BUILD_TEST_TIMEOUT, &[], &|_| {})
+                .unwrap();
+        assert!(matches!(result, BuildTestOutcome::BuildFailed { .. }));
+    }
+
+    #[test]
+    fn run_build_and_test_test_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let commands = BuildTestCommands {
+            build: "true".to_string(),
+            test: "false".to_string(),
+            per_test_timeout_secs: None,
+        };
+
+        let result =
+            run_build_and_test(temp_dir.path(), &commands, DEFAULT_BUILD_TEST_TIMEOUT, &[], &|_| {})
+                .unwrap();
+        assert!(matches!(result, BuildTestOutcome::TestFailed { .. }));
+    }
+
+    #[test]
+    fn run_build_and_test_captures_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let commands = BuildTestCommands {
+            build: "echo build_ok && exit 1".to_string(),
+            test: "true".to_string(),
+            per_test_timeout_secs: None,
         };
 
-        let result = run_build_and_test(temp_dir.path(), &commands).unwrap();
-        assert!(matches!(result, BuildTestOutcome::BuildFailed { .. }));
+        let result =
+            run_build_and_test(temp_dir.path(), &commands, DEFAULT_BUILD_TEST_TIMEOUT, &[], &|_| {})
+                .unwrap();
+        if let BuildTestOutcome::BuildFailed { output } = result {
+            assert!(output.contains("build_ok"));
+        } else {
+            panic!("expected BuildFailed");
+        }
+    }
+
+    #[test]
+    fn build_cache_env_matches_detected_frameworks() {
+        let cache_dir = Path::new("/var/cache/bear");
+        let cases = [
+            (vec!["Cargo.toml"], Some(("CARGO_TARGET_DIR", "cargo-target"))),
+            (vec!["go.mod"], Some(("GOCACHE", "go-build"))),
+            (vec!["package.json"], Some(("npm_config_cache", "npm"))),
+            (vec!["pyproject.toml", "setup.py"], Some(("PIP_CACHE_DIR", "pip"))),
+            (vec!["Makefile", "CMakeLists.txt"], None),
+        ];
+
+        for (marker_files, expected) in cases {
+            let temp_dir = TempDir::new().unwrap();
+            for marker_file in &marker_files {
+                fs::write(temp_dir.path().join(marker_file), "").unwrap();
+            }
+
+            let env = build_cache_env(temp_dir.path(), cache_dir);
+
+            let expected: Vec<(String, PathBuf)> = expected
+                .into_iter()
+                .map(|(name, subdirectory)| (name.to_string(), cache_dir.join(subdirectory)))
+                .collect();
+            assert_eq!(env, expected, "{:?}", marker_files);
+        }
+    }
+
+    #[test]
+    fn build_cache_env_covers_every_framework_in_polyglot_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("Cargo.toml"), "").unwrap();
+        fs::write(temp_dir.path().join("package.json"), "{}").unwrap();
+
+        let names: Vec<String> = build_cache_env(temp_dir.path(), Path::new("/cache"))
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        assert_eq!(names, vec!["CARGO_TARGET_DIR", "npm_config_cache"]);
     }
 
     #[test]
-    fn run_build_and_test_test_failure() {
+    fn run_build_and_test_passes_cache_env_to_both_commands() {
         let temp_dir = TempDir::new().unwrap();
+        let cache_env = vec![("CARGO_TARGET_DIR".to_string(), PathBuf::from("/shared/target"))];
         let commands = BuildTestCommands {
-            build: "true".to_string(),
-            test: "false".to_string(),
+            build: "test \"$CARGO_TARGET_DIR\" = /shared/target".to_string(),
+            test: "test \"$CARGO_TARGET_DIR\" = /shared/target".to_string(),
+            per_test_timeout_secs: None,
         };
 
-        let result = run_build_and_test(temp_dir.path(), &commands).unwrap();
-        assert!(matches!(result, BuildTestOutcome::TestFailed { .. }));
+        let result = run_build_and_test(
+            temp_dir.path(),
+            &commands,
+            DEFAULT_BUILD_TEST_TIMEOUT,
+            &cache_env,
+            &|_| {},
+        )
+        .unwrap();
+
+        assert!(matches!(result, BuildTestOutcome::Success));
     }
 
     #[test]
-    fn run_build_and_test_captures_output() {
+    fn run_build_and_test_forwards_output_lines_as_they_arrive() {
         let temp_dir = TempDir::new().unwrap();
         let commands = BuildTestCommands {
-            build: "echo build_ok && exit 1".to_string(),
-            test: "true".to_string(),
+            build: "echo compiling; echo warning >&2; printf 'no newline'".to_string(),
+            test: "echo 'test result: ok'; exit 1".to_string(),
+            per_test_timeout_secs: None,
         };
+        let lines = std::cell::RefCell::new(Vec::new());
+
+        let result = run_build_and_test(
+            temp_dir.path(),
+            &commands,
+            DEFAULT_BUILD_TEST_TIMEOUT,
+            &[],
+            &|line| lines.borrow_mut().push(line),
+        )
+        .unwrap();
 
-        let result = run_build_and_test(temp_dir.path(), &commands).unwrap();
-        if let BuildTestOutcome::BuildFailed { output } = result {
-            assert!(output.contains("build_ok"));
-        } else {
-            panic!("expected BuildFailed");
-        }
+        let mut lines = lines.into_inner();
+        // stdout과 stderr는 별도 스레드에서 읽으므로 빌드 단계 안의 순서는 보장되지 않는다.
+        lines[..3].sort();
+        assert_eq!(lines, ["compiling", "no newline", "warning", "test result: ok"]);
+        let BuildTestOutcome::TestFailed { output } = result else {
+            panic!("expected TestFailed");
+        };
+        assert_eq!(output, "--- stdout ---\ntest result: ok\n--- stderr ---\n");
     }
 
     // -----------------------------------------------------------------------
@@ -2242,12 +5405,30 @@ mod tests {
             "make build",
             "make test",
             "error: cannot find module",
+            1,
         );
 
         assert!(prompt.contains("TASK-01"));
         assert!(prompt.contains("make build"));
         assert!(prompt.contains("make test"));
         assert!(prompt.contains("cannot find module"));
+        assert!(prompt.contains("error: cannot find module\n\nHard requirement"));
+        assert!(!prompt.contains("repair attempt"));
+    }
+
+    #[test]
+    fn later_build_test_repair_prompt_mentions_failed_attempts() {
+        let prompt = build_build_test_repair_prompt(
+            "TASK-01",
+            "make build",
+            "make test",
+            "error: still broken",
+            3,
+        );
+
+        assert!(prompt.contains("error: still broken\n\nThis is repair attempt 3."));
+        assert!(prompt.contains("The previous 2 repair attempt(s)"));
+        assert!(!prompt.contains("{{PREVIOUS_ATTEMPTS}}"));
     }
 
     // -----------------------------------------------------------------------
@@ -2265,6 +5446,8 @@ mod tests {
         assert!(result_enum.iter().any(|v| v == "APPROVED"));
         assert!(result_enum.iter().any(|v| v == "REQUEST_CHANGES"));
         assert!(schema["properties"]["review_comment"].is_object());
+        let severity = &schema["properties"]["findings"]["items"]["properties"]["severity"];
+        assert_eq!(severity["enum"], serde_json::json!(["BLOCKING", "NONBLOCKING"]));
     }
 
     #[test]
@@ -2291,6 +5474,70 @@ mod tests {
         assert!(result.review_comment.contains("에러 핸들링"));
     }
 
+    #[test]
+    fn deserialize_review_result_with_mixed_severity_findings() {
+        let json = serde_json::json!({
+            "review_result": "REQUEST_CHANGES",
+            "review_comment": "# Findings",
+            "findings": [
+                { "severity": "BLOCKING", "description": "Panics on empty input" },
+                { "severity": "NONBLOCKING", "description": "Rename `tmp`" }
+            ]
+        });
+
+        let result: ReviewResult = serde_json::from_value(json).unwrap();
+        assert_eq!(result.findings.len(), 2);
+        assert_eq!(result.findings[0].severity, FindingSeverity::Blocking);
+        assert_eq!(result.findings[1].severity, FindingSeverity::Nonblocking);
+        assert_eq!(result.findings[1].description, "Rename `tmp`");
+        assert!(!result.has_only_nonblocking_findings());
+    }
+
+    #[test]
+    fn only_nonblocking_findings_allow_auto_approval() {
+        let review = |review_result: &str, findings: serde_json::Value| -> ReviewResult {
+            serde_json::from_value(serde_json::json!({
+                "review_result": review_result,
+                "review_comment": "comment",
+                "findings": findings,
+            }))
+            .unwrap()
+        };
+        let nit = serde_json::json!([{ "severity": "NONBLOCKING", "description": "nit" }]);
+
+        assert!(review("REQUEST_CHANGES", nit.clone()).has_only_nonblocking_findings());
+        assert!(!review("APPROVED", nit).has_only_nonblocking_findings());
+        assert!(!review("REQUEST_CHANGES", serde_json::json!([])).has_only_nonblocking_findings());
+
+        let legacy: ReviewResult = serde_json::from_value(serde_json::json!({
+            "review_result": "REQUEST_CHANGES",
+            "review_comment": "fix it"
+        }))
+        .unwrap();
+        assert!(legacy.findings.is_empty());
+        assert!(!legacy.has_only_nonblocking_findings());
+    }
+
+    #[test]
+    fn nonblocking_findings_are_appended_to_report() {
+        let findings = vec![
+            ReviewFinding {
+                severity: FindingSeverity::Nonblocking,
+                description: "Rename `tmp` ".to_string(),
+            },
+            ReviewFinding {
+                severity: FindingSeverity::Blocking,
+                description: "ignored".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            append_nonblocking_findings("# Report\n", &findings),
+            "# Report\n\n## 나중에 처리할 리뷰 지적 사항 (차단하지 않음)\n- Rename `tmp`\n",
+        );
+        assert_eq!(append_nonblocking_findings("# Report", &findings[1..]), "# Report");
+    }
+
     #[test]
     fn initial_review_prompt_contains_all_fields() {
         let prompt = build_initial_review_prompt(
@@ -2330,6 +5577,8 @@ mod tests {
             title: "기본 타입 정의".to_string(),
             description: "핵심 타입을 정의합니다.".to_string(),
             dependencies: vec![],
+            files: Vec::new(),
+            subdir: None,
         };
 
         let prompt = build_coding_revision_prompt(
@@ -2360,6 +5609,61 @@ mod tests {
         assert!(revision.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    /// `.gitignore`가 `plan.md`를 무시하는 저장소와 복사할 스펙·플랜이 든 원본 디렉토리.
+    fn artifact_copy_fixture() -> (TempDir, PathBuf, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        init_git_repo(&repo);
+        make_commit(&repo, ".gitignore", "plan.md\n", "ignore plans");
+        let source = temp_dir.path().join("journal");
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("spec.md"), "# Spec").unwrap();
+        fs::write(source.join("plan.md"), "# Plan").unwrap();
+        (temp_dir, repo, source)
+    }
+
+    #[test]
+    fn copy_artifacts_reports_gitignored_files_without_staging_them() {
+        let (_temp_dir, repo, source) = artifact_copy_fixture();
+        let target = repo.join(".bear").join("20260101").join("session");
+
+        let file_names = ["spec.md", "plan.md", "missing.md"];
+
+        let outcome = copy_artifacts_to_worktree(&source, &target, &file_names, false);
+
+        assert_eq!(outcome.ignored, vec!["plan.md"]);
+        assert!(outcome.force_added.is_empty());
+        assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+        assert_eq!(fs::read_to_string(target.join("plan.md")).unwrap(), "# Plan");
+        assert!(!target.join("missing.md").exists());
+        assert_eq!(git_stdout(&repo, &["diff", "--cached", "--name-only"], None).unwrap(), "");
+    }
+
+    #[test]
+    fn copy_artifacts_force_adds_only_gitignored_files() {
+        let (_temp_dir, repo, source) = artifact_copy_fixture();
+        let target = repo.join("docs");
+
+        let outcome = copy_artifacts_to_worktree(&source, &target, &["spec.md", "plan.md"], true);
+
+        assert_eq!(outcome.force_added, vec!["plan.md"]);
+        assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+        assert_eq!(
+            git_stdout(&repo, &["diff", "--cached", "--name-only"], None).unwrap(),
+            "docs/plan.md",
+        );
+    }
+
+    #[test]
+    fn copy_artifacts_reports_nothing_when_no_file_is_ignored() {
+        let (_temp_dir, repo, source) = artifact_copy_fixture();
+
+        let outcome = copy_artifacts_to_worktree(&source, &repo.join("docs"), &["spec.md"], true);
+
+        assert_eq!(outcome, ArtifactCopyOutcome::default());
+    }
+
     #[test]
     fn save_and_commit_task_report_in_worktree_creates_committed_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -2367,9 +5671,11 @@ mod tests {
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
 
-        let integration = create_integration_branch(workspace, "test").unwrap();
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
         let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
-        let worktree_path = create_worktree(workspace, &task_branch).unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
         make_commit(&worktree_path, "feature.txt", "feature", "feature commit");
 
         let report_path = save_and_commit_task_report_in_worktree(
@@ -2378,6 +5684,8 @@ mod tests {
             "test-session",
             "TASK-00",
             "# Test Report\nImplementation complete.",
+            &integration,
+            "[TASK-00] Add feature",
         )
         .unwrap();
 
@@ -2410,6 +5718,98 @@ mod tests {
         remove_worktree(workspace, &worktree_path).unwrap();
     }
 
+    #[test]
+    fn report_without_task_commit_is_committed_with_template_subject() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        let integration =
+            create_integration_branch(workspace, "test", &Default::default()).unwrap();
+        let task_branch = create_task_branch(workspace, &integration, "TASK-00").unwrap();
+        let worktree_path =
+            create_worktree(workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+        let message = CommitMessageTemplate::default().render("TASK-00", "Add docs");
+
+        save_and_commit_task_report_in_worktree(
+            &worktree_path,
+            "20260216",
+            "test-session",
+            "TASK-00",
+            "# Report",
+            &integration,
+            &message,
+        )
+        .unwrap();
+
+        assert_eq!(
+            git_log_subjects(&worktree_path, &format!("{}..HEAD", integration)),
+            vec!["[TASK-00] Add docs"],
+        );
+        assert_eq!(git_log_subjects(workspace, "-1"), vec!["initial commit"]);
+
+        remove_worktree(workspace, &worktree_path).unwrap();
+    }
+
+    #[test]
+    fn report_commits_of_two_tasks_merge_without_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path().join("repo");
+        fs::create_dir(&workspace).unwrap();
+        init_git_repo(&workspace);
+        make_commit(&workspace, "init.txt", "init", "initial commit");
+        let integration =
+            create_integration_branch(&workspace, "test", &Default::default()).unwrap();
+
+        // 두 태스크가 같은 통합 브랜치 시점에서 출발해 각자 코드와 리포트를 커밋한다.
+        let mut task_worktrees = Vec::new();
+        for task_id in ["TASK-00", "TASK-01"] {
+            let task_branch = create_task_branch(&workspace, &integration, task_id).unwrap();
+            let worktree_path =
+                create_worktree(&workspace, &temp_dir.path().join("worktrees"), &task_branch).unwrap();
+            let file_name = format!("{}.txt", task_id);
+            make_commit(&worktree_path, &file_name, task_id, task_id);
+            save_and_commit_task_report_in_worktree(
+                &worktree_path,
+                "20260301",
+                "test-session",
+                task_id,
+                &format!("# {} report", task_id),
+                &integration,
+                &format!("[{}] Add feature", task_id),
+            )
+            .unwrap();
+            task_worktrees.push((task_id, task_branch, worktree_path));
+        }
+
+        for (task_id, task_branch, worktree_path) in &task_worktrees {
+            let rebase = rebase_onto_integration(worktree_path, &integration).unwrap();
+            assert!(matches!(rebase, RebaseOutcome::Success), "{} rebase conflicted", task_id);
+            merge_task_branch(&workspace, task_branch, task_id, MergeStrategy::FastForwardOnly)
+                .unwrap();
+            remove_worktree(&workspace, worktree_path).unwrap();
+        }
+
+        let journal_dir = workspace.join(".bear").join("20260301").join("test-session");
+        assert_eq!(
+            fs::read_to_string(journal_dir.join("TASK-00.md")).unwrap(),
+            "# TASK-00 report",
+        );
+        assert_eq!(
+            fs::read_to_string(journal_dir.join("TASK-01.md")).unwrap(),
+            "# TASK-01 report",
+        );
+    }
+
+    #[test]
+    fn report_file_name_rejects_path_like_task_ids() {
+        assert_eq!(task_report_file_name("TASK-00").unwrap(), "TASK-00.md");
+        assert!(task_report_file_name("../TASK-00").is_err());
+        assert!(task_report_file_name("a/b").is_err());
+        assert!(task_report_file_name("..").is_err());
+        assert!(task_report_file_name("").is_err());
+    }
+
     #[test]
     fn commit_file_in_workspace_commits_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -2417,7 +5817,7 @@ mod tests {
         init_git_repo(workspace);
         make_commit(workspace, "init.txt", "init", "initial commit");
 
-        create_integration_branch(workspace, "test").unwrap();
+        create_integration_branch(workspace, "test", &Default::default()).unwrap();
 
         let bear_dir = workspace.join(".bear").join("20260218").join("test-session");
         fs::create_dir_all(&bear_dir).unwrap();
@@ -2454,4 +5854,295 @@ mod tests {
         let result = commit_file_in_workspace(workspace, &nonexistent, "Should fail");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn is_git_repository_detects_repo_and_plain_directory() {
+        let repo_dir = TempDir::new().unwrap();
+        init_git_repo(repo_dir.path());
+        let plain_dir = TempDir::new().unwrap();
+
+        assert!(is_git_repository(repo_dir.path()));
+        assert!(!is_git_repository(plain_dir.path()));
+    }
+
+    #[test]
+    fn commit_file_to_branch_leaves_working_tree_untouched() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+
+        let bear_dir = workspace.join(".bear").join("20260218").join("test-session");
+        fs::create_dir_all(&bear_dir).unwrap();
+        let file_path = bear_dir.join("spec.md");
+        fs::write(&file_path, "# Spec").unwrap();
+
+        commit_file_to_branch(workspace, &file_path, "bear/docs", "Add approved specification")
+            .unwrap();
+
+        let show_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["show", "bear/docs:.bear/20260218/test-session/spec.md"])
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&show_output.stdout), "# Spec");
+
+        let log_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["log", "--format=%s", "bear/docs"])
+            .output()
+            .unwrap();
+        let log = String::from_utf8_lossy(&log_output.stdout);
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            vec!["Add approved specification", "initial commit"]
+        );
+
+        let head_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&head_output.stdout).trim(), "master");
+
+        let status_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["status", "--porcelain"])
+            .output()
+            .unwrap();
+        assert!(String::from_utf8_lossy(&status_output.stdout).contains("?? .bear/"));
+    }
+
+    #[test]
+    fn commit_file_to_branch_appends_to_existing_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        init_git_repo(workspace);
+        make_commit(workspace, "init.txt", "init", "initial commit");
+        fs::write(workspace.join("spec.md"), "spec").unwrap();
+        fs::write(workspace.join("plan.md"), "plan").unwrap();
+
+        commit_file_to_branch(workspace, &workspace.join("spec.md"), "docs", "Add spec").unwrap();
+        commit_file_to_branch(workspace, &workspace.join("plan.md"), "docs", "Add plan").unwrap();
+
+        let tree_output = Command::new("git")
+            .current_dir(workspace)
+            .args(["ls-tree", "--name-only", "docs"])
+            .output()
+            .unwrap();
+        let tree = String::from_utf8_lossy(&tree_output.stdout);
+        assert!(tree.contains("spec.md"));
+        assert!(tree.contains("plan.md"));
+        assert!(tree.contains("init.txt"));
+    }
+
+    fn paths(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn predict_conflicts_reports_overlapping_files_sorted() {
+        let task_files = paths(&["src/main.rs", "./src/lib.rs", "README.md", "src/lib.rs"]);
+        let integration_files = paths(&["src/lib.rs", "Cargo.toml", "src/main.rs"]);
+
+        assert_eq!(
+            predict_conflicts(&task_files, &integration_files),
+            paths(&["src/lib.rs", "src/main.rs"])
+        );
+    }
+
+    #[test]
+    fn predict_conflicts_is_empty_for_disjoint_files() {
+        let task_files = paths(&["src/a.rs", "src/b.rs"]);
+        let integration_files = paths(&["src/c.rs"]);
+
+        assert!(predict_conflicts(&task_files, &integration_files).is_empty());
+        assert!(predict_conflicts(&[], &integration_files).is_empty());
+        assert!(predict_conflicts(&task_files, &[]).is_empty());
+    }
+
+    #[test]
+    fn changed_dependency_manifests_detects_manifest_and_lockfile_edits() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        init_git_repo(repo);
+        make_commit(repo, "base.txt", "base", "base commit");
+        Command::new("git")
+            .current_dir(repo)
+            .args(["checkout", "-b", "feature"])
+            .output()
+            .unwrap();
+        make_commit(repo, "Cargo.toml", "[dependencies]\nserde = \"1\"\n", "add serde");
+        fs::create_dir_all(repo.join("web")).unwrap();
+        make_commit(repo, "web/package.json", "{}", "add web package");
+        make_commit(repo, "main.rs", "fn main() {}", "add main");
+
+        let manifests = changed_dependency_manifests(repo, "master").unwrap();
+
+        assert_eq!(manifests, paths(&["Cargo.toml", "web/package.json"]));
+        let note = build_dependency_change_review_note(&manifests);
+        assert!(note.contains("  - web/package.json"));
+    }
+
+    #[test]
+    fn changed_files_since_merge_base_lists_only_head_side_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        init_git_repo(repo);
+        make_commit(repo, "base.txt", "base", "base commit");
+        Command::new("git")
+            .current_dir(repo)
+            .args(["branch", "feature"])
+            .output()
+            .unwrap();
+        make_commit(repo, "master.txt", "master", "master change");
+        Command::new("git")
+            .current_dir(repo)
+            .args(["checkout", "feature"])
+            .output()
+            .unwrap();
+        make_commit(repo, "feature.txt", "feature", "feature change");
+
+        assert_eq!(
+            changed_files_since_merge_base(repo, "master", "HEAD").unwrap(),
+            paths(&["feature.txt"])
+        );
+        assert_eq!(
+            changed_files_since_merge_base(repo, "HEAD", "master").unwrap(),
+            paths(&["master.txt"])
+        );
+    }
+
+    fn review_of_chain() -> TaskReviewState {
+        TaskReviewState::new(vec![
+            coding_task("TASK-00", &[]),
+            coding_task("TASK-01", &["TASK-00"]),
+            coding_task("TASK-02", &[]),
+        ])
+    }
+
+    #[test]
+    fn task_selection_accepts_order_that_respects_dependencies() {
+        let tasks = vec![
+            coding_task("TASK-02", &[]),
+            coding_task("TASK-00", &[]),
+            coding_task("TASK-01", &["TASK-00", "TASK-99"]),
+        ];
+
+        assert!(validate_task_selection(&tasks, &[true, true, true]).is_ok());
+        assert!(validate_task_selection(&tasks, &[false, true, true]).is_ok());
+        assert!(validate_task_selection(&tasks, &[true, true, false]).is_ok());
+    }
+
+    #[test]
+    fn task_selection_rejects_dependency_after_dependent() {
+        let tasks = vec![coding_task("TASK-01", &["TASK-00"]), coding_task("TASK-00", &[])];
+
+        let err = validate_task_selection(&tasks, &[true, true]).unwrap_err();
+
+        assert!(err.contains("TASK-01") && err.contains("TASK-00"), "{}", err);
+        assert!(validate_task_selection(&tasks, &[false, true]).is_ok());
+    }
+
+    #[test]
+    fn task_review_rejects_deselecting_a_dependency() {
+        let mut review = review_of_chain();
+
+        let err = review.toggle(0).unwrap_err();
+
+        assert!(err.contains("제외할 수 없습니다"), "{}", err);
+        assert_eq!(review.included, vec![true, true, true]);
+
+        review.toggle(1).unwrap();
+        review.toggle(0).unwrap();
+        assert_eq!(review.included, vec![false, false, true]);
+        assert!(review.toggle(1).is_err());
+    }
+
+    #[test]
+    fn task_review_moves_within_dependency_constraints() {
+        let mut review = review_of_chain();
+
+        assert!(review.move_task(0, 2).is_err());
+        assert!(review.move_task(0, 3).is_err());
+        review.move_task(2, 0).unwrap();
+        review.toggle(0).unwrap();
+
+        assert!(review.describe().starts_with("1. [ ] [TASK-02] TASK-02 title"));
+        let selected: Vec<String> =
+            review.selected_tasks().into_iter().map(|task| task.task_id).collect();
+        assert_eq!(selected, vec!["TASK-00", "TASK-01"]);
+    }
+
+    #[test]
+    fn task_graph_accepts_consistent_tasks() {
+        let tasks = vec![
+            coding_task("TASK-00", &[]),
+            coding_task("TASK-01", &["TASK-00"]),
+            coding_task("TASK-02", &["TASK-00", "TASK-01"]),
+        ];
+
+        assert!(validate_task_graph(&tasks).is_ok());
+    }
+
+    #[test]
+    fn task_graph_rejects_duplicate_ids() {
+        let tasks = vec![coding_task("TASK-00", &[]), coding_task("TASK-00", &[])];
+
+        let err = validate_task_graph(&tasks).unwrap_err();
+
+        assert_eq!(err, "태스크 ID TASK-00가 중복되었습니다.");
+    }
+
+    #[test]
+    fn task_graph_rejects_dangling_dependency() {
+        let tasks = vec![coding_task("TASK-00", &[]), coding_task("TASK-01", &["TASK-07"])];
+
+        let err = validate_task_graph(&tasks).unwrap_err();
+
+        assert_eq!(err, "TASK-01이(가) 존재하지 않는 태스크 TASK-07에 의존합니다.");
+    }
+
+    #[test]
+    fn task_graph_rejects_cycle() {
+        let tasks = vec![
+            coding_task("TASK-00", &[]),
+            coding_task("TASK-01", &["TASK-00", "TASK-03"]),
+            coding_task("TASK-02", &["TASK-01"]),
+            coding_task("TASK-03", &["TASK-02"]),
+        ];
+
+        let err = validate_task_graph(&tasks).unwrap_err();
+
+        assert_eq!(err, "태스크 의존성이 순환합니다: TASK-01 -> TASK-03 -> TASK-02 -> TASK-01");
+    }
+
+    fn numbered_tasks(count: usize) -> Vec<CodingTask> {
+        (0..count)
+            .map(|index| coding_task(&format!("TASK-{:03}", index), &[]))
+            .collect()
+    }
+
+    #[test]
+    fn task_count_allows_up_to_the_limit() {
+        assert!(validate_task_count(&numbered_tasks(MAX_TASKS_PER_PLAN)).is_ok());
+
+        let err = validate_task_count(&numbered_tasks(MAX_TASKS_PER_PLAN + 1)).unwrap_err();
+
+        assert!(err.contains("101개"), "{}", err);
+        assert!(err.contains("플랜을 여러 세션으로 나누는"), "{}", err);
+    }
+
+    #[test]
+    fn split_at_task_limit_defers_the_overflow() {
+        let (kept, deferred) = split_at_task_limit(numbered_tasks(103)).unwrap();
+        assert_eq!(kept.len(), MAX_TASKS_PER_PLAN);
+        let deferred_ids: Vec<&str> = deferred.iter().map(|task| task.task_id.as_str()).collect();
+        assert_eq!(deferred_ids, vec!["TASK-100", "TASK-101", "TASK-102"]);
+
+        let mut tasks = numbered_tasks(101);
+        tasks[5].dependencies = vec!["TASK-100".to_string()];
+        let err = split_at_task_limit(tasks).unwrap_err();
+        assert_eq!(err, "TASK-005이(가) 뒤로 미뤄질 태스크 TASK-100에 의존합니다.");
+    }
 }
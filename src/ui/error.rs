@@ -8,4 +8,7 @@ pub enum UiError {
 
     #[error("Agent error: {message}")]
     AgentError { message: String },
+
+    #[error("Headless setup error: {message}")]
+    HeadlessSetupError { message: String },
 }
@@ -1,13 +1,195 @@
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 
-use crossterm::event::{self, Event};
+use crossterm::event::{self, Event, MouseEventKind};
 
-use super::error::UiError;
+pub enum UiEvent {
+    Terminal(Event),
+    AgentMessageArrived,
+    TerminalReadFailed(io::Error),
+}
+
+/// 터미널 입력과 에이전트 메시지 도착 알림을 하나의 채널로 모은다.
+/// UI 루프는 이 채널에서 recv_timeout으로 대기하므로, 이벤트가 없으면
+/// 매 프레임 폴링하지 않고 잠들어 있다가 이벤트가 오는 즉시 깨어난다.
+pub struct EventSource {
+    sender: mpsc::Sender<UiEvent>,
+    receiver: mpsc::Receiver<UiEvent>,
+    input_gate: Arc<InputGate>,
+}
+
+struct InputGate {
+    paused: AtomicBool,
+    reading: Mutex<()>,
+}
+
+/// 외부 에디터처럼 stdin을 직접 사용하는 프로세스가 실행되는 동안
+/// 터미널 입력 스레드가 키 입력을 가로채지 않도록 막는다.
+pub struct TerminalInputPause<'a> {
+    gate: &'a InputGate,
+    _reading: MutexGuard<'a, ()>,
+}
+
+impl Drop for TerminalInputPause<'_> {
+    fn drop(&mut self) {
+        self.gate.paused.store(false, Ordering::SeqCst);
+    }
+}
+
+impl EventSource {
+    pub fn start(poll_interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let input_gate = Arc::new(InputGate {
+            paused: AtomicBool::new(false),
+            reading: Mutex::new(()),
+        });
+
+        let terminal_sender = sender.clone();
+        let thread_gate = Arc::clone(&input_gate);
+        std::thread::spawn(move || {
+            read_terminal_events(terminal_sender, thread_gate, poll_interval);
+        });
+
+        Self {
+            sender,
+            receiver,
+            input_gate,
+        }
+    }
+
+    pub fn wake_sender(&self) -> mpsc::Sender<UiEvent> {
+        self.sender.clone()
+    }
+
+    pub fn next(&self, timeout: Duration) -> Option<UiEvent> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    pub fn pause_terminal_input(&self) -> TerminalInputPause<'_> {
+        self.input_gate.paused.store(true, Ordering::SeqCst);
+        let reading = self
+            .input_gate
+            .reading
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        TerminalInputPause {
+            gate: &self.input_gate,
+            _reading: reading,
+        }
+    }
+}
+
+fn read_terminal_events(
+    sender: mpsc::Sender<UiEvent>,
+    gate: Arc<InputGate>,
+    poll_interval: Duration,
+) {
+    loop {
+        if gate.paused.load(Ordering::SeqCst) {
+            std::thread::sleep(poll_interval);
+            continue;
+        }
+
+        let _reading = gate.reading.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if gate.paused.load(Ordering::SeqCst) {
+            continue;
+        }
+
+        let event = match event::poll(poll_interval) {
+            Ok(true) => event::read().map(UiEvent::Terminal),
+            Ok(false) => continue,
+            Err(err) => Err(err),
+        };
+        // 마우스 캡처가 켜져 있으면 커서 이동마다 이벤트가 오므로 휠 이외의 마우스 이벤트는
+        // UI 루프를 깨우지 않도록 여기서 버린다.
+        if let Ok(UiEvent::Terminal(Event::Mouse(mouse_event))) = &event
+            && !matches!(mouse_event.kind, MouseEventKind::ScrollUp | MouseEventKind::ScrollDown)
+        {
+            continue;
+        }
+        let event = event.unwrap_or_else(UiEvent::TerminalReadFailed);
+        let is_failure = matches!(event, UiEvent::TerminalReadFailed(_));
+
+        if sender.send(event).is_err() || is_failure {
+            return;
+        }
+    }
+}
+
+/// 에이전트 스레드의 메시지를 전용 스레드에서 블로킹 수신하여 그대로 전달하고,
+/// 메시지가 도착할 때마다 UI 루프를 깨운다. 원본 채널이 끊기면 마지막으로 한 번 더
+/// 깨워서 UI가 연결 종료를 감지하도록 한다.
+pub fn forward_agent_messages<T: Send + 'static>(
+    source: mpsc::Receiver<T>,
+    wake_sender: mpsc::Sender<UiEvent>,
+) -> mpsc::Receiver<T> {
+    let (forward_sender, forward_receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        while let Ok(message) = source.recv() {
+            if forward_sender.send(message).is_err() {
+                return;
+            }
+            let _ = wake_sender.send(UiEvent::AgentMessageArrived);
+        }
+        drop(forward_sender);
+        let _ = wake_sender.send(UiEvent::AgentMessageArrived);
+    });
+
+    forward_receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn forward_agent_messages_delivers_in_order_and_wakes_each_time() {
+        let (wake_sender, wake_receiver) = mpsc::channel();
+        let (source_sender, source_receiver) = mpsc::channel();
+        let forwarded = forward_agent_messages(source_receiver, wake_sender);
+
+        source_sender.send("first").unwrap();
+        source_sender.send("second").unwrap();
+
+        assert_eq!(forwarded.recv_timeout(TEST_TIMEOUT).unwrap(), "first");
+        assert_eq!(forwarded.recv_timeout(TEST_TIMEOUT).unwrap(), "second");
+        for _ in 0..2 {
+            let wake = wake_receiver.recv_timeout(TEST_TIMEOUT).unwrap();
+            assert!(matches!(wake, UiEvent::AgentMessageArrived));
+        }
+    }
+
+    #[test]
+    fn forward_agent_messages_propagates_disconnect_with_final_wake() {
+        let (wake_sender, wake_receiver) = mpsc::channel();
+        let (source_sender, source_receiver) = mpsc::channel::<String>();
+        let forwarded = forward_agent_messages(source_receiver, wake_sender);
+
+        drop(source_sender);
+
+        let wake = wake_receiver.recv_timeout(TEST_TIMEOUT).unwrap();
+        assert!(matches!(wake, UiEvent::AgentMessageArrived));
+        assert!(matches!(
+            forwarded.recv_timeout(TEST_TIMEOUT),
+            Err(mpsc::RecvTimeoutError::Disconnected)
+        ));
+    }
+
+    #[test]
+    fn forward_agent_messages_does_not_wake_without_messages() {
+        let (wake_sender, wake_receiver) = mpsc::channel();
+        let (_source_sender, source_receiver) = mpsc::channel::<String>();
+        let _forwarded = forward_agent_messages(source_receiver, wake_sender);
 
-pub fn poll_event(timeout: Duration) -> Result<Option<Event>, UiError> {
-    if event::poll(timeout)? {
-        Ok(Some(event::read()?))
-    } else {
-        Ok(None)
+        assert!(matches!(
+            wake_receiver.recv_timeout(Duration::from_millis(50)),
+            Err(mpsc::RecvTimeoutError::Timeout)
+        ));
     }
 }
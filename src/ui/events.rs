@@ -0,0 +1,258 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+
+use super::coding::BuildTestOutcome;
+
+/// 이벤트 파일에 기록하는 작업 단계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventPhase {
+    Clarification,
+    Spec,
+    Plan,
+    Coding,
+}
+
+/// 빌드/테스트 검증 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildResultKind {
+    Success,
+    BuildFailed,
+    TestFailed,
+}
+
+impl From<&BuildTestOutcome> for BuildResultKind {
+    fn from(outcome: &BuildTestOutcome) -> Self {
+        match outcome {
+            BuildTestOutcome::Success => BuildResultKind::Success,
+            BuildTestOutcome::BuildFailed { .. } => BuildResultKind::BuildFailed,
+            BuildTestOutcome::TestFailed { .. } => BuildResultKind::TestFailed,
+        }
+    }
+}
+
+/// 외부 모니터링 도구가 진행 상황을 따라갈 수 있도록 이벤트 파일에 한 줄씩 남기는 상태 변화.
+/// `event` 필드에 변형 이름이 snake_case로 들어가며, 필드를 추가할 수는 있어도 기존 필드의
+/// 이름이나 의미는 바꾸지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SessionEvent {
+    PhaseStarted {
+        phase: EventPhase,
+    },
+    TaskStarted {
+        task_id: String,
+        title: String,
+        /// 1부터 세는 태스크 순번.
+        position: usize,
+        total: usize,
+    },
+    ReviewResult {
+        task_id: String,
+        iteration: usize,
+        approved: bool,
+    },
+    BuildResult {
+        task_id: String,
+        result: BuildResultKind,
+        /// 실패한 빌드/테스트를 코딩 에이전트가 고친 뒤 다시 실행한 결과인지 여부.
+        after_repair: bool,
+    },
+    TaskCompleted {
+        task_id: String,
+    },
+    TaskBlocked {
+        task_id: String,
+        reason: String,
+    },
+    CodingFinished {
+        success_count: usize,
+        blocked_count: usize,
+    },
+}
+
+#[derive(Serialize)]
+struct EventRecord<'a> {
+    timestamp: String,
+    #[serde(flatten)]
+    event: &'a SessionEvent,
+}
+
+/// 이벤트 하나를 시각과 함께 JSON 한 줄(줄바꿈 제외)로 만든다.
+pub fn event_line(event: &SessionEvent, at: DateTime<Utc>) -> String {
+    let record = EventRecord {
+        timestamp: at.to_rfc3339_opts(SecondsFormat::Millis, true),
+        event,
+    };
+    serde_json::to_string(&record).expect("session events always serialize")
+}
+
+/// `--events`로 지정한 JSON Lines 파일. 외부에서 `tail -f`로 따라 읽을 수 있도록 이벤트마다
+/// 파일을 추가 모드로 열어 한 줄을 쓰고 바로 닫는다. 이전 실행의 이벤트는 지우지 않는다.
+pub struct EventLog {
+    path: PathBuf,
+}
+
+impl EventLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn append(&self, event: &SessionEvent) -> io::Result<()> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut line = event_line(event, Utc::now());
+        line.push('\n');
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn fixed_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap()
+    }
+
+    fn parsed_line(event: SessionEvent) -> serde_json::Value {
+        serde_json::from_str(&event_line(&event, fixed_time())).unwrap()
+    }
+
+    #[test]
+    fn line_starts_with_timestamp_then_event_name() {
+        let event = SessionEvent::PhaseStarted { phase: EventPhase::Coding };
+
+        let line = event_line(&event, fixed_time());
+
+        assert_eq!(
+            line,
+            concat!(
+                r#"{"timestamp":"2026-01-02T03:04:05.000Z","#,
+                r#""event":"phase_started","phase":"coding"}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn serializes_task_lifecycle_events() {
+        assert_eq!(
+            parsed_line(SessionEvent::TaskStarted {
+                task_id: "TASK-00".to_string(),
+                title: "Add login".to_string(),
+                position: 1,
+                total: 3,
+            }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "task_started",
+                "task_id": "TASK-00",
+                "title": "Add login",
+                "position": 1,
+                "total": 3,
+            }),
+        );
+        assert_eq!(
+            parsed_line(SessionEvent::TaskCompleted { task_id: "TASK-00".to_string() }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "task_completed",
+                "task_id": "TASK-00",
+            }),
+        );
+        assert_eq!(
+            parsed_line(SessionEvent::TaskBlocked {
+                task_id: "TASK-01".to_string(),
+                reason: "missing \"API\" key\nsee report".to_string(),
+            }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "task_blocked",
+                "task_id": "TASK-01",
+                "reason": "missing \"API\" key\nsee report",
+            }),
+        );
+        assert_eq!(
+            parsed_line(SessionEvent::CodingFinished { success_count: 2, blocked_count: 1 }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "coding_finished",
+                "success_count": 2,
+                "blocked_count": 1,
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_review_and_build_results() {
+        assert_eq!(
+            parsed_line(SessionEvent::ReviewResult {
+                task_id: "TASK-00".to_string(),
+                iteration: 2,
+                approved: false,
+            }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "review_result",
+                "task_id": "TASK-00",
+                "iteration": 2,
+                "approved": false,
+            }),
+        );
+        assert_eq!(
+            parsed_line(SessionEvent::BuildResult {
+                task_id: "TASK-00".to_string(),
+                result: BuildResultKind::TestFailed,
+                after_repair: true,
+            }),
+            json!({
+                "timestamp": "2026-01-02T03:04:05.000Z",
+                "event": "build_result",
+                "task_id": "TASK-00",
+                "result": "test_failed",
+                "after_repair": true,
+            }),
+        );
+    }
+
+    #[test]
+    fn appends_one_line_per_event_and_keeps_earlier_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("monitor").join("events.jsonl");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{\"event\":\"from_earlier_run\"}\n").unwrap();
+        let log = EventLog::new(path.clone());
+
+        log.append(&SessionEvent::PhaseStarted { phase: EventPhase::Spec }).unwrap();
+        log.append(&SessionEvent::TaskCompleted { task_id: "TASK-00".to_string() }).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let last: serde_json::Value = serde_json::from_str(lines[2]).unwrap();
+        assert_eq!(last["event"], "task_completed");
+        assert!(last["timestamp"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[test]
+    fn creates_missing_parent_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("a").join("b").join("events.jsonl");
+
+        EventLog::new(path.clone())
+            .append(&SessionEvent::PhaseStarted { phase: EventPhase::Plan })
+            .unwrap();
+
+        assert!(std::fs::read_to_string(&path).unwrap().contains("\"phase\":\"plan\""));
+    }
+}
@@ -0,0 +1,331 @@
+use std::io;
+use std::process::Output;
+
+/// git 명령이 실패한 원인. 흔한 실패는 git이 출력한 메시지로 분류해 두어, 호출자가 문자열을
+/// 비교하지 않고도 재시도·건너뛰기·사용자 확인 같은 처리를 고를 수 있게 한다.
+///
+/// 분류된 변형은 모두 어떤 작업이 실패했는지를 담은 `context`와 git이 출력한 `message`를 가지며,
+/// 표시 문자열은 분류와 관계없이 `"{context}: {message}"`이다.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GitError {
+    /// git 프로세스를 실행하지 못했다.
+    #[error("{context}: {message}")]
+    ExecutionFailed { context: String, message: String },
+
+    /// 실행 디렉토리가 git 저장소가 아니다.
+    #[error("{context}: {message}")]
+    NotARepository { context: String, message: String },
+
+    /// 만들려는 브랜치가 이미 있다.
+    #[error("{context}: {message}")]
+    BranchExists { context: String, message: String },
+
+    /// 브랜치가 다른 워크트리에 체크아웃되어 있어 조작할 수 없다.
+    #[error("{context}: {message}")]
+    BranchInUse { context: String, message: String },
+
+    /// 브랜치나 리비전을 찾을 수 없다.
+    #[error("{context}: {message}")]
+    UnknownRevision { context: String, message: String },
+
+    /// 리베이스나 머지 중 충돌이 났다.
+    #[error("{context}: {message}")]
+    Conflict { context: String, message: String },
+
+    /// 대상 브랜치가 앞서 나가 fast-forward할 수 없다.
+    #[error("{context}: {message}")]
+    NotFastForward { context: String, message: String },
+
+    /// 커밋하지 않은 변경이 덮어써질 수 있어 git이 작업을 거부했다.
+    #[error("{context}: {message}")]
+    LocalChanges { context: String, message: String },
+
+    /// 커밋할 변경이 없다.
+    #[error("{context}: {message}")]
+    NothingToCommit { context: String, message: String },
+
+    /// 다른 git 프로세스가 잠금 파일을 쥐고 있다.
+    #[error("{context}: {message}")]
+    Locked { context: String, message: String },
+
+    /// 원격 저장소와 통신하지 못했다.
+    #[error("{context}: {message}")]
+    Network { context: String, message: String },
+
+    /// 위 분류에 들지 않는 실패.
+    #[error("{context}: {message}")]
+    Other { context: String, message: String },
+
+    /// 통합 브랜치의 시작점으로 지정한 브랜치가 없다.
+    #[error("integration base branch `{branch}` does not exist")]
+    BaseBranchNotFound { branch: String },
+
+    /// 커밋하려는 파일이 저장소 밖에 있다.
+    #[error("{path} is outside the repository")]
+    PathOutsideRepository { path: String },
+}
+
+/// stderr 분류 규칙. 패턴은 소문자로 바꾼 메시지와 비교하며, 메시지 하나가 여러 규칙에 걸리면
+/// 앞에 있는 규칙을 따른다. git 버전과 로케일에 따라 문구가 달라질 수 있으므로 분류되지 않은
+/// 실패는 `GitError::Other`로 남는다.
+const CLASSIFICATION_RULES: &[(GitErrorKind, &[&str])] = &[
+    (GitErrorKind::NotARepository, &["not a git repository"]),
+    (
+        GitErrorKind::Locked,
+        &["index.lock", ".lock': file exists", "another git process seems to be running"],
+    ),
+    (
+        GitErrorKind::Network,
+        &[
+            "could not resolve host",
+            "unable to access",
+            "failed to connect",
+            "connection refused",
+            "connection timed out",
+            "connection reset",
+            "network is unreachable",
+            "could not read from remote repository",
+            "the remote end hung up",
+            "early eof",
+        ],
+    ),
+    (
+        GitErrorKind::LocalChanges,
+        &[
+            "would be overwritten",
+            "please commit your changes or stash them",
+            "you have unstaged changes",
+            "your index contains uncommitted changes",
+        ],
+    ),
+    (
+        GitErrorKind::NotFastForward,
+        &["not possible to fast-forward", "non-fast-forward", "can't be fast-forwarded"],
+    ),
+    (
+        GitErrorKind::Conflict,
+        &["conflict", "could not apply", "unmerged", "you have not concluded your merge"],
+    ),
+    (GitErrorKind::BranchExists, &["a branch named"]),
+    (
+        GitErrorKind::BranchInUse,
+        &["is already checked out at", "is already used by worktree"],
+    ),
+    (
+        GitErrorKind::UnknownRevision,
+        &[
+            "unknown revision",
+            "not a valid object name",
+            "invalid reference",
+            "did not match any",
+            "needed a single revision",
+            "not something we can merge",
+            "invalid upstream",
+        ],
+    ),
+    (GitErrorKind::NothingToCommit, &["nothing to commit", "nothing added to commit"]),
+];
+
+/// stderr로 분류할 수 있는 `GitError` 변형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GitErrorKind {
+    NotARepository,
+    BranchExists,
+    BranchInUse,
+    UnknownRevision,
+    Conflict,
+    NotFastForward,
+    LocalChanges,
+    NothingToCommit,
+    Locked,
+    Network,
+}
+
+impl GitError {
+    /// 실패한 git 명령의 출력으로 오류를 만든다. stderr가 비어 있으면(예: `nothing to commit`)
+    /// git이 원인을 stdout에 쓴 것이므로 stdout을 메시지로 쓴다.
+    pub fn from_git_output(context: impl Into<String>, output: &Output) -> Self {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let message = if stderr.trim().is_empty() { stdout.trim() } else { stderr.trim() };
+        Self::from_stderr(context, message)
+    }
+
+    /// git이 출력한 메시지를 분류해 오류를 만든다.
+    pub fn from_stderr(context: impl Into<String>, stderr: &str) -> Self {
+        let context = context.into();
+        let message = stderr.trim().to_string();
+        let lowercase = message.to_lowercase();
+        let kind = CLASSIFICATION_RULES
+            .iter()
+            .find(|(_, patterns)| patterns.iter().any(|pattern| lowercase.contains(pattern)))
+            .map(|(kind, _)| *kind);
+
+        match kind {
+            Some(GitErrorKind::NotARepository) => GitError::NotARepository { context, message },
+            Some(GitErrorKind::BranchExists) => GitError::BranchExists { context, message },
+            Some(GitErrorKind::BranchInUse) => GitError::BranchInUse { context, message },
+            Some(GitErrorKind::UnknownRevision) => GitError::UnknownRevision { context, message },
+            Some(GitErrorKind::Conflict) => GitError::Conflict { context, message },
+            Some(GitErrorKind::NotFastForward) => GitError::NotFastForward { context, message },
+            Some(GitErrorKind::LocalChanges) => GitError::LocalChanges { context, message },
+            Some(GitErrorKind::NothingToCommit) => GitError::NothingToCommit { context, message },
+            Some(GitErrorKind::Locked) => GitError::Locked { context, message },
+            Some(GitErrorKind::Network) => GitError::Network { context, message },
+            None => GitError::Other { context, message },
+        }
+    }
+
+    /// git 프로세스를 실행하지 못했을 때의 오류.
+    pub fn execution(context: impl Into<String>, err: io::Error) -> Self {
+        GitError::ExecutionFailed {
+            context: context.into(),
+            message: err.to_string(),
+        }
+    }
+
+    /// 분류는 그대로 두고 `context` 앞에 바깥 작업의 설명을 붙인다.
+    pub fn within(mut self, outer: &str) -> Self {
+        if let Some(context) = self.context_mut() {
+            *context = format!("{}: {}", outer, context);
+        }
+        self
+    }
+
+    /// 같은 명령을 잠시 뒤 다시 실행하면 성공할 수 있는 일시적 실패인지 판단한다.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, GitError::Locked { .. } | GitError::Network { .. })
+    }
+
+    fn context_mut(&mut self) -> Option<&mut String> {
+        match self {
+            GitError::ExecutionFailed { context, .. }
+            | GitError::NotARepository { context, .. }
+            | GitError::BranchExists { context, .. }
+            | GitError::BranchInUse { context, .. }
+            | GitError::UnknownRevision { context, .. }
+            | GitError::Conflict { context, .. }
+            | GitError::NotFastForward { context, .. }
+            | GitError::LocalChanges { context, .. }
+            | GitError::NothingToCommit { context, .. }
+            | GitError::Locked { context, .. }
+            | GitError::Network { context, .. }
+            | GitError::Other { context, .. } => Some(context),
+            GitError::BaseBranchNotFound { .. } | GitError::PathOutsideRepository { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    fn classify(stderr: &str) -> GitError {
+        GitError::from_stderr("failed", stderr)
+    }
+
+    fn failed_output(stdout: &str, stderr: &str) -> Output {
+        Output {
+            status: ExitStatus::from_raw(1 << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: stderr.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn classifies_representative_stderr() {
+        let cases = [
+            (
+                "fatal: not a git repository (or any of the parent directories): .git",
+                "NotARepository",
+            ),
+            ("fatal: a branch named 'bear/task/TASK-00' already exists", "BranchExists"),
+            (
+                "fatal: 'bear/task/TASK-00' is already checked out at '/tmp/ws-bear-worktree'",
+                "BranchInUse",
+            ),
+            (
+                "fatal: ambiguous argument 'missing': unknown revision or path not in the \
+                 working tree.",
+                "UnknownRevision",
+            ),
+            ("merge: missing - not something we can merge", "UnknownRevision"),
+            (
+                "CONFLICT (content): Merge conflict in feature.txt\n\
+                 error: could not apply 1a2b3c4... Add feature",
+                "Conflict",
+            ),
+            ("fatal: Not possible to fast-forward, aborting.", "NotFastForward"),
+            (
+                "error: Your local changes to the following files would be overwritten by \
+                 checkout:\n\tREADME.md",
+                "LocalChanges",
+            ),
+            ("nothing to commit, working tree clean", "NothingToCommit"),
+            (
+                "fatal: Unable to create '/repo/.git/index.lock': File exists.\n\n\
+                 Another git process seems to be running in this repository",
+                "Locked",
+            ),
+            (
+                "fatal: unable to access 'https://example.com/repo.git/': \
+                 Could not resolve host: example.com",
+                "Network",
+            ),
+            ("fatal: The remote end hung up unexpectedly", "Network"),
+            ("error: pathspec 'x' is something git has never said", "Other"),
+        ];
+
+        for (stderr, expected) in cases {
+            let variant = format!("{:?}", classify(stderr));
+            assert!(variant.starts_with(expected), "{:?} → {}", stderr, variant);
+        }
+    }
+
+    #[test]
+    fn display_keeps_context_and_trimmed_message() {
+        let err = GitError::from_stderr(
+            "failed to create task branch",
+            "fatal: a branch named 'x' already exists\n",
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "failed to create task branch: fatal: a branch named 'x' already exists",
+        );
+        assert_eq!(
+            err.within("failed to commit to branch docs").to_string(),
+            "failed to commit to branch docs: failed to create task branch: \
+             fatal: a branch named 'x' already exists",
+        );
+        assert_eq!(
+            GitError::BaseBranchNotFound { branch: "develop".to_string() }.to_string(),
+            "integration base branch `develop` does not exist",
+        );
+    }
+
+    #[test]
+    fn falls_back_to_stdout_when_stderr_is_empty() {
+        let output = failed_output("nothing to commit, working tree clean\n", "  \n");
+
+        let err = GitError::from_git_output("failed to git commit", &output);
+
+        assert_eq!(
+            err,
+            GitError::NothingToCommit {
+                context: "failed to git commit".to_string(),
+                message: "nothing to commit, working tree clean".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn only_lock_and_network_failures_are_retryable() {
+        assert!(classify("fatal: Unable to create '.git/index.lock': File exists.").is_retryable());
+        assert!(classify("fatal: Could not read from remote repository.").is_retryable());
+        assert!(!classify("CONFLICT (content): Merge conflict in a.txt").is_retryable());
+        assert!(!GitError::execution("failed", io::Error::other("boom")).is_retryable());
+    }
+}
@@ -0,0 +1,195 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::config::Config;
+use super::app::App;
+use super::error::UiError;
+use super::termination::install_termination_handler;
+
+/// headless 실행이 끝난 결과. 프로세스 종료 코드로 변환된다.
+///
+/// | 코드 | 의미 |
+/// |---|---|
+/// | 0 | 모든 태스크가 성공적으로 머지됨 |
+/// | 1 | 설정 또는 실행 준비 실패 (스펙/플랜 파일 없음 등) |
+/// | 2 | 하나 이상의 태스크가 `ImplementationBlocked`로 끝남 |
+/// | 3 | 에이전트 오류 또는 사용자 입력이 필요한 단계에 도달하여 중단됨 |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadlessExit {
+    Success,
+    TasksBlocked,
+    Aborted,
+}
+
+impl HeadlessExit {
+    pub fn code(self) -> u8 {
+        match self {
+            HeadlessExit::Success => 0,
+            HeadlessExit::TasksBlocked => 2,
+            HeadlessExit::Aborted => 3,
+        }
+    }
+}
+
+/// 터미널 없이 스펙과 플랜이 있는 세션 디렉토리로부터 작업 추출, 코딩, 리뷰,
+/// 빌드/테스트를 끝까지 실행한다. 진행 메시지는 한 줄씩 `out`으로 출력한다.
+pub fn run_headless(
+    config: Config,
+    workspace: PathBuf,
+    session_dir: &Path,
+    out: &mut impl Write,
+) -> Result<HeadlessExit, UiError> {
+    let poll_interval = config.poll_interval();
+    run_headless_app(App::new(config)?, poll_interval, workspace, session_dir, out)
+}
+
+/// 이미 만든 `app`으로 헤드리스 실행을 끝까지 진행한다.
+fn run_headless_app(
+    mut app: App,
+    poll_interval: Duration,
+    workspace: PathBuf,
+    session_dir: &Path,
+    out: &mut impl Write,
+) -> Result<HeadlessExit, UiError> {
+    if let Err(err) = install_termination_handler(app.cleanup_registry(), false) {
+        crate::cli_log!("종료 시그널 처리기 등록 실패: {}", err);
+    }
+
+    // 워크스페이스 확인 안내 등 대화형 초기 메시지는 출력하지 않는다.
+    let mut printed = app.messages.len();
+    let start_result = app.start_headless_run(workspace, session_dir);
+    printed = print_new_messages(&app, printed, out)?;
+    if let Err(message) = start_result {
+        return Err(UiError::HeadlessSetupError { message });
+    }
+
+    loop {
+        app.tick();
+        printed = print_new_messages(&app, printed, out)?;
+
+        if let Some(exit) = app.headless_exit() {
+            app.restore_auto_stash();
+            print_new_messages(&app, printed, out)?;
+            if let Some(message) = app.fatal_error() {
+                writeln!(out, "Error: {}", message)?;
+            } else if exit == HeadlessExit::Aborted {
+                writeln!(
+                    out,
+                    "Error: headless 모드에서 진행할 수 없는 단계에 도달하여 중단합니다.",
+                )?;
+            }
+            out.flush()?;
+            return Ok(exit);
+        }
+
+        std::thread::sleep(poll_interval);
+    }
+}
+
+fn print_new_messages(app: &App, printed: usize, out: &mut impl Write) -> Result<usize, UiError> {
+    for message in &app.messages[printed..] {
+        writeln!(out, "{}", message.content)?;
+    }
+    out.flush()?;
+    Ok(app.messages.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::test_support::{run_git, setup_workspace, write_script};
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Creates a git workspace with a Makefile whose build/test targets always pass,
+    /// and a session directory holding spec.md and plan.md.
+    fn setup() -> (TempDir, PathBuf, PathBuf) {
+        let (root, workspace) = setup_workspace();
+        fs::write(workspace.join("Makefile"), "build:\n\ttrue\ntest:\n\ttrue\n").unwrap();
+        run_git(&workspace, &["add", "."]);
+        run_git(&workspace, &["commit", "-m", "add Makefile"]);
+
+        let session_dir = root.path().join("session");
+        fs::create_dir(&session_dir).unwrap();
+        fs::write(session_dir.join("spec.md"), "# Spec").unwrap();
+        fs::write(session_dir.join("plan.md"), "# Plan").unwrap();
+
+        (root, workspace, session_dir)
+    }
+
+    /// Fake CLI: answers task extraction with a single task and the coding request with
+    /// `coding_status`, committing a file first when the task succeeds.
+    fn write_fake_claude_binary(dir: &Path, coding_status: &str) -> PathBuf {
+        let script = format!(
+            r#"#!/bin/sh
+prompt=$(cat)
+case "$prompt" in
+  *"Extract all implementation tasks"*)
+    out='{{"tasks":[{{"task_id":"TASK-01","title":"t","description":"d","dependencies":[]}}]}}' ;;
+  *)
+    if [ "{status}" = "IMPLEMENTATION_SUCCESS" ]; then
+      echo feature > feature.txt && git add feature.txt && git commit -qm feature
+    fi
+    out='{{"status":"{status}","report":"fake report"}}' ;;
+esac
+printf '{{"type":"result","is_error":false,"session_id":"fake","structured_output":%s}}\n' "$out"
+"#,
+            status = coding_status,
+        );
+        write_script(dir, "fake-claude", &script)
+    }
+
+    fn run_with_fake_agent(coding_status: &str) -> (TempDir, PathBuf, HeadlessExit, String) {
+        let (root, workspace, session_dir) = setup();
+        let poll_interval = Duration::from_millis(10);
+        let config = Config::new("test-key".to_string())
+            .with_poll_interval(poll_interval)
+            .with_max_review_iterations(0)
+            .with_max_retries(0);
+        let app = App::new(config)
+            .unwrap()
+            .with_claude_binary(write_fake_claude_binary(root.path(), coding_status));
+
+        let mut out = Vec::new();
+        let exit = run_headless_app(app, poll_interval, workspace.clone(), &session_dir, &mut out)
+            .unwrap();
+        (root, workspace, exit, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn headless_run_merges_tasks_and_exits_successfully() {
+        let (_root, workspace, exit, output) = run_with_fake_agent("IMPLEMENTATION_SUCCESS");
+
+        assert_eq!(exit, HeadlessExit::Success);
+        assert_eq!(exit.code(), 0);
+        assert!(output.contains("1개 작업이 추출되었습니다"));
+        assert!(output.contains("코딩 단계 완료. 성공: 1, 차단: 0"));
+        assert!(workspace.join("feature.txt").exists());
+    }
+
+    #[test]
+    fn headless_run_exits_with_blocked_code_when_a_task_is_blocked() {
+        let (_root, _workspace, exit, output) = run_with_fake_agent("IMPLEMENTATION_BLOCKED");
+
+        assert_eq!(exit, HeadlessExit::TasksBlocked);
+        assert_eq!(exit.code(), 2);
+        assert!(output.contains("코딩 단계 완료. 성공: 0, 차단: 1"));
+    }
+
+    #[test]
+    fn headless_run_requires_spec_and_plan() {
+        let (_root, workspace, session_dir) = setup();
+        fs::remove_file(session_dir.join("plan.md")).unwrap();
+
+        let mut out = Vec::new();
+        let result = run_headless(
+            Config::new("test-key".to_string()),
+            workspace,
+            &session_dir,
+            &mut out,
+        );
+
+        assert!(matches!(result, Err(UiError::HeadlessSetupError { .. })));
+    }
+}
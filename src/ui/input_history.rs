@@ -0,0 +1,221 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// 워크스페이스의 `.bear` 디렉토리에 두는 입력 기록 파일 이름. 세션마다 저널 디렉토리가
+/// 달라지므로, 다시 시작한 뒤에도 이어 쓸 수 있도록 세션 디렉토리가 아닌 `.bear` 바로 아래에 둔다.
+pub const INPUT_HISTORY_FILE_NAME: &str = "input-history.json";
+
+/// 입력란 종류마다 기억하는 입력 수. 넘치면 가장 오래된 입력부터 버린다.
+const MAX_ENTRIES_PER_FIELD: usize = 50;
+
+/// 기록을 따로 두는 입력란 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputField {
+    /// 요구사항 입력과 요구사항 수정.
+    Requirements,
+    /// 명확화 질문에 대한 답변.
+    Answer,
+    /// 스펙, 섹션, 플랜 초안에 대한 피드백.
+    Feedback,
+}
+
+/// 한 입력란의 제출 기록. 크기가 정해진 링 버퍼이며, 기록을 훑는 동안의 위치와 훑기 전에
+/// 쓰고 있던 입력을 함께 기억해 가장 최근 기록보다 더 내려가면 그 입력으로 돌아간다.
+#[derive(Debug)]
+pub struct HistoryRing {
+    entries: VecDeque<String>,
+    capacity: usize,
+    position: Option<usize>,
+    draft: String,
+}
+
+impl HistoryRing {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+            position: None,
+            draft: String::new(),
+        }
+    }
+
+    /// 입력을 가장 최근 기록으로 넣고 훑던 위치를 처음으로 되돌린다. 비어 있거나 직전 기록과
+    /// 같은 입력은 넣지 않는다.
+    pub fn push(&mut self, entry: &str) {
+        self.position = None;
+        if entry.trim().is_empty() || self.entries.back().is_some_and(|last| last == entry) {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry.to_string());
+    }
+
+    /// 한 단계 이전 기록을 반환한다. 처음 훑기 시작할 때는 `current`를 기억해 둔다.
+    /// 더 이전 기록이 없으면 가장 오래된 기록에 머문다.
+    pub fn previous(&mut self, current: &str) -> Option<&str> {
+        let position = match self.position {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(position) => position.saturating_sub(1),
+        };
+        self.position = Some(position);
+        self.entries.get(position).map(String::as_str)
+    }
+
+    /// 한 단계 다음 기록을 반환한다. 가장 최근 기록 다음은 훑기 전에 쓰던 입력이며,
+    /// 훑는 중이 아니면 None.
+    pub fn next(&mut self) -> Option<&str> {
+        let position = self.position?;
+        if position + 1 < self.entries.len() {
+            self.position = Some(position + 1);
+            return self.entries.get(position + 1).map(String::as_str);
+        }
+        self.position = None;
+        Some(&self.draft)
+    }
+
+    fn entries(&self) -> Vec<String> {
+        self.entries.iter().cloned().collect()
+    }
+}
+
+/// 입력란 종류별 제출 기록. 경로가 정해져 있으면 기록할 때마다 파일에 저장한다.
+#[derive(Debug, Default)]
+pub struct InputHistory {
+    rings: BTreeMap<InputField, HistoryRing>,
+    path: Option<PathBuf>,
+}
+
+impl InputHistory {
+    /// `path`의 기록을 읽는다. 파일이 없으면 빈 기록으로 시작한다.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        let saved: BTreeMap<InputField, Vec<String>> = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).map_err(io::Error::other)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        let mut history = Self { rings: BTreeMap::new(), path: Some(path) };
+        for (field, entries) in saved {
+            let ring = history.ring(field);
+            for entry in entries {
+                ring.push(&entry);
+            }
+        }
+        Ok(history)
+    }
+
+    /// 제출한 입력을 기록하고 파일에 저장한다. 저장에 실패해도 메모리의 기록은 남는다.
+    pub fn record(&mut self, field: InputField, entry: &str) -> io::Result<()> {
+        self.ring(field).push(entry);
+        self.save()
+    }
+
+    pub fn previous(&mut self, field: InputField, current: &str) -> Option<String> {
+        self.ring(field).previous(current).map(str::to_string)
+    }
+
+    pub fn next(&mut self, field: InputField) -> Option<String> {
+        self.ring(field).next().map(str::to_string)
+    }
+
+    fn ring(&mut self, field: InputField) -> &mut HistoryRing {
+        self.rings.entry(field).or_insert_with(|| HistoryRing::new(MAX_ENTRIES_PER_FIELD))
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let saved: BTreeMap<InputField, Vec<String>> =
+            self.rings.iter().map(|(field, ring)| (*field, ring.entries())).collect();
+        let json = serde_json::to_string_pretty(&saved).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn ring_with(entries: &[&str]) -> HistoryRing {
+        let mut ring = HistoryRing::new(3);
+        for entry in entries {
+            ring.push(entry);
+        }
+        ring
+    }
+
+    #[test]
+    fn ring_drops_oldest_entry_when_full_and_skips_repeats() {
+        let ring = ring_with(&["a", "b", "b", "  ", "c", "d"]);
+
+        assert_eq!(ring.entries(), vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn previous_and_next_walk_history_and_restore_draft() {
+        let mut ring = ring_with(&["first", "second"]);
+
+        assert_eq!(ring.previous("typing"), Some("second"));
+        assert_eq!(ring.previous("second"), Some("first"));
+        assert_eq!(ring.previous("first"), Some("first"));
+        assert_eq!(ring.next(), Some("second"));
+        assert_eq!(ring.next(), Some("typing"));
+        assert_eq!(ring.next(), None);
+    }
+
+    #[test]
+    fn empty_ring_has_nothing_to_recall() {
+        let mut ring = HistoryRing::new(3);
+
+        assert_eq!(ring.previous("typing"), None);
+        assert_eq!(ring.next(), None);
+    }
+
+    #[test]
+    fn push_resets_navigation() {
+        let mut ring = ring_with(&["first", "second"]);
+        ring.previous("");
+        ring.previous("");
+
+        ring.push("third");
+
+        assert_eq!(ring.next(), None);
+        assert_eq!(ring.previous(""), Some("third"));
+    }
+
+    #[test]
+    fn history_is_kept_per_field_and_survives_reload() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".bear").join(INPUT_HISTORY_FILE_NAME);
+        let mut history = InputHistory::load(path.clone()).unwrap();
+
+        history.record(InputField::Requirements, "로그인 기능\n추가").unwrap();
+        history.record(InputField::Feedback, "테스트를 보강해 주세요").unwrap();
+
+        let mut reloaded = InputHistory::load(path).unwrap();
+        assert_eq!(
+            reloaded.previous(InputField::Requirements, "").as_deref(),
+            Some("로그인 기능\n추가"),
+        );
+        assert_eq!(
+            reloaded.previous(InputField::Feedback, "").as_deref(),
+            Some("테스트를 보강해 주세요"),
+        );
+        assert_eq!(reloaded.previous(InputField::Answer, ""), None);
+    }
+}
@@ -0,0 +1,67 @@
+/// CLI 호출 수 경고 한도. 호출 수가 마지막으로 계속하기로 한 시점보다 `threshold`만큼 늘면
+/// 한도에 닿은 것으로 본다. 설정 파일은 세션 중에 적용되므로 한도는 설정에서 그때그때 받는다.
+#[derive(Debug, Default)]
+pub struct InvocationBudget {
+    /// 사용자가 마지막으로 계속하기로 했을 때의 호출 수.
+    acknowledged: usize,
+    /// 지금 한도에 닿았다고 이미 경고했는지 여부.
+    warned: bool,
+}
+
+impl InvocationBudget {
+    /// 호출 수 `count`가 한도에 닿았는지 여부. `threshold`가 None이면 한도가 없다.
+    pub fn is_exhausted(&self, count: usize, threshold: Option<usize>) -> bool {
+        threshold.is_some_and(|threshold| count >= self.acknowledged + threshold)
+    }
+
+    /// 한도에 처음 닿았을 때만 true를 반환한다. 계속하기로 하기 전까지는 다시 경고하지 않는다.
+    pub fn take_warning(&mut self, count: usize, threshold: Option<usize>) -> bool {
+        if self.warned || !self.is_exhausted(count, threshold) {
+            return false;
+        }
+        self.warned = true;
+        true
+    }
+
+    /// 사용자가 계속하기로 했다. 지금부터 다시 `threshold`만큼 호출할 수 있다.
+    pub fn continue_from(&mut self, count: usize) {
+        self.acknowledged = count;
+        self.warned = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_is_exhausted_when_count_reaches_threshold() {
+        let budget = InvocationBudget::default();
+
+        assert!(!budget.is_exhausted(49, Some(50)));
+        assert!(budget.is_exhausted(50, Some(50)));
+        assert!(budget.is_exhausted(51, Some(50)));
+        assert!(!budget.is_exhausted(1_000, None));
+    }
+
+    #[test]
+    fn warning_is_given_once_per_crossing() {
+        let mut budget = InvocationBudget::default();
+
+        assert!(!budget.take_warning(2, Some(3)));
+        assert!(budget.take_warning(3, Some(3)));
+        assert!(!budget.take_warning(4, Some(3)));
+    }
+
+    #[test]
+    fn continuing_allows_another_threshold_of_invocations() {
+        let mut budget = InvocationBudget::default();
+        assert!(budget.take_warning(4, Some(3)));
+
+        budget.continue_from(4);
+
+        assert!(!budget.is_exhausted(6, Some(3)));
+        assert!(!budget.take_warning(6, Some(3)));
+        assert!(budget.take_warning(7, Some(3)));
+    }
+}
@@ -0,0 +1,105 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// 로그 보기 창을 그릴 때 파일 끝에서부터 읽는 최대 바이트 수. 쿼리 로그는 CLI 출력 전체를
+/// 담아 수 MB가 될 수 있으므로 매 렌더링마다 파일 전체를 읽지 않는다.
+const TAIL_READ_BYTES: u64 = 64 * 1024;
+
+/// `dir`에 있는 쿼리 로그(`bear-*.log`) 중 가장 최근에 기록된 파일.
+pub fn newest_debug_log(dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("bear-") && name.ends_with(".log")
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .max()
+        .map(|(_, path)| path)
+}
+
+/// 파일의 마지막 `max_lines`줄을 읽는다. 라이브 영역의 줄 수가 어긋나지 않도록 탭은 공백으로
+/// 바꾸고 나머지 제어 문자는 뺀다.
+pub fn read_tail(path: &Path, max_lines: usize) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let length = file.metadata()?.len();
+    let start = length.saturating_sub(TAIL_READ_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let text = String::from_utf8_lossy(&bytes);
+    let mut lines: Vec<&str> = text.lines().collect();
+    // 중간부터 읽었다면 첫 줄은 잘린 줄이다.
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+    let skip = lines.len().saturating_sub(max_lines);
+    Ok(lines[skip..].iter().map(|line| sanitize_line(line)).collect())
+}
+
+fn sanitize_line(line: &str) -> String {
+    line.replace('\t', "    ")
+        .chars()
+        .filter(|ch| !ch.is_control())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    #[test]
+    fn reads_only_the_last_lines_without_control_characters() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bear-a.log");
+        fs::write(&path, "first\nsecond\n\tindented\x1b[31m\r\nlast\n").unwrap();
+
+        assert_eq!(
+            read_tail(&path, 3).unwrap(),
+            vec!["second", "    indented[31m", "last"],
+        );
+        assert_eq!(read_tail(&path, 10).unwrap().len(), 4);
+        assert!(read_tail(&dir.path().join("missing.log"), 3).is_err());
+    }
+
+    #[test]
+    fn drops_the_partial_first_line_of_a_large_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bear-a.log");
+        let line = "x".repeat(1000);
+        let content: Vec<String> = (0..100).map(|index| format!("{:03}{}", index, line)).collect();
+        fs::write(&path, content.join("\n")).unwrap();
+
+        let lines = read_tail(&path, 1000).unwrap();
+
+        assert!(lines.len() < 100);
+        assert!(lines.iter().all(|line| line.len() == 1003));
+        assert!(lines.last().unwrap().starts_with("099"));
+    }
+
+    #[test]
+    fn picks_the_most_recently_written_query_log() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(newest_debug_log(dir.path()), None);
+
+        let older = dir.path().join("bear-older.log");
+        let newer = dir.path().join("bear-newer.log");
+        fs::write(&older, "").unwrap();
+        fs::write(&newer, "").unwrap();
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+        let past = SystemTime::now() - Duration::from_secs(60);
+        File::options().write(true).open(&older).unwrap().set_modified(past).unwrap();
+
+        assert_eq!(newest_debug_log(dir.path()), Some(newer));
+        assert_eq!(newest_debug_log(&dir.path().join("missing")), None);
+    }
+}
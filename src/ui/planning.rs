@@ -4,6 +4,8 @@ use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
+use super::coding::{CodingTaskStatus, TaskReport};
+
 #[derive(Debug, Deserialize)]
 pub struct PlanWritingResponse {
     pub response_type: PlanResponseType,
@@ -42,7 +44,54 @@ pub fn plan_writing_schema() -> serde_json::Value {
     })
 }
 
-pub fn system_prompt() -> &'static str {
+/// 점진적 계획 모드에서 다음 작업 하나를 계획한 응답.
+#[derive(Debug, Deserialize)]
+pub struct NextTaskPlanResponse {
+    pub status: NextTaskPlanStatus,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub files: Vec<String>,
+    pub subdir: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum NextTaskPlanStatus {
+    #[serde(rename = "NEXT_TASK")]
+    NextTask,
+    #[serde(rename = "ALL_DONE")]
+    AllDone,
+}
+
+pub fn next_task_plan_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "status": {
+                "type": "string",
+                "enum": ["NEXT_TASK", "ALL_DONE"]
+            },
+            "title": {
+                "type": "string",
+                "minLength": 1
+            },
+            "description": {
+                "type": "string",
+                "minLength": 1
+            },
+            "files": {
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "subdir": { "type": "string" }
+        },
+        "required": ["status"],
+        "additionalProperties": false
+    })
+}
+
+/// 플래닝 에이전트의 시스템 프롬프트. 플랜의 서술 부분을 `output_language`로 쓰게 한다.
+pub fn system_prompt(output_language: &str) -> String {
     r#"# Role
 
 You are the **planning** assistant. Your job is to produce a high-quality implementation plan for the user's request based on the provided specification.
@@ -128,15 +177,15 @@ Your plan MUST be reviewer-friendly:
 ---
 
 # Output Language (mandatory)
-- Your default output language MUST be Korean.
-- This prompt may be written in English, but you MUST output in Korean regardless of the prompt language.
-- Write all explanations, reasoning, and narrative text in Korean.
+- Your default output language MUST be {{OUTPUT_LANGUAGE}}.
+- This prompt may be written in English, but you MUST output in {{OUTPUT_LANGUAGE}} regardless of the prompt language.
+- Write all explanations, reasoning, and narrative text in {{OUTPUT_LANGUAGE}}.
 - You MAY use English only when one of the following is true:
   - The user explicitly requests English output.
-  - Using Korean would likely distort meaning for technical terms, standards, proper nouns, or established acronyms.
+  - Using {{OUTPUT_LANGUAGE}} would likely distort meaning for technical terms, standards, proper nouns, or established acronyms.
   - You are quoting exact identifiers or artifacts that must remain unchanged (file paths, symbol names, command names, configuration keys, error messages).
 - Do NOT translate or localize code identifiers, file paths, configuration keys, CLI commands, or log/error strings.
-- If you use English for a specific phrase to avoid ambiguity, keep it minimal and immediately continue in Korean.
+- If you use English for a specific phrase to avoid ambiguity, keep it minimal and immediately continue in {{OUTPUT_LANGUAGE}}.
 
 ---
 
@@ -271,25 +320,24 @@ Example IO header usage (illustrative only):
 - Inside ```pseudocode``` blocks, write everything in English only.
 - This includes: keywords/control tokens, IO header lines, placeholders, and all intent/action lines.
 - Symbol names MUST be in English only (function names, helper names, module names, file names, and placeholder names).
-- Do NOT include any Korean text inside ```pseudocode``` blocks, even as comments-as-text.
-- Outside pseudocode blocks (the rest of the plan document), write in Korean by default.
+- Do NOT include any non-English text inside ```pseudocode``` blocks, even as comments-as-text.
+- Outside pseudocode blocks (the rest of the plan document), write in {{OUTPUT_LANGUAGE}} by default.
 
 **How to apply the placeholder rule with English-only pseudocode:**
 - Inside ```pseudocode``` blocks, placeholders MUST remain English only.
-- If a Korean clarifier is helpful, add it in Korean prose immediately before or after the pseudocode block (not inside the block).
+- If a clarifier in {{OUTPUT_LANGUAGE}} is helpful, add it in prose immediately before or after the pseudocode block (not inside the block).
 
-**Korean terminology policy (applies to prose sections only):**
+**Terminology policy (applies to prose sections only):**
 - This policy applies only outside ```pseudocode``` blocks, since pseudocode blocks are English-only.
-- Do NOT transliterate English technical words into awkward Hangul spellings (do NOT write things like "인티저", "아토믹", "펑션").
-- Use established Korean words where they are natural and unambiguous:
-  - Use "정수", "원자적", "함수" as the default terms.
-- Use commonly adopted loanwords where they are the de-facto standard in Korean technical writing:
-  - Use "카운터", "뮤텍스", "핸들" as the default terms.
-- Keep original English acronyms/terms when Korean translation or transliteration is uncommon or harms clarity:
+- Do NOT transliterate English technical words into awkward spellings of {{OUTPUT_LANGUAGE}} (for example, in Hangul do NOT write things like "인티저", "아토믹", "펑션").
+- Use established native words of {{OUTPUT_LANGUAGE}} where they are natural and unambiguous (for example, "정수", "원자적", "함수" in Hangul).
+- Use commonly adopted loanwords where they are the de-facto standard in technical writing of {{OUTPUT_LANGUAGE}} (for example, "카운터", "뮤텍스", "핸들" in Hangul).
+- Keep original English acronyms/terms when translation or transliteration is uncommon or harms clarity:
   - Use "NULL", "RAII" exactly as-is.
+- When {{OUTPUT_LANGUAGE}} is English, this policy only means keeping established technical terms unchanged.
 
 **Conflict resolution rule:**
-- If two rules conflict, prioritize (1) meaning/precision, then (2) common Korean technical usage, then (3) consistency within the document.
+- If two rules conflict, prioritize (1) meaning/precision, then (2) common technical usage in {{OUTPUT_LANGUAGE}}, then (3) consistency within the document.
 
 ## Pseudocode block character whitelist (strict)
 - Inside ```pseudocode``` blocks, you MUST NOT use any of these characters/tokens:
@@ -413,6 +461,7 @@ When you finish you MUST produce an output in Markdown format that includes:
 **Mandatory detail level:**
 - Always include both a high-level summary (in **Overview**) and a detailed, file-by-file implementation plan (in **Implementation**).
 - Do not replace the detailed plan with a summary."#
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
 }
 
 const INITIAL_PLAN_PROMPT_TEMPLATE: &str = r#"Based on the initial user request and the approved specification below, produce a detailed implementation plan.
@@ -505,7 +554,7 @@ If the user's feedback is ambiguous and you need clarification before revising,
 
 IMPORTANT:
 - The session conversation history contains all prior specifications, plans, and feedback. Use this context to revise the plan.
-- Write the plan in Korean.
+- Write the plan in {{OUTPUT_LANGUAGE}}.
 - DECISION ESCALATION: The Decision Escalation rules from the system prompt still apply during revision. If the user's feedback introduces new topics or reveals undecided design/technology choices that require user approval (technology selection, architecture patterns, interface design, concurrency model, trade-offs, etc.), you MUST set response_type to "clarifying_questions" and ask the user to decide before producing a revised plan. Present options with pros/cons and your recommendation. Do NOT silently incorporate your own choices into the revised plan.
 - USER RESPONSE CLASSIFICATION: When the previous conversation shows that the most recent model output was a set of clarifying questions (a CLARIFYING_QUESTIONS entry, especially decision-escalation questions), you MUST classify the user's current message into one of three categories before taking any other action:
 
@@ -564,14 +613,123 @@ User feedback:
 {{USER_FEEDBACK}}
 >>>"#;
 
+/// 점진적 계획 모드 전용 시스템 프롬프트. 전체 플랜 대신 다음 작업 하나만 계획한다.
+pub fn incremental_planning_system_prompt(output_language: &str) -> String {
+    r#"# Role
+
+You are the **incremental planning** assistant. Instead of planning the whole implementation up front, you plan exactly ONE next task at a time. After each task is implemented, you are called again with the reports of the tasks completed so far, and you plan the next task based on the actual result.
+
+**Core rules:**
+- The approved specification is the canonical source of requirements and constraints.
+- Do NOT create or modify files EVER. Use available tools only to inspect the current codebase.
+- Plan a cohesive, independently reviewable unit of work. Do not split one feature into implementation phases.
+- Read the reports of completed tasks and adapt: if a previous task was blocked or deviated from expectations, plan the next task to recover or route around it.
+- When every requirement of the specification is satisfied by the completed tasks, report that all work is done instead of inventing more tasks.
+- The task description MUST be handoff-ready for an autonomous coding agent: goal, files/modules to touch, constraints, and acceptance criteria.
+- The task description MUST NOT contain production-ready code. Describe the work in natural language.
+- Use workspace-root relative file paths only.
+
+# Output Language (mandatory)
+- Write the task title and description in {{OUTPUT_LANGUAGE}}.
+- Do NOT translate code identifiers, file paths, configuration keys, or CLI commands."#
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
+}
+
+const NEXT_TASK_PLAN_PROMPT_TEMPLATE: &str = r#"Plan the next single implementation task for the user's request.
+
+- Initial user request: {{USER_REQUEST_PATH}}
+- Approved specification: {{SPEC_PATH}}
+- Tasks planned so far (accumulated plan): {{PLAN_PATH}}
+
+Completed tasks and their reports:
+{{COMPLETED_TASKS}}
+
+Instructions:
+- Read the user request, the specification, and every report file listed above before deciding.
+- Inspect the current state of the codebase; it already contains the merged results of the completed tasks.
+- If the specification is fully implemented, set status to "ALL_DONE" and leave the other fields empty.
+- Otherwise set status to "NEXT_TASK" and fill in the title and description of task {{NEXT_TASK_ID}}.
+- Fill in "files" with the workspace-root relative paths the task is expected to modify, if you can predict them.
+- If the task is confined to one project inside a monorepo, set "subdir" to that workspace-root relative directory so the task is coded, built, and tested there. Omit it otherwise.
+
+Output MUST be valid JSON conforming to the provided JSON Schema."#;
+
 pub fn build_initial_plan_prompt(user_request_path: &Path, spec_path: &Path) -> String {
     INITIAL_PLAN_PROMPT_TEMPLATE
         .replace("{{USER_REQUEST_PATH}}", &user_request_path.display().to_string())
         .replace("{{SPEC_PATH}}", &spec_path.display().to_string())
 }
 
-pub fn build_plan_revision_prompt(user_feedback: &str) -> String {
-    REVISION_PLAN_PROMPT_TEMPLATE.replace("{{USER_FEEDBACK}}", user_feedback)
+pub fn build_plan_revision_prompt(user_feedback: &str, output_language: &str) -> String {
+    REVISION_PLAN_PROMPT_TEMPLATE
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
+        .replace("{{USER_FEEDBACK}}", user_feedback)
+}
+
+pub fn build_next_task_plan_prompt(
+    user_request_path: &Path,
+    spec_path: &Path,
+    plan_path: &Path,
+    completed_reports: &[TaskReport],
+    next_task_id: &str,
+) -> String {
+    let completed_tasks = if completed_reports.is_empty() {
+        "(none yet — this is the first task)".to_string()
+    } else {
+        completed_reports
+            .iter()
+            .map(|report| {
+                let status = match report.status {
+                    CodingTaskStatus::ImplementationSuccess => "IMPLEMENTATION_SUCCESS",
+                    CodingTaskStatus::ImplementationBlocked => "IMPLEMENTATION_BLOCKED",
+                };
+                // 차단된 태스크는 리포트 파일이 없으므로 리포트 본문을 직접 싣는다.
+                if report.report_file_path.as_os_str().is_empty() {
+                    format!(
+                        "- [{}] {}\n<<<\n{}\n>>>",
+                        report.task_id, status, report.report,
+                    )
+                } else {
+                    format!(
+                        "- [{}] {} (report: {})",
+                        report.task_id,
+                        status,
+                        report.report_file_path.display(),
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    NEXT_TASK_PLAN_PROMPT_TEMPLATE
+        .replace("{{USER_REQUEST_PATH}}", &user_request_path.display().to_string())
+        .replace("{{SPEC_PATH}}", &spec_path.display().to_string())
+        .replace("{{PLAN_PATH}}", &plan_path.display().to_string())
+        .replace("{{COMPLETED_TASKS}}", &completed_tasks)
+        .replace("{{NEXT_TASK_ID}}", next_task_id)
+}
+
+/// 점진적 계획 모드에서 새로 계획된 작업을 plan.md 끝에 덧붙인다.
+/// 코딩 에이전트는 지금까지 계획된 작업 전체를 plan.md로 참고한다.
+pub fn append_planned_task(
+    dir: &Path,
+    task_id: &str,
+    title: &str,
+    description: &str,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let file_path = dir.join("plan.md");
+    let mut plan_text = match fs::read_to_string(&file_path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => "# 점진적 개발 계획\n".to_string(),
+        Err(err) => return Err(err),
+    };
+    plan_text.push_str(&format!("\n## [{}] {}\n\n{}\n", task_id, title, description));
+    fs::write(&file_path, plan_text)?;
+
+    Ok(file_path)
 }
 
 pub fn save_approved_plan(dir: &Path, plan_text: &str) -> io::Result<PathBuf> {
@@ -586,6 +744,7 @@ pub fn save_approved_plan(dir: &Path, plan_text: &str) -> io::Result<PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::coding::TaskProgress;
     use std::fs;
     use tempfile::TempDir;
 
@@ -656,7 +815,7 @@ mod tests {
 
     #[test]
     fn revision_plan_prompt_contains_approval_detection_instruction() {
-        let prompt = build_plan_revision_prompt("some feedback");
+        let prompt = build_plan_revision_prompt("some feedback", "Korean");
 
         assert!(prompt.contains("APPROVAL DETECTION"));
     }
@@ -674,11 +833,19 @@ mod tests {
 
     #[test]
     fn build_revision_prompt_contains_feedback() {
-        let prompt = build_plan_revision_prompt("Please add error handling section");
+        let prompt = build_plan_revision_prompt("Please add error handling section", "Korean");
 
         assert!(prompt.contains("Please add error handling section"));
     }
 
+    #[test]
+    fn revision_prompt_asks_for_configured_output_language() {
+        let prompt = build_plan_revision_prompt("feedback", "English");
+
+        assert!(prompt.contains("Write the plan in English."));
+        assert!(!prompt.contains("Korean"));
+    }
+
     #[test]
     fn save_approved_plan_creates_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -699,4 +866,86 @@ mod tests {
         let expected = temp_dir.path().join("plan.md");
         assert_eq!(path, expected);
     }
+
+    #[test]
+    fn deserialize_next_task_plan_response() {
+        let json = serde_json::json!({
+            "status": "NEXT_TASK",
+            "title": "설정 파서 추가",
+            "description": "src/config.rs에 파서를 추가한다.",
+            "files": ["src/config.rs"]
+        });
+
+        let response: NextTaskPlanResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.status, NextTaskPlanStatus::NextTask);
+        assert_eq!(response.title.as_deref(), Some("설정 파서 추가"));
+        assert_eq!(response.files, vec!["src/config.rs"]);
+
+        let done: NextTaskPlanResponse =
+            serde_json::from_value(serde_json::json!({ "status": "ALL_DONE" })).unwrap();
+        assert_eq!(done.status, NextTaskPlanStatus::AllDone);
+        assert!(done.title.is_none());
+        assert!(done.files.is_empty());
+    }
+
+    #[test]
+    fn next_task_plan_prompt_lists_completed_reports() {
+        let reports = vec![
+            TaskReport {
+                task_id: "TASK-01".to_string(),
+                status: CodingTaskStatus::ImplementationSuccess,
+                report: "done".to_string(),
+                report_file_path: PathBuf::from("/ws/.bear/TASK-01.md"),
+                merged_with_failing_build: false,
+                progress: TaskProgress::default(),
+            },
+            TaskReport {
+                task_id: "TASK-02".to_string(),
+                status: CodingTaskStatus::ImplementationBlocked,
+                report: "워크트리 생성 실패".to_string(),
+                report_file_path: PathBuf::new(),
+                merged_with_failing_build: false,
+                progress: TaskProgress::default(),
+            },
+        ];
+
+        let prompt = build_next_task_plan_prompt(
+            Path::new("/ws/.bear/user-request.md"),
+            Path::new("/ws/.bear/spec.md"),
+            Path::new("/ws/.bear/plan.md"),
+            &reports,
+            "TASK-03",
+        );
+
+        assert!(
+            prompt.contains("- [TASK-01] IMPLEMENTATION_SUCCESS (report: /ws/.bear/TASK-01.md)")
+        );
+        assert!(prompt.contains("- [TASK-02] IMPLEMENTATION_BLOCKED\n<<<\n워크트리 생성 실패\n>>>"));
+        assert!(prompt.contains("task TASK-03"));
+        assert!(!prompt.contains("{{"));
+
+        let first = build_next_task_plan_prompt(
+            Path::new("/ws/.bear/user-request.md"),
+            Path::new("/ws/.bear/spec.md"),
+            Path::new("/ws/.bear/plan.md"),
+            &[],
+            "TASK-01",
+        );
+        assert!(first.contains("(none yet"));
+    }
+
+    #[test]
+    fn append_planned_task_accumulates_plan() {
+        let temp_dir = TempDir::new().unwrap();
+
+        append_planned_task(temp_dir.path(), "TASK-01", "첫 작업", "설명 1").unwrap();
+        let path = append_planned_task(temp_dir.path(), "TASK-02", "둘째 작업", "설명 2").unwrap();
+
+        let content = fs::read_to_string(path).unwrap();
+        assert!(content.starts_with("# 점진적 개발 계획\n"));
+        let first = content.find("## [TASK-01] 첫 작업").unwrap();
+        let second = content.find("## [TASK-02] 둘째 작업").unwrap();
+        assert!(first < second);
+    }
 }
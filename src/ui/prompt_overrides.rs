@@ -0,0 +1,207 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{clarification, coding, file_validation, planning};
+use crate::config::PromptOverrideMode;
+
+/// 워크스페이스에서 시스템 프롬프트 오버라이드 파일을 찾는 디렉토리.
+pub const WORKSPACE_PROMPT_DIR: &str = ".bear/prompts";
+
+/// 시스템 프롬프트를 쓰는 에이전트 종류. 종류마다 오버라이드 파일 이름이 하나씩 있다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    Clarification,
+    Planning,
+    IncrementalPlanning,
+    TaskExtraction,
+    Coding,
+    Review,
+    FileValidation,
+}
+
+impl PromptKind {
+    /// 오버라이드 디렉토리에서 찾을 파일 이름.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            PromptKind::Clarification => "clarification.md",
+            PromptKind::Planning => "planning.md",
+            PromptKind::IncrementalPlanning => "incremental-planning.md",
+            PromptKind::TaskExtraction => "task-extraction.md",
+            PromptKind::Coding => "coding.md",
+            PromptKind::Review => "review.md",
+            PromptKind::FileValidation => "file-validation.md",
+        }
+    }
+
+    /// 코드에 들어 있는 기본 시스템 프롬프트. 산출물을 쓰는 에이전트에는 `output_language`로
+    /// 쓰라는 지시가 들어간다.
+    pub fn built_in(self, output_language: &str) -> String {
+        match self {
+            PromptKind::Clarification => clarification::system_prompt().to_string(),
+            PromptKind::Planning => planning::system_prompt(output_language),
+            PromptKind::IncrementalPlanning => {
+                planning::incremental_planning_system_prompt(output_language)
+            }
+            PromptKind::TaskExtraction => coding::task_extraction_system_prompt(output_language),
+            PromptKind::Coding => coding::coding_agent_system_prompt(output_language),
+            PromptKind::Review => coding::review_agent_system_prompt().to_string(),
+            PromptKind::FileValidation => file_validation::system_prompt().to_string(),
+        }
+    }
+}
+
+/// 시스템 프롬프트 오버라이드 파일을 찾는 규칙. 워크스페이스의 `.bear/prompts/`를 먼저 보고,
+/// 없으면 사용자 디렉토리를 본다. 처음 찾은 파일 하나만 쓰며, 내용이 비어 있는 파일은 없는
+/// 것으로 본다. 파일은 프롬프트가 필요할 때마다 다시 읽으므로 세션 중에 고쳐도 다음 에이전트
+/// 호출부터 반영된다.
+pub struct PromptOverrides {
+    search_dirs: Vec<PathBuf>,
+    mode: PromptOverrideMode,
+}
+
+impl PromptOverrides {
+    pub fn new(workspace: &Path, user_prompt_dir: Option<&Path>, mode: PromptOverrideMode) -> Self {
+        let mut search_dirs = vec![workspace.join(WORKSPACE_PROMPT_DIR)];
+        search_dirs.extend(user_prompt_dir.map(Path::to_path_buf));
+        Self { search_dirs, mode }
+    }
+
+    /// `kind`에 쓸 오버라이드 파일과 그 내용. 어느 디렉토리에도 없으면 None이다.
+    pub fn find(&self, kind: PromptKind) -> io::Result<Option<(PathBuf, String)>> {
+        for dir in &self.search_dirs {
+            let path = dir.join(kind.file_name());
+            match fs::read_to_string(&path) {
+                Ok(content) if content.trim().is_empty() => continue,
+                Ok(content) => return Ok(Some((path, content))),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => {
+                    return Err(io::Error::new(
+                        err.kind(),
+                        format!("{}: {}", path.display(), err),
+                    ));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// 오버라이드를 적용한 시스템 프롬프트. 오버라이드 파일이 없으면 기본 프롬프트 그대로다.
+    pub fn resolve(&self, kind: PromptKind, output_language: &str) -> io::Result<String> {
+        let built_in = kind.built_in(output_language);
+        let Some((_, content)) = self.find(kind)? else {
+            return Ok(built_in);
+        };
+        Ok(match self.mode {
+            PromptOverrideMode::Append => {
+                format!("{}\n\n{}\n", built_in.trim_end(), content.trim())
+            }
+            PromptOverrideMode::Replace => format!("{}\n", content.trim()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_prompt(dir: &Path, kind: PromptKind, content: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join(kind.file_name()), content).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_built_in_prompt_without_override_files() {
+        let workspace = TempDir::new().unwrap();
+        let overrides = PromptOverrides::new(
+            workspace.path(),
+            Some(&workspace.path().join("missing")),
+            PromptOverrideMode::Replace,
+        );
+
+        assert_eq!(overrides.find(PromptKind::Coding).unwrap(), None);
+        assert_eq!(
+            overrides.resolve(PromptKind::Coding, "Korean").unwrap(),
+            coding::coding_agent_system_prompt("Korean"),
+        );
+    }
+
+    #[test]
+    fn appends_or_replaces_according_to_mode() {
+        let workspace = TempDir::new().unwrap();
+        let prompt_dir = workspace.path().join(WORKSPACE_PROMPT_DIR);
+        write_prompt(&prompt_dir, PromptKind::Review, "\nUse our house style.\n\n");
+
+        let appended = PromptOverrides::new(workspace.path(), None, PromptOverrideMode::Append)
+            .resolve(PromptKind::Review, "Korean")
+            .unwrap();
+        let replaced = PromptOverrides::new(workspace.path(), None, PromptOverrideMode::Replace)
+            .resolve(PromptKind::Review, "Korean")
+            .unwrap();
+
+        assert!(appended.starts_with(coding::review_agent_system_prompt().trim_end()));
+        assert!(appended.ends_with("\n\nUse our house style.\n"), "{}", appended);
+        assert_eq!(replaced, "Use our house style.\n");
+        assert_eq!(
+            PromptOverrides::new(workspace.path(), None, PromptOverrideMode::Replace)
+                .resolve(PromptKind::Coding, "Korean")
+                .unwrap(),
+            coding::coding_agent_system_prompt("Korean"),
+        );
+    }
+
+    #[test]
+    fn workspace_file_takes_precedence_over_user_directory() {
+        let workspace = TempDir::new().unwrap();
+        let user_dir = TempDir::new().unwrap();
+        write_prompt(user_dir.path(), PromptKind::Planning, "user planning");
+        write_prompt(user_dir.path(), PromptKind::Coding, "user coding");
+        let prompt_dir = workspace.path().join(WORKSPACE_PROMPT_DIR);
+        write_prompt(&prompt_dir, PromptKind::Planning, "workspace planning");
+        write_prompt(&prompt_dir, PromptKind::Coding, "  \n");
+        let overrides = PromptOverrides::new(
+            workspace.path(),
+            Some(user_dir.path()),
+            PromptOverrideMode::Replace,
+        );
+
+        assert_eq!(
+            overrides.find(PromptKind::Planning).unwrap(),
+            Some((prompt_dir.join("planning.md"), "workspace planning".to_string())),
+        );
+        // 비어 있는 워크스페이스 파일은 없는 것으로 보고 사용자 디렉토리 파일을 쓴다.
+        assert_eq!(overrides.resolve(PromptKind::Coding, "Korean").unwrap(), "user coding\n");
+    }
+
+    #[test]
+    fn unreadable_override_is_reported_with_its_path() {
+        let workspace = TempDir::new().unwrap();
+        let prompt_dir = workspace.path().join(WORKSPACE_PROMPT_DIR);
+        // 디렉토리는 파일로 읽을 수 없다.
+        fs::create_dir_all(prompt_dir.join("coding.md")).unwrap();
+        let overrides = PromptOverrides::new(workspace.path(), None, PromptOverrideMode::Append);
+
+        let err = overrides.resolve(PromptKind::Coding, "Korean").unwrap_err();
+
+        assert!(err.to_string().contains("coding.md"), "{}", err);
+    }
+
+    #[test]
+    fn built_in_prompts_ask_for_configured_output_language() {
+        for kind in [
+            PromptKind::Planning,
+            PromptKind::IncrementalPlanning,
+            PromptKind::TaskExtraction,
+            PromptKind::Coding,
+        ] {
+            let prompt = kind.built_in("Esperanto");
+
+            assert!(prompt.contains("Esperanto"), "{:?}", kind);
+            assert!(!prompt.contains("Korean"), "{:?}", kind);
+            assert!(!prompt.contains("{{OUTPUT_LANGUAGE}}"), "{:?}", kind);
+        }
+        assert!(PromptKind::Planning.built_in("English").contains("MUST be English."));
+        assert!(PromptKind::Coding.built_in("Korean").contains("MUST be Korean unless"));
+    }
+}
@@ -0,0 +1,322 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use super::coding::{CodingSummary, ReviewApproval, TaskReport};
+
+/// 통합 브랜치를 푸시할 원격 저장소 이름.
+pub const PUSH_REMOTE: &str = "origin";
+
+/// 풀 리퀘스트를 만들지 못한 이유.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PullRequestError {
+    #[error("failed to push {branch} to {remote}: {message}")]
+    PushFailed {
+        remote: String,
+        branch: String,
+        message: String,
+    },
+
+    #[error("gh pr create failed: {message}")]
+    CreateFailed { message: String },
+}
+
+/// 통합 브랜치를 푸시하고 풀 리퀘스트를 만드는 작업. 네트워크를 기다리는 동안 화면이 멈추지
+/// 않도록 UI 스레드 밖에서 `run`한다.
+#[derive(Debug, Clone)]
+pub struct PublishJob {
+    pub gh_binary: PathBuf,
+    pub workspace: PathBuf,
+    pub branch: String,
+    pub base_branch: Option<String>,
+    pub title: String,
+    pub body: String,
+    /// 이미 만든 풀 리퀘스트의 URL. 있으면 푸시만 한다.
+    pub existing_url: Option<String>,
+}
+
+/// 게시 작업의 결과.
+#[derive(Debug, PartialEq)]
+pub enum PublishOutcome {
+    /// 이미 있던 풀 리퀘스트를 다시 푸시해 갱신했다.
+    Updated { url: String },
+    /// 새 풀 리퀘스트를 만들었다.
+    Created { url: String },
+}
+
+/// 게시 작업 스레드가 UI로 보내는 결과.
+pub type PublishResult = Result<PublishOutcome, PullRequestError>;
+
+impl PublishJob {
+    pub fn run(&self) -> PublishResult {
+        push_branch(&self.workspace, &self.branch)?;
+        if let Some(url) = &self.existing_url {
+            return Ok(PublishOutcome::Updated { url: url.clone() });
+        }
+        let url = create_pull_request(
+            &self.gh_binary,
+            &self.workspace,
+            &self.branch,
+            self.base_branch.as_deref(),
+            &self.title,
+            &self.body,
+        )?;
+        Ok(PublishOutcome::Created { url })
+    }
+}
+
+/// PATH에서 `gh` CLI를 찾는다. 없으면 None이다.
+pub fn find_gh_binary() -> Option<PathBuf> {
+    which::which("gh").ok()
+}
+
+/// 코딩 단계 요약과 태스크 리포트로 풀 리퀘스트 본문을 만든다. 요약 섹션에는 태스크별 상태를,
+/// 리포트 섹션에는 완료된 순서대로 각 태스크의 리포트를 넣는다.
+pub fn build_pull_request_body(summary: &CodingSummary, reports: &[TaskReport]) -> String {
+    let mut lines = vec![
+        "## Summary".to_string(),
+        String::new(),
+        format!("- 성공: {}, 차단: {}", summary.success_count, summary.blocked_count),
+    ];
+    if summary.build_test_skipped {
+        lines.push("- 빌드/테스트 검증: 건너뜀".to_string());
+    }
+    for task in &summary.tasks {
+        let mut line = format!(
+            "- [{}] {} — {} (리뷰 {}회",
+            task.task_id,
+            task.title,
+            task.status.label(),
+            task.review_iterations,
+        );
+        if task.review_approval == ReviewApproval::AutoApproved {
+            line.push_str(", 자동 승인");
+        }
+        line.push(')');
+        if task.build_test_passed == Some(false) {
+            line.push_str(" ⚠ 빌드/테스트 실패 상태로 머지됨");
+        }
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    lines.push("## Reports".to_string());
+    for report in reports {
+        lines.push(String::new());
+        lines.push(format!("### [{}] {}", report.task_id, report.status.label()));
+        lines.push(String::new());
+        lines.push(report.report.trim_end().to_string());
+    }
+    if reports.is_empty() {
+        lines.push(String::new());
+        lines.push("(없음)".to_string());
+    }
+
+    let mut body = lines.join("\n");
+    body.push('\n');
+    body
+}
+
+/// 통합 브랜치를 원격 저장소에 푸시하고 upstream으로 설정한다.
+pub fn push_branch(workspace: &Path, branch: &str) -> Result<(), PullRequestError> {
+    let push_failed = |message: String| PullRequestError::PushFailed {
+        remote: PUSH_REMOTE.to_string(),
+        branch: branch.to_string(),
+        message,
+    };
+    let output = Command::new("git")
+        .current_dir(workspace)
+        .args(["push", "--set-upstream", PUSH_REMOTE, branch])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| push_failed(err.to_string()))?;
+    if !output.status.success() {
+        return Err(push_failed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(())
+}
+
+/// `gh pr create`로 풀 리퀘스트를 만들고 gh가 출력한 URL을 반환한다. 본문은 길 수 있으므로
+/// 명령줄 인자 대신 표준 입력으로 넘긴다. `base_branch`가 없으면 저장소의 기본 브랜치를 쓴다.
+pub fn create_pull_request(
+    gh_binary: &Path,
+    workspace: &Path,
+    branch: &str,
+    base_branch: Option<&str>,
+    title: &str,
+    body: &str,
+) -> Result<String, PullRequestError> {
+    let create_failed = |message: String| PullRequestError::CreateFailed { message };
+    let mut command = Command::new(gh_binary);
+    command
+        .current_dir(workspace)
+        .args(["pr", "create", "--head", branch, "--title", title, "--body-file", "-"]);
+    if let Some(base_branch) = base_branch {
+        command.args(["--base", base_branch]);
+    }
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| create_failed(err.to_string()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(body.as_bytes()).map_err(|err| create_failed(err.to_string()))?;
+    }
+    let output = child.wait_with_output().map_err(|err| create_failed(err.to_string()))?;
+    if !output.status.success() {
+        return Err(create_failed(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.lines().last().unwrap_or_default().trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::coding::{CodingTaskStatus, TaskSummary};
+    use crate::ui::test_support::{task_report, write_script};
+
+    fn task_summary(task_id: &str, status: CodingTaskStatus) -> TaskSummary {
+        TaskSummary {
+            task_id: task_id.to_string(),
+            title: format!("{} title", task_id),
+            status,
+            report_file_path: None,
+            review_iterations: 1,
+            review_approval: ReviewApproval::Approved,
+            build_test_passed: Some(true),
+        }
+    }
+
+    #[test]
+    fn body_lists_task_outcomes_and_reports_in_order() {
+        let mut auto_approved = task_summary("TASK-01", CodingTaskStatus::ImplementationSuccess);
+        auto_approved.review_approval = ReviewApproval::AutoApproved;
+        auto_approved.build_test_passed = Some(false);
+        let summary = CodingSummary {
+            integration_branch: "bear/integration/login-abc".to_string(),
+            success_count: 2,
+            blocked_count: 1,
+            tasks: vec![
+                task_summary("TASK-00", CodingTaskStatus::ImplementationSuccess),
+                auto_approved,
+                task_summary("TASK-02", CodingTaskStatus::ImplementationBlocked),
+            ],
+            build_test_skipped: false,
+        };
+        let reports = vec![
+            task_report("TASK-00", CodingTaskStatus::ImplementationSuccess, "Added login form\n\n"),
+            task_report("TASK-02", CodingTaskStatus::ImplementationBlocked, "Missing API key"),
+        ];
+
+        let body = build_pull_request_body(&summary, &reports);
+
+        assert_eq!(
+            body,
+            "## Summary\n\n\
+             - 성공: 2, 차단: 1\n\
+             - [TASK-00] TASK-00 title — 성공 (리뷰 1회)\n\
+             - [TASK-01] TASK-01 title — 성공 (리뷰 1회, 자동 승인) ⚠ 빌드/테스트 실패 상태로 머지됨\n\
+             - [TASK-02] TASK-02 title — 차단 (리뷰 1회)\n\
+             \n## Reports\n\n\
+             ### [TASK-00] 성공\n\nAdded login form\n\n\
+             ### [TASK-02] 차단\n\nMissing API key\n",
+        );
+    }
+
+    #[test]
+    fn body_marks_skipped_verification_and_missing_reports() {
+        let summary = CodingSummary {
+            integration_branch: "bear/integration/login-abc".to_string(),
+            success_count: 0,
+            blocked_count: 0,
+            tasks: Vec::new(),
+            build_test_skipped: true,
+        };
+
+        let body = build_pull_request_body(&summary, &[]);
+
+        assert!(body.contains("- 빌드/테스트 검증: 건너뜀\n"), "{}", body);
+        assert!(body.ends_with("## Reports\n\n(없음)\n"), "{}", body);
+    }
+
+    #[test]
+    fn create_pull_request_passes_body_on_stdin_and_returns_url() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let captured = temp_dir.path().join("captured");
+        let script = format!(
+            "#!/bin/sh\necho \"$@\" > {0}.args\ncat > {0}.body\n\
+             echo 'Creating pull request'\necho https://example.com/pull/7\n",
+            captured.display(),
+        );
+        let gh = write_script(temp_dir.path(), "gh", &script);
+
+        let url = create_pull_request(
+            &gh,
+            temp_dir.path(),
+            "bear/integration/login-abc",
+            Some("develop"),
+            "login",
+            "## Summary\n",
+        )
+        .unwrap();
+
+        assert_eq!(url, "https://example.com/pull/7");
+        let args = std::fs::read_to_string(temp_dir.path().join("captured.args")).unwrap();
+        assert_eq!(
+            args.trim(),
+            "pr create --head bear/integration/login-abc --title login --body-file - --base develop",
+        );
+        let body = std::fs::read_to_string(temp_dir.path().join("captured.body")).unwrap();
+        assert_eq!(body, "## Summary\n");
+    }
+
+    #[test]
+    fn failed_gh_command_reports_stderr() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let script = "#!/bin/sh\ncat > /dev/null\necho 'not logged in' >&2\nexit 1\n";
+        let gh = write_script(temp_dir.path(), "gh", script);
+
+        let err = create_pull_request(&gh, temp_dir.path(), "branch", None, "title", "body")
+            .unwrap_err();
+
+        assert_eq!(err, PullRequestError::CreateFailed { message: "not logged in".to_string() });
+    }
+
+    #[test]
+    fn publish_job_pushes_and_only_creates_a_pull_request_once() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let git = |dir: &Path, args: &[&str]| {
+            let output = Command::new("git").current_dir(dir).args(args).output().unwrap();
+            assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        };
+        let remote = temp_dir.path().join("remote.git");
+        let workspace = temp_dir.path().join("workspace");
+        std::fs::create_dir_all(&workspace).unwrap();
+        git(temp_dir.path(), &["init", "--bare", "remote.git"]);
+        git(&workspace, &["init", "-b", "feature"]);
+        let identity = ["-c", "user.name=bear", "-c", "user.email=bear@example.com"];
+        git(&workspace, &[&identity[..], &["commit", "--allow-empty", "-m", "init"]].concat());
+        git(&workspace, &["remote", "add", PUSH_REMOTE, remote.to_str().unwrap()]);
+        let script = "#!/bin/sh\ncat > /dev/null\necho https://example.com/pull/9\n";
+        let gh = write_script(temp_dir.path(), "gh", script);
+        let mut job = PublishJob {
+            gh_binary: gh,
+            workspace,
+            branch: "feature".to_string(),
+            base_branch: None,
+            title: "title".to_string(),
+            body: "body".to_string(),
+            existing_url: None,
+        };
+
+        let url = "https://example.com/pull/9".to_string();
+        assert_eq!(job.run().unwrap(), PublishOutcome::Created { url: url.clone() });
+        git(&remote, &["rev-parse", "--verify", "refs/heads/feature"]);
+
+        job.existing_url = Some(url.clone());
+        assert_eq!(job.run().unwrap(), PublishOutcome::Updated { url });
+    }
+}
@@ -1,12 +1,17 @@
 use std::io::{Stdout, Write, stdout};
+use std::path::Path;
 
 use crossterm::{cursor, queue, style, terminal};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use super::app::{App, ChatMessage, MessageRole};
+use crate::config::WrapMode;
+use crate::text::truncate_chars;
+use super::app::{App, ChatMessage, DraftPreview, MessageRole, Overlay};
+use super::log_viewer;
 
 pub const SYSTEM_PREFIX: &str = "Bear> ";
 pub const USER_PREFIX: &str = " You> ";
+const STATUS_SEPARATOR: &str = "  │  ";
 
 const BEAR_TEXTS: [&str; 7] = [
     "",
@@ -70,6 +75,11 @@ impl TerminalWriter {
         self.terminal_width = width;
     }
 
+    pub fn ring_bell(&mut self) -> Result<(), std::io::Error> {
+        self.stdout.write_all(b"\x07")?;
+        self.stdout.flush()
+    }
+
     pub fn finalize(&mut self) -> Result<(), std::io::Error> {
         self.erase_live_area()?;
         queue!(self.stdout, style::Print("\r\n"))?;
@@ -107,7 +117,7 @@ impl TerminalWriter {
 
         while self.committed_message_count < app.messages.len() {
             let message = &app.messages[self.committed_message_count];
-            self.write_message(message)?;
+            self.write_message(message, app.wrap_mode())?;
             self.committed_message_count += 1;
         }
 
@@ -158,7 +168,11 @@ impl TerminalWriter {
         Ok(())
     }
 
-    fn write_message(&mut self, message: &ChatMessage) -> Result<(), std::io::Error> {
+    fn write_message(
+        &mut self,
+        message: &ChatMessage,
+        wrap_mode: WrapMode,
+    ) -> Result<(), std::io::Error> {
         let (prefix, prefix_color, text_color) = match message.role {
             MessageRole::System => (SYSTEM_PREFIX, style::Color::Cyan, style::Color::Reset),
             MessageRole::User => (USER_PREFIX, style::Color::Green, style::Color::Green),
@@ -172,7 +186,7 @@ impl TerminalWriter {
             let is_bold_line =
                 matches!(message.role, MessageRole::System) && is_tool_label(text_line);
 
-            for visual_line in wrap_text_by_char_width(text_line, text_width) {
+            for visual_line in wrap_text(text_line, text_width, wrap_mode) {
                 if is_first {
                     queue!(
                         self.stdout,
@@ -210,7 +224,48 @@ impl TerminalWriter {
         let mut line_count: u16 = 0;
         let mut cursor_position_on_screen: Option<(u16, u16)> = None;
 
-        if app.is_mode_selection() {
+        if let Some(overlay) = app.overlay() {
+            line_count += match overlay {
+                Overlay::DebugLog => write_log_viewer(
+                    &mut self.stdout,
+                    app.current_log_path().as_deref(),
+                    self.terminal_width,
+                )?,
+                Overlay::SessionInfo => write_overlay_lines(
+                    &mut self.stdout,
+                    "── 세션 정보  [Ctrl+T] 닫기",
+                    &app.session_info().lines(),
+                    self.terminal_width,
+                )?,
+                Overlay::TaskSchedule => write_task_schedule(
+                    &mut self.stdout,
+                    app.task_schedule_lines(),
+                    self.terminal_width,
+                )?,
+                Overlay::DraftPreview => write_draft_preview(
+                    &mut self.stdout,
+                    app.draft_preview().as_ref(),
+                    self.terminal_width,
+                )?,
+            };
+        } else if let Some(view_start) = app.scrolled_view_start() {
+            line_count += write_scrollback_view(
+                &mut self.stdout,
+                &app.messages,
+                view_start,
+                self.terminal_width,
+                app.wrap_mode(),
+            )?;
+        }
+
+        if let Some(lines) = app.fatal_error_screen() {
+            line_count += write_fatal_error_screen(
+                &mut self.stdout,
+                &lines,
+                self.terminal_width,
+                app.wrap_mode(),
+            )?;
+        } else if app.is_mode_selection() {
             line_count += write_mode_selection_menu(
                 &mut self.stdout,
                 app.selected_mode_index(),
@@ -221,6 +276,7 @@ impl TerminalWriter {
                 &app.input_buffer,
                 app.cursor_position,
                 self.terminal_width,
+                app.wrap_mode(),
             )?;
             line_count += result.line_count;
             cursor_position_on_screen = Some((result.cursor_row, result.cursor_screen_col));
@@ -234,8 +290,24 @@ impl TerminalWriter {
                 style::SetForegroundColor(style::Color::Yellow),
                 style::Print(app.thinking_indicator()),
                 style::ResetColor,
-                style::Print("\r\n"),
             )?;
+            // 진행 표시가 줄바꿈되면 라이브 영역 줄 수 계산이 어긋나므로 한 줄에 들어갈 때만 표시한다.
+            if let Some(progress) = app.progress_status() {
+                let used_width = SYSTEM_PREFIX.width()
+                    + app.thinking_indicator().width()
+                    + STATUS_SEPARATOR.width()
+                    + progress.width();
+                if used_width < self.terminal_width as usize {
+                    queue!(
+                        self.stdout,
+                        style::SetForegroundColor(style::Color::DarkGrey),
+                        style::Print(STATUS_SEPARATOR),
+                        style::Print(progress),
+                        style::ResetColor,
+                    )?;
+                }
+            }
+            queue!(self.stdout, style::Print("\r\n"))?;
             line_count += 1;
         } else {
             queue!(self.stdout, style::Print("\r\n"))?;
@@ -249,8 +321,19 @@ impl TerminalWriter {
             style::Print(separator),
             style::Print("\r\n"),
             style::Print(app.help_text()),
-            style::ResetColor,
         )?;
+        // 상태 줄이 줄바꿈되면 라이브 영역 줄 수 계산이 어긋나므로 한 줄에 들어갈 때만 표시한다.
+        if let Some(usage) = app.usage_status() {
+            let used_width = app.help_text().width() + STATUS_SEPARATOR.width() + usage.width();
+            if used_width < self.terminal_width as usize {
+                queue!(
+                    self.stdout,
+                    style::Print(STATUS_SEPARATOR),
+                    style::Print(usage),
+                )?;
+            }
+        }
+        queue!(self.stdout, style::ResetColor)?;
         line_count += 2;
 
         if let Some((cursor_row, cursor_col)) = cursor_position_on_screen {
@@ -286,6 +369,7 @@ fn write_input_lines(
     input_buffer: &str,
     cursor_position: usize,
     max_width: u16,
+    wrap_mode: WrapMode,
 ) -> Result<InputRenderResult, std::io::Error> {
     let cursor_reserved = 1;
     let text_width = (max_width as usize).saturating_sub(USER_PREFIX.len() + cursor_reserved);
@@ -299,7 +383,7 @@ fn write_input_lines(
     let mut cursor_screen_col: u16 = prefix_display_width;
 
     for (logical_idx, logical_line) in logical_lines.iter().enumerate() {
-        let visual_lines = wrap_text_by_char_width(logical_line, text_width);
+        let visual_lines = wrap_text(logical_line, text_width, wrap_mode);
         let visual_line_count = visual_lines.len();
         let mut line_char_offset = 0;
 
@@ -314,7 +398,7 @@ fn write_input_lines(
                 visual_char_count,
                 is_last_visual_of_logical,
             ) {
-                let before_cursor: String = visual_text.chars().take(col).collect();
+                let before_cursor = truncate_chars(visual_text, col);
                 cursor_row = line_count;
                 cursor_screen_col = prefix_display_width + before_cursor.width() as u16;
             }
@@ -378,6 +462,15 @@ fn cursor_column_on_visual_line(
     None
 }
 
+/// `wrap_mode`에 따라 한 줄을 화면 폭에 맞는 여러 줄로 나눈다.
+/// 어느 방식이든 나뉜 줄을 이어 붙이면 원래 문자열이 되므로 커서 위치 계산에 그대로 쓸 수 있다.
+pub(super) fn wrap_text(text: &str, max_width: usize, wrap_mode: WrapMode) -> Vec<String> {
+    match wrap_mode {
+        WrapMode::Char => wrap_text_by_char_width(text, max_width),
+        WrapMode::Word => wrap_text_by_word(text, max_width),
+    }
+}
+
 pub(super) fn wrap_text_by_char_width(text: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
         return vec![text.to_string()];
@@ -402,6 +495,241 @@ pub(super) fn wrap_text_by_char_width(text: &str, max_width: usize) -> Vec<Strin
     result
 }
 
+/// 가능하면 공백 뒤에서 줄을 나눈다. 공백은 앞 단어의 줄에 남기고, 줄 폭보다 긴 토큰은
+/// 문자 단위로 자른다. 폭이 넓은 한중일 문자는 앞뒤 어디서든 나눌 수 있는 것으로 본다.
+pub(super) fn wrap_text_by_word(text: &str, max_width: usize) -> Vec<String> {
+    if max_width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut result = Vec::new();
+    let mut current_line = String::new();
+    let mut current_width: usize = 0;
+    // 현재 줄에서 나눌 수 있는 마지막 위치(바이트 오프셋).
+    let mut last_break: Option<usize> = None;
+
+    for ch in text.chars() {
+        let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        let is_wide = char_width > 1;
+
+        if current_width + char_width > max_width && current_width > 0 {
+            match last_break.filter(|_| !ch.is_whitespace() && !is_wide) {
+                Some(break_at) => {
+                    let rest = current_line.split_off(break_at);
+                    result.push(current_line);
+                    current_width = rest.width();
+                    current_line = rest;
+                }
+                None => {
+                    result.push(std::mem::take(&mut current_line));
+                    current_width = 0;
+                }
+            }
+            last_break = None;
+        }
+
+        if is_wide && !current_line.is_empty() {
+            last_break = Some(current_line.len());
+        }
+        current_line.push(ch);
+        current_width += char_width;
+        if ch.is_whitespace() || is_wide {
+            last_break = Some(current_line.len());
+        }
+    }
+
+    result.push(current_line);
+    result
+}
+
+/// 위로 스크롤한 상태에서 `view_start`번째 메시지부터 터미널 높이의 절반까지를
+/// 라이브 영역에 보여준다. 이미 출력된 메시지는 터미널 스크롤백에 남아 있으므로
+/// 여기서는 색상 없이 텍스트만 다시 그린다.
+fn write_scrollback_view(
+    stdout: &mut Stdout,
+    messages: &[ChatMessage],
+    view_start: usize,
+    terminal_width: u16,
+    wrap_mode: WrapMode,
+) -> Result<u16, std::io::Error> {
+    let (_, terminal_height) = terminal::size().unwrap_or((terminal_width, 24));
+    let max_lines = (terminal_height as usize / 2).max(3);
+
+    let mut lines = Vec::new();
+    for message in &messages[view_start..] {
+        lines.extend(message_plain_lines(message, terminal_width as usize, wrap_mode));
+        lines.push(String::new());
+        if lines.len() >= max_lines {
+            break;
+        }
+    }
+    lines.truncate(max_lines);
+
+    let mut header = format!(
+        "── 이전 메시지 보기 ({}/{})  [PageDown] 아래로  [Ctrl+End] 맨 아래로",
+        view_start + 1,
+        messages.len(),
+    );
+    // 헤더가 줄바꿈되면 라이브 영역 줄 수 계산이 어긋나므로 좁은 터미널에서는 위치만 표시한다.
+    if header.width() >= terminal_width as usize {
+        header = format!("── {}/{}", view_start + 1, messages.len());
+    }
+    queue!(
+        stdout,
+        style::SetForegroundColor(style::Color::DarkGrey),
+        style::Print(header),
+        style::ResetColor,
+        style::Print("\r\n"),
+    )?;
+    for line in &lines {
+        queue!(stdout, style::Print(line), style::Print("\r\n"))?;
+    }
+
+    Ok(lines.len() as u16 + 1)
+}
+
+/// 디버그 로그의 마지막 줄들을 터미널 높이의 절반까지 라이브 영역에 보여준다. 매 렌더링마다
+/// 파일을 다시 읽으므로 로그가 늘어나면 창도 따라 갱신된다.
+fn write_log_viewer(
+    stdout: &mut Stdout,
+    log_path: Option<&Path>,
+    terminal_width: u16,
+) -> Result<u16, std::io::Error> {
+    let (_, terminal_height) = terminal::size().unwrap_or((terminal_width, 24));
+    let max_lines = (terminal_height as usize / 2).max(3);
+
+    let (title, lines) = match log_path {
+        Some(path) => match log_viewer::read_tail(path, max_lines) {
+            Ok(lines) => (path.display().to_string(), lines),
+            Err(err) => (path.display().to_string(), vec![format!("로그를 읽을 수 없습니다: {}", err)]),
+        },
+        None => (
+            "없음".to_string(),
+            vec!["디버그 로그가 꺼져 있거나 아직 기록된 로그가 없습니다.".to_string()],
+        ),
+    };
+
+    let header = format!("── 디버그 로그: {}  [Ctrl+L] 닫기", title);
+    write_overlay_lines(stdout, &header, &lines, terminal_width)
+}
+
+/// 남은 태스크의 실행 예정을 터미널 높이의 절반까지 보여준다. 넘치는 태스크는 개수만 알린다.
+fn write_task_schedule(
+    stdout: &mut Stdout,
+    mut lines: Vec<String>,
+    terminal_width: u16,
+) -> Result<u16, std::io::Error> {
+    let (_, terminal_height) = terminal::size().unwrap_or((terminal_width, 24));
+    let max_lines = (terminal_height as usize / 2).max(3);
+    if lines.len() > max_lines {
+        let hidden = lines.len() - (max_lines - 1);
+        lines.truncate(max_lines - 1);
+        lines.push(format!("… 외 {}개", hidden));
+    }
+    write_overlay_lines(stdout, "── 실행 예정  [Ctrl+U] 닫기", &lines, terminal_width)
+}
+
+/// 피드백 중인 초안의 보이는 부분을 그린다. 헤더에 보이는 줄 범위를 함께 보여준다.
+fn write_draft_preview(
+    stdout: &mut Stdout,
+    preview: Option<&DraftPreview>,
+    terminal_width: u16,
+) -> Result<u16, std::io::Error> {
+    let Some(preview) = preview else {
+        let lines = vec!["미리볼 초안이 없습니다.".to_string()];
+        return write_overlay_lines(stdout, "── 초안 미리보기  [Ctrl+P] 닫기", &lines, terminal_width);
+    };
+    let header = format!(
+        "── {} 초안 {}-{}/{}줄  [PgUp/PgDn] 스크롤  [Ctrl+P] 닫기",
+        preview.title,
+        (preview.first_line + 1).min(preview.total_lines),
+        preview.first_line + preview.lines.len(),
+        preview.total_lines,
+    );
+    write_overlay_lines(stdout, &header, &preview.lines, terminal_width)
+}
+
+/// 회색 헤더 아래에 읽기 전용 창의 줄들을 그리고 그린 줄 수를 반환한다. 줄바꿈되면 라이브
+/// 영역 줄 수 계산이 어긋나므로 각 줄은 터미널 폭에 맞게 자른다.
+fn write_overlay_lines(
+    stdout: &mut Stdout,
+    header: &str,
+    lines: &[String],
+    terminal_width: u16,
+) -> Result<u16, std::io::Error> {
+    let max_width = (terminal_width as usize).saturating_sub(1);
+    queue!(
+        stdout,
+        style::SetForegroundColor(style::Color::DarkGrey),
+        style::Print(fit_to_width(header, max_width)),
+        style::ResetColor,
+        style::Print("\r\n"),
+    )?;
+    for line in lines {
+        queue!(stdout, style::Print(fit_to_width(line, max_width)), style::Print("\r\n"))?;
+    }
+
+    Ok(lines.len() as u16 + 1)
+}
+
+/// 치명적 오류 화면. 오류 메시지와 경로는 잘리면 사후 분석에 쓸 수 없으므로 자르지 않고
+/// 줄바꿈한다.
+fn write_fatal_error_screen(
+    stdout: &mut Stdout,
+    lines: &[String],
+    terminal_width: u16,
+    wrap_mode: WrapMode,
+) -> Result<u16, std::io::Error> {
+    let max_width = (terminal_width as usize).saturating_sub(1);
+    queue!(
+        stdout,
+        style::SetForegroundColor(style::Color::Red),
+        style::SetAttribute(style::Attribute::Bold),
+        style::Print(fit_to_width("── 치명적 오류로 작업을 멈췄습니다  [Enter/Esc] 종료", max_width)),
+        style::SetAttribute(style::Attribute::NormalIntensity),
+        style::ResetColor,
+        style::Print("\r\n"),
+    )?;
+    let mut line_count = 1;
+    for line in lines {
+        for visual_line in wrap_text(line, max_width, wrap_mode) {
+            queue!(stdout, style::Print(visual_line), style::Print("\r\n"))?;
+            line_count += 1;
+        }
+    }
+    Ok(line_count)
+}
+
+/// 표시 폭이 `max_width`를 넘는 부분을 잘라낸다.
+fn fit_to_width(text: &str, max_width: usize) -> String {
+    wrap_text_by_char_width(text, max_width)
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+}
+
+fn message_plain_lines(
+    message: &ChatMessage,
+    terminal_width: usize,
+    wrap_mode: WrapMode,
+) -> Vec<String> {
+    let prefix = match message.role {
+        MessageRole::System => SYSTEM_PREFIX,
+        MessageRole::User => USER_PREFIX,
+    };
+    let padding = " ".repeat(prefix.len());
+    let text_width = terminal_width.saturating_sub(prefix.len());
+
+    let mut lines = Vec::new();
+    for text_line in message.content.lines() {
+        for visual_line in wrap_text(text_line, text_width, wrap_mode) {
+            let line_prefix = if lines.is_empty() { prefix } else { &padding };
+            lines.push(format!("{}{}", line_prefix, visual_line));
+        }
+    }
+    lines
+}
+
 fn is_tool_label(line: &str) -> bool {
     line.starts_with("[Tool Call:") || line.starts_with("[Tool Result]")
 }
@@ -501,3 +829,70 @@ fn wrap_words(text: &str, max_width: usize) -> Vec<String> {
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_lines_fit(lines: &[String], max_width: usize) {
+        for line in lines {
+            assert!(line.width() <= max_width, "{:?} is wider than {}", line, max_width);
+        }
+    }
+
+    #[test]
+    fn word_wrap_breaks_prose_at_spaces() {
+        let lines = wrap_text_by_word("the quick brown fox jumps", 10);
+
+        assert_eq!(lines, vec!["the quick ", "brown fox ", "jumps"]);
+    }
+
+    #[test]
+    fn word_wrap_splits_long_url_by_char_width() {
+        let text = "see https://example.com/a/very/long/path for details";
+
+        let lines = wrap_text_by_word(text, 12);
+
+        assert_eq!(
+            lines,
+            vec!["see ", "https://exam", "ple.com/a/ve", "ry/long/path", " for details"]
+        );
+        assert_lines_fit(&lines, 12);
+        assert_eq!(lines.concat(), text);
+    }
+
+    #[test]
+    fn word_wrap_breaks_korean_per_character() {
+        let text = "한국어문장은공백없이도나뉜다";
+
+        let lines = wrap_text_by_word(text, 9);
+
+        assert_eq!(lines, vec!["한국어문", "장은공백", "없이도나", "뉜다"]);
+        assert_eq!(lines.concat(), text);
+    }
+
+    #[test]
+    fn word_wrap_keeps_english_words_whole_next_to_korean() {
+        let text = "리뷰 결과 refactoring 필요";
+
+        let lines = wrap_text_by_word(text, 13);
+
+        assert_eq!(lines, vec!["리뷰 결과 ", "refactoring ", "필요"]);
+        assert_lines_fit(&lines, 13);
+        assert_eq!(lines.concat(), text);
+    }
+
+    #[test]
+    fn wrap_text_selects_function_by_mode() {
+        assert_eq!(wrap_text("hello world", 8, WrapMode::Char), vec!["hello wo", "rld"]);
+        assert_eq!(wrap_text("hello world", 8, WrapMode::Word), vec!["hello ", "world"]);
+        assert_eq!(wrap_text("", 8, WrapMode::Word), vec![""]);
+    }
+
+    #[test]
+    fn log_viewer_lines_are_cut_to_the_terminal_width() {
+        assert_eq!(fit_to_width("2026-01-01 12:00:00: app.rs:10: 시작", 24), "2026-01-01 12:00:00: app");
+        assert_eq!(fit_to_width("한국어 로그", 5), "한국");
+        assert_eq!(fit_to_width("", 5), "");
+    }
+}
@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::Path;
+
+use super::coding::{self, CodingSummary};
+
+/// 워크스페이스의 `.bear/<날짜>/<세션 이름>` 아래에 남아 있는 이전 세션.
+pub struct SessionRecord {
+    pub date_dir: String,
+    pub session_name: String,
+    /// 코딩 단계까지 끝난 세션만 `summary.json`을 가진다.
+    pub summary: Option<CodingSummary>,
+}
+
+/// 워크스페이스의 저널 디렉토리를 훑어 이전 세션 목록을 날짜, 이름 순으로 반환한다.
+pub fn list_sessions(workspace: &Path) -> Vec<SessionRecord> {
+    let mut sessions = Vec::new();
+
+    for date_dir in sorted_subdirectories(&workspace.join(".bear")) {
+        if date_dir.len() != 8 || !date_dir.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let date_path = workspace.join(".bear").join(&date_dir);
+        for session_name in sorted_subdirectories(&date_path) {
+            let summary = coding::load_coding_summary(&date_path.join(&session_name)).ok();
+            sessions.push(SessionRecord {
+                date_dir: date_dir.clone(),
+                session_name,
+                summary,
+            });
+        }
+    }
+
+    sessions
+}
+
+fn sorted_subdirectories(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|entry| entry.file_name().to_str().map(|n| n.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+/// 워크스페이스를 확정한 직후에 보여줄 이전 실행 요약을 만든다.
+/// 이전 세션, 남은 bear 브랜치, 남은 워크트리가 하나도 없으면 `None`을 반환한다.
+/// `branch_prefix`는 설정한 통합 브랜치 접두사다.
+pub fn build_startup_summary(workspace: &Path, branch_prefix: &str) -> Option<String> {
+    let sessions = list_sessions(workspace);
+    // git 저장소가 아니면 브랜치/워크트리 정보는 생략한다.
+    let branches = coding::list_bear_branches(workspace, branch_prefix).unwrap_or_default();
+    let worktrees = coding::list_bear_worktrees(workspace).unwrap_or_default();
+
+    if sessions.is_empty() && branches.is_empty() && worktrees.is_empty() {
+        return None;
+    }
+
+    let mut lines = vec!["이 워크스페이스의 이전 실행 기록:".to_string()];
+
+    if !sessions.is_empty() {
+        lines.push(format!("세션 {}개:", sessions.len()));
+        for session in &sessions {
+            let status = match &session.summary {
+                Some(summary) => format!(
+                    "성공 {}, 차단 {} ({})",
+                    summary.success_count, summary.blocked_count, summary.integration_branch,
+                ),
+                None => "코딩 단계 미완료".to_string(),
+            };
+            lines.push(format!(
+                "  - {} {}: {}",
+                session.date_dir, session.session_name, status,
+            ));
+        }
+    }
+
+    if !branches.is_empty() {
+        lines.push(format!("남아 있는 bear 브랜치 {}개:", branches.len()));
+        lines.extend(branches.iter().map(|branch| format!("  - {}", branch)));
+    }
+
+    if !worktrees.is_empty() {
+        lines.push(format!("남아 있는 워크트리 {}개:", worktrees.len()));
+        lines.extend(worktrees.iter().map(|path| format!("  - {}", path.display())));
+    }
+
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_INTEGRATION_BRANCH_PREFIX;
+    use crate::ui::coding::{CodingTaskStatus, ReviewApproval, TaskSummary};
+    use std::path::PathBuf;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    #[test]
+    fn startup_summary_reflects_existing_session_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let session_dir = workspace.join(".bear").join("20260301").join("old-session");
+        coding::save_coding_summary(
+            &session_dir,
+            &CodingSummary {
+                integration_branch: "bear/integration/old-session-1234".to_string(),
+                success_count: 2,
+                blocked_count: 1,
+                tasks: vec![TaskSummary {
+                    task_id: "TASK-00".to_string(),
+                    title: "작업".to_string(),
+                    status: CodingTaskStatus::ImplementationSuccess,
+                    report_file_path: Some(PathBuf::from("TASK-00.md")),
+                    review_iterations: 1,
+                    review_approval: ReviewApproval::Approved,
+                    build_test_passed: Some(true),
+                }],
+                build_test_skipped: false,
+            },
+        )
+        .unwrap();
+        fs::create_dir_all(workspace.join(".bear").join("20260302").join("unfinished")).unwrap();
+
+        let summary = build_startup_summary(workspace, DEFAULT_INTEGRATION_BRANCH_PREFIX).unwrap();
+
+        assert!(summary.contains("세션 2개:"));
+        assert!(summary.contains(
+            "20260301 old-session: 성공 2, 차단 1 (bear/integration/old-session-1234)"
+        ));
+        assert!(summary.contains("20260302 unfinished: 코딩 단계 미완료"));
+    }
+
+    #[test]
+    fn startup_summary_lists_leftover_bear_branches() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        for args in [
+            vec!["init"],
+            vec!["symbolic-ref", "HEAD", "refs/heads/master"],
+            vec![
+                "-c", "user.email=t@t", "-c", "user.name=T", "-c", "commit.gpgsign=false",
+                "commit", "--allow-empty", "-m", "initial commit",
+            ],
+            vec!["branch", "bear/integration/leftover"],
+            vec!["branch", "team/integration/custom-leftover"],
+            vec!["branch", "feature/unrelated"],
+        ] {
+            Command::new("git").current_dir(workspace).args(&args).output().unwrap();
+        }
+
+        let summary = build_startup_summary(workspace, DEFAULT_INTEGRATION_BRANCH_PREFIX).unwrap();
+
+        assert!(summary.contains("남아 있는 bear 브랜치 1개:"));
+        assert!(summary.contains("bear/integration/leftover"));
+        assert!(!summary.contains("세션"));
+
+        let summary = build_startup_summary(workspace, "team/integration").unwrap();
+
+        assert!(summary.contains("남아 있는 bear 브랜치 2개:"), "{}", summary);
+        assert!(summary.contains("team/integration/custom-leftover"));
+        assert!(!summary.contains("feature/unrelated"));
+    }
+
+    #[test]
+    fn startup_summary_is_none_for_fresh_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let summary = build_startup_summary(temp_dir.path(), DEFAULT_INTEGRATION_BRANCH_PREFIX);
+
+        assert!(summary.is_none());
+    }
+}
@@ -1,6 +1,29 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
 use chrono::{FixedOffset, Utc};
 use uuid::Uuid;
 
+use crate::config::{NameCasing, SessionNameStyle};
+use crate::text::truncate_chars;
+
+/// 설정한 최대 길이와 관계없이 적용하는 세션 이름 길이 상한. 날짜 디렉토리와 함께 쓰여도
+/// 파일 시스템의 경로 구성 요소 길이 제한에 걸리지 않도록 한다.
+pub const MAX_SESSION_NAME_LENGTH: usize = 100;
+
+/// 정리한 결과가 비었거나 쓸 수 없는 이름일 때 대신 쓰는 이름.
+const FALLBACK_SESSION_NAME: &str = "session";
+
+/// git 참조 이름의 끝에 올 수 없는 접미사.
+const FORBIDDEN_REF_SUFFIX: &str = ".lock";
+
+/// Windows에서 파일 이름으로 쓸 수 없는 장치 이름.
+const RESERVED_DEVICE_NAMES: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
 /// Asia/Seoul (KST, UTC+9) 타임존 기준 오늘 날짜를 `YYYYMMDD` 형식으로 반환한다.
 pub fn today_date_string() -> String {
     let kst = FixedOffset::east_opt(9 * 3600).expect("valid KST offset");
@@ -12,6 +35,83 @@ pub fn generate_session_id() -> String {
     Uuid::new_v4().to_string()
 }
 
+/// 설정된 형식으로 새 세션 이름을 만든다. 접두사 뒤에 UUID를 붙인 뒤 정리한다.
+pub fn generate_session_name(style: &SessionNameStyle) -> String {
+    let id = generate_session_id();
+    let raw = match &style.prefix {
+        Some(prefix) => format!("{}{}{}", prefix, style.separator, id),
+        None => id,
+    };
+    sanitize_session_name(&raw, style)
+}
+
+/// 세션 이름을 디렉토리와 git 브랜치 이름으로 안전하게 쓸 수 있도록 정리한다.
+/// ASCII 영숫자가 아닌 문자는 구분자로 바꾸고, 연속된 구분자는 하나로 합치며,
+/// 대소문자 규칙과 최대 길이(최대 [`MAX_SESSION_NAME_LENGTH`])를 적용한다.
+/// 끝에 남은 구분자, 점, 공백과 git이 거부하는 `.lock` 접미사를 제거하고, 결과가 비었거나
+/// Windows 장치 이름이면 대신 `session`을 쓰므로 항상 비어 있지 않은 이름을 반환한다.
+pub fn sanitize_session_name(raw: &str, style: &SessionNameStyle) -> String {
+    let mut name = String::new();
+    for ch in raw.chars() {
+        if ch.is_ascii_alphanumeric() {
+            name.push(ch);
+        } else if !name.is_empty() && !name.ends_with(style.separator) {
+            name.push(style.separator);
+        }
+    }
+
+    let name = match style.casing {
+        NameCasing::Preserve => name,
+        NameCasing::Lower => name.to_ascii_lowercase(),
+        NameCasing::Upper => name.to_ascii_uppercase(),
+    };
+
+    let max_length = effective_max_length(style);
+    let mut name = trim_unsafe_end(truncate_chars(&name, max_length), style.separator);
+    while name.to_ascii_lowercase().ends_with(FORBIDDEN_REF_SUFFIX) {
+        name.truncate(name.len() - FORBIDDEN_REF_SUFFIX.len());
+        name = trim_unsafe_end(&name, style.separator);
+    }
+
+    if name.is_empty() || RESERVED_DEVICE_NAMES.contains(&name.to_ascii_lowercase().as_str()) {
+        return truncate_chars(FALLBACK_SESSION_NAME, max_length).to_string();
+    }
+    name
+}
+
+/// `parent_dir`에 이미 같은 이름의 항목이 있으면 구분자와 번호(`-2`, `-3`, ...)를 붙인
+/// 이름을 반환한다. 대소문자를 구분하지 않는 파일 시스템에서도 겹치지 않도록 소문자로
+/// 비교하며, 번호를 붙여도 최대 길이를 넘지 않도록 앞부분을 자른다.
+pub fn ensure_unique_name(parent_dir: &Path, name: &str, style: &SessionNameStyle) -> String {
+    let existing: HashSet<String> = fs::read_dir(parent_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_lowercase())
+        .collect();
+    if !existing.contains(&name.to_lowercase()) {
+        return name.to_string();
+    }
+
+    (2..)
+        .map(|number: usize| {
+            let suffix = format!("{}{}", style.separator, number);
+            let base_length = effective_max_length(style).saturating_sub(suffix.len());
+            let base = trim_unsafe_end(truncate_chars(name, base_length), style.separator);
+            format!("{}{}", base, suffix)
+        })
+        .find(|candidate| !existing.contains(&candidate.to_lowercase()))
+        .expect("an unused numbered name always exists")
+}
+
+fn effective_max_length(style: &SessionNameStyle) -> usize {
+    style.max_length.clamp(1, MAX_SESSION_NAME_LENGTH)
+}
+
+fn trim_unsafe_end(name: &str, separator: char) -> String {
+    name.trim_end_matches([separator, '.', ' ']).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +136,117 @@ mod tests {
         let id2 = generate_session_id();
         assert_ne!(id1, id2);
     }
+
+    #[test]
+    fn default_style_keeps_uuid_session_name() {
+        let name = generate_session_name(&SessionNameStyle::default());
+        assert!(Uuid::parse_str(&name).is_ok());
+    }
+
+    #[test]
+    fn sanitize_applies_separator_and_casing() {
+        let snake_upper = SessionNameStyle {
+            prefix: None,
+            separator: '_',
+            max_length: 64,
+            casing: NameCasing::Upper,
+        };
+        assert_eq!(
+            sanitize_session_name("jira PROJ-123 / short--slug!", &snake_upper),
+            "JIRA_PROJ_123_SHORT_SLUG",
+        );
+
+        let kebab_lower = SessionNameStyle {
+            casing: NameCasing::Lower,
+            ..SessionNameStyle::default()
+        };
+        assert_eq!(
+            sanitize_session_name("__Jira_PROJ 123__", &kebab_lower),
+            "jira-proj-123",
+        );
+    }
+
+    #[test]
+    fn sanitize_truncates_without_trailing_separator() {
+        let style = SessionNameStyle {
+            max_length: 9,
+            ..SessionNameStyle::default()
+        };
+        assert_eq!(sanitize_session_name("jira-PROJ-123", &style), "jira-PROJ");
+        assert_eq!(sanitize_session_name("jira-PRO-12", &style), "jira-PRO");
+    }
+
+    #[test]
+    fn generate_session_name_uses_prefix() {
+        let style = SessionNameStyle {
+            prefix: Some("jira PROJ-123".to_string()),
+            casing: NameCasing::Lower,
+            ..SessionNameStyle::default()
+        };
+
+        let name = generate_session_name(&style);
+
+        assert!(name.starts_with("jira-proj-123-"));
+        assert!(name.len() <= 64);
+    }
+
+    #[test]
+    fn sanitize_survives_adversarial_input() {
+        let dotted = SessionNameStyle {
+            separator: '.',
+            ..SessionNameStyle::default()
+        };
+        let samples = [
+            ("", "session"),
+            ("🙂🙂🙂", "session"),
+            ("한글 세션 이름", "session"),
+            ("...   ", "session"),
+            ("release v2. ", "release.v2"),
+            ("CON", "session"),
+            ("nul.txt", "nul.txt"),
+            ("feature.lock", "feature"),
+            ("feature.LOCK.lock", "feature"),
+            ("../../etc/passwd", "etc.passwd"),
+        ];
+
+        for (raw, expected) in samples {
+            assert_eq!(sanitize_session_name(raw, &dotted), expected, "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn sanitize_caps_length_and_never_returns_empty() {
+        let unlimited = SessionNameStyle {
+            max_length: 10_000,
+            ..SessionNameStyle::default()
+        };
+        let zero = SessionNameStyle {
+            max_length: 0,
+            ..SessionNameStyle::default()
+        };
+
+        let long = sanitize_session_name(&"a".repeat(500), &unlimited);
+
+        assert_eq!(long.len(), MAX_SESSION_NAME_LENGTH);
+        assert_eq!(sanitize_session_name("abc", &zero), "a");
+        assert_eq!(sanitize_session_name("", &zero), "s");
+    }
+
+    #[test]
+    fn ensure_unique_name_ignores_case_and_respects_max_length() {
+        let parent = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(parent.path().join("Login-Feature")).unwrap();
+        std::fs::create_dir(parent.path().join("LOGIN-FEATU-2")).unwrap();
+        let style = SessionNameStyle {
+            max_length: 13,
+            ..SessionNameStyle::default()
+        };
+
+        assert_eq!(ensure_unique_name(parent.path(), "other", &style), "other");
+        assert_eq!(ensure_unique_name(parent.path(), "login-feature", &style), "login-featu-3");
+        assert_eq!(
+            ensure_unique_name(&parent.path().join("missing"), "login-feature", &style),
+            "login-feature",
+        );
+    }
 }
@@ -61,7 +61,7 @@ IMPORTANT:
 - The spec describes WHAT the system must do, not HOW it is implemented internally.
 - The spec MUST be testable with clear acceptance criteria.
 - Inspect the workspace using available tools to understand existing code context.
-- Write the spec in Korean.
+- Write the spec in {{OUTPUT_LANGUAGE}}.
 - This spec MUST be workspace-root relative if it references any file paths. 
   - Do NOT use absolute paths or environment-specific paths. 
   - Instead, for example, use `src/main.rs` instead of `/workspace/src/main.rs`. 
@@ -132,7 +132,7 @@ If you need more clarification before revising, set response_type to "clarifying
 IMPORTANT:
 - The spec describes WHAT the system must do, not HOW it is implemented internally.
 - The spec MUST be testable with clear acceptance criteria.
-- Write the spec in Korean.
+- Write the spec in {{OUTPUT_LANGUAGE}}.
 - The session conversation history contains all prior requirements, Q&A, and previous spec drafts. Use this context to revise the spec.
 - DECISION ESCALATION: The same decision-escalation rules from the initial spec phase still apply. If the user's feedback introduces or reveals new undecided spec-level topics that require user approval (external interface contract, UI/UX behavior, user-facing auth flow, breaking changes to public contracts, observable behavior trade-offs, platform constraints), you MUST set response_type to "clarifying_questions" and ask the user to decide before revising the spec. When asking, present options with pros/cons and your recommendation. Do NOT silently incorporate your own choices into the revised spec. Remember: do NOT ask about implementation details (library choices, architecture patterns, storage engines, etc.) — those belong to the planning phase.
 - USER RESPONSE CLASSIFICATION: When the previous conversation shows that the most recent model output was a set of clarifying questions (especially decision-escalation questions), you MUST classify the user's current message into one of three categories before taking any other action:
@@ -156,16 +156,200 @@ User feedback:
 {{USER_FEEDBACK}}
 >>>"#;
 
-pub fn build_initial_spec_prompt(user_request_path: &Path, qa_log: &[QaRound]) -> String {
+const SECTION_REVISION_PROMPT_TEMPLATE: &str = r#"The user is reviewing the spec draft section by section. Revise ONLY the section below according to the user's feedback.
+
+IMPORTANT:
+- Return the complete revised section in the section_content field, starting with the same heading line.
+- Do NOT rewrite, summarize, or include any other section of the spec. Other sections are already approved or reviewed separately.
+- Keep the revised section consistent with the rest of the spec in the session conversation history.
+- The spec describes WHAT the system must do, not HOW it is implemented internally.
+- Write the section in {{OUTPUT_LANGUAGE}}.
+
+Output MUST be valid JSON conforming to the provided JSON Schema.
+
+Section to revise:
+<<<
+{{SECTION_CONTENT}}
+>>>
+
+User feedback:
+<<<
+{{USER_FEEDBACK}}
+>>>"#;
+
+#[derive(Debug, Deserialize)]
+pub struct SectionRevisionResponse {
+    pub section_content: String,
+}
+
+pub fn section_revision_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "section_content": {
+                "type": "string",
+                "minLength": 1
+            }
+        },
+        "required": ["section_content"],
+        "additionalProperties": false
+    })
+}
+
+pub fn build_section_revision_prompt(
+    section_content: &str,
+    user_feedback: &str,
+    output_language: &str,
+) -> String {
+    SECTION_REVISION_PROMPT_TEMPLATE
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
+        .replace("{{SECTION_CONTENT}}", section_content)
+        .replace("{{USER_FEEDBACK}}", user_feedback)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecSection {
+    pub title: String,
+    pub content: String,
+}
+
+/// 스펙 Markdown을 `## ` 헤딩 기준으로 섹션 단위로 나눈다. 첫 헤딩 이전의 내용은
+/// 별도 섹션으로 유지하며, 코드 블록 안의 `## `는 헤딩으로 취급하지 않는다.
+pub fn split_into_sections(markdown: &str) -> Vec<SpecSection> {
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if !in_code_block && line.starts_with("## ") && !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+        current.push(line);
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+
+    sections
+        .into_iter()
+        .map(|lines| lines.join("\n").trim_end().to_string())
+        .filter(|content| !content.trim().is_empty())
+        .map(|content| SpecSection {
+            title: section_title(&content),
+            content,
+        })
+        .collect()
+}
+
+fn section_title(content: &str) -> String {
+    content
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .map(|line| line.trim_start_matches('#').trim().to_string())
+        .unwrap_or_default()
+}
+
+pub fn assemble_sections(sections: &[SpecSection]) -> String {
+    sections
+        .iter()
+        .map(|section| section.content.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// 섹션별 스펙 검토 진행 상태. 승인된 섹션은 잠기고, 모든 섹션이 승인되어야
+/// 최종 스펙을 조립할 수 있다.
+pub struct SectionReviewState {
+    sections: Vec<SpecSection>,
+    approved: Vec<bool>,
+    current_index: usize,
+}
+
+impl SectionReviewState {
+    pub fn new(sections: Vec<SpecSection>) -> Self {
+        let approved = vec![false; sections.len()];
+        Self {
+            sections,
+            approved,
+            current_index: 0,
+        }
+    }
+
+    pub fn section_count(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    pub fn current_section(&self) -> Option<&SpecSection> {
+        self.sections.get(self.current_index)
+    }
+
+    pub fn is_approved(&self, index: usize) -> bool {
+        self.approved.get(index).copied().unwrap_or(false)
+    }
+
+    /// 현재 섹션을 승인하고 다음 미승인 섹션으로 이동한다.
+    /// 모든 섹션이 승인되었으면 true를 반환한다.
+    pub fn approve_current(&mut self) -> bool {
+        if let Some(approved) = self.approved.get_mut(self.current_index) {
+            *approved = true;
+        }
+
+        let count = self.sections.len();
+        let next_unapproved = (1..=count)
+            .map(|offset| (self.current_index + offset) % count)
+            .find(|index| !self.approved[*index]);
+
+        match next_unapproved {
+            Some(index) => {
+                self.current_index = index;
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// 재작성된 내용으로 현재 섹션을 교체한다. 승인된 섹션은 잠겨 있으므로 교체하지 않는다.
+    pub fn replace_current(&mut self, content: String) -> bool {
+        if self.is_approved(self.current_index) {
+            return false;
+        }
+        let Some(section) = self.sections.get_mut(self.current_index) else {
+            return false;
+        };
+        section.title = section_title(&content);
+        section.content = content.trim_end().to_string();
+        true
+    }
+
+    pub fn assemble(&self) -> String {
+        assemble_sections(&self.sections)
+    }
+}
+
+pub fn build_initial_spec_prompt(
+    user_request_path: &Path,
+    qa_log: &[QaRound],
+    output_language: &str,
+) -> String {
     let qa_log_text = format_qa_log(qa_log);
 
     INITIAL_SPEC_PROMPT_TEMPLATE
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
         .replace("{{USER_REQUEST_PATH}}", &user_request_path.display().to_string())
         .replace("{{QA_LOG_TEXT}}", &qa_log_text)
 }
 
-pub fn build_revision_prompt(user_feedback: &str) -> String {
-    REVISION_PROMPT_TEMPLATE.replace("{{USER_FEEDBACK}}", user_feedback)
+pub fn build_revision_prompt(user_feedback: &str, output_language: &str) -> String {
+    REVISION_PROMPT_TEMPLATE
+        .replace("{{OUTPUT_LANGUAGE}}", output_language)
+        .replace("{{USER_FEEDBACK}}", user_feedback)
 }
 
 pub fn build_followup_revision_prompt(user_feedback: &str) -> String {
@@ -276,7 +460,7 @@ mod tests {
 
     #[test]
     fn revision_prompt_contains_approval_detection_instruction() {
-        let prompt = build_revision_prompt("some feedback");
+        let prompt = build_revision_prompt("some feedback", "Korean");
 
         assert!(prompt.contains("APPROVAL DETECTION"));
     }
@@ -289,16 +473,31 @@ mod tests {
         }];
 
         let user_request_path = Path::new("/workspace/.bear/20250101/session/user-request.md");
-        let prompt = build_initial_spec_prompt(user_request_path, &qa_log);
+        let prompt = build_initial_spec_prompt(user_request_path, &qa_log, "Korean");
 
         assert!(prompt.contains("/workspace/.bear/20250101/session/user-request.md"));
         assert!(prompt.contains("What scope?"));
         assert!(prompt.contains("Full scope"));
     }
 
+    #[test]
+    fn spec_prompts_ask_for_configured_output_language() {
+        let user_request_path = Path::new("user-request.md");
+        let prompts = [
+            build_initial_spec_prompt(user_request_path, &[], "English"),
+            build_revision_prompt("feedback", "English"),
+            build_section_revision_prompt("## 1. Overview", "feedback", "English"),
+        ];
+
+        for prompt in prompts {
+            assert!(prompt.contains(" in English."), "{}", prompt);
+            assert!(!prompt.contains("Korean"), "{}", prompt);
+        }
+    }
+
     #[test]
     fn build_revision_prompt_contains_feedback() {
-        let prompt = build_revision_prompt("Please add error handling section");
+        let prompt = build_revision_prompt("Please add error handling section", "Korean");
 
         assert!(prompt.contains("Please add error handling section"));
     }
@@ -345,6 +544,85 @@ mod tests {
         assert_eq!(path, expected);
     }
 
+    const SAMPLE_SPEC: &str = "# 스펙\n\n소개 문단\n\n## 1. 개요\n\n개요 내용\n\n## 2. 목표\n\n```md\n## 코드 안의 헤딩\n```\n\n## 3. 인수 조건\n\n- 조건";
+
+    #[test]
+    fn split_into_sections_uses_level_two_headings() {
+        let sections = split_into_sections(SAMPLE_SPEC);
+
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["스펙", "1. 개요", "2. 목표", "3. 인수 조건"]);
+        assert!(sections[2].content.contains("## 코드 안의 헤딩"));
+    }
+
+    #[test]
+    fn assemble_sections_round_trips_split() {
+        let sections = split_into_sections(SAMPLE_SPEC);
+
+        assert_eq!(assemble_sections(&sections), SAMPLE_SPEC);
+    }
+
+    #[test]
+    fn section_review_walks_sections_until_all_approved() {
+        let mut state = SectionReviewState::new(split_into_sections(SAMPLE_SPEC));
+        assert_eq!(state.section_count(), 4);
+        assert_eq!(state.current_index(), 0);
+
+        assert!(!state.approve_current());
+        assert_eq!(state.current_index(), 1);
+        assert!(!state.approve_current());
+        assert!(!state.approve_current());
+        assert_eq!(state.current_index(), 3);
+        assert!(!state.is_approved(3));
+
+        assert!(state.approve_current());
+        assert!((0..4).all(|index| state.is_approved(index)));
+    }
+
+    #[test]
+    fn section_review_replace_keeps_section_unapproved() {
+        let mut state = SectionReviewState::new(split_into_sections(SAMPLE_SPEC));
+        state.approve_current();
+
+        assert!(state.replace_current("## 1. 개요 (수정)\n\n새 내용\n".to_string()));
+
+        assert_eq!(state.current_index(), 1);
+        assert!(!state.is_approved(1));
+        let section = state.current_section().unwrap();
+        assert_eq!(section.title, "1. 개요 (수정)");
+        assert_eq!(section.content, "## 1. 개요 (수정)\n\n새 내용");
+        assert!(state.assemble().contains("새 내용"));
+        assert!(!state.assemble().contains("개요 내용"));
+    }
+
+    #[test]
+    fn section_review_refuses_to_replace_section_once_all_are_approved() {
+        let sections = split_into_sections("## A\n\na\n\n## B\n\nb");
+        let mut state = SectionReviewState::new(sections);
+
+        state.approve_current();
+        assert!(state.approve_current());
+        assert!(!state.replace_current("## B\n\nchanged".to_string()));
+    }
+
+    #[test]
+    fn section_revision_prompt_is_scoped_to_section() {
+        let prompt = build_section_revision_prompt("## 2. 목표\n\n내용", "더 구체적으로", "Korean");
+
+        assert!(prompt.contains("## 2. 목표"));
+        assert!(prompt.contains("더 구체적으로"));
+        assert!(prompt.contains("Revise ONLY the section below"));
+    }
+
+    #[test]
+    fn deserialize_section_revision_response() {
+        let json = serde_json::json!({ "section_content": "## 1. 개요\n\n수정됨" });
+
+        let response: SectionRevisionResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.section_content, "## 1. 개요\n\n수정됨");
+    }
+
     #[test]
     fn build_followup_revision_prompt_contains_only_feedback() {
         let prompt = build_followup_revision_prompt("에러 처리 섹션을 추가해주세요");
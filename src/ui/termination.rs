@@ -0,0 +1,263 @@
+use std::io::{self, stdout};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crossterm::{cursor, terminal};
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::iterator::Signals;
+
+use super::coding;
+use crate::claude_code_client::CancellationToken;
+
+/// 종료 시그널을 받은 뒤 실행 중인 에이전트 CLI가 끝나기를 기다리는 최대 시간.
+pub const AGENT_EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 아직 정리하지 않은 태스크 워크트리와 그 브랜치.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveWorktree {
+    pub workspace: PathBuf,
+    pub worktree_path: PathBuf,
+    pub task_branch: String,
+}
+
+/// 자동 stash로 치워 둔 워크스페이스의 변경.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashedChanges {
+    pub workspace: PathBuf,
+    pub stash_commit: String,
+}
+
+/// 프로세스가 SIGINT/SIGTERM으로 끝날 때 정리해야 할 워크트리 목록.
+///
+/// 워크트리의 상태는 `App`이 메인 스레드에서 관리하지만 시그널은 별도 스레드에서 처리하므로,
+/// 두 스레드가 같은 목록을 `Arc<Mutex<_>>`로 공유한다. `App`은 워크트리를 만들 때 등록하고
+/// 정리하거나 일부러 남겨 둘 때 등록을 해제하며, 시그널 스레드는 잠금을 잡은 채 목록을 비워
+/// 가져간 뒤 잠금을 풀고 정리한다. 목록을 가져가는 순간 비워지므로 같은 워크트리를 두 번
+/// 정리하지 않고, 그 뒤에 `App`이 등록을 해제해도 아무 일도 일어나지 않는다. 잠금은 목록을
+/// 고치는 동안에만 잡으므로 git 명령 실행 중에 다른 스레드를 막지 않으며, 메인 스레드가
+/// 패닉으로 잠금을 오염시켰더라도 정리는 계속한다. 자동 stash도 같은 방식으로 한 번만 되돌린다.
+/// 워크트리에서 실행 중인 에이전트를 정리 전에 멈출 수 있도록 현재 작업의 취소 토큰도 함께 둔다.
+#[derive(Clone, Default)]
+pub struct CleanupRegistry {
+    worktrees: Arc<Mutex<Vec<ActiveWorktree>>>,
+    stash: Arc<Mutex<Option<StashedChanges>>>,
+    cancellation: Arc<Mutex<Option<CancellationToken>>>,
+}
+
+impl CleanupRegistry {
+    pub fn register(&self, worktree: ActiveWorktree) {
+        self.lock().push(worktree);
+    }
+
+    pub fn unregister(&self, worktree_path: &Path) {
+        self.lock().retain(|worktree| worktree.worktree_path != worktree_path);
+    }
+
+    /// 등록된 워크트리를 모두 꺼내고 목록을 비운다.
+    pub fn take_all(&self) -> Vec<ActiveWorktree> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    pub fn register_stash(&self, stash: StashedChanges) {
+        *self.stash.lock().unwrap_or_else(PoisonError::into_inner) = Some(stash);
+    }
+
+    /// 현재 에이전트 작업의 취소 토큰을 등록한다. 새 작업을 시작할 때마다 바꿔 둔다.
+    pub fn register_cancellation(&self, token: CancellationToken) {
+        *self.cancellation.lock().unwrap_or_else(PoisonError::into_inner) = Some(token);
+    }
+
+    /// 등록된 에이전트 작업을 취소하고 그 CLI 프로세스가 끝날 때까지 최대 `timeout` 동안
+    /// 기다린다. 프로세스가 모두 끝났거나 등록된 작업이 없으면 true를 반환한다.
+    pub fn cancel_running_agent(&self, timeout: Duration) -> bool {
+        let token = self.cancellation.lock().unwrap_or_else(PoisonError::into_inner).take();
+        let Some(token) = token else {
+            return true;
+        };
+        token.cancel();
+        token.wait_for_processes(timeout)
+    }
+
+    /// 등록된 자동 stash를 꺼내고 등록을 지운다.
+    pub fn take_stash(&self) -> Option<StashedChanges> {
+        self.stash.lock().unwrap_or_else(PoisonError::into_inner).take()
+    }
+
+    /// 등록된 워크트리마다 진행 중인 리베이스를 중단하고 워크트리와 태스크 브랜치를 지운 뒤,
+    /// 자동 stash가 있으면 워크스페이스에 되돌린다. 하나가 실패해도 나머지는 계속 정리하며,
+    /// 실패한 이유를 모아 반환한다.
+    pub fn cleanup_all(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        for worktree in self.take_all() {
+            // 리베이스 중이 아니면 실패하므로 결과는 보지 않는다.
+            let _ = coding::abort_rebase(&worktree.worktree_path);
+            if let Err(err) = coding::remove_worktree(&worktree.workspace, &worktree.worktree_path)
+            {
+                errors.push(err.to_string());
+            }
+            if let Err(err) = coding::delete_branch(&worktree.workspace, &worktree.task_branch) {
+                errors.push(err.to_string());
+            }
+        }
+        if let Some(stash) = self.take_stash()
+            && let Err(err) = coding::restore_stashed_changes(&stash.workspace, &stash.stash_commit)
+        {
+            errors.push(format!("{} (stash {})", err, stash.stash_commit));
+        }
+        errors
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<ActiveWorktree>> {
+        self.worktrees.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// SIGINT/SIGTERM을 받으면 실행 중인 에이전트를 멈춘 뒤 `registry`의 워크트리를 정리하고
+/// `128 + 시그널 번호`로 종료하는 스레드를 시작한다. `restore_terminal`이 켜져 있으면 종료하기 전에 raw 모드를 끄고 커서를
+/// 다시 보이게 한다. TUI는 raw 모드에서 Ctrl+C를 키 입력으로 받으므로 SIGINT는 주로 headless
+/// 모드에서, SIGTERM은 두 모드 모두에서 들어온다.
+pub fn install_termination_handler(
+    registry: CleanupRegistry,
+    restore_terminal: bool,
+) -> io::Result<()> {
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    std::thread::spawn(move || {
+        let Some(signal) = signals.forever().next() else {
+            return;
+        };
+        crate::cli_log!("[종료 시그널] {}: 워크트리를 정리합니다.", signal);
+        // 에이전트가 워크트리에 파일을 쓰는 도중에 워크트리를 지우지 않도록 먼저 멈춘다.
+        if !registry.cancel_running_agent(AGENT_EXIT_TIMEOUT) {
+            crate::cli_log!("[종료 시그널] 에이전트가 제한 시간 안에 끝나지 않았습니다.");
+        }
+        for err in registry.cleanup_all() {
+            crate::cli_log!("[종료 시그널] 정리 실패: {}", err);
+        }
+        if restore_terminal {
+            let _ = crossterm::execute!(stdout(), cursor::Show);
+            let _ = terminal::disable_raw_mode();
+        }
+        std::process::exit(128 + signal);
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::claude_code_client::{ClaudeCodeClient, ClaudeCodeClientError, ClaudeCodeRequest};
+    use crate::ui::test_support::{setup_workspace, write_script};
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git").current_dir(dir).args(args).output().unwrap();
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    fn commit_file(dir: &Path, content: &str) {
+        std::fs::write(dir.join("file.txt"), content).unwrap();
+        git(dir, &["add", "file.txt"]);
+        git(dir, &["commit", "-m", content]);
+    }
+
+    fn add_worktree(workspace: &Path, branch: &str) -> ActiveWorktree {
+        git(workspace, &["branch", branch]);
+        let worktree_path =
+            coding::create_worktree(workspace, workspace.parent().unwrap(), branch).unwrap();
+        ActiveWorktree {
+            workspace: workspace.to_path_buf(),
+            worktree_path,
+            task_branch: branch.to_string(),
+        }
+    }
+
+    fn branch_exists(workspace: &Path, branch: &str) -> bool {
+        !git(workspace, &["branch", "--list", branch]).trim().is_empty()
+    }
+
+    #[test]
+    fn register_and_unregister_track_active_worktrees() {
+        let registry = CleanupRegistry::default();
+        let shared = registry.clone();
+        let worktree = |name: &str| ActiveWorktree {
+            workspace: PathBuf::from("/ws"),
+            worktree_path: PathBuf::from(format!("/ws-bear-worktree-{}", name)),
+            task_branch: format!("bear/task/{}", name),
+        };
+
+        registry.register(worktree("a"));
+        registry.register(worktree("b"));
+        shared.unregister(Path::new("/ws-bear-worktree-a"));
+
+        assert_eq!(shared.take_all(), vec![worktree("b")]);
+        assert!(registry.take_all().is_empty());
+    }
+
+    #[test]
+    fn cleanup_aborts_rebase_and_removes_registered_worktrees_once() {
+        let (_root, workspace) = setup_workspace();
+        let registry = CleanupRegistry::default();
+        let rebasing = add_worktree(&workspace, "bear/task/TASK-00");
+        let retained = add_worktree(&workspace, "bear/task/TASK-01");
+        commit_file(&rebasing.worktree_path, "task change");
+        commit_file(&workspace, "conflicting change");
+        git(&rebasing.worktree_path, &["rebase", "master"]);
+        assert!(git(&rebasing.worktree_path, &["status"]).contains("rebase in progress"));
+        registry.register(rebasing.clone());
+
+        let errors = registry.cleanup_all();
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert!(!rebasing.worktree_path.exists());
+        assert!(!branch_exists(&workspace, "bear/task/TASK-00"));
+        assert!(retained.worktree_path.exists());
+        assert!(branch_exists(&workspace, "bear/task/TASK-01"));
+        assert!(registry.cleanup_all().is_empty());
+    }
+
+    #[test]
+    fn cleanup_restores_registered_stash_once() {
+        let (_root, workspace) = setup_workspace();
+        let registry = CleanupRegistry::default();
+        std::fs::write(workspace.join("file.txt"), "user edit").unwrap();
+        let stash_commit = coding::stash_working_changes(&workspace, "session").unwrap().unwrap();
+        registry.register_stash(StashedChanges {
+            workspace: workspace.clone(),
+            stash_commit,
+        });
+
+        let errors = registry.cleanup_all();
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(std::fs::read_to_string(workspace.join("file.txt")).unwrap(), "user edit");
+        assert_eq!(registry.take_stash(), None);
+    }
+
+    #[test]
+    fn cancel_running_agent_stops_the_cli_before_cleanup() {
+        let (root, workspace) = setup_workspace();
+        let binary = write_script(root.path(), "hanging-claude", "#!/bin/sh\nexec sleep 30\n");
+        let registry = CleanupRegistry::default();
+        assert!(registry.cancel_running_agent(Duration::ZERO));
+        let token = CancellationToken::new();
+        registry.register_cancellation(token.clone());
+        let mut client =
+            ClaudeCodeClient::with_binary_path("test-key".to_string(), workspace, None, binary);
+        client.set_cancellation_token(token.clone());
+        let agent = std::thread::spawn(move || {
+            let request = ClaudeCodeRequest {
+                user_prompt: "implement".to_string(),
+                output_schema: serde_json::json!({}),
+            };
+            client.query::<serde_json::Value>(&request)
+        });
+        while token.wait_for_processes(Duration::ZERO) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(registry.cancel_running_agent(AGENT_EXIT_TIMEOUT));
+        assert!(token.is_cancelled());
+        assert!(matches!(agent.join().unwrap(), Err(ClaudeCodeClientError::Cancelled)));
+    }
+}
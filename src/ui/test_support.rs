@@ -0,0 +1,74 @@
+//! 여러 모듈의 테스트가 함께 쓰는 도우미: git 저장소 준비, 실행 스크립트 작성, 태스크와
+//! 리포트 생성.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use super::coding::{CodingTask, CodingTaskStatus, TaskProgress, TaskReport};
+
+pub fn run_git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+/// 임시 디렉토리 아래 `workspace`에 초기 커밋이 있는 git 저장소를 만든다.
+/// 워크트리는 워크스페이스의 형제 디렉토리로 생성되므로 루트 TempDir 안에 머문다.
+pub fn setup_workspace() -> (TempDir, PathBuf) {
+    let root = TempDir::new().unwrap();
+    let workspace = root.path().join("workspace");
+    std::fs::create_dir(&workspace).unwrap();
+
+    run_git(&workspace, &["init"]);
+    run_git(&workspace, &["checkout", "-b", "master"]);
+    run_git(&workspace, &["config", "user.email", "test@test.com"]);
+    run_git(&workspace, &["config", "user.name", "Test"]);
+    run_git(&workspace, &["config", "commit.gpgsign", "false"]);
+    std::fs::write(workspace.join("README.md"), "init").unwrap();
+    run_git(&workspace, &["add", "."]);
+    run_git(&workspace, &["commit", "-m", "initial commit"]);
+
+    (root, workspace)
+}
+
+/// `dir/name`에 `body`를 쓰고 실행 권한을 준 뒤 그 경로를 반환한다. 가짜 CLI와 가짜 빌드
+/// 도구를 만들 때 쓴다.
+pub fn write_script(dir: &Path, name: &str, body: &str) -> PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, body).unwrap();
+    std::fs::set_permissions(&path, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+    path
+}
+
+/// 제목과 설명이 태스크 ID로 정해지는 태스크.
+pub fn coding_task(task_id: &str, dependencies: &[&str]) -> CodingTask {
+    CodingTask {
+        task_id: task_id.to_string(),
+        title: format!("{} title", task_id),
+        description: format!("{} description", task_id),
+        dependencies: dependencies.iter().map(|id| id.to_string()).collect(),
+        files: Vec::new(),
+        subdir: None,
+    }
+}
+
+pub fn task_report(task_id: &str, status: CodingTaskStatus, report: &str) -> TaskReport {
+    TaskReport {
+        task_id: task_id.to_string(),
+        status,
+        report: report.to_string(),
+        report_file_path: PathBuf::new(),
+        merged_with_failing_build: false,
+        progress: TaskProgress::default(),
+    }
+}
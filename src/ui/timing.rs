@@ -0,0 +1,197 @@
+use std::time::{Duration, Instant};
+
+use crate::config::UsagePhase;
+
+/// 소요 시간을 기록하는 작업 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    AgentQuery,
+    Rebase,
+    BuildTest,
+}
+
+impl OperationKind {
+    const ALL: [OperationKind; 3] = [
+        OperationKind::AgentQuery,
+        OperationKind::Rebase,
+        OperationKind::BuildTest,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OperationKind::AgentQuery => "에이전트 쿼리",
+            OperationKind::Rebase => "리베이스",
+            OperationKind::BuildTest => "빌드/테스트",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimingRecord {
+    pub kind: OperationKind,
+    pub phase: UsagePhase,
+    pub task_id: Option<String>,
+    pub duration: Duration,
+}
+
+/// 에이전트 쿼리, git, 빌드 작업의 소요 시간을 모아 병목을 파악할 수 있게 한다.
+#[derive(Debug, Default)]
+pub struct TimingRecorder {
+    records: Vec<TimingRecord>,
+}
+
+impl TimingRecorder {
+    pub fn record(
+        &mut self,
+        kind: OperationKind,
+        phase: UsagePhase,
+        task_id: Option<&str>,
+        duration: Duration,
+    ) {
+        self.records.push(TimingRecord {
+            kind,
+            phase,
+            task_id: task_id.map(String::from),
+            duration,
+        });
+    }
+
+    /// 동기 작업을 실행하면서 소요 시간을 기록한다.
+    pub fn time<T>(
+        &mut self,
+        kind: OperationKind,
+        phase: UsagePhase,
+        task_id: Option<&str>,
+        operation: impl FnOnce() -> T,
+    ) -> T {
+        let started_at = Instant::now();
+        let result = operation();
+        self.record(kind, phase, task_id, started_at.elapsed());
+        result
+    }
+
+    /// 작업 종류별, 단계별, 태스크별 소요 시간 합계를 여러 줄로 정리한다. 기록이 없으면 None.
+    pub fn summary_text(&self) -> Option<String> {
+        if self.records.is_empty() {
+            return None;
+        }
+
+        let mut lines = vec!["작업별 소요 시간:".to_string()];
+        for kind in OperationKind::ALL {
+            let matching: Vec<&TimingRecord> =
+                self.records.iter().filter(|r| r.kind == kind).collect();
+            if matching.is_empty() {
+                continue;
+            }
+            let total: Duration = matching.iter().map(|r| r.duration).sum();
+            lines.push(format!(
+                "- {}: {}회, {}",
+                kind.label(),
+                matching.len(),
+                format_duration(total),
+            ));
+        }
+
+        let mut phases: Vec<UsagePhase> = self.records.iter().map(|r| r.phase).collect();
+        phases.sort();
+        phases.dedup();
+        for phase in phases {
+            let total: Duration = self
+                .records
+                .iter()
+                .filter(|r| r.phase == phase)
+                .map(|r| r.duration)
+                .sum();
+            lines.push(format!("- {} 단계: {}", phase.label(), format_duration(total)));
+        }
+
+        let mut task_ids: Vec<&str> = Vec::new();
+        for record in &self.records {
+            if let Some(task_id) = record.task_id.as_deref()
+                && !task_ids.contains(&task_id)
+            {
+                task_ids.push(task_id);
+            }
+        }
+        for task_id in task_ids {
+            let parts: Vec<String> = OperationKind::ALL
+                .iter()
+                .filter_map(|kind| {
+                    let total: Duration = self
+                        .records
+                        .iter()
+                        .filter(|r| r.kind == *kind && r.task_id.as_deref() == Some(task_id))
+                        .map(|r| r.duration)
+                        .sum();
+                    (!total.is_zero()).then(|| format!("{} {}", kind.label(), format_duration(total)))
+                })
+                .collect();
+            lines.push(format!("- [{}] {}", task_id, parts.join(", ")));
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+/// 1분 미만은 소수점 한 자리 초로, 그 이상은 분과 초로 표시한다.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 60.0 {
+        format!("{}m {:02}s", duration.as_secs() / 60, duration.as_secs() % 60)
+    } else {
+        format!("{:.1}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_captures_each_operation_in_order() {
+        let mut recorder = TimingRecorder::default();
+
+        let value = recorder.time(OperationKind::Rebase, UsagePhase::Coding, Some("TASK-00"), || {
+            std::thread::sleep(Duration::from_millis(5));
+            42
+        });
+        recorder.time(OperationKind::BuildTest, UsagePhase::Coding, Some("TASK-00"), || ());
+        recorder.record(OperationKind::AgentQuery, UsagePhase::Spec, None, Duration::from_secs(3));
+
+        assert_eq!(value, 42);
+        let kinds: Vec<OperationKind> = recorder.records.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![OperationKind::Rebase, OperationKind::BuildTest, OperationKind::AgentQuery]
+        );
+        assert!(recorder.records[0].duration >= Duration::from_millis(5));
+        assert_eq!(recorder.records[0].task_id.as_deref(), Some("TASK-00"));
+        assert_eq!(recorder.records[2].phase, UsagePhase::Spec);
+    }
+
+    #[test]
+    fn summary_aggregates_per_operation_and_per_task() {
+        let mut recorder = TimingRecorder::default();
+        let coding = UsagePhase::Coding;
+        recorder.record(OperationKind::AgentQuery, coding, Some("TASK-00"), Duration::from_secs(30));
+        recorder.record(OperationKind::BuildTest, coding, Some("TASK-00"), Duration::from_secs(5));
+        recorder.record(OperationKind::AgentQuery, coding, Some("TASK-01"), Duration::from_secs(45));
+        recorder.record(OperationKind::Rebase, coding, Some("TASK-01"), Duration::from_millis(200));
+
+        assert_eq!(
+            recorder.summary_text().unwrap(),
+            "작업별 소요 시간:\n\
+             - 에이전트 쿼리: 2회, 1m 15s\n\
+             - 리베이스: 1회, 0.2s\n\
+             - 빌드/테스트: 1회, 5.0s\n\
+             - 코딩 단계: 1m 20s\n\
+             - [TASK-00] 에이전트 쿼리 30.0s, 빌드/테스트 5.0s\n\
+             - [TASK-01] 에이전트 쿼리 45.0s, 리베이스 0.2s"
+        );
+    }
+
+    #[test]
+    fn summary_is_absent_without_records() {
+        assert!(TimingRecorder::default().summary_text().is_none());
+    }
+}
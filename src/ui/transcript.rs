@@ -0,0 +1,288 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::app::{ChatMessage, MessageRole};
+use super::clarification::QaRound;
+use super::coding::{self, CodingTask, TaskReport};
+
+/// 저널 디렉토리에 저장하는 트랜스크립트 파일 이름.
+pub const TRANSCRIPT_FILE_NAME: &str = "transcript.md";
+
+/// 트랜스크립트를 만드는 데 쓰는 세션 상태. 파일로만 남는 내용(요구사항, 스펙, 플랜,
+/// 리뷰 코멘트, 요약)은 `journal_dirs`를 앞에서부터 찾아 처음 발견한 파일을 쓴다.
+pub struct TranscriptSources<'a> {
+    pub journal_dirs: Vec<PathBuf>,
+    pub qa_log: &'a [QaRound],
+    pub tasks: &'a [CodingTask],
+    pub task_reports: &'a [TaskReport],
+    pub messages: &'a [ChatMessage],
+}
+
+/// 세션 전체를 하나의 마크다운 문서로 만든다. 섹션 순서는 세션이 진행되는 순서를 따라
+/// Requirements, Q&A, Spec, Plan, Tasks, Reports, Summary, Conversation으로 고정한다.
+/// 아직 도달하지 못한 단계의 섹션도 제목은 남기고 내용을 "(없음)"으로 둔다.
+pub fn build_transcript(sources: &TranscriptSources) -> String {
+    let sections = [
+        ("Requirements", read_journal_file(sources, "user-request.md")),
+        ("Q&A", format_qa_log(sources.qa_log)),
+        ("Spec", read_journal_file(sources, "spec.md")),
+        ("Plan", read_journal_file(sources, "plan.md")),
+        ("Tasks", format_tasks(sources.tasks)),
+        ("Reports", format_reports(sources)),
+        ("Summary", format_summary(sources)),
+        ("Conversation", format_messages(sources.messages)),
+    ];
+
+    let mut transcript = String::from("# Session Transcript\n");
+    for (title, body) in sections {
+        let body = body.filter(|body| !body.trim().is_empty());
+        transcript.push_str(&format!(
+            "\n## {}\n\n{}\n",
+            title,
+            body.as_deref().map(str::trim_end).unwrap_or("(없음)"),
+        ));
+    }
+    transcript
+}
+
+pub fn save_transcript(dir: &Path, transcript: &str) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let file_path = dir.join(TRANSCRIPT_FILE_NAME);
+    fs::write(&file_path, transcript)?;
+
+    Ok(file_path)
+}
+
+fn read_journal_file(sources: &TranscriptSources, file_name: &str) -> Option<String> {
+    sources
+        .journal_dirs
+        .iter()
+        .find_map(|dir| fs::read_to_string(dir.join(file_name)).ok())
+}
+
+fn format_qa_log(qa_log: &[QaRound]) -> Option<String> {
+    let rounds: Vec<String> = qa_log
+        .iter()
+        .enumerate()
+        .map(|(index, round)| {
+            let questions: Vec<String> = round
+                .questions
+                .iter()
+                .enumerate()
+                .map(|(number, question)| format!("{}. {}", number + 1, question))
+                .collect();
+            format!(
+                "### Round {}\n\n**Questions**\n\n{}\n\n**Answer**\n\n{}",
+                index + 1,
+                questions.join("\n"),
+                round.answer.trim_end(),
+            )
+        })
+        .collect();
+    Some(rounds.join("\n\n"))
+}
+
+fn format_tasks(tasks: &[CodingTask]) -> Option<String> {
+    let entries: Vec<String> = tasks
+        .iter()
+        .map(|task| {
+            let dependencies = if task.dependencies.is_empty() {
+                "없음".to_string()
+            } else {
+                task.dependencies.join(", ")
+            };
+            format!(
+                "### [{}] {}\n\n{}\n\n의존: {}",
+                task.task_id,
+                task.title,
+                task.description.trim_end(),
+                dependencies,
+            )
+        })
+        .collect();
+    Some(entries.join("\n\n"))
+}
+
+/// 완료된 순서대로 각 태스크의 리포트와, 저널에 남아 있는 리뷰 코멘트를 반복 순서대로 붙인다.
+fn format_reports(sources: &TranscriptSources) -> Option<String> {
+    let entries: Vec<String> = sources
+        .task_reports
+        .iter()
+        .map(|report| {
+            let mut entry = format!(
+                "### [{}] {}\n\n{}",
+                report.task_id,
+                report.status.label(),
+                report.report.trim_end(),
+            );
+            for iteration in 1.. {
+                let file_name = format!("{}.review-{}.md", report.task_id, iteration);
+                let Some(comment) = read_journal_file(sources, &file_name) else {
+                    break;
+                };
+                entry.push_str(&format!(
+                    "\n\n#### Review {}\n\n{}",
+                    iteration,
+                    comment.trim_end(),
+                ));
+            }
+            entry
+        })
+        .collect();
+    Some(entries.join("\n\n"))
+}
+
+fn format_summary(sources: &TranscriptSources) -> Option<String> {
+    let summary = sources
+        .journal_dirs
+        .iter()
+        .find_map(|dir| coding::load_coding_summary(dir).ok())?;
+    let mut lines = vec![
+        format!("- 통합 브랜치: {}", summary.integration_branch),
+        format!("- 성공: {}, 차단: {}", summary.success_count, summary.blocked_count),
+    ];
+    if summary.build_test_skipped {
+        lines.push("- 빌드/테스트 검증: 건너뜀".to_string());
+    }
+    for task in &summary.tasks {
+        lines.push(format!(
+            "- [{}] {} — {} (리뷰 {}회)",
+            task.task_id,
+            task.title,
+            task.status.label(),
+            task.review_iterations,
+        ));
+    }
+    Some(lines.join("\n"))
+}
+
+fn format_messages(messages: &[ChatMessage]) -> Option<String> {
+    let entries: Vec<String> = messages
+        .iter()
+        .map(|message| {
+            let speaker = match message.role {
+                MessageRole::System => "System",
+                MessageRole::User => "User",
+            };
+            let content = message.full_content.as_deref().unwrap_or(&message.content);
+            format!("**{}**\n\n{}", speaker, content.trim_end())
+        })
+        .collect();
+    Some(entries.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::coding::CodingTaskStatus;
+    use crate::ui::test_support::{coding_task, task_report};
+    use tempfile::TempDir;
+
+    fn message(role: MessageRole, content: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: content.to_string(),
+            full_content: None,
+        }
+    }
+
+    #[test]
+    fn assembles_sections_in_session_order() {
+        let journal = TempDir::new().unwrap();
+        let dir = journal.path();
+        fs::write(dir.join("user-request.md"), "로그인 기능 추가").unwrap();
+        fs::write(dir.join("spec.md"), "# Spec body").unwrap();
+        fs::write(dir.join("plan.md"), "# Plan body").unwrap();
+        fs::write(dir.join("TASK-00.review-1.md"), "테스트를 추가하세요").unwrap();
+        fs::write(dir.join("TASK-00.review-2.md"), "승인").unwrap();
+        let qa_log = vec![QaRound {
+            questions: vec!["어떤 인증 방식인가요?".to_string()],
+            answer: "OAuth".to_string(),
+        }];
+        let tasks = vec![coding_task("TASK-00", &[]), coding_task("TASK-01", &["TASK-00"])];
+        let task_reports = vec![
+            task_report("TASK-00", CodingTaskStatus::ImplementationSuccess, "TASK-00 report"),
+            task_report("TASK-01", CodingTaskStatus::ImplementationBlocked, "TASK-01 report"),
+        ];
+        let messages = vec![
+            message(MessageRole::User, "로그인 기능 추가"),
+            ChatMessage {
+                full_content: Some("잘리지 않은 전체 출력".to_string()),
+                ..message(MessageRole::System, "잘린 출력")
+            },
+        ];
+        let sources = TranscriptSources {
+            journal_dirs: vec![PathBuf::from("/nonexistent"), dir.to_path_buf()],
+            qa_log: &qa_log,
+            tasks: &tasks,
+            task_reports: &task_reports,
+            messages: &messages,
+        };
+
+        let transcript = build_transcript(&sources);
+
+        let positions: Vec<usize> = [
+            "## Requirements\n\n로그인 기능 추가",
+            "## Q&A\n\n### Round 1\n\n**Questions**\n\n1. 어떤 인증 방식인가요?",
+            "**Answer**\n\nOAuth",
+            "## Spec\n\n# Spec body",
+            "## Plan\n\n# Plan body",
+            "### [TASK-01] TASK-01 title\n\nTASK-01 description\n\n의존: TASK-00",
+            "### [TASK-00] 성공\n\nTASK-00 report",
+            "#### Review 1\n\n테스트를 추가하세요",
+            "#### Review 2\n\n승인",
+            "### [TASK-01] 차단\n\nTASK-01 report",
+            "## Summary\n\n(없음)",
+            "**User**\n\n로그인 기능 추가",
+            "**System**\n\n잘리지 않은 전체 출력",
+        ]
+        .iter()
+        .map(|needle| {
+            transcript
+                .find(needle)
+                .unwrap_or_else(|| panic!("{:?} not found in:\n{}", needle, transcript))
+        })
+        .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{}", transcript);
+        assert!(!transcript.contains("잘린 출력\n"));
+    }
+
+    #[test]
+    fn summary_section_reads_saved_summary() {
+        let journal = TempDir::new().unwrap();
+        let summary = coding::CodingSummary {
+            integration_branch: "bear/integration/abc".to_string(),
+            success_count: 1,
+            blocked_count: 0,
+            tasks: vec![coding::TaskSummary {
+                task_id: "TASK-00".to_string(),
+                title: "Add login".to_string(),
+                status: CodingTaskStatus::ImplementationSuccess,
+                report_file_path: None,
+                review_iterations: 2,
+                review_approval: coding::ReviewApproval::Approved,
+                build_test_passed: Some(true),
+            }],
+            build_test_skipped: true,
+        };
+        coding::save_coding_summary(journal.path(), &summary).unwrap();
+        let sources = TranscriptSources {
+            journal_dirs: vec![journal.path().to_path_buf()],
+            qa_log: &[],
+            tasks: &[],
+            task_reports: &[],
+            messages: &[],
+        };
+
+        let transcript = build_transcript(&sources);
+        let path = save_transcript(journal.path(), &transcript).unwrap();
+
+        assert_eq!(fs::read_to_string(path).unwrap(), transcript);
+        assert!(transcript.contains("## Requirements\n\n(없음)"), "{}", transcript);
+        assert!(transcript.contains("- 통합 브랜치: bear/integration/abc"), "{}", transcript);
+        assert!(transcript.contains("- 빌드/테스트 검증: 건너뜀"), "{}", transcript);
+        assert!(transcript.contains("- [TASK-00] Add login — 성공 (리뷰 2회)"), "{}", transcript);
+    }
+}
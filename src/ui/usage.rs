@@ -0,0 +1,108 @@
+use crate::claude_code_client::Usage;
+use crate::config::UsagePhase;
+
+/// 세션 전체의 단계별 토큰 사용량과 비용 누계.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    phases: Vec<(UsagePhase, Usage)>,
+}
+
+impl UsageTracker {
+    pub fn record(&mut self, phase: UsagePhase, usage: &Usage) {
+        match self.phases.iter_mut().find(|(p, _)| *p == phase) {
+            Some((_, total)) => total.add(usage),
+            None => {
+                self.phases.push((phase, *usage));
+                self.phases.sort_by_key(|(p, _)| *p);
+            }
+        }
+    }
+
+    pub fn total(&self) -> Usage {
+        let mut total = Usage::default();
+        for (_, usage) in &self.phases {
+            total.add(usage);
+        }
+        total
+    }
+
+    /// 상태 줄에 표시할 누적 비용 요약. 아직 기록된 사용량이 없으면 None.
+    pub fn status_text(&self) -> Option<String> {
+        if self.phases.is_empty() {
+            return None;
+        }
+        Some(format_usage(&self.total()))
+    }
+
+    /// 단계별 사용량과 합계를 여러 줄로 정리한다. 기록된 사용량이 없으면 None.
+    pub fn summary_text(&self) -> Option<String> {
+        if self.phases.is_empty() {
+            return None;
+        }
+        let mut lines = vec!["단계별 에이전트 사용량:".to_string()];
+        for (phase, usage) in &self.phases {
+            lines.push(format!("- {}: {}", phase.label(), format_usage(usage)));
+        }
+        lines.push(format!("- 합계: {}", format_usage(&self.total())));
+        Some(lines.join("\n"))
+    }
+}
+
+fn format_usage(usage: &Usage) -> String {
+    format!(
+        "${:.4}  {} in / {} out",
+        usage.cost_usd,
+        format_token_count(usage.input_tokens),
+        format_token_count(usage.output_tokens),
+    )
+}
+
+fn format_token_count(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 1_000 {
+        format!("{:.1}k", tokens as f64 / 1_000.0)
+    } else {
+        tokens.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(input_tokens: u64, output_tokens: u64, cost_usd: f64) -> Usage {
+        Usage {
+            input_tokens,
+            output_tokens,
+            cost_usd,
+        }
+    }
+
+    #[test]
+    fn records_totals_per_phase_and_overall() {
+        let mut tracker = UsageTracker::default();
+        tracker.record(UsagePhase::Coding, &usage(100, 10, 0.5));
+        tracker.record(UsagePhase::Spec, &usage(50, 5, 0.25));
+        tracker.record(UsagePhase::Coding, &usage(200, 20, 1.0));
+
+        assert_eq!(tracker.total(), usage(350, 35, 1.75));
+        assert_eq!(
+            tracker.summary_text().unwrap(),
+            "단계별 에이전트 사용량:\n\
+             - 스펙 작성: $0.2500  50 in / 5 out\n\
+             - 코딩: $1.5000  300 in / 30 out\n\
+             - 합계: $1.7500  350 in / 35 out"
+        );
+    }
+
+    #[test]
+    fn status_text_is_absent_until_usage_is_recorded() {
+        let mut tracker = UsageTracker::default();
+        assert_eq!(tracker.status_text(), None);
+
+        tracker.record(UsagePhase::Plan, &usage(63_366, 2_310, 0.4127));
+
+        assert_eq!(tracker.status_text().unwrap(), "$0.4127  63.4k in / 2.3k out");
+    }
+}